@@ -1,5 +1,6 @@
 use crate::error::Error;
 use binrw::binrw;
+use rgb::{RGB8, RGBA8};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::{Read, Seek};
@@ -40,6 +41,26 @@ pub fn check_done_reading<T: Read + Seek>(reader: &mut T) -> Result<(), Error> {
     }
 }
 
+/// Reads a four byte colour inside a binrw struct, for use with `#[br(map)]`
+pub fn rgba_from_bytes(bytes: [u8; 4]) -> RGBA8 {
+    bytes.into()
+}
+
+/// Writes a colour back out as four bytes, for use with `#[bw(map)]`
+pub fn rgba_to_bytes(color: &RGBA8) -> [u8; 4] {
+    [color.r, color.g, color.b, color.a]
+}
+
+/// Reads a three byte colour inside a binrw struct, for use with `#[br(map)]`
+pub fn rgb_from_bytes(bytes: [u8; 3]) -> RGB8 {
+    bytes.into()
+}
+
+/// Writes a colour back out as three bytes, for use with `#[bw(map)]`
+pub fn rgb_to_bytes(color: &RGB8) -> [u8; 3] {
+    [color.r, color.g, color.b]
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Clone, Serialize, Deserialize)]
@@ -61,6 +82,15 @@ impl fmt::Display for WString {
     }
 }
 
+impl From<String> for WString {
+    fn from(s: String) -> Self {
+        Self {
+            size: s.len() as u16,
+            data: s.into_bytes(),
+        }
+    }
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Clone, Serialize, Deserialize)]
@@ -81,3 +111,12 @@ impl fmt::Display for WString32 {
         write!(f, "{}", String::from_utf8_lossy(&self.data[..]))
     }
 }
+
+impl From<String> for WString32 {
+    fn from(s: String) -> Self {
+        Self {
+            size: s.len() as u32,
+            data: s.into_bytes(),
+        }
+    }
+}
@@ -0,0 +1,469 @@
+use crate::common::FormID;
+use crate::records::Record;
+use serde::ser::{self, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// One plugin's version of a record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub plugin: String,
+
+    /// Whether this version is identical to the one it overrides
+    pub itm: bool,
+}
+
+/// Every version of a record, in load order
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub form_id: FormID,
+    pub versions: Vec<Version>,
+}
+
+impl Conflict {
+    /// The plugin that defines the record
+    pub fn origin(&self) -> &Version {
+        &self.versions[0]
+    }
+
+    /// The plugin whose version is used by the game
+    pub fn winner(&self) -> &Version {
+        &self.versions[self.versions.len() - 1]
+    }
+
+    /// Whether more than one plugin provides a version of the record
+    pub fn is_overridden(&self) -> bool {
+        self.versions.len() > 1
+    }
+}
+
+/// All records in a [crate::game::Game], keyed by load order FormID
+#[derive(Debug, Clone, Default)]
+pub struct ConflictIndex {
+    entries: BTreeMap<u32, Conflict>,
+}
+
+impl ConflictIndex {
+    pub(crate) fn insert(&mut self, fid: FormID, plugin: &str) {
+        self.entries
+            .entry(fid.0)
+            .or_insert_with(|| Conflict {
+                form_id: fid,
+                versions: Vec::new(),
+            })
+            .versions
+            .push(Version {
+                plugin: plugin.into(),
+                itm: false,
+            });
+    }
+
+    pub(crate) fn entries_mut(&mut self) -> impl Iterator<Item = &mut Conflict> {
+        self.entries.values_mut()
+    }
+
+    pub fn get(&self, fid: &FormID) -> Option<&Conflict> {
+        self.entries.get(&fid.0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Conflict> {
+        self.entries.values()
+    }
+
+    /// Records provided by more than one plugin
+    pub fn overridden(&self) -> impl Iterator<Item = &Conflict> {
+        self.iter().filter(|c| c.is_overridden())
+    }
+
+    /// Overrides that are identical to the version they override
+    pub fn itms(&self) -> impl Iterator<Item = (&Conflict, &Version)> {
+        self.iter()
+            .flat_map(|c| c.versions.iter().filter(|v| v.itm).map(move |v| (c, v)))
+    }
+}
+
+/// A field that differs between two versions of a record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Path to the field, such as `keywords[2]` or `model.model`
+    pub path: String,
+
+    /// Value in the winning version, if present
+    pub winner: Option<String>,
+
+    /// Value in the compared version, if present
+    pub other: Option<String>,
+}
+
+/// Flattened record fields, keyed by path
+pub(crate) type Fields = BTreeMap<String, String>;
+
+/// Flattens a record into its fields, mapping every [FormID] through `remap`
+/// so that versions from different plugins can be compared
+pub(crate) fn flatten(record: &Record, remap: &dyn Fn(FormID) -> String) -> Fields {
+    let mut fields = Fields::new();
+    let flattener = Flattener {
+        fields: &mut fields,
+        path: String::new(),
+        remap,
+        form_id: false,
+    };
+    // Only groups fail to serialize, and those are never compared
+    let _ = record.serialize(flattener);
+    fields
+}
+
+/// Whether two flattened versions match. The record header only differs in
+/// bookkeeping (size, FormID, timestamps), so only its flags are compared.
+pub(crate) fn identical(a: &Fields, b: &Fields) -> bool {
+    let relevant =
+        |(k, _): &(&String, &String)| !k.starts_with("header.") || k.starts_with("header.flags");
+    a.iter().filter(relevant).eq(b.iter().filter(relevant))
+}
+
+pub(crate) fn diff(winner: &Fields, other: &Fields) -> Vec<FieldDiff> {
+    let mut keys: Vec<_> = winner.keys().chain(other.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter(|k| !k.starts_with("header.") || k.starts_with("header.flags"))
+        .filter(|k| winner.get(*k) != other.get(*k))
+        .map(|k| FieldDiff {
+            path: k.clone(),
+            winner: winner.get(k).cloned(),
+            other: other.get(k).cloned(),
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct FlattenError(String);
+
+impl fmt::Display for FlattenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for FlattenError {}
+
+impl ser::Error for FlattenError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Serializer that records every leaf value under its field path
+struct Flattener<'a> {
+    fields: &'a mut Fields,
+    path: String,
+    remap: &'a dyn Fn(FormID) -> String,
+    form_id: bool,
+}
+
+impl<'a> Flattener<'a> {
+    fn child(&mut self, segment: &str) -> Flattener<'_> {
+        let path = match (self.path.is_empty(), segment.starts_with('[')) {
+            (true, _) | (_, true) => format!("{}{}", self.path, segment),
+            _ => format!("{}.{}", self.path, segment),
+        };
+        Flattener {
+            fields: self.fields,
+            path,
+            remap: self.remap,
+            form_id: false,
+        }
+    }
+
+    fn leaf(self, value: String) -> Result<(), FlattenError> {
+        self.fields.insert(self.path, value);
+        Ok(())
+    }
+
+    fn compound(self) -> Compound<'a> {
+        Compound {
+            flattener: self,
+            index: 0,
+            key: None,
+        }
+    }
+}
+
+macro_rules! leaf {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<(), FlattenError> {
+                self.leaf(v.to_string())
+            }
+        )*
+    };
+}
+
+impl<'a> ser::Serializer for Flattener<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    leaf!(
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u64: u64,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str
+    );
+
+    fn serialize_u32(self, v: u32) -> Result<(), FlattenError> {
+        if self.form_id {
+            let value = (self.remap)(FormID(v));
+            self.leaf(value)
+        } else {
+            self.leaf(v.to_string())
+        }
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), FlattenError> {
+        self.leaf(format!("{:?}", v))
+    }
+
+    fn serialize_none(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), FlattenError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), FlattenError> {
+        self.leaf("()".into())
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<(), FlattenError> {
+        self.leaf(name.into())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), FlattenError> {
+        self.leaf(variant.into())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), FlattenError> {
+        self.form_id = name == "FormID";
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        mut self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), FlattenError> {
+        // The record type itself is not part of the field paths
+        if self.path.is_empty() {
+            value.serialize(self)
+        } else {
+            value.serialize(self.child(variant))
+        }
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>, FlattenError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'a>, FlattenError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, FlattenError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_variant(
+        mut self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, FlattenError> {
+        let path = self.child(variant).path;
+        self.path = path;
+        Ok(self.compound())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>, FlattenError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, FlattenError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct_variant(
+        mut self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, FlattenError> {
+        let path = self.child(variant).path;
+        self.path = path;
+        Ok(self.compound())
+    }
+}
+
+struct Compound<'a> {
+    flattener: Flattener<'a>,
+    index: usize,
+    key: Option<String>,
+}
+
+impl<'a> Compound<'a> {
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlattenError> {
+        let segment = format!("[{}]", self.index);
+        self.index += 1;
+        value.serialize(self.flattener.child(&segment))
+    }
+}
+
+impl<'a> ser::SerializeSeq for Compound<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlattenError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeTuple for Compound<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlattenError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeTupleStruct for Compound<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlattenError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeTupleVariant for Compound<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlattenError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeMap for Compound<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), FlattenError> {
+        let mut fields = Fields::new();
+        key.serialize(Flattener {
+            fields: &mut fields,
+            path: String::new(),
+            remap: self.flattener.remap,
+            form_id: false,
+        })?;
+        let key: Vec<_> = fields.into_values().collect();
+        self.key = Some(key.join(","));
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlattenError> {
+        let segment = format!("[{}]", self.key.take().unwrap_or_default());
+        value.serialize(self.flattener.child(&segment))
+    }
+
+    fn end(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeStruct for Compound<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), FlattenError> {
+        value.serialize(self.flattener.child(key))
+    }
+
+    fn end(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeStructVariant for Compound<'a> {
+    type Ok = ();
+    type Error = FlattenError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), FlattenError> {
+        value.serialize(self.flattener.child(key))
+    }
+
+    fn end(self) -> Result<(), FlattenError> {
+        Ok(())
+    }
+}
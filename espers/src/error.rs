@@ -29,6 +29,9 @@ pub enum Error {
     #[error("Extra bytes after parsing record ({:?}) - Raw: ({:?})", _0, _1)]
     ExtraBytesRaw(Vec<u8>, RawRecord),
 
+    #[error("{} - Raw: ({:?})", _0, _1)]
+    ParseErrorRaw(Box<Error>, RawRecord),
+
     #[error("Duplicate field encountered: ({})", _0)]
     DuplicateField(String),
 
@@ -37,4 +40,26 @@ pub enum Error {
 
     #[error("Duplicate String ID encountered: ({})", _0)]
     DuplicateStringID(u32),
+
+    #[error("Invalid script property: ({})", _0)]
+    InvalidProperty(String),
+
+    #[error("Invalid cloud texture layer: {}", _0)]
+    InvalidCloudLayer(String),
+
+    #[error("Invalid load order: {:?}", _0)]
+    InvalidLoadOrder(Vec<MasterError>),
+
+    #[error("No load index left for plugin: {}", _0)]
+    LoadIndexOverflow(String),
+}
+
+/// Problem with a plugin's masters in a load order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MasterError {
+    /// `master` is not in the load order at all
+    Missing { plugin: String, master: String },
+
+    /// `master` is in the load order, but after `plugin`
+    OutOfOrder { plugin: String, master: String },
 }
@@ -0,0 +1,40 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"ALCA")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ALCA {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<ALCA> for u32 {
+    type Error = Error;
+
+    fn try_from(raw: ALCA) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<u32> for ALCA {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
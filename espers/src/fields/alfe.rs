@@ -0,0 +1,41 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, BinRead, BinWrite};
+use serde_derive::{Deserialize, Serialize};
+use std::io::Cursor;
+
+#[binrw]
+#[brw(little, magic = b"ALFE")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ALFE {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<ALFE> for [u8; 4] {
+    type Error = Error;
+
+    fn try_from(raw: ALFE) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<[u8; 4]> for ALFE {
+    type Error = Error;
+
+    fn try_from(obj: [u8; 4]) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"ALID")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ALID {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<ALID> for String {
+    type Error = Error;
+
+    fn try_from(raw: ALID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = NullString::read_le(&mut cursor)?.to_string();
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<String> for ALID {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<ALID> for i32 {
+    type Error = Error;
+
+    fn try_from(raw: ALID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<i32> for ALID {
+    type Error = Error;
+
+    fn try_from(obj: i32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead, NullString};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -45,3 +45,126 @@ impl TryFrom<ANAM> for String {
         Ok(result)
     }
 }
+
+impl TryFrom<(FormID, FormID)> for ANAM {
+    type Error = Error;
+
+    fn try_from(obj: (FormID, FormID)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<FormID> for ANAM {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<String> for ANAM {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<ANAM> for u32 {
+    type Error = Error;
+
+    fn try_from(raw: ANAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<u32> for ANAM {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<ANAM> for u16 {
+    type Error = Error;
+
+    fn try_from(raw: ANAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<u16> for ANAM {
+    type Error = Error;
+
+    fn try_from(obj: u16) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<ANAM> for u8 {
+    type Error = Error;
+
+    fn try_from(raw: ANAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<u8> for ANAM {
+    type Error = Error;
+
+    fn try_from(obj: u8) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
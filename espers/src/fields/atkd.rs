@@ -0,0 +1,89 @@
+use super::ATKE;
+use crate::common::{check_done_reading, FormID};
+use crate::error::Error;
+use binrw::{binrw, BinRead, BinWrite};
+use serde_derive::{Deserialize, Serialize};
+use std::io::Cursor;
+
+#[binrw]
+#[brw(little, magic = b"ATKD")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ATKD {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AttackData {
+    pub damage_multiplier: f32,
+    pub attack_chance: f32,
+    pub attack_spell: FormID,
+    pub flags: u32,
+    pub attack_angle: f32,
+    pub strike_angle: f32,
+    pub stagger: f32,
+    pub attack_type: FormID,
+    pub knockdown: f32,
+    pub recovery_time: f32,
+    pub stamina_multiplier: f32,
+}
+
+impl TryFrom<ATKD> for AttackData {
+    type Error = Error;
+
+    fn try_from(raw: ATKD) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<AttackData> for ATKD {
+    type Error = Error;
+
+    fn try_from(obj: AttackData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+/// Attack data (ATKD) together with its animation event (ATKE)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Attack {
+    pub data: AttackData,
+    pub event: Option<String>,
+}
+
+impl Attack {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let data = ATKD::read(cursor)?.try_into()?;
+        let event = ATKE::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self { data, event })
+    }
+
+    pub fn load_multiple(cursor: &mut Cursor<&Vec<u8>>) -> Result<Vec<Self>, Error> {
+        let mut attacks = Vec::new();
+        while let Ok(a) = Self::load(cursor) {
+            attacks.push(a);
+        }
+        Ok(attacks)
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        ATKD::try_from(self.data)?.write(cursor)?;
+        self.event.map(ATKE::try_from).transpose()?.write(cursor)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"ATKE")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ATKE {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<ATKE> for String {
+    type Error = Error;
+
+    fn try_from(raw: ATKE) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = NullString::read_le(&mut cursor)?.to_string();
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<String> for ATKE {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
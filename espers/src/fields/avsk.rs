@@ -1,5 +1,5 @@
 use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -22,3 +22,18 @@ impl<const N: usize> TryFrom<AVSK> for [f32; N] {
         Ok(result)
     }
 }
+
+impl<const N: usize> TryFrom<[f32; N]> for AVSK {
+    type Error = Error;
+
+    fn try_from(obj: [f32; N]) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
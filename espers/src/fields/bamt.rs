@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -23,3 +23,18 @@ impl TryFrom<BAMT> for FormID {
         Ok(result)
     }
 }
+
+impl TryFrom<FormID> for BAMT {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
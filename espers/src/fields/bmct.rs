@@ -1,6 +1,6 @@
 use crate::common::check_done_reading;
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead, NullString};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -23,3 +23,18 @@ impl TryFrom<BMCT> for String {
         Ok(result)
     }
 }
+
+impl TryFrom<String> for BMCT {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead, NullString};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -45,3 +45,59 @@ impl TryFrom<BNAM> for u32 {
         Ok(result)
     }
 }
+
+impl TryFrom<FormID> for BNAM {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<String> for BNAM {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<Vec<u8>> for BNAM {
+    type Error = Error;
+
+    fn try_from(obj: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            size: obj.len() as u16,
+            data: obj,
+        })
+    }
+}
+
+impl TryFrom<u32> for BNAM {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
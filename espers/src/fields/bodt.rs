@@ -1,5 +1,5 @@
 use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -56,3 +56,18 @@ impl BodyTemplate {
         })
     }
 }
+
+impl TryFrom<BodyTemplate> for BODT {
+    type Error = Error;
+
+    fn try_from(obj: BodyTemplate) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
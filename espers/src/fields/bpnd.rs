@@ -1,6 +1,6 @@
 use crate::common::check_done_reading;
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -23,3 +23,18 @@ impl<const N: usize> TryFrom<BPND> for [u32; N] {
         Ok(result)
     }
 }
+
+impl<const N: usize> TryFrom<[u32; N]> for BPND {
+    type Error = Error;
+
+    fn try_from(obj: [u32; N]) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
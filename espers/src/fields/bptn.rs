@@ -1,6 +1,6 @@
-use crate::common::check_done_reading;
+use crate::common::{check_done_reading, LocalizedString};
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead, NullString};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -34,3 +34,44 @@ impl TryFrom<BPTN> for u32 {
         Ok(result)
     }
 }
+
+impl TryFrom<u32> for BPTN {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<String> for BPTN {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<LocalizedString> for BPTN {
+    type Error = Error;
+
+    fn try_from(obj: LocalizedString) -> Result<Self, Self::Error> {
+        match obj {
+            LocalizedString::Localized(l) => l.try_into(),
+            LocalizedString::ZString(z) => z.try_into(),
+        }
+    }
+}
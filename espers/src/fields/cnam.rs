@@ -1,4 +1,4 @@
-use crate::common::{check_done_reading, FormID};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
 use rgb::RGBA8;
@@ -86,3 +86,152 @@ impl TryFrom<CNAM> for Vec<FormID> {
         Ok(result)
     }
 }
+
+impl TryFrom<u32> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<LocalizedString> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: LocalizedString) -> Result<Self, Self::Error> {
+        match obj {
+            LocalizedString::Localized(l) => l.try_into(),
+            LocalizedString::ZString(z) => z.try_into(),
+        }
+    }
+}
+
+impl TryFrom<RGBA8> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: RGBA8) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        [obj.r, obj.g, obj.b, obj.a].write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<Vec<FormID>> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: Vec<FormID>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<FormID> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<CNAM> for u8 {
+    type Error = Error;
+
+    fn try_from(raw: CNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<u8> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: u8) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<CNAM> for i32 {
+    type Error = Error;
+
+    fn try_from(raw: CNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<i32> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: i32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<CNAM> for f32 {
+    type Error = Error;
+
+    fn try_from(raw: CNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<f32> for CNAM {
+    type Error = Error;
+
+    fn try_from(obj: f32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
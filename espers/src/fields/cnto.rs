@@ -1,6 +1,7 @@
+use super::COED;
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -23,3 +24,49 @@ impl TryFrom<CNTO> for (FormID, u32) {
         Ok(result)
     }
 }
+
+impl TryFrom<(FormID, u32)> for CNTO {
+    type Error = Error;
+
+    fn try_from(obj: (FormID, u32)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+/// An inventory entry (CNTO) together with its optional extra data (COED)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContainerItem {
+    pub item: FormID,
+    pub count: u32,
+    pub extra: Option<(FormID, FormID, f32)>,
+}
+
+impl ContainerItem {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let (item, count) = CNTO::read(cursor)?.try_into()?;
+        let extra = COED::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self { item, count, extra })
+    }
+
+    pub fn load_multiple(cursor: &mut Cursor<&Vec<u8>>) -> Result<Vec<Self>, Error> {
+        let mut items = Vec::new();
+        while let Ok(i) = Self::load(cursor) {
+            items.push(i);
+        }
+        Ok(items)
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        CNTO::try_from((self.item, self.count))?.write(cursor)?;
+        self.extra.map(COED::try_from).transpose()?.write(cursor)?;
+        Ok(())
+    }
+}
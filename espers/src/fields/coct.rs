@@ -1,5 +1,5 @@
 use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -22,3 +22,18 @@ impl TryFrom<COCT> for u32 {
         Ok(result)
     }
 }
+
+impl TryFrom<u32> for COCT {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -34,3 +34,33 @@ impl TryFrom<COED> for (FormID, FormID, f32) {
         Ok(result)
     }
 }
+
+impl TryFrom<(FormID, FormID, f32)> for COED {
+    type Error = Error;
+
+    fn try_from(obj: (FormID, FormID, f32)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<(FormID, u32)> for COED {
+    type Error = Error;
+
+    fn try_from(obj: (FormID, u32)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
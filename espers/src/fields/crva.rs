@@ -1,5 +1,5 @@
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 #[binrw]
 #[brw(little, magic = b"CRVA")]
@@ -37,3 +37,18 @@ impl TryInto<CrimeGold> for CRVA {
         Ok(CrimeGold::read_le(&mut Cursor::new(&self.data))?)
     }
 }
+
+impl TryFrom<CrimeGold> for CRVA {
+    type Error = Error;
+
+    fn try_from(obj: CrimeGold) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
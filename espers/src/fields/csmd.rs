@@ -1,4 +1,4 @@
-use binrw::{binrw, io::Cursor, BinRead};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 
 use crate::{common::check_done_reading, error::Error};
@@ -23,3 +23,18 @@ impl TryFrom<CSMD> for (f32, f32) {
         Ok(result)
     }
 }
+
+impl TryFrom<(f32, f32)> for CSMD {
+    type Error = Error;
+
+    fn try_from(obj: (f32, f32)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
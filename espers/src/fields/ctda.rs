@@ -1,7 +1,7 @@
 use super::{CIS1, CIS2, CITC};
 use crate::common::check_done_reading;
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -79,4 +79,52 @@ impl EffectCondition {
         }
         Ok(items)
     }
+
+    /// Reads a CITC count followed by the conditions it counts
+    pub fn load_counted(cursor: &mut Cursor<&Vec<u8>>) -> Result<Vec<Self>, Error> {
+        let _: Option<u32> = CITC::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        Self::load_multiple(cursor)
+    }
+
+    pub fn write_counted(conditions: Vec<Self>, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        if !conditions.is_empty() {
+            CITC::try_from(conditions.len() as u32)?.write(cursor)?;
+            for c in conditions {
+                c.write(cursor)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        CTDA::try_from(self.condition)?.write(cursor)?;
+        self.condition_item_count
+            .map(CITC::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.param1_override
+            .map(CIS1::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.param2_override
+            .map(CIS2::try_from)
+            .transpose()?
+            .write(cursor)?;
+        Ok(())
+    }
+}
+
+impl TryFrom<Condition> for CTDA {
+    type Error = Error;
+
+    fn try_from(obj: Condition) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
 }
@@ -1,5 +1,8 @@
-use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, io::Cursor, BinRead, NullString};
+use crate::{
+    common::{check_done_reading, LocalizedString},
+    error::Error,
+};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -33,3 +36,44 @@ impl TryFrom<DESC> for u32 {
         Ok(result)
     }
 }
+
+impl TryFrom<u32> for DESC {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<String> for DESC {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<LocalizedString> for DESC {
+    type Error = Error;
+
+    fn try_from(obj: LocalizedString) -> Result<Self, Self::Error> {
+        match obj {
+            LocalizedString::Localized(l) => l.try_into(),
+            LocalizedString::ZString(z) => z.try_into(),
+        }
+    }
+}
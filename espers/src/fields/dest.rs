@@ -6,7 +6,7 @@ use super::{
     dstf::DSTF,
 };
 use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -85,4 +85,47 @@ impl DestructionData {
 
         Ok(Some(Self { data, stage_data }))
     }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        DEST::try_from(self.data)?.write(cursor)?;
+
+        for stage in self.stage_data {
+            DSTD::try_from(stage.header)?.write(cursor)?;
+            stage
+                .replacement_model
+                .map(DMDL::try_from)
+                .transpose()?
+                .write(cursor)?;
+            if !stage.unknown1.is_empty() {
+                DMDT::try_from(stage.unknown1)?.write(cursor)?;
+            }
+            stage
+                .destruction_textures
+                .map(DMDS::try_from)
+                .transpose()?
+                .write(cursor)?;
+            DSTF {
+                size: 0,
+                data: Vec::new(),
+            }
+            .write(cursor)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<DestructionDataHeader> for DEST {
+    type Error = Error;
+
+    fn try_from(obj: DestructionDataHeader) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
 }
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID, WString32};
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -42,3 +42,18 @@ impl TryFrom<DMDS> for DestructionTextures {
         Ok(result)
     }
 }
+
+impl TryFrom<DestructionTextures> for DMDS {
+    type Error = Error;
+
+    fn try_from(obj: DestructionTextures) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use std::io::Cursor;
 
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 
 use crate::{common::check_done_reading, error::Error};
@@ -27,3 +27,18 @@ impl TryFrom<DMDT> for Vec<[u8; 12]> {
         Ok(result)
     }
 }
+
+impl TryFrom<Vec<[u8; 12]>> for DMDT {
+    type Error = Error;
+
+    fn try_from(obj: Vec<[u8; 12]>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
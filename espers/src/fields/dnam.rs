@@ -1,6 +1,6 @@
-use crate::common::{check_done_reading, FormID};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use binrw::{binrw, BinRead, NullString};
+use binrw::{binrw, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -78,3 +78,141 @@ impl TryFrom<DNAM> for String {
         Ok(result)
     }
 }
+
+impl TryFrom<u32> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl<const N: usize> TryFrom<[u8; N]> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: [u8; N]) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<FormID> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<String> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<u8> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: u8) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<LocalizedString> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: LocalizedString) -> Result<Self, Self::Error> {
+        match obj {
+            LocalizedString::Localized(l) => l.try_into(),
+            LocalizedString::ZString(z) => z.try_into(),
+        }
+    }
+}
+
+impl TryFrom<DNAM> for (FormID, FormID) {
+    type Error = Error;
+
+    fn try_from(raw: DNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<(FormID, FormID)> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: (FormID, FormID)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<DNAM> for f32 {
+    type Error = Error;
+
+    fn try_from(raw: DNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<f32> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: f32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
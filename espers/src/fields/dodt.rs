@@ -1,5 +1,5 @@
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 
@@ -48,3 +48,18 @@ impl TryInto<DecalData> for DODT {
         Ok(DecalData::read(&mut Cursor::new(&self.data))?)
     }
 }
+
+impl TryFrom<DecalData> for DODT {
+    type Error = Error;
+
+    fn try_from(obj: DecalData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     common::{check_done_reading, FormID},
     error::Error,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -39,3 +39,18 @@ impl TryFrom<DSTD> for StageDataHeader {
         Ok(result)
     }
 }
+
+impl TryFrom<StageDataHeader> for DSTD {
+    type Error = Error;
+
+    fn try_from(obj: StageDataHeader) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -49,4 +49,28 @@ impl Effect {
             conditions,
         })
     }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        EFID::try_from(self.id)?.write(cursor)?;
+        EFIT::try_from(self.item)?.write(cursor)?;
+        for condition in self.conditions {
+            condition.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<FormID> for EFID {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
 }
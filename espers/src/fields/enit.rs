@@ -1,6 +1,6 @@
 use crate::common::check_done_reading;
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -40,3 +40,18 @@ impl TryFrom<ENIT> for EnchantedItem {
         Ok(result)
     }
 }
+
+impl TryFrom<EnchantedItem> for ENIT {
+    type Error = Error;
+
+    fn try_from(obj: EnchantedItem) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use crate::common::{check_done_reading, LocalizedString};
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"EPF2")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EPF2 {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<EPF2> for u32 {
+    type Error = Error;
+
+    fn try_from(raw: EPF2) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<EPF2> for String {
+    type Error = Error;
+
+    fn try_from(raw: EPF2) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = NullString::read_le(&mut cursor)?.to_string();
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<u32> for EPF2 {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<String> for EPF2 {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<LocalizedString> for EPF2 {
+    type Error = Error;
+
+    fn try_from(obj: LocalizedString) -> Result<Self, Self::Error> {
+        match obj {
+            LocalizedString::Localized(l) => l.try_into(),
+            LocalizedString::ZString(z) => z.try_into(),
+        }
+    }
+}
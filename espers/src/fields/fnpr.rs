@@ -1,5 +1,5 @@
 use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, io::Cursor, BinRead};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -22,3 +22,18 @@ impl TryFrom<FNPR> for (u16, u16) {
         Ok(result)
     }
 }
+
+impl TryFrom<(u16, u16)> for FNPR {
+    type Error = Error;
+
+    fn try_from(obj: (u16, u16)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
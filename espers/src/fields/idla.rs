@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, until_eof, Endian};
+use binrw::{binrw, io::Cursor, until_eof, BinWrite, Endian};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -23,3 +23,18 @@ impl TryFrom<IDLA> for Vec<FormID> {
         Ok(result)
     }
 }
+
+impl TryFrom<Vec<FormID>> for IDLA {
+    type Error = Error;
+
+    fn try_from(obj: Vec<FormID>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, until_eof, BinRead, Endian};
+use binrw::{binrw, io::Cursor, until_eof, BinRead, BinWrite, Endian};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -34,3 +34,18 @@ impl TryFrom<KWDA> for Vec<FormID> {
         Ok(result)
     }
 }
+
+impl TryFrom<Vec<FormID>> for KWDA {
+    type Error = Error;
+
+    fn try_from(obj: Vec<FormID>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
+use serde_derive::{Deserialize, Serialize};
+use std::io::Cursor;
+
+#[binrw]
+#[brw(little, magic = b"LVLF")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LVLF {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct LeveledFlags: u8 {
+        const CALCULATE_FROM_ALL_LEVELS = 0x01;
+        const CALCULATE_FOR_EACH_ITEM = 0x02;
+        const USE_ALL = 0x04;
+        const SPECIAL_LOOT = 0x08;
+    }
+}
+
+impl TryFrom<LVLF> for LeveledFlags {
+    type Error = Error;
+
+    fn try_from(raw: LVLF) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<LeveledFlags> for LVLF {
+    type Error = Error;
+
+    fn try_from(obj: LeveledFlags) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
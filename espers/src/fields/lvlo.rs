@@ -0,0 +1,85 @@
+use super::COED;
+use crate::common::{check_done_reading, FormID};
+use crate::error::Error;
+use binrw::{binrw, BinRead, BinWrite};
+use serde_derive::{Deserialize, Serialize};
+use std::io::Cursor;
+
+#[binrw]
+#[brw(little, magic = b"LVLO")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LVLO {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct LeveledObject {
+    level: u16,
+    unknown1: u16,
+    reference: FormID,
+    count: u16,
+    unknown2: u16,
+}
+
+/// A leveled list entry (LVLO) together with its optional extra data (COED)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeveledEntry {
+    pub level: u16,
+    pub unknown1: u16,
+    pub reference: FormID,
+    pub count: u16,
+    pub unknown2: u16,
+    pub extra: Option<(FormID, FormID, f32)>,
+}
+
+impl LeveledEntry {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let raw = LVLO::read(cursor)?;
+        let mut data = Cursor::new(&raw.data);
+        let obj = LeveledObject::read(&mut data)?;
+        check_done_reading(&mut data)?;
+        let extra = COED::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self {
+            level: obj.level,
+            unknown1: obj.unknown1,
+            reference: obj.reference,
+            count: obj.count,
+            unknown2: obj.unknown2,
+            extra,
+        })
+    }
+
+    pub fn load_multiple(cursor: &mut Cursor<&Vec<u8>>) -> Result<Vec<Self>, Error> {
+        let mut items = Vec::new();
+        while let Ok(i) = Self::load(cursor) {
+            items.push(i);
+        }
+        Ok(items)
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        let mut data = Cursor::new(Vec::new());
+        LeveledObject {
+            level: self.level,
+            unknown1: self.unknown1,
+            reference: self.reference,
+            count: self.count,
+            unknown2: self.unknown2,
+        }
+        .write(&mut data)?;
+        let data = data.into_inner();
+        LVLO {
+            size: data.len() as u16,
+            data,
+        }
+        .write(cursor)?;
+        self.extra.map(COED::try_from).transpose()?.write(cursor)?;
+        Ok(())
+    }
+}
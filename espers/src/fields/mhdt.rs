@@ -1,12 +1,34 @@
+use super::XXXX;
+use crate::error::Error;
 use binrw::binrw;
 use serde_derive::{Deserialize, Serialize};
 
+/// Worldspace height data, which can outgrow a 16 bit size and then comes
+/// after an [XXXX] holding its real size
 #[binrw]
-#[brw(little, magic = b"MHDT")]
+#[brw(little)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MHDT {
+    #[br(temp, try)]
+    #[bw(calc = XXXX::for_len(data.len()))]
+    oversize: Option<XXXX>,
+
+    /// Recomputed from `data` when writing
+    #[brw(magic = b"MHDT")]
+    #[bw(map = |_: &u16| XXXX::field_size(data.len()))]
     pub size: u16,
 
-    #[br(count = size)]
+    #[br(count = oversize.as_ref().map_or(size as u32, |x| x.data))]
     pub data: Vec<u8>,
 }
+
+impl TryFrom<Vec<u8>> for MHDT {
+    type Error = Error;
+
+    fn try_from(obj: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            size: XXXX::field_size(obj.len()),
+            data: obj,
+        })
+    }
+}
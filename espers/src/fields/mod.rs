@@ -1,4 +1,41 @@
+pub mod acbs;
+pub mod acec;
+pub mod acep;
+pub mod acid;
+pub mod acpr;
+pub mod acsr;
+pub mod acun;
+pub mod ahcf;
+pub mod ahcm;
+pub mod aidt;
+pub mod alca;
+pub mod alcl;
+pub mod alco;
+pub mod aldn;
+pub mod alea;
+pub mod aled;
+pub mod aleq;
+pub mod alfa;
+pub mod alfc;
+pub mod alfd;
+pub mod alfe;
+pub mod alfi;
+pub mod alfl;
+pub mod alfr;
+pub mod alid;
+pub mod alls;
+pub mod alna;
+pub mod alnt;
+pub mod alpc;
+pub mod alrt;
+pub mod alsp;
+pub mod alst;
+pub mod alua;
 pub mod anam;
+pub mod atkd;
+pub mod atke;
+pub mod atkr;
+pub mod atxt;
 pub mod avsk;
 pub mod bamt;
 pub mod bids;
@@ -11,6 +48,7 @@ pub mod bpni;
 pub mod bpnn;
 pub mod bpnt;
 pub mod bptn;
+pub mod btxt;
 pub mod cis1;
 pub mod cis2;
 pub mod citc;
@@ -18,61 +56,106 @@ pub mod cnam;
 pub mod cnto;
 pub mod coct;
 pub mod coed;
+pub mod crdt;
 pub mod crgr;
+pub mod crif;
 pub mod crva;
 pub mod cscr;
+pub mod csdc;
+pub mod csdi;
+pub mod csdt;
 pub mod csfl;
 pub mod csgd;
 pub mod cslr;
 pub mod csmd;
 pub mod csme;
 pub mod ctda;
+pub mod dalc;
 pub mod data;
+pub mod demo;
 pub mod desc;
 pub mod dest;
+pub mod deva;
+pub mod dftf;
+pub mod dftm;
+pub mod dmax;
 pub mod dmdl;
 pub mod dmds;
 pub mod dmdt;
+pub mod dmin;
 pub mod dnam;
 pub mod dodt;
+pub mod doft;
+pub mod dplt;
 pub mod dstd;
 pub mod dstf;
 pub mod eamt;
+pub mod ecor;
 pub mod edid;
 pub mod efid;
 pub mod efit;
 pub mod eitm;
 pub mod enam;
 pub mod enit;
+pub mod epf2;
+pub mod epf3;
+pub mod epfd;
+pub mod epft;
+pub mod esce;
 pub mod etyp;
 pub mod fcht;
+pub mod flmv;
+pub mod fltr;
 pub mod fltv;
 pub mod fnam;
 pub mod fnmk;
 pub mod fnpr;
 pub mod fprt;
+pub mod ftsf;
+pub mod ftsm;
+pub mod ftst;
 pub mod full;
 pub mod gnam;
+pub mod gwor;
+pub mod hclf;
+pub mod head;
 pub mod hedr;
 pub mod hnam;
+pub mod htid;
 pub mod ico2;
 pub mod icon;
 pub mod idla;
+pub mod idlb;
 pub mod idlc;
 pub mod idlf;
 pub mod idlt;
+pub mod imsp;
 pub mod inam;
 pub mod incc;
+pub mod indx;
 pub mod intv;
 pub mod jail;
+pub mod jnam;
 pub mod jout;
 pub mod knam;
 pub mod ksiz;
 pub mod kwda;
+pub mod lcec;
+pub mod lcep;
+pub mod lcid;
+pub mod lcpr;
+pub mod lcsr;
+pub mod lcun;
+pub mod llct;
 pub mod lnam;
 pub mod ltmp;
+pub mod lvld;
+pub mod lvlf;
+pub mod lvlg;
+pub mod lvlo;
 pub mod mast;
 pub mod mcht;
+pub mod mdob;
 pub mod mhdt;
 pub mod mic2;
 pub mod mico;
@@ -81,27 +164,105 @@ pub mod model;
 pub mod modl;
 pub mod mods;
 pub mod modt;
+pub mod mpai;
+pub mod mpav;
 pub mod mprt;
+pub mod mtnm;
+pub mod mtyp;
+pub mod nama;
 pub mod name;
+pub mod next;
+pub mod nnam;
+pub mod nvnm;
 pub mod obnd;
+pub mod ocor;
+pub mod ofst;
 pub mod onam;
 pub mod pdto;
 pub mod pfig;
+pub mod pfo2;
+pub mod pfor;
 pub mod pfpc;
+pub mod phtn;
+pub mod phwt;
+pub mod pkc2;
+pub mod pkcu;
+pub mod pkdt;
+pub mod pkid;
 pub mod plcn;
+pub mod pldt;
 pub mod plvd;
 pub mod pnam;
+pub mod poba;
+pub mod poca;
+pub mod poea;
+pub mod prcb;
+pub mod prkc;
+pub mod prke;
+pub mod prkf;
+pub mod prkr;
+pub mod prkz;
+pub mod psdt;
+pub mod ptda;
 pub mod qnam;
+pub mod qobj;
+pub mod qsdt;
+pub mod qsta;
+pub mod qtgl;
 pub mod qual;
 pub mod raga;
+pub mod rcec;
+pub mod rclr;
+pub mod rcpr;
+pub mod rcsr;
+pub mod rcun;
 pub mod rdat;
+pub mod rdgs;
+pub mod rdid;
+pub mod rdmo;
+pub mod rdmp;
+pub mod rdot;
+pub mod rdsa;
+pub mod rdwt;
 pub mod rnam;
+pub mod rnmv;
+pub mod rpld;
+pub mod rpli;
+pub mod rprf;
+pub mod rprm;
+pub mod schr;
+pub mod sctx;
+pub mod sdsc;
+pub mod shrt;
 pub mod snam;
 pub mod sndd;
+pub mod snmv;
+pub mod soft;
+pub mod spct;
+pub mod sped;
+pub mod spit;
+pub mod splo;
+pub mod spmv;
+pub mod spor;
 pub mod stol;
+pub mod swmv;
+pub mod tclt;
+pub mod tias;
 pub mod tifc;
+pub mod tinc;
+pub mod tind;
+pub mod tini;
+pub mod tinl;
+pub mod tinp;
+pub mod tint;
+pub mod tinv;
+pub mod tirs;
 pub mod tnam;
+pub mod tpic;
+pub mod tplt;
+pub mod trdt;
 pub mod tvdt;
+pub mod twat;
 pub mod tx00;
 pub mod tx01;
 pub mod tx02;
@@ -111,21 +272,34 @@ pub mod tx05;
 pub mod tx06;
 pub mod tx07;
 pub mod unam;
+pub mod unes;
 pub mod unknown;
+pub mod vclr;
 pub mod venc;
 pub mod vend;
 pub mod venv;
+pub mod vhgt;
 pub mod vmad;
 pub mod vnam;
+pub mod vnml;
+pub mod vtck;
+pub mod vtex;
+pub mod vtxt;
 pub mod wait;
 pub mod wbdt;
+pub mod wctr;
+pub mod wkmv;
 pub mod wlst;
 pub mod wnam;
+pub mod xact;
+pub mod xalp;
 pub mod xapd;
 pub mod xapr;
+pub mod xatr;
 pub mod xcas;
 pub mod xccm;
 pub mod xcgd;
+pub mod xchg;
 pub mod xcim;
 pub mod xclc;
 pub mod xcll;
@@ -133,33 +307,99 @@ pub mod xclr;
 pub mod xclw;
 pub mod xcmo;
 pub mod xcnt;
+pub mod xcvl;
+pub mod xcvr;
 pub mod xcwt;
+pub mod xcza;
+pub mod xczc;
+pub mod xczr;
+pub mod xemi;
 pub mod xesp;
 pub mod xezn;
+pub mod xfvc;
 pub mod xhor;
+pub mod xhtw;
+pub mod xibs;
 pub mod xill;
 pub mod xis2;
 pub mod xlcm;
 pub mod xlcn;
+pub mod xlib;
+pub mod xlig;
 pub mod xlkr;
+pub mod xloc;
 pub mod xlrl;
+pub mod xlrm;
 pub mod xlrt;
+pub mod xltw;
+pub mod xmbo;
+pub mod xmbr;
 pub mod xmrk;
 pub mod xnam;
+pub mod xndp;
+pub mod xocp;
 pub mod xown;
+pub mod xpod;
 pub mod xppa;
 pub mod xprd;
+pub mod xprm;
+pub mod xpwr;
+pub mod xrds;
 pub mod xrgb;
 pub mod xrgd;
+pub mod xrmr;
+pub mod xrnk;
 pub mod xscl;
+pub mod xspc;
+pub mod xtel;
+pub mod xtnm;
+pub mod xtri;
 pub mod xwcn;
 pub mod xwcs;
 pub mod xwcu;
 pub mod xwem;
+pub mod xxxx;
 pub mod ynam;
 pub mod znam;
 
+pub use acbs::ACBS;
+pub use acec::ACEC;
+pub use acep::ACEP;
+pub use acid::ACID;
+pub use acpr::ACPR;
+pub use acsr::ACSR;
+pub use acun::ACUN;
+pub use ahcf::AHCF;
+pub use ahcm::AHCM;
+pub use aidt::AIDT;
+pub use alca::ALCA;
+pub use alcl::ALCL;
+pub use alco::ALCO;
+pub use aldn::ALDN;
+pub use alea::ALEA;
+pub use aled::ALED;
+pub use aleq::ALEQ;
+pub use alfa::ALFA;
+pub use alfc::ALFC;
+pub use alfd::ALFD;
+pub use alfe::ALFE;
+pub use alfi::ALFI;
+pub use alfl::ALFL;
+pub use alfr::ALFR;
+pub use alid::ALID;
+pub use alls::ALLS;
+pub use alna::ALNA;
+pub use alnt::ALNT;
+pub use alpc::ALPC;
+pub use alrt::ALRT;
+pub use alsp::ALSP;
+pub use alst::ALST;
+pub use alua::ALUA;
 pub use anam::ANAM;
+pub use atkd::{Attack, AttackData, ATKD};
+pub use atke::ATKE;
+pub use atkr::ATKR;
+pub use atxt::ATXT;
 pub use avsk::AVSK;
 pub use bamt::BAMT;
 pub use bids::BIDS;
@@ -172,99 +412,224 @@ pub use bpni::BPNI;
 pub use bpnn::BPNN;
 pub use bpnt::BPNT;
 pub use bptn::BPTN;
+pub use btxt::BTXT;
 pub use cis1::CIS1;
 pub use cis2::CIS2;
 pub use citc::CITC;
 pub use cnam::CNAM;
-pub use cnto::CNTO;
+pub use cnto::{ContainerItem, CNTO};
 pub use coct::COCT;
 pub use coed::COED;
+pub use crdt::CRDT;
 pub use crgr::CRGR;
+pub use crif::CRIF;
 pub use crva::{CrimeGold, CRVA};
 pub use cscr::CSCR;
+pub use csdc::CSDC;
+pub use csdi::CSDI;
+pub use csdt::CSDT;
 pub use csfl::CSFL;
 pub use csgd::CSGD;
 pub use cslr::CSLR;
 pub use csmd::CSMD;
 pub use csme::CSME;
 pub use ctda::{Condition, EffectCondition, CTDA};
+pub use dalc::DALC;
 pub use data::DATA;
+pub use demo::DEMO;
 pub use desc::DESC;
 pub use dest::{DestructionData, DEST};
+pub use deva::DEVA;
+pub use dftf::DFTF;
+pub use dftm::DFTM;
+pub use dmax::DMAX;
 pub use dmdl::DMDL;
 pub use dmds::DMDS;
 pub use dmdt::DMDT;
+pub use dmin::DMIN;
 pub use dnam::DNAM;
 pub use dodt::{DecalData, DODT};
+pub use doft::DOFT;
+pub use dplt::DPLT;
 pub use dstd::DSTD;
 pub use dstf::DSTF;
 pub use eamt::EAMT;
+pub use ecor::ECOR;
 pub use edid::EDID;
 pub use efid::{Effect, EFID};
 pub use efit::{EffectItem, EFIT};
 pub use eitm::EITM;
 pub use enam::ENAM;
 pub use enit::{EnchantedItem, ENIT};
+pub use epf2::EPF2;
+pub use epf3::EPF3;
+pub use epfd::EPFD;
+pub use epft::EPFT;
+pub use esce::ESCE;
 pub use etyp::ETYP;
 pub use fcht::FCHT;
+pub use flmv::FLMV;
+pub use fltr::FLTR;
 pub use fltv::FLTV;
 pub use fnam::FNAM;
 pub use fnmk::FNMK;
 pub use fnpr::FNPR;
 pub use fprt::FPRT;
+pub use ftsf::FTSF;
+pub use ftsm::FTSM;
+pub use ftst::FTST;
 pub use full::FULL;
 pub use gnam::GNAM;
+pub use gwor::GWOR;
+pub use hclf::HCLF;
+pub use head::HEAD;
 pub use hedr::HEDR;
 pub use hnam::HNAM;
+pub use htid::HTID;
 pub use ico2::ICO2;
 pub use icon::ICON;
 pub use idla::IDLA;
+pub use idlb::IDLB;
 pub use idlc::IDLC;
 pub use idlf::IDLF;
 pub use idlt::IDLT;
+pub use imsp::IMSP;
 pub use inam::INAM;
 pub use incc::INCC;
+pub use indx::INDX;
 pub use intv::INTV;
 pub use jail::JAIL;
+pub use jnam::JNAM;
 pub use jout::JOUT;
 pub use knam::KNAM;
 pub use ksiz::KSIZ;
 pub use kwda::KWDA;
+pub use lcec::LCEC;
+pub use lcep::LCEP;
+pub use lcid::LCID;
+pub use lcpr::LCPR;
+pub use lcsr::LCSR;
+pub use lcun::LCUN;
+pub use llct::LLCT;
 pub use lnam::LNAM;
 pub use ltmp::LTMP;
+pub use lvld::LVLD;
+pub use lvlf::{LeveledFlags, LVLF};
+pub use lvlg::LVLG;
+pub use lvlo::{LeveledEntry, LVLO};
 pub use mast::MAST;
 pub use mcht::MCHT;
+pub use mdob::MDOB;
 pub use mhdt::MHDT;
 pub use mic2::MIC2;
 pub use mico::MICO;
 pub use mnam::MNAM;
 pub use model::{
     AlternateTexture, AlternateTextures, Model, ModelTextures, ReadTextures, Textures, Unknown4,
+    WriteTextures,
 };
 pub use modl::{MOD2, MOD3, MOD4, MOD5, MODL};
 pub use mods::{MO2S, MO3S, MO4S, MO5S, MODS};
 pub use modt::{MO2T, MO3T, MO4T, MO5T, MODT};
+pub use mpai::MPAI;
+pub use mpav::MPAV;
 pub use mprt::MPRT;
+pub use mtnm::MTNM;
+pub use mtyp::MTYP;
+pub use nama::NAMA;
 pub use name::{NAM0, NAM1, NAM2, NAM3, NAM4, NAM5, NAM6, NAM7, NAM8, NAM9, NAME};
+pub use next::NEXT;
+pub use nnam::NNAM;
+pub use nvnm::NVNM;
 pub use obnd::{ObjectBounds, OBND};
+pub use ocor::OCOR;
+pub use ofst::OFST;
 pub use onam::ONAM;
 pub use pdto::PDTO;
 pub use pfig::PFIG;
+pub use pfo2::PFO2;
+pub use pfor::PFOR;
 pub use pfpc::PFPC;
+pub use phtn::PHTN;
+pub use phwt::PHWT;
+pub use pkc2::PKC2;
+pub use pkcu::PKCU;
+pub use pkdt::PKDT;
+pub use pkid::PKID;
 pub use plcn::PLCN;
+pub use pldt::PLDT;
 pub use plvd::PLVD;
 pub use pnam::PNAM;
+pub use poba::POBA;
+pub use poca::POCA;
+pub use poea::POEA;
+pub use prcb::PRCB;
+pub use prkc::PRKC;
+pub use prke::PRKE;
+pub use prkf::PRKF;
+pub use prkr::PRKR;
+pub use prkz::PRKZ;
+pub use psdt::PSDT;
+pub use ptda::PTDA;
 pub use qnam::QNAM;
+pub use qobj::QOBJ;
+pub use qsdt::QSDT;
+pub use qsta::QSTA;
+pub use qtgl::QTGL;
 pub use qual::QUAL;
 pub use raga::RAGA;
+pub use rcec::RCEC;
+pub use rclr::RCLR;
+pub use rcpr::RCPR;
+pub use rcsr::RCSR;
+pub use rcun::RCUN;
 pub use rdat::RDAT;
+pub use rdgs::RDGS;
+pub use rdid::RDID;
+pub use rdmo::RDMO;
+pub use rdmp::RDMP;
+pub use rdot::RDOT;
+pub use rdsa::RDSA;
+pub use rdwt::RDWT;
 pub use rnam::RNAM;
+pub use rnmv::RNMV;
+pub use rpld::RPLD;
+pub use rpli::RPLI;
+pub use rprf::RPRF;
+pub use rprm::RPRM;
+pub use schr::SCHR;
+pub use sctx::SCTX;
+pub use sdsc::SDSC;
+pub use shrt::SHRT;
 pub use snam::SNAM;
 pub use sndd::SNDD;
+pub use snmv::SNMV;
+pub use soft::SOFT;
+pub use spct::SPCT;
+pub use sped::SPED;
+pub use spit::{CastType, Delivery, SpellItem, SpellType, SPIT};
+pub use splo::SPLO;
+pub use spmv::SPMV;
+pub use spor::SPOR;
 pub use stol::STOL;
+pub use swmv::SWMV;
+pub use tclt::TCLT;
+pub use tias::TIAS;
 pub use tifc::TIFC;
+pub use tinc::TINC;
+pub use tind::TIND;
+pub use tini::TINI;
+pub use tinl::TINL;
+pub use tinp::TINP;
+pub use tint::TINT;
+pub use tinv::TINV;
+pub use tirs::TIRS;
 pub use tnam::TNAM;
+pub use tpic::TPIC;
+pub use tplt::TPLT;
+pub use trdt::TRDT;
 pub use tvdt::TVDT;
+pub use twat::TWAT;
 pub use tx00::TX00;
 pub use tx01::TX01;
 pub use tx02::TX02;
@@ -274,21 +639,38 @@ pub use tx05::TX05;
 pub use tx06::TX06;
 pub use tx07::TX07;
 pub use unam::UNAM;
+pub use unes::UNES;
 pub use unknown::UNKNOWN;
+pub use vclr::VCLR;
 pub use venc::VENC;
 pub use vend::VEND;
 pub use venv::VENV;
-pub use vmad::{Property, Script, ScriptList, VMAD};
+pub use vhgt::VHGT;
+pub use vmad::{
+    Fragment, FragmentList, PackageFragmentList, PerkFragment, PerkFragmentList, PhaseFragment,
+    Property, QuestAlias, QuestFragmentList, SceneFragmentList, Script, ScriptList,
+    TopicInfoFragmentList, VMAD,
+};
 pub use vnam::VNAM;
+pub use vnml::VNML;
+pub use vtck::VTCK;
+pub use vtex::VTEX;
+pub use vtxt::VTXT;
 pub use wait::WAIT;
 pub use wbdt::WBDT;
+pub use wctr::WCTR;
+pub use wkmv::WKMV;
 pub use wlst::{Weather, WLST};
 pub use wnam::WNAM;
+pub use xact::XACT;
+pub use xalp::XALP;
 pub use xapd::XAPD;
 pub use xapr::XAPR;
+pub use xatr::XATR;
 pub use xcas::XCAS;
 pub use xccm::XCCM;
 pub use xcgd::XCGD;
+pub use xchg::XCHG;
 pub use xcim::XCIM;
 pub use xclc::XCLC;
 pub use xcll::XCLL;
@@ -296,28 +678,57 @@ pub use xclr::XCLR;
 pub use xclw::XCLW;
 pub use xcmo::XCMO;
 pub use xcnt::XCNT;
+pub use xcvl::XCVL;
+pub use xcvr::XCVR;
 pub use xcwt::XCWT;
+pub use xcza::XCZA;
+pub use xczc::XCZC;
+pub use xczr::XCZR;
+pub use xemi::XEMI;
 pub use xesp::XESP;
 pub use xezn::XEZN;
+pub use xfvc::XFVC;
 pub use xhor::XHOR;
+pub use xhtw::XHTW;
+pub use xibs::XIBS;
 pub use xill::XILL;
 pub use xis2::XIS2;
 pub use xlcm::XLCM;
 pub use xlcn::XLCN;
+pub use xlib::XLIB;
+pub use xlig::XLIG;
 pub use xlkr::XLKR;
+pub use xloc::XLOC;
 pub use xlrl::XLRL;
+pub use xlrm::XLRM;
 pub use xlrt::XLRT;
+pub use xltw::XLTW;
+pub use xmbo::XMBO;
+pub use xmbr::XMBR;
 pub use xmrk::XMRK;
 pub use xnam::XNAM;
+pub use xndp::XNDP;
+pub use xocp::XOCP;
 pub use xown::XOWN;
+pub use xpod::XPOD;
 pub use xppa::XPPA;
 pub use xprd::XPRD;
+pub use xprm::XPRM;
+pub use xpwr::XPWR;
+pub use xrds::XRDS;
 pub use xrgb::XRGB;
 pub use xrgd::XRGD;
+pub use xrmr::XRMR;
+pub use xrnk::XRNK;
 pub use xscl::XSCL;
+pub use xspc::XSPC;
+pub use xtel::XTEL;
+pub use xtnm::XTNM;
+pub use xtri::XTRI;
 pub use xwcn::XWCN;
 pub use xwcs::XWCS;
 pub use xwcu::XWCU;
 pub use xwem::XWEM;
+pub use xxxx::XXXX;
 pub use ynam::YNAM;
 pub use znam::ZNAM;
@@ -11,6 +11,10 @@ pub trait ReadModel {
     fn try_read_model(cursor: &mut Cursor<&Vec<u8>>) -> Result<Option<String>, Error>;
 }
 
+pub trait WriteModel {
+    fn write_model(model: String, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error>;
+}
+
 pub trait ReadTextures {
     fn read_textures(cursor: &mut Cursor<&Vec<u8>>, version: u16) -> Result<Textures, Error>;
     fn try_read_textures(
@@ -19,6 +23,10 @@ pub trait ReadTextures {
     ) -> Result<Option<Textures>, Error>;
 }
 
+pub trait WriteTextures {
+    fn write_textures(textures: Textures, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error>;
+}
+
 pub trait ReadAlternateTextures {
     fn read_alt_textures(cursor: &mut Cursor<&Vec<u8>>) -> Result<AlternateTextures, Error>;
     fn try_read_alt_textures(
@@ -26,6 +34,13 @@ pub trait ReadAlternateTextures {
     ) -> Result<Option<AlternateTextures>, Error>;
 }
 
+pub trait WriteAlternateTextures {
+    fn write_alt_textures(
+        textures: AlternateTextures,
+        cursor: &mut Cursor<Vec<u8>>,
+    ) -> Result<(), Error>;
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum Textures {
     Header(ModelTextures),
@@ -75,6 +90,22 @@ impl Model {
             None => Ok(None),
         }
     }
+
+    pub fn write<M, D, AT>(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error>
+    where
+        M: WriteModel,
+        D: WriteTextures,
+        AT: WriteAlternateTextures,
+    {
+        M::write_model(self.model, cursor)?;
+        if let Some(t) = self.textures {
+            D::write_textures(t, cursor)?;
+        }
+        if let Some(t) = self.alternate_textures {
+            AT::write_alt_textures(t, cursor)?;
+        }
+        Ok(())
+    }
 }
 #[binrw]
 #[brw(little)]
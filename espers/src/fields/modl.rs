@@ -1,7 +1,7 @@
-use super::model::ReadModel;
+use super::model::{ReadModel, WriteModel};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead, NullString};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 
 macro_rules! impl_model {
@@ -38,9 +38,39 @@ macro_rules! impl_model {
             }
         }
 
+        impl TryFrom<String> for $modl {
+            type Error = Error;
+
+            fn try_from(obj: String) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                NullString::from(obj).write(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<FormID> for $modl {
+            type Error = Error;
+
+            fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
         impl ReadModel for $modl {
             fn read_model(cursor: &mut Cursor<&Vec<u8>>) -> Result<String, Error> {
-                Ok(Self::read_le(cursor)?.try_into()?)
+                Self::read_le(cursor)?.try_into()
             }
             fn try_read_model(cursor: &mut Cursor<&Vec<u8>>) -> Result<Option<String>, Error> {
                 match Self::read_le(cursor) {
@@ -51,6 +81,12 @@ macro_rules! impl_model {
                 }
             }
         }
+
+        impl WriteModel for $modl {
+            fn write_model(model: String, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+                Ok(Self::try_from(model)?.write(cursor)?)
+            }
+        }
     };
 }
 
@@ -1,6 +1,8 @@
-use super::model::{AlternateTexture, AlternateTextures, ReadAlternateTextures};
+use super::model::{
+    AlternateTexture, AlternateTextures, ReadAlternateTextures, WriteAlternateTextures,
+};
 use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -20,7 +22,7 @@ macro_rules! impl_model {
             fn read_alt_textures(
                 cursor: &mut Cursor<&Vec<u8>>,
             ) -> Result<AlternateTextures, Error> {
-                Ok(Self::read_le(cursor)?.try_into()?)
+                Self::read_le(cursor)?.try_into()
             }
             fn try_read_alt_textures(
                 cursor: &mut Cursor<&Vec<u8>>,
@@ -40,6 +42,30 @@ macro_rules! impl_model {
             }
         }
 
+        impl WriteAlternateTextures for $mods {
+            fn write_alt_textures(
+                textures: AlternateTextures,
+                cursor: &mut Cursor<Vec<u8>>,
+            ) -> Result<(), Error> {
+                Ok(Self::try_from(textures)?.write(cursor)?)
+            }
+        }
+
+        impl TryFrom<AlternateTextures> for $mods {
+            type Error = Error;
+
+            fn try_from(obj: AlternateTextures) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
         impl TryFrom<$mods> for Vec<AlternateTexture> {
             type Error = Error;
 
@@ -1,6 +1,6 @@
-use super::{ModelTextures, ReadTextures, Textures, Unknown4};
+use super::{ModelTextures, ReadTextures, Textures, Unknown4, WriteTextures};
 use crate::{common::check_done_reading, error::Error};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -21,7 +21,7 @@ macro_rules! impl_model {
                 cursor: &mut Cursor<&Vec<u8>>,
                 version: u16,
             ) -> Result<Textures, Error> {
-                Ok((Self::read_le(cursor)?, version).try_into()?)
+                (Self::read_le(cursor)?, version).try_into()
             }
             fn try_read_textures(
                 cursor: &mut Cursor<&Vec<u8>>,
@@ -34,6 +34,15 @@ macro_rules! impl_model {
             }
         }
 
+        impl WriteTextures for $modt {
+            fn write_textures(
+                textures: Textures,
+                cursor: &mut Cursor<Vec<u8>>,
+            ) -> Result<(), Error> {
+                Ok(Self::try_from(textures)?.write(cursor)?)
+            }
+        }
+
         impl TryFrom<($modt, u16)> for Textures {
             type Error = Error;
 
@@ -54,6 +63,47 @@ macro_rules! impl_model {
             }
         }
 
+        impl TryFrom<Textures> for $modt {
+            type Error = Error;
+
+            fn try_from(obj: Textures) -> Result<Self, Self::Error> {
+                match obj {
+                    Textures::Header(t) => t.try_into(),
+                    Textures::NoHeader(t) => t.try_into(),
+                }
+            }
+        }
+
+        impl TryFrom<ModelTextures> for $modt {
+            type Error = Error;
+
+            fn try_from(obj: ModelTextures) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<Vec<Unknown4>> for $modt {
+            type Error = Error;
+
+            fn try_from(obj: Vec<Unknown4>) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
         impl TryFrom<$modt> for Vec<Unknown4> {
             type Error = Error;
 
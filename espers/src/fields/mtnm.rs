@@ -0,0 +1,35 @@
+use crate::error::Error;
+use binrw::binrw;
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"MTNM")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MTNM {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+/// Movement type names are four characters, without a terminator
+impl TryFrom<MTNM> for String {
+    type Error = Error;
+
+    fn try_from(raw: MTNM) -> Result<Self, Self::Error> {
+        Ok(std::str::from_utf8(&raw.data)?.to_string())
+    }
+}
+
+impl TryFrom<String> for MTNM {
+    type Error = Error;
+
+    fn try_from(obj: String) -> Result<Self, Self::Error> {
+        let data = obj.into_bytes();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
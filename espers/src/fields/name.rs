@@ -1,7 +1,6 @@
-use crate::common::check_done_reading;
-use crate::common::FormID;
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use binrw::{binrw, BinRead, NullString};
+use binrw::{binrw, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -71,6 +70,155 @@ macro_rules! impl_name {
                 Ok(result)
             }
         }
+
+        impl TryFrom<(u16, u16)> for $name {
+            type Error = Error;
+
+            fn try_from(obj: (u16, u16)) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<FormID> for $name {
+            type Error = Error;
+
+            fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<u32> for $name {
+            type Error = Error;
+
+            fn try_from(obj: u32) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+
+            fn try_from(obj: String) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                NullString::from(obj).write(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<Vec<u8>> for $name {
+            type Error = Error;
+
+            fn try_from(obj: Vec<u8>) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    size: obj.len() as u16,
+                    data: obj,
+                })
+            }
+        }
+
+        impl TryFrom<u16> for $name {
+            type Error = Error;
+
+            fn try_from(obj: u16) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<$name> for f32 {
+            type Error = Error;
+
+            fn try_from(raw: $name) -> Result<Self, Error> {
+                let mut cursor = Cursor::new(&raw.data);
+                let result = Self::read_le(&mut cursor)?;
+                check_done_reading(&mut cursor)?;
+                Ok(result)
+            }
+        }
+
+        impl TryFrom<f32> for $name {
+            type Error = Error;
+
+            fn try_from(obj: f32) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+
+        impl TryFrom<LocalizedString> for $name {
+            type Error = Error;
+
+            fn try_from(obj: LocalizedString) -> Result<Self, Self::Error> {
+                match obj {
+                    LocalizedString::Localized(l) => l.try_into(),
+                    LocalizedString::ZString(z) => z.try_into(),
+                }
+            }
+        }
+
+        impl TryFrom<$name> for [f32; 2] {
+            type Error = Error;
+
+            fn try_from(raw: $name) -> Result<Self, Error> {
+                let mut cursor = Cursor::new(&raw.data);
+                let result = Self::read_le(&mut cursor)?;
+                check_done_reading(&mut cursor)?;
+                Ok(result)
+            }
+        }
+
+        impl TryFrom<[f32; 2]> for $name {
+            type Error = Error;
+
+            fn try_from(obj: [f32; 2]) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
     };
 }
 
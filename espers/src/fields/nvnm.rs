@@ -0,0 +1,22 @@
+use super::XXXX;
+use binrw::binrw;
+use serde_derive::{Deserialize, Serialize};
+
+/// Navmesh geometry, which can outgrow a 16 bit size and then comes after an
+/// [XXXX] holding its real size
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NVNM {
+    #[br(temp, try)]
+    #[bw(calc = XXXX::for_len(data.len()))]
+    oversize: Option<XXXX>,
+
+    /// Recomputed from `data` when writing
+    #[brw(magic = b"NVNM")]
+    #[bw(map = |_: &u16| XXXX::field_size(data.len()))]
+    pub size: u16,
+
+    #[br(count = oversize.as_ref().map_or(size as u32, |x| x.data))]
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,50 @@
+use super::XXXX;
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, until_eof, BinWrite, Endian};
+use serde_derive::{Deserialize, Serialize};
+
+/// Worldspace cell offsets, which can outgrow a 16 bit size and then come
+/// after an [XXXX] holding their real size
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OFST {
+    #[br(temp, try)]
+    #[bw(calc = XXXX::for_len(data.len()))]
+    oversize: Option<XXXX>,
+
+    /// Recomputed from `data` when writing
+    #[brw(magic = b"OFST")]
+    #[bw(map = |_: &u16| XXXX::field_size(data.len()))]
+    pub size: u16,
+
+    #[br(count = oversize.as_ref().map_or(size as u32, |x| x.data))]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<OFST> for Vec<u32> {
+    type Error = Error;
+
+    fn try_from(raw: OFST) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = until_eof(&mut cursor, Endian::Little, ())?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<Vec<u32>> for OFST {
+    type Error = Error;
+
+    fn try_from(obj: Vec<u32>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: XXXX::field_size(data.len()),
+            data,
+        })
+    }
+}
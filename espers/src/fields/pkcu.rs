@@ -0,0 +1,12 @@
+use binrw::binrw;
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"PKCU")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PKCU {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
@@ -1,6 +1,6 @@
 use crate::common::FormID;
 use crate::error::Error;
-use binrw::{binrw, io::Cursor, BinRead};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 
 #[binrw]
@@ -20,3 +20,18 @@ impl TryInto<FormID> for PLCN {
         Ok(FormID::read_le(&mut Cursor::new(&self.data))?)
     }
 }
+
+impl TryFrom<FormID> for PLCN {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
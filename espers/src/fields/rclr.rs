@@ -0,0 +1,41 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
+use rgb::RGBA8;
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"RCLR")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RCLR {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<RCLR> for RGBA8 {
+    type Error = Error;
+
+    fn try_from(raw: RCLR) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result: [u8; 4] = BinRead::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result.into())
+    }
+}
+
+impl TryFrom<RGBA8> for RCLR {
+    type Error = Error;
+
+    fn try_from(obj: RGBA8) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        [obj.r, obj.g, obj.b, obj.a].write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use binrw::binrw;
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"SCTX")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SCTX {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
@@ -61,3 +61,95 @@ impl TryFrom<FormID> for SNAM {
         })
     }
 }
+
+impl TryFrom<Vec<u8>> for SNAM {
+    type Error = Error;
+
+    fn try_from(obj: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            size: obj.len() as u16,
+            data: obj,
+        })
+    }
+}
+
+impl TryFrom<SNAM> for u32 {
+    type Error = Error;
+
+    fn try_from(raw: SNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<u32> for SNAM {
+    type Error = Error;
+
+    fn try_from(obj: u32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<SNAM> for f32 {
+    type Error = Error;
+
+    fn try_from(raw: SNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<f32> for SNAM {
+    type Error = Error;
+
+    fn try_from(obj: f32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<SNAM> for Vec<FormID> {
+    type Error = Error;
+
+    fn try_from(raw: SNAM) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(raw.data);
+        let mut result = Vec::new();
+        while let Ok(x) = FormID::read_le(&mut cursor) {
+            result.push(x);
+        }
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<Vec<FormID>> for SNAM {
+    type Error = Error;
+
+    fn try_from(obj: Vec<FormID>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -0,0 +1,108 @@
+use crate::common::{check_done_reading, FormID};
+use crate::error::Error;
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
+use serde_derive::{Deserialize, Serialize};
+use std::io::Cursor;
+
+#[binrw]
+#[brw(little, magic = b"SPIT")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SPIT {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct SpellFlags: u32 {
+        const MANUAL_COST_CALC = 0x00000001;
+        const PC_START_SPELL = 0x00020000;
+        const AREA_EFFECT_IGNORES_LOS = 0x00080000;
+        const IGNORE_RESISTANCE = 0x00100000;
+        const NO_ABSORB_REFLECT = 0x00200000;
+        const NO_DUAL_CAST_MODIFICATIONS = 0x00800000;
+    }
+}
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpellType {
+    Spell = 0,
+    Disease = 1,
+    Power = 2,
+    LesserPower = 3,
+    Ability = 4,
+    Poison = 5,
+    Addiction = 10,
+    Voice = 11,
+}
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CastType {
+    ConstantEffect = 0,
+    FireAndForget,
+    Concentration,
+    Scroll,
+}
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Delivery {
+    OnSelf = 0,
+    Touch,
+    Aimed,
+    TargetActor,
+    TargetLocation,
+}
+
+/// Spell data (SPIT), shared by [SPEL](crate::records::Spell) and
+/// [SCRL](crate::records::Scroll)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpellItem {
+    pub cost: u32,
+    pub flags: SpellFlags,
+    pub kind: SpellType,
+    pub charge_time: f32,
+    pub cast_type: CastType,
+    pub delivery: Delivery,
+    pub cast_duration: f32,
+    pub range: f32,
+    pub half_cost_perk: FormID,
+}
+
+impl TryFrom<SPIT> for SpellItem {
+    type Error = Error;
+
+    fn try_from(raw: SPIT) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<SpellItem> for SPIT {
+    type Error = Error;
+
+    fn try_from(obj: SpellItem) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
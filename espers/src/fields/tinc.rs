@@ -0,0 +1,67 @@
+use crate::common::{check_done_reading, FormID};
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
+use rgb::RGBA8;
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"TINC")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TINC {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<TINC> for RGBA8 {
+    type Error = Error;
+
+    fn try_from(raw: TINC) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result: [u8; 4] = BinRead::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result.into())
+    }
+}
+
+impl TryFrom<RGBA8> for TINC {
+    type Error = Error;
+
+    fn try_from(obj: RGBA8) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        [obj.r, obj.g, obj.b, obj.a].write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+impl TryFrom<TINC> for FormID {
+    type Error = Error;
+
+    fn try_from(raw: TINC) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<FormID> for TINC {
+    type Error = Error;
+
+    fn try_from(obj: FormID) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::error::Error;
 use binrw::binrw;
 use serde_derive::{Deserialize, Serialize};
 
@@ -10,3 +11,14 @@ pub struct TVDT {
     #[br(count = size)]
     pub data: Vec<u8>,
 }
+
+impl TryFrom<Vec<u8>> for TVDT {
+    type Error = Error;
+
+    fn try_from(obj: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            size: obj.len() as u16,
+            data: obj,
+        })
+    }
+}
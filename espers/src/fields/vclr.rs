@@ -0,0 +1,39 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, until_eof, Endian};
+use rgb::RGB8;
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"VCLR")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VCLR {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<VCLR> for Vec<RGB8> {
+    type Error = Error;
+
+    fn try_from(raw: VCLR) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result: Vec<[u8; 3]> = until_eof(&mut cursor, Endian::Little, ())?;
+        check_done_reading(&mut cursor)?;
+        Ok(result.into_iter().map(Into::into).collect())
+    }
+}
+
+impl TryFrom<Vec<RGB8>> for VCLR {
+    type Error = Error;
+
+    fn try_from(obj: Vec<RGB8>) -> Result<Self, Self::Error> {
+        let data: Vec<u8> = obj.into_iter().flat_map(|c| [c.r, c.g, c.b]).collect();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID, WString};
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::{Cursor, Read};
 
@@ -55,7 +55,7 @@ pub enum RawPropertyType {
         #[br(count = count)]
         data: Vec<f32>,
     },
-    #[brw(magic = 14u8)]
+    #[brw(magic = 15u8)]
     BoolList {
         status: u8,
         count: u32,
@@ -134,9 +134,216 @@ pub struct PerkFragmentList {
 
 #[binrw]
 #[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fragment {
+    pub unknown: i8,
+    pub script_name: WString,
+    pub fragment_name: WString,
+}
+
+/// Begin (0x01) and end (0x02) fragments of a dialogue response, present
+/// according to `flags`
+#[binrw]
+#[brw(little, magic = 2u8)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicInfoFragmentList {
+    pub flags: u8,
+    pub filename: WString,
+    #[br(count = flags.count_ones())]
+    pub fragments: Vec<Fragment>,
+}
+
+/// Begin (0x01), end (0x02) and change (0x04) fragments of an AI package,
+/// present according to `flags`
+#[binrw]
+#[brw(little, magic = 2u8)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageFragmentList {
+    pub flags: u8,
+    pub filename: WString,
+    #[br(count = flags.count_ones())]
+    pub fragments: Vec<Fragment>,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseFragment {
+    /// On start (0x01) or on completion (0x02)
+    pub flags: u8,
+    pub phase: u8,
+    pub unknown1: i16,
+    pub unknown2: i8,
+    pub script_name: WString,
+    pub fragment_name: WString,
+}
+
+/// Begin (0x01) and end (0x02) fragments of a scene, present according to
+/// `flags`, followed by the fragments run by its phases
+#[binrw]
+#[brw(little, magic = 2u8)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneFragmentList {
+    pub flags: u8,
+    pub filename: WString,
+    #[br(count = flags.count_ones())]
+    pub fragments: Vec<Fragment>,
+    pub phase_fragment_count: u16,
+    #[br(count = phase_fragment_count)]
+    pub phase_fragments: Vec<PhaseFragment>,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestFragment {
+    pub stage: u16,
+    pub unknown1: i16,
+    pub log_entry: i32,
+    pub unknown2: i8,
+    pub script_name: WString,
+    pub fragment_name: WString,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawQuestAlias {
+    pub object: [u32; 2],
+    pub version: u16,
+    pub object_format: u16,
+    pub script_count: u16,
+
+    #[br(count = script_count)]
+    pub scripts: Vec<RawScript>,
+}
+
+#[binrw]
+#[brw(little, magic = 2u8)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawQuestFragmentList {
+    pub fragment_count: u16,
+    pub filename: WString,
+
+    #[br(count = fragment_count)]
+    pub fragments: Vec<QuestFragment>,
+
+    pub alias_count: u16,
+
+    #[br(count = alias_count)]
+    pub aliases: Vec<RawQuestAlias>,
+}
+
+/// Scripts attached to a quest alias
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestAlias {
+    pub object: Property,
+    pub version: u16,
+    pub object_format: u16,
+    pub scripts: Vec<Script>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestFragmentList {
+    pub filename: String,
+    pub fragments: Vec<QuestFragment>,
+    pub aliases: Vec<QuestAlias>,
+}
+
+impl TryFrom<RawQuestFragmentList> for QuestFragmentList {
+    type Error = Error;
+
+    fn try_from(raw: RawQuestFragmentList) -> Result<Self, Self::Error> {
+        let aliases = raw
+            .aliases
+            .into_iter()
+            .map(|a| -> Result<QuestAlias, Error> {
+                Ok(QuestAlias {
+                    object: unpack_object(a.object, a.object_format)?,
+                    version: a.version,
+                    object_format: a.object_format,
+                    scripts: a
+                        .scripts
+                        .into_iter()
+                        .map(|s| load_script(s, a.object_format))
+                        .collect::<Result<Vec<_>, _>>()?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            filename: raw.filename.to_string(),
+            fragments: raw.fragments,
+            aliases,
+        })
+    }
+}
+
+impl TryFrom<QuestFragmentList> for RawQuestFragmentList {
+    type Error = Error;
+
+    fn try_from(obj: QuestFragmentList) -> Result<Self, Self::Error> {
+        let aliases = obj
+            .aliases
+            .into_iter()
+            .map(|a| -> Result<RawQuestAlias, Error> {
+                let scripts = a
+                    .scripts
+                    .into_iter()
+                    .map(raw_script)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(RawQuestAlias {
+                    object: pack_object(a.object)?,
+                    version: a.version,
+                    object_format: a.object_format,
+                    script_count: scripts.len() as u16,
+                    scripts,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            fragment_count: obj.fragments.len() as u16,
+            filename: obj.filename.into(),
+            fragments: obj.fragments,
+            alias_count: aliases.len() as u16,
+            aliases,
+        })
+    }
+}
+
+/// Fragment section following the scripts, whose layout depends on the type
+/// of record the VMAD belongs to
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum FragmentList {
     Perk(PerkFragmentList),
+    Quest(QuestFragmentList),
+    TopicInfo(TopicInfoFragmentList),
+    Package(PackageFragmentList),
+    Scene(SceneFragmentList),
+}
+
+impl FragmentList {
+    fn load(cursor: &mut Cursor<&Vec<u8>>, record: &[u8; 4]) -> Result<Self, Error> {
+        match record {
+            b"QUST" => Ok(Self::Quest(RawQuestFragmentList::read(cursor)?.try_into()?)),
+            b"INFO" => Ok(Self::TopicInfo(TopicInfoFragmentList::read(cursor)?)),
+            b"PACK" => Ok(Self::Package(PackageFragmentList::read(cursor)?)),
+            b"SCEN" => Ok(Self::Scene(SceneFragmentList::read(cursor)?)),
+            _ => Ok(Self::Perk(PerkFragmentList::read(cursor)?)),
+        }
+    }
+
+    fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        match self {
+            Self::Perk(p) => p.write(cursor)?,
+            Self::Quest(q) => RawQuestFragmentList::try_from(q)?.write(cursor)?,
+            Self::TopicInfo(t) => t.write(cursor)?,
+            Self::Package(p) => p.write(cursor)?,
+            Self::Scene(s) => s.write(cursor)?,
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -197,6 +404,31 @@ pub enum Property {
     PackedBool {
         value: bool,
     },
+    ObjectList {
+        name: String,
+        status: u8,
+        values: Vec<Property>,
+    },
+    StringList {
+        name: String,
+        status: u8,
+        values: Vec<Property>,
+    },
+    IntList {
+        name: String,
+        status: u8,
+        values: Vec<Property>,
+    },
+    FloatList {
+        name: String,
+        status: u8,
+        values: Vec<Property>,
+    },
+    BoolList {
+        name: String,
+        status: u8,
+        values: Vec<Property>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -214,112 +446,350 @@ pub struct ScriptList {
     pub fragments: Option<FragmentList>,
 }
 
-fn helper(p: RawProperty, object_format: u16) -> Vec<Property> {
-    match (p.kind, object_format) {
-        (RawPropertyType::Object { status, data }, 1) => vec![Property::ObjectV1 {
-            name: String::from_utf8_lossy(&p.name).into(),
+fn invalid_object_format(object_format: u16) -> Error {
+    Error::InvalidProperty(format!("Invalid Object Format Version: {}", object_format))
+}
+
+fn unpack_object(data: [u32; 2], object_format: u16) -> Result<Property, Error> {
+    match object_format {
+        1 => Ok(Property::PackedObjectV1 {
+            form_id: FormID(data[0]),
+            alias: (data[1] & 0xFFFF) as i16,
+            unused: (data[1] >> 0x10) as u16,
+        }),
+        2 => Ok(Property::PackedObjectV2 {
+            unused: (data[0] & 0xFFFF) as u16,
+            alias: (data[0] >> 0x10) as i16,
+            form_id: FormID(data[1]),
+        }),
+        _ => Err(invalid_object_format(object_format)),
+    }
+}
+
+fn helper(p: RawProperty, object_format: u16) -> Result<Property, Error> {
+    let name = String::from_utf8_lossy(&p.name).into();
+    Ok(match (p.kind, object_format) {
+        (RawPropertyType::Object { status, data }, 1) => Property::ObjectV1 {
+            name,
             status,
             form_id: FormID(data[0]),
             alias: (data[1] & 0xFFFF) as i16,
             unused: (data[1] >> 0x10) as u16,
-        }],
-        (RawPropertyType::Object { status, data }, 2) => vec![Property::ObjectV2 {
-            name: String::from_utf8_lossy(&p.name).into(),
+        },
+        (RawPropertyType::Object { status, data }, 2) => Property::ObjectV2 {
+            name,
             status,
             unused: (data[0] & 0xFFFF) as u16,
             alias: (data[0] >> 0x10) as i16,
             form_id: FormID(data[1]),
-        }],
-        (RawPropertyType::Object { .. }, _) => {
-            unreachable!("Invalid Object Format Version")
-        }
-        (RawPropertyType::String { status, data }, _) => vec![Property::String {
-            name: String::from_utf8_lossy(&p.name).into(),
+        },
+        (RawPropertyType::Object { .. }, _) => return Err(invalid_object_format(object_format)),
+        (RawPropertyType::String { status, data }, _) => Property::String {
+            name,
             status,
             value: data.to_string(),
-        }],
-        (RawPropertyType::Int { status, data }, _) => vec![Property::Int {
-            name: String::from_utf8_lossy(&p.name).into(),
+        },
+        (RawPropertyType::Int { status, data }, _) => Property::Int {
+            name,
             status,
             value: data,
-        }],
-        (RawPropertyType::Float { status, data }, _) => vec![Property::Float {
-            name: String::from_utf8_lossy(&p.name).into(),
+        },
+        (RawPropertyType::Float { status, data }, _) => Property::Float {
+            name,
             status,
             value: data,
-        }],
-        (RawPropertyType::Bool { status, data }, _) => vec![Property::Bool {
-            name: String::from_utf8_lossy(&p.name).into(),
+        },
+        (RawPropertyType::Bool { status, data }, _) => Property::Bool {
+            name,
             status,
             value: data == 1,
-        }],
-        (RawPropertyType::ObjectList { data, .. }, 1) => data
-            .into_iter()
-            .map(|data| Property::PackedObjectV1 {
-                form_id: FormID(data[0]),
-                alias: (data[1] & 0xFFFF) as i16,
-                unused: (data[1] >> 0x10) as u16,
-            })
-            .collect(),
-        (RawPropertyType::ObjectList { data, .. }, 2) => data
-            .into_iter()
-            .map(|data| Property::PackedObjectV2 {
-                unused: (data[0] & 0xFFFF) as u16,
-                alias: (data[0] >> 0x10) as i16,
-                form_id: FormID(data[1]),
-            })
-            .collect(),
-        (RawPropertyType::ObjectList { .. }, _) => {
-            unreachable!("Invalid Object Format Version")
+        },
+        (RawPropertyType::ObjectList { status, data, .. }, _) => Property::ObjectList {
+            name,
+            status,
+            values: data
+                .into_iter()
+                .map(|data| unpack_object(data, object_format))
+                .collect::<Result<Vec<_>, _>>()?,
+        },
+        (RawPropertyType::StringList { status, data, .. }, _) => Property::StringList {
+            name,
+            status,
+            values: data
+                .into_iter()
+                .map(|value| Property::PackedString {
+                    value: value.to_string(),
+                })
+                .collect(),
+        },
+        (RawPropertyType::IntList { status, data, .. }, _) => Property::IntList {
+            name,
+            status,
+            values: data
+                .into_iter()
+                .map(|value| Property::PackedInt { value })
+                .collect(),
+        },
+        (RawPropertyType::FloatList { status, data, .. }, _) => Property::FloatList {
+            name,
+            status,
+            values: data
+                .into_iter()
+                .map(|value| Property::PackedFloat { value })
+                .collect(),
+        },
+        (RawPropertyType::BoolList { status, data, .. }, _) => Property::BoolList {
+            name,
+            status,
+            values: data
+                .into_iter()
+                .map(|value| Property::PackedBool { value: value == 1 })
+                .collect(),
+        },
+    })
+}
+
+fn pack_object(p: Property) -> Result<[u32; 2], Error> {
+    match p {
+        Property::ObjectV1 {
+            form_id,
+            alias,
+            unused,
+            ..
         }
-        (RawPropertyType::StringList { data, .. }, _) => data
-            .into_iter()
-            .map(|value| Property::PackedString {
-                value: value.to_string(),
-            })
-            .collect(),
-        (RawPropertyType::IntList { data, .. }, _) => data
-            .into_iter()
-            .map(|value| Property::PackedInt { value })
-            .collect(),
-        (RawPropertyType::FloatList { data, .. }, _) => data
-            .into_iter()
-            .map(|value| Property::PackedFloat { value })
-            .collect(),
-        (RawPropertyType::BoolList { data, .. }, _) => data
-            .into_iter()
-            .map(|value| Property::PackedBool { value: value == 1 })
-            .collect(),
+        | Property::PackedObjectV1 {
+            form_id,
+            alias,
+            unused,
+        } => Ok([form_id.0, (alias as u16 as u32) | ((unused as u32) << 0x10)]),
+        Property::ObjectV2 {
+            unused,
+            alias,
+            form_id,
+            ..
+        }
+        | Property::PackedObjectV2 {
+            unused,
+            alias,
+            form_id,
+        } => Ok([(unused as u32) | ((alias as u16 as u32) << 0x10), form_id.0]),
+        other => Err(Error::InvalidProperty(format!("{:?}", other))),
     }
 }
 
-impl TryInto<ScriptList> for VMAD {
+fn unpack_values<T>(values: Vec<Property>, f: fn(Property) -> Option<T>) -> Result<Vec<T>, Error> {
+    values
+        .into_iter()
+        .map(|v| f(v.clone()).ok_or_else(|| Error::InvalidProperty(format!("{:?}", v))))
+        .collect()
+}
+
+impl TryFrom<Property> for RawProperty {
     type Error = Error;
 
-    fn try_into(self) -> Result<ScriptList, Self::Error> {
+    fn try_from(obj: Property) -> Result<Self, Self::Error> {
+        let (name, kind) = match obj {
+            Property::ObjectV1 {
+                ref name, status, ..
+            }
+            | Property::ObjectV2 {
+                ref name, status, ..
+            } => (
+                name.clone(),
+                RawPropertyType::Object {
+                    status,
+                    data: pack_object(obj.clone())?,
+                },
+            ),
+            Property::String {
+                name,
+                status,
+                value,
+            } => (
+                name,
+                RawPropertyType::String {
+                    status,
+                    data: value.into(),
+                },
+            ),
+            Property::Int {
+                name,
+                status,
+                value,
+            } => (
+                name,
+                RawPropertyType::Int {
+                    status,
+                    data: value,
+                },
+            ),
+            Property::Float {
+                name,
+                status,
+                value,
+            } => (
+                name,
+                RawPropertyType::Float {
+                    status,
+                    data: value,
+                },
+            ),
+            Property::Bool {
+                name,
+                status,
+                value,
+            } => (
+                name,
+                RawPropertyType::Bool {
+                    status,
+                    data: value as u8,
+                },
+            ),
+            Property::ObjectList {
+                name,
+                status,
+                values,
+            } => {
+                let data = values
+                    .into_iter()
+                    .map(pack_object)
+                    .collect::<Result<Vec<_>, _>>()?;
+                (
+                    name,
+                    RawPropertyType::ObjectList {
+                        status,
+                        count: data.len() as u32,
+                        data,
+                    },
+                )
+            }
+            Property::StringList {
+                name,
+                status,
+                values,
+            } => {
+                let data = unpack_values(values, |v| match v {
+                    Property::PackedString { value } => Some(value.into()),
+                    _ => None,
+                })?;
+                (
+                    name,
+                    RawPropertyType::StringList {
+                        status,
+                        count: data.len() as u32,
+                        data,
+                    },
+                )
+            }
+            Property::IntList {
+                name,
+                status,
+                values,
+            } => {
+                let data = unpack_values(values, |v| match v {
+                    Property::PackedInt { value } => Some(value),
+                    _ => None,
+                })?;
+                (
+                    name,
+                    RawPropertyType::IntList {
+                        status,
+                        count: data.len() as u32,
+                        data,
+                    },
+                )
+            }
+            Property::FloatList {
+                name,
+                status,
+                values,
+            } => {
+                let data = unpack_values(values, |v| match v {
+                    Property::PackedFloat { value } => Some(value),
+                    _ => None,
+                })?;
+                (
+                    name,
+                    RawPropertyType::FloatList {
+                        status,
+                        count: data.len() as u32,
+                        data,
+                    },
+                )
+            }
+            Property::BoolList {
+                name,
+                status,
+                values,
+            } => {
+                let data = unpack_values(values, |v| match v {
+                    Property::PackedBool { value } => Some(value as u8),
+                    _ => None,
+                })?;
+                (
+                    name,
+                    RawPropertyType::BoolList {
+                        status,
+                        count: data.len() as u32,
+                        data,
+                    },
+                )
+            }
+            other => return Err(Error::InvalidProperty(format!("{:?}", other))),
+        };
+
+        Ok(Self {
+            name_size: name.len() as u16,
+            name: name.into_bytes(),
+            kind,
+        })
+    }
+}
+
+fn load_script(s: RawScript, object_format: u16) -> Result<Script, Error> {
+    Ok(Script {
+        name: String::from_utf8_lossy(&s.name).into(),
+        status: s.status,
+        properties: s
+            .properties
+            .into_iter()
+            .map(|p| helper(p, object_format))
+            .collect::<Result<Vec<_>, _>>()?,
+    })
+}
+
+fn raw_script(s: Script) -> Result<RawScript, Error> {
+    let properties = s
+        .properties
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<Vec<RawProperty>, _>>()?;
+    Ok(RawScript {
+        name_size: s.name.len() as u16,
+        name: s.name.into_bytes(),
+        status: s.status,
+        property_count: properties.len() as u16,
+        properties,
+    })
+}
+
+impl VMAD {
+    /// Parses the scripts, along with the fragment section in the layout used
+    /// by `record` (INFO, PACK, PERK, QUST and SCEN are supported)
+    pub fn parse(self, record: &[u8; 4]) -> Result<ScriptList, Error> {
         let mut cursor = Cursor::new(&self.data);
         let raw_scripts = RawScriptList::read(&mut cursor)?;
-        let scripts: Result<_, _> = raw_scripts
+        let scripts = raw_scripts
             .scripts
             .into_iter()
-            .map(|s| -> Result<Script, Error> {
-                Ok(Script {
-                    name: String::from_utf8_lossy(&s.name).into(),
-                    status: s.status,
-                    properties: s
-                        .properties
-                        .into_iter()
-                        .flat_map(|p| helper(p, raw_scripts.object_format))
-                        .collect(),
-                })
-            })
-            .collect();
+            .map(|s| load_script(s, raw_scripts.object_format))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut fragments = Vec::new();
         cursor.read_to_end(&mut fragments)?;
         let fragments = if !fragments.is_empty() {
             let mut cursor = Cursor::new(&fragments);
-            let fragments = FragmentList::read(&mut cursor)?;
+            let fragments = FragmentList::load(&mut cursor, record)?;
             check_done_reading(&mut cursor)?;
             Some(fragments)
         } else {
@@ -331,8 +801,48 @@ impl TryInto<ScriptList> for VMAD {
         Ok(ScriptList {
             version: raw_scripts.version,
             object_format: raw_scripts.object_format,
-            scripts: scripts?,
+            scripts,
             fragments,
         })
     }
 }
+
+impl TryInto<ScriptList> for VMAD {
+    type Error = Error;
+
+    /// Parses records without a record-specific fragment section; any
+    /// fragments are read as perk fragments
+    fn try_into(self) -> Result<ScriptList, Self::Error> {
+        self.parse(b"PERK")
+    }
+}
+
+impl TryFrom<ScriptList> for VMAD {
+    type Error = Error;
+
+    fn try_from(obj: ScriptList) -> Result<Self, Self::Error> {
+        let scripts = obj
+            .scripts
+            .into_iter()
+            .map(raw_script)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut cursor = Cursor::new(Vec::new());
+        RawScriptList {
+            version: obj.version,
+            object_format: obj.object_format,
+            script_count: scripts.len() as u16,
+            scripts,
+        }
+        .write(&mut cursor)?;
+        if let Some(f) = obj.fragments {
+            f.write(&mut cursor)?;
+        }
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, io::Cursor, until_eof, BinWrite, Endian};
+use serde_derive::{Deserialize, Serialize};
+
+#[binrw]
+#[brw(little, magic = b"VNML")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VNML {
+    pub size: u16,
+
+    #[br(count = size)]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<VNML> for Vec<[i8; 3]> {
+    type Error = Error;
+
+    fn try_from(raw: VNML) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = until_eof(&mut cursor, Endian::Little, ())?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<Vec<[i8; 3]>> for VNML {
+    type Error = Error;
+
+    fn try_from(obj: Vec<[i8; 3]>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::io::Cursor;
 
@@ -36,3 +36,18 @@ impl TryFrom<WLST> for Vec<Weather> {
         Ok(result)
     }
 }
+
+impl TryFrom<Vec<Weather>> for WLST {
+    type Error = Error;
+
+    fn try_from(obj: Vec<Weather>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
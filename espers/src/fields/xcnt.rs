@@ -1,5 +1,8 @@
-use binrw::binrw;
+use crate::common::check_done_reading;
+use crate::error::Error;
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
+use std::io::Cursor;
 
 #[binrw]
 #[brw(little, magic = b"XCNT")]
@@ -10,3 +13,29 @@ pub struct XCNT {
     #[br(count = size)]
     pub data: Vec<u8>,
 }
+
+impl TryFrom<XCNT> for i32 {
+    type Error = Error;
+
+    fn try_from(raw: XCNT) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_le(&mut cursor)?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<i32> for XCNT {
+    type Error = Error;
+
+    fn try_from(obj: i32) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use binrw::binrw;
+use serde_derive::{Deserialize, Serialize};
+
+/// Size of the subrecord that follows, for subrecords too large for their own
+/// 16 bit size, which is then written as 0
+#[binrw]
+#[brw(little, magic = b"XXXX")]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct XXXX {
+    pub size: u16,
+    pub data: u32,
+}
+
+impl XXXX {
+    /// The XXXX to write before a subrecord holding `len` bytes, if it needs one
+    pub fn for_len(len: usize) -> Option<Self> {
+        (len > u16::MAX as usize).then_some(Self {
+            size: 4,
+            data: len as u32,
+        })
+    }
+
+    /// The size to write in a subrecord holding `len` bytes
+    pub fn field_size(len: usize) -> u16 {
+        u16::try_from(len).unwrap_or(0)
+    }
+}
@@ -1,67 +1,357 @@
 use crate::common::FormID;
-use crate::error::Error;
+use crate::conflict::{diff, flatten, identical, ConflictIndex, FieldDiff, Fields};
+use crate::error::{Error, MasterError};
 use crate::plugin::Plugin;
-use crate::records::Record;
+use crate::records::{tes4::Flags, Record};
 use crate::string_table::StringTables;
 use glob::glob;
 use std::collections::HashMap;
-use std::fs::File;
-use std::path::PathBuf;
+use std::fs::{read_to_string, File};
+use std::path::{Path, PathBuf};
+
+/// Where a plugin's records live in the global FormID space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadIndex {
+    /// Regular plugin, addressed as `0xXX000000`
+    Full(u8),
+
+    /// Light plugin, addressed as `0xFEXXX000`
+    Light(u16),
+}
+
+impl LoadIndex {
+    /// Maps an object index within the plugin to a global FormID
+    pub fn form_id(&self, object: u32) -> FormID {
+        match self {
+            LoadIndex::Full(i) => FormID(((*i as u32) << 24) | (object & 0x00FFFFFF)),
+            LoadIndex::Light(i) => FormID(0xFE000000 | ((*i as u32) << 12) | (object & 0x00000FFF)),
+        }
+    }
+
+    /// Returns the object index if the global FormID belongs to this plugin
+    pub fn object(&self, fid: &FormID) -> Option<u32> {
+        match self {
+            LoadIndex::Full(i) if fid.0 >> 24 == *i as u32 => Some(fid.0 & 0x00FFFFFF),
+            LoadIndex::Light(i) if fid.0 >> 12 == 0xFE000 | *i as u32 => Some(fid.0 & 0x00000FFF),
+            _ => None,
+        }
+    }
+}
+
+/// Highest load index of a regular plugin. 0xFE is the light plugin prefix
+/// and 0xFF is reserved for FormIDs created at runtime.
+const FULL_MAX: u32 = 0xFD;
+
+/// Highest load index of a light plugin
+const LIGHT_MAX: u32 = 0xFFF;
 
 pub struct Game {
     plugins: HashMap<String, Plugin>,
+    load_order: Vec<String>,
+    load_indices: HashMap<String, LoadIndex>,
     string_tables: StringTables,
+    conflicts: ConflictIndex,
+}
+
+fn is_light(name: &str, plugin: &Plugin) -> bool {
+    plugin.header.header.flags.contains(Flags::LIGHT_MASTER)
+        || Path::new(name)
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("esl"))
+}
+
+/// Masters the game loads without them being listed in `plugins.txt`, in the
+/// order it loads them
+pub const IMPLICIT_MASTERS: [&str; 5] = [
+    "Skyrim.esm",
+    "Update.esm",
+    "Dawnguard.esm",
+    "HearthFires.esm",
+    "Dragonborn.esm",
+];
+
+/// Parses the contents of a `plugins.txt` or `loadorder.txt` file
+pub fn parse_load_order(contents: &str) -> Vec<String> {
+    let lines: Vec<_> = contents
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .collect();
+
+    if lines.iter().any(|l| l.starts_with('*')) {
+        lines
+            .into_iter()
+            .filter_map(|l| l.strip_prefix('*'))
+            .map(String::from)
+            .collect()
+    } else {
+        lines.into_iter().map(String::from).collect()
+    }
+}
+
+/// Checks that every plugin's masters are loaded before it
+fn check_masters(plugins: &[(String, Plugin)]) -> Result<(), Error> {
+    let position = |name: &str| {
+        plugins
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+    };
+    let mut errors = Vec::new();
+
+    for (i, (name, plugin)) in plugins.iter().enumerate() {
+        for master in &plugin.header.masters {
+            let (plugin, master) = (name.clone(), master.clone());
+            match position(&master) {
+                None => errors.push(MasterError::Missing { plugin, master }),
+                Some(j) if j > i => errors.push(MasterError::OutOfOrder { plugin, master }),
+                _ => {}
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidLoadOrder(errors))
+    }
+}
+
+fn is_master(name: &str, plugin: &Plugin) -> bool {
+    plugin.header.header.flags.contains(Flags::MASTER)
+        || Path::new(name)
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("esm") || e.eq_ignore_ascii_case("esl"))
 }
 
 impl Game {
     pub fn load(paths: &[&str], language: &str) -> Result<Self, Error> {
         let mut string_tables = StringTables::new();
-        let mut plugins = HashMap::new();
+        let mut plugins = Vec::new();
 
         for path in paths {
             let g = PathBuf::from(path)
-                .join("*.es[mp]")
+                .join("*.es[lmp]")
                 .to_string_lossy()
                 .to_string();
             for plugin_path in glob(&g).unwrap() {
                 let pp = plugin_path.unwrap();
-                println!("Loading {}", pp.display());
                 let plugin = Plugin::parse(&mut File::open(&pp)?)?;
                 string_tables.load_plugin_path(pp.display().to_string().as_ref(), language)?;
 
-                plugins.insert(
+                plugins.push((
                     pp.file_name().unwrap().to_string_lossy().to_string(),
                     plugin,
-                );
+                ));
             }
         }
 
-        Ok(Self {
-            plugins,
+        // Without an explicit load order, masters load before everything else
+        plugins.sort_by_key(|(name, p)| !is_master(name, p));
+
+        Self::from_load_order(plugins, string_tables)
+    }
+
+    /// Loads the plugins in `data_dir` listed in a `plugins.txt` or
+    /// `loadorder.txt` file, in the order they are listed. In a `plugins.txt`
+    /// using `*` markers, only the marked (active) plugins are loaded.
+    ///
+    /// The game always loads the [IMPLICIT_MASTERS] present in `data_dir`
+    /// first, and `plugins.txt` leaves them out, so any that are not listed
+    /// are loaded before the listed plugins.
+    pub fn load_from_load_order(
+        data_dir: &str,
+        load_order_path: &str,
+        language: &str,
+    ) -> Result<Self, Error> {
+        let listed = parse_load_order(&read_to_string(load_order_path)?);
+        let mut names: Vec<_> = IMPLICIT_MASTERS
+            .iter()
+            .filter(|m| !listed.iter().any(|l| l.eq_ignore_ascii_case(m)))
+            .filter(|m| Path::new(data_dir).join(m).exists())
+            .map(|m| m.to_string())
+            .collect();
+        names.extend(listed);
+        let mut string_tables = StringTables::new();
+        let mut plugins = Vec::new();
+
+        for name in names {
+            let pp = PathBuf::from(data_dir).join(&name);
+            let plugin = Plugin::parse(&mut File::open(&pp)?)?;
+            string_tables.load_plugin_path(pp.display().to_string().as_ref(), language)?;
+            plugins.push((name, plugin));
+        }
+
+        check_masters(&plugins)?;
+
+        Self::from_load_order(plugins, string_tables)
+    }
+
+    /// Builds a game from plugins that are already in load order. Fails if
+    /// there are more regular or light plugins than the FormID space can
+    /// address.
+    pub fn from_load_order(
+        plugins: Vec<(String, Plugin)>,
+        string_tables: StringTables,
+    ) -> Result<Self, Error> {
+        let mut load_order = Vec::new();
+        let mut load_indices = HashMap::new();
+        let mut full = 0u32;
+        let mut light = 0u32;
+
+        for (name, plugin) in &plugins {
+            let index = match is_light(name, plugin) {
+                true if light <= LIGHT_MAX => {
+                    light += 1;
+                    LoadIndex::Light(light as u16 - 1)
+                }
+                false if full <= FULL_MAX => {
+                    full += 1;
+                    LoadIndex::Full(full as u8 - 1)
+                }
+                _ => return Err(Error::LoadIndexOverflow(name.clone())),
+            };
+            load_order.push(name.clone());
+            load_indices.insert(name.to_lowercase(), index);
+        }
+
+        let mut game = Self {
+            plugins: plugins.into_iter().collect(),
+            load_order,
+            load_indices,
             string_tables,
-        })
+            conflicts: ConflictIndex::default(),
+        };
+        game.conflicts = game.build_conflicts();
+        Ok(game)
+    }
+
+    fn build_conflicts(&self) -> ConflictIndex {
+        let mut index = ConflictIndex::default();
+
+        for name in &self.load_order {
+            for fid in self.plugins[name].form_ids.keys() {
+                if let Some(global) = self.global_form_id(name, &FormID(*fid)) {
+                    index.insert(global, name);
+                }
+            }
+        }
+
+        for conflict in index.entries_mut() {
+            for i in 1..conflict.versions.len() {
+                let plugin = &conflict.versions[i].plugin;
+                let masters = &self.plugins[plugin].header.masters;
+
+                // Compare against the latest version from one of the plugin's
+                // masters, falling back to the previous version
+                let base = (0..i)
+                    .rev()
+                    .find(|j| {
+                        masters
+                            .iter()
+                            .any(|m| m.eq_ignore_ascii_case(&conflict.versions[*j].plugin))
+                    })
+                    .unwrap_or(i - 1);
+
+                let this = self.fields(plugin, &conflict.form_id);
+                let base = self.fields(&conflict.versions[base].plugin, &conflict.form_id);
+                conflict.versions[i].itm = match (this, base) {
+                    (Some(a), Some(b)) => identical(&a, &b),
+                    _ => false,
+                };
+            }
+        }
+
+        index
+    }
+
+    /// Flattens a plugin's version of a record, with load order FormIDs
+    fn fields(&self, plugin: &str, fid: &FormID) -> Option<Fields> {
+        let local = self.local_form_id(plugin, fid)?;
+        let record = self.plugins[plugin]
+            .get_record_by_form_id(&local)?
+            .as_ref()
+            .ok()?;
+
+        Some(flatten(record, &|f| match f.0 {
+            0 => f.to_string(),
+            _ => match self.global_form_id(plugin, &f) {
+                Some(g) => g.to_string(),
+                None => format!("{} (unresolved in {})", f, plugin),
+            },
+        }))
     }
 
     pub fn plugins(&self) -> &HashMap<String, Plugin> {
         &self.plugins
     }
 
+    /// Plugin names, in the order they were loaded
+    pub fn load_order(&self) -> &[String] {
+        &self.load_order
+    }
+
+    pub fn load_index(&self, plugin: &str) -> Option<LoadIndex> {
+        self.load_indices.get(&plugin.to_lowercase()).copied()
+    }
+
     pub fn string_tables(&self) -> &StringTables {
         &self.string_tables
     }
 
-    pub fn get_record_by_form_id(&self, fid: &FormID) -> Option<&Result<Record, Error>> {
-        for plugin in self.plugins.values() {
-            let rec = plugin
-                .form_ids
-                .get(&fid.0)
-                .and_then(|fid| plugin.get_record_by_key(&fid));
-
-            if rec.is_some() {
-                return rec;
-            }
+    /// Every plugin providing each record, by load order FormID
+    pub fn conflicts(&self) -> &ConflictIndex {
+        &self.conflicts
+    }
+
+    /// Fields that differ between the winning version of a record and the
+    /// version in `plugin`
+    pub fn diff(&self, fid: &FormID, plugin: &str) -> Option<Vec<FieldDiff>> {
+        let winner = self.conflicts.get(fid)?.winner();
+        let winner = self.fields(&winner.plugin, fid)?;
+        let other = self.fields(plugin, fid)?;
+
+        Some(diff(&winner, &other))
+    }
+
+    /// Resolves the name of the plugin a FormID in `plugin` refers to,
+    /// using the top byte as an index into its masters list
+    fn owner<'a>(&'a self, plugin: &'a str, fid: &FormID) -> Option<&'a str> {
+        let masters = &self.plugins.get(plugin)?.header.masters;
+        let index = (fid.0 >> 24) as usize;
+
+        match index.cmp(&masters.len()) {
+            std::cmp::Ordering::Less => Some(&masters[index]),
+            std::cmp::Ordering::Equal => Some(plugin),
+            std::cmp::Ordering::Greater => None,
         }
+    }
+
+    /// Converts a FormID as stored in `plugin` into a load order FormID
+    pub fn global_form_id(&self, plugin: &str, fid: &FormID) -> Option<FormID> {
+        let owner = self.owner(plugin, fid)?;
+        Some(self.load_index(owner)?.form_id(fid.0))
+    }
+
+    /// Converts a load order FormID into the FormID `plugin` uses for it
+    pub fn local_form_id(&self, plugin: &str, fid: &FormID) -> Option<FormID> {
+        let p = self.plugins.get(plugin)?;
+        let masters = p.header.masters.iter().map(String::as_str);
 
-        None
+        masters
+            .chain(std::iter::once(plugin))
+            .enumerate()
+            .find_map(|(i, m)| {
+                let object = self.load_index(m)?.object(fid)?;
+                Some(FormID(((i as u32) << 24) | object))
+            })
+    }
+
+    /// Returns the winning (last loaded) version of a record, given a load
+    /// order FormID
+    pub fn get_record_by_form_id(&self, fid: &FormID) -> Option<&Result<Record, Error>> {
+        self.load_order.iter().rev().find_map(|name| {
+            let local = self.local_form_id(name, fid)?;
+            self.plugins[name].get_record_by_form_id(&local)
+        })
     }
 }
@@ -0,0 +1,223 @@
+use crate::common::FormID;
+use crate::fields::{LeveledEntry, LeveledFlags};
+use crate::game::Game;
+use crate::plugin::Plugin;
+use crate::records::{LeveledActor, LeveledItem, LeveledSpell, Record};
+
+/// Nesting depth after which a list is assumed to contain itself
+const MAX_DEPTH: usize = 32;
+
+/// Fields shared by [LeveledItem], [LeveledActor] and [LeveledSpell]
+pub trait LeveledList {
+    fn chance_none(&self) -> u8;
+    fn flags(&self) -> LeveledFlags;
+    fn entries(&self) -> &[LeveledEntry];
+
+    /// Global variable overriding the chance none
+    fn global(&self) -> Option<FormID> {
+        None
+    }
+}
+
+impl LeveledList for LeveledItem {
+    fn chance_none(&self) -> u8 {
+        self.chance_none
+    }
+
+    fn flags(&self) -> LeveledFlags {
+        self.flags
+    }
+
+    fn entries(&self) -> &[LeveledEntry] {
+        &self.entries
+    }
+
+    fn global(&self) -> Option<FormID> {
+        self.global.clone()
+    }
+}
+
+impl LeveledList for LeveledActor {
+    fn chance_none(&self) -> u8 {
+        self.chance_none
+    }
+
+    fn flags(&self) -> LeveledFlags {
+        self.flags
+    }
+
+    fn entries(&self) -> &[LeveledEntry] {
+        &self.entries
+    }
+}
+
+impl LeveledList for LeveledSpell {
+    fn chance_none(&self) -> u8 {
+        self.chance_none
+    }
+
+    fn flags(&self) -> LeveledFlags {
+        self.flags
+    }
+
+    fn entries(&self) -> &[LeveledEntry] {
+        &self.entries
+    }
+}
+
+/// Returns the record as a leveled list, if it is one
+pub fn as_leveled_list(record: &Record) -> Option<&dyn LeveledList> {
+    match record {
+        Record::LeveledItem(x) => Some(x),
+        Record::LeveledActor(x) => Some(x),
+        Record::LeveledSpell(x) => Some(x),
+        _ => None,
+    }
+}
+
+/// Somewhere to look up the records a leveled list refers to
+pub trait ListSource {
+    /// Returns the leveled list `fid`, or `None` if it is not a leveled list
+    fn list(&self, fid: &FormID) -> Option<&dyn LeveledList>;
+
+    /// Returns the value of the global variable `fid`
+    fn global(&self, fid: &FormID) -> Option<f32>;
+
+    /// Converts `fid`, as stored in the entries of `list`, into a FormID
+    /// that can be passed back to this source
+    fn resolve(&self, list: &FormID, fid: &FormID) -> Option<FormID>;
+}
+
+impl ListSource for Plugin {
+    fn list(&self, fid: &FormID) -> Option<&dyn LeveledList> {
+        as_leveled_list(self.get_record_by_form_id(fid)?.as_ref().ok()?)
+    }
+
+    fn global(&self, fid: &FormID) -> Option<f32> {
+        match self.get_record_by_form_id(fid)? {
+            Ok(Record::GlobalVariable(g)) => Some(g.value),
+            _ => None,
+        }
+    }
+
+    fn resolve(&self, _list: &FormID, fid: &FormID) -> Option<FormID> {
+        Some(fid.clone())
+    }
+}
+
+/// Uses the winning version of every list, addressed by load order FormID
+impl ListSource for Game {
+    fn list(&self, fid: &FormID) -> Option<&dyn LeveledList> {
+        as_leveled_list(self.get_record_by_form_id(fid)?.as_ref().ok()?)
+    }
+
+    fn global(&self, fid: &FormID) -> Option<f32> {
+        match self.get_record_by_form_id(fid)? {
+            Ok(Record::GlobalVariable(g)) => Some(g.value),
+            _ => None,
+        }
+    }
+
+    fn resolve(&self, list: &FormID, fid: &FormID) -> Option<FormID> {
+        let plugin = &self.conflicts().get(list)?.winner().plugin;
+        self.global_form_id(plugin, fid)
+    }
+}
+
+/// Resolves leveled lists the way the game does, using a seeded
+/// pseudo-random generator so that every roll can be reproduced
+#[derive(Debug, Clone)]
+pub struct Roller {
+    state: u64,
+}
+
+impl Roller {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// splitmix64
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Resolves the leveled list `list` for a player of level `level` into
+    /// the items, actors or spells it gives, and how many of each.
+    /// Nested lists are resolved recursively.
+    pub fn roll<S: ListSource + ?Sized>(
+        &mut self,
+        source: &S,
+        list: &FormID,
+        level: u16,
+    ) -> Vec<(FormID, u32)> {
+        let mut result = Vec::new();
+        self.roll_list(source, list, level, 0, &mut result);
+        result
+    }
+
+    fn roll_list<S: ListSource + ?Sized>(
+        &mut self,
+        source: &S,
+        fid: &FormID,
+        level: u16,
+        depth: usize,
+        result: &mut Vec<(FormID, u32)>,
+    ) {
+        let list = match source.list(fid) {
+            Some(l) if depth < MAX_DEPTH => l,
+            _ => return,
+        };
+
+        let chance_none = list
+            .global()
+            .and_then(|g| source.resolve(fid, &g))
+            .and_then(|g| source.global(&g))
+            .unwrap_or(list.chance_none() as f32);
+        if (self.below(100) as f32) < chance_none {
+            return;
+        }
+
+        let flags = list.flags();
+        let mut eligible: Vec<_> = list.entries().iter().filter(|e| e.level <= level).collect();
+        if !flags.contains(LeveledFlags::CALCULATE_FROM_ALL_LEVELS) {
+            let highest = eligible.iter().map(|e| e.level).max();
+            eligible.retain(|e| Some(e.level) == highest);
+        }
+        if eligible.is_empty() {
+            return;
+        }
+        let picked = if flags.contains(LeveledFlags::USE_ALL) {
+            eligible
+        } else {
+            vec![eligible[self.below(eligible.len())]]
+        };
+
+        for entry in picked {
+            let reference = match source.resolve(fid, &entry.reference) {
+                Some(r) => r,
+                None => continue,
+            };
+            let count = entry.count as u32;
+
+            if source.list(&reference).is_none() {
+                result.push((reference, count));
+            } else if flags.contains(LeveledFlags::CALCULATE_FOR_EACH_ITEM) {
+                for _ in 0..count {
+                    self.roll_list(source, &reference, level, depth + 1, result);
+                }
+            } else {
+                let mut nested = Vec::new();
+                self.roll_list(source, &reference, level, depth + 1, &mut nested);
+                result.extend(nested.into_iter().map(|(f, c)| (f, c * count)));
+            }
+        }
+    }
+}
@@ -6,9 +6,15 @@
 //! let game = Game::load(&vec!["assets/"], "English").unwrap();
 //! ````
 
+// binrw expands `count = ...` into a `try_into()` on the length field
+#![allow(clippy::unnecessary_fallible_conversions)]
+
 /// Utility functions
 pub mod common;
 
+/// Tracks which plugins provide each record in a [game::Game]
+pub mod conflict;
+
 /// Errors related to processing plugin files
 pub mod error;
 
@@ -18,12 +24,18 @@ pub mod fields;
 /// Overarching struct that can load multiple plugin files
 pub mod game;
 
+/// Resolves leveled lists into the records they give
+pub mod leveled;
+
 /// Loads an individual `*.es[mp]` file, contained in a [game::Game]
 pub mod plugin;
 
 /// Record structs contained in a [plugin::Plugin]
 pub mod records;
 
+/// Arranges story manager nodes into the tree the game walks
+pub mod story;
+
 /// Structs for loading string table files
 pub mod string_table;
 
@@ -1,9 +1,9 @@
 use crate::common::FormID;
 use crate::error::Error;
-use crate::records::{tes4::Flags, Header, RawRecord, Record, TES4};
-use binrw::{until_eof, BinRead, Endian};
+use crate::records::{from_raw, tes4::Flags, to_raw, Header, RawRecord, Record, TES4};
+use binrw::{until_eof, BinRead, BinWrite, Endian};
 use std::collections::HashMap;
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, Write};
 
 type RecordKey = Vec<usize>;
 
@@ -31,13 +31,45 @@ fn helper(rec: &Result<Record, Error>, path: Vec<usize>) -> Vec<(u32, RecordKey)
     }
 }
 
+/// Counts the records and groups in raw group data. This walks the record
+/// headers directly, as the data may be what failed to parse.
+fn count_raw(data: &[u8]) -> u32 {
+    let mut count = 0;
+    let mut offset = 0;
+
+    while offset + 24 <= data.len() {
+        let size = u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        count += 1;
+
+        if data[offset..offset + 4] == *b"GRUP" {
+            let end = (offset + size).min(data.len());
+            count += count_raw(&data[offset + 24..end.max(offset + 24)]);
+            offset += size.max(24);
+        } else {
+            offset += 24 + size;
+        }
+    }
+
+    count
+}
+
+fn count(rec: &Result<Record, Error>) -> u32 {
+    match rec {
+        Ok(Record::Group(g)) => 1 + g.records.iter().map(count).sum::<u32>(),
+        Err(Error::BinaryParseErrorExtra(_, RawRecord::GRUP(g)))
+        | Err(Error::ExtraBytesRaw(_, RawRecord::GRUP(g)))
+        | Err(Error::ParseErrorRaw(_, RawRecord::GRUP(g))) => 1 + count_raw(&g.data),
+        _ => 1,
+    }
+}
+
 impl Plugin {
     pub fn parse<T: Read + Seek>(reader: &mut T) -> Result<Self, Error> {
         let header: Header = TES4::read(reader)?.try_into()?;
         let args = (header.header.flags.contains(Flags::LOCALIZED),);
         let recs: Vec<RawRecord> = until_eof(reader, Endian::Little, args)?;
 
-        let records: Vec<_> = recs.into_iter().map(Record::try_from).collect();
+        let records: Vec<_> = recs.into_iter().map(from_raw).collect();
         let form_ids = records
             .iter()
             .enumerate()
@@ -51,6 +83,22 @@ impl Plugin {
         })
     }
 
+    /// Serializes the plugin, recomputing record/group sizes and the HEDR count.
+    /// Compressed records are compressed again at the default zlib level, so
+    /// their contents are unchanged but the zlib stream may differ from the one
+    /// in the original file.
+    pub fn write<W: Write + Seek>(self, writer: &mut W) -> Result<(), Error> {
+        let mut header = self.header;
+        header.hedr.records_and_groups_count = self.records.iter().map(count).sum();
+        TES4::try_from(header)?.write(writer)?;
+
+        for rec in self.records {
+            to_raw(rec)?.write(writer)?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_record_by_key(&self, key: &RecordKey) -> Option<&Result<Record, Error>> {
         let mut selected: Option<&Result<Record, Error>> = None;
         for i in key {
@@ -66,6 +114,6 @@ impl Plugin {
     pub fn get_record_by_form_id(&self, fid: &FormID) -> Option<&Result<Record, Error>> {
         self.form_ids
             .get(&fid.0)
-            .and_then(|fid| self.get_record_by_key(&fid))
+            .and_then(|fid| self.get_record_by_key(fid))
     }
 }
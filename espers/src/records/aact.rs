@@ -1,4 +1,4 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{CNAM, EDID};
@@ -60,10 +60,22 @@ impl TryFrom<Action> for AACT {
     fn try_from(obj: Action) -> Result<Self, Self::Error> {
         let mut data = Cursor::new(Vec::new());
         EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.color
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
 
         Ok(Self {
-            header: obj.header,
-            data: data.into_inner(),
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
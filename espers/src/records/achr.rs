@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{
     ScriptList, DATA, EDID, INAM, NAME, PDTO, VMAD, XAPD, XAPR, XESP, XEZN, XHOR, XIS2, XLCM, XLCN,
     XLKR, XLRL, XLRT, XOWN, XPPA, XPRD, XRGB, XRGD, XSCL,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -55,6 +55,21 @@ impl TryFrom<DATA> for Coords {
     }
 }
 
+impl TryFrom<Coords> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: Coords) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"ACHR")]
@@ -250,3 +265,88 @@ impl TryFrom<ACHR> for ActorRef {
         })
     }
 }
+
+impl TryFrom<ActorRef> for ACHR {
+    type Error = Error;
+
+    fn try_from(obj: ActorRef) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        NAME::try_from(obj.name)?.write(&mut data)?;
+        // The reader accepts these in any order; write them back in the
+        // order the Creation Kit emits them.
+        obj.encounter_zone
+            .map(XEZN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.ragdoll.write(&mut data)?;
+        obj.unknown3.write(&mut data)?;
+        obj.patrol_idle
+            .map(XPRD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown1
+            .map(XPPA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown2
+            .map(INAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.topic_data.write(&mut data)?;
+        obj.leveled_creature_data
+            .map(XLCM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.activation_parent_flags
+            .map(XAPD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.activate_parent.write(&mut data)?;
+        obj.location_ref_type
+            .map(XLRT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.horse_id
+            .map(XHOR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.enable_parent.write(&mut data)?;
+        obj.owner
+            .map(XOWN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.location
+            .map(XLCN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.location_route.write(&mut data)?;
+        obj.xis2.write(&mut data)?;
+        obj.xlrl.map(XLRL::try_from).transpose()?.write(&mut data)?;
+        obj.scale
+            .map(XSCL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.coords
+            .map(DATA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     DestructionData, Model, ObjectBounds, ScriptList, EDID, FNAM, FULL, KNAM, KSIZ, KWDA, MODL,
     MODS, MODT, OBND, PNAM, RNAM, SNAM, VMAD, VNAM, WNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -71,19 +71,14 @@ impl TryFrom<ACTI> for Activator {
         };
         let model = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
         let destruction_data = DestructionData::load(&mut cursor)?;
-        let keyword_count: Option<u32> = KSIZ::read(&mut cursor)
+        let _: Option<u32> = KSIZ::read(&mut cursor)
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
         let mut keywords = Vec::new();
-
-        if let Some(kc) = keyword_count {
-            for _ in 0..kc {
-                // It's actually only up to keyword count
-                if let Ok(kwda) = KWDA::read(&mut cursor) {
-                    keywords.push(FormID::read_le(&mut Cursor::new(kwda.data)).unwrap());
-                }
-            }
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
         }
 
         let marker_color = PNAM::read(&mut cursor)
@@ -137,3 +132,73 @@ impl TryFrom<ACTI> for Activator {
         })
     }
 }
+
+impl TryFrom<Activator> for ACTI {
+    type Error = Error;
+
+    fn try_from(obj: Activator) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.marker_color
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.sound_looping
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.sound_activation
+            .map(VNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.water_type
+            .map(WNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.activate_text_override
+            .map(RNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flags
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.interaction_keyword
+            .map(KNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{Model, ObjectBounds, DATA, DNAM, EDID, MODL, MODS, MODT, OBND, SNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -29,6 +29,21 @@ impl TryFrom<DNAM> for (u16, AddonNodeFlags) {
     }
 }
 
+impl TryFrom<(u16, AddonNodeFlags)> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: (u16, AddonNodeFlags)) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"ADDN")]
@@ -88,3 +103,33 @@ impl TryFrom<ADDN> for AddonNode {
         })
     }
 }
+
+impl TryFrom<AddonNode> for ADDN {
+    type Error = Error;
+
+    fn try_from(obj: AddonNode) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.model.write::<MODL, MODT, MODS>(&mut data)?;
+        DATA::try_from(obj.addon_node_index)?.write(&mut data)?;
+        obj.ambient_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DNAM::try_from((obj.particle_system_cap, obj.flags))?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     AlternateTextures, Condition, EffectItem, ObjectBounds, Unknown4, CTDA, DATA, EDID, EFID, EFIT,
     ENIT, FULL, ICON, KSIZ, KWDA, MICO, MODL, MODS, MODT, OBND, YNAM, ZNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -47,6 +47,21 @@ impl TryFrom<ENIT> for EnchantedItem {
     }
 }
 
+impl TryFrom<EnchantedItem> for ENIT {
+    type Error = Error;
+
+    fn try_from(obj: EnchantedItem) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Alchemy {
     pub header: RecordHeader,
@@ -87,19 +102,14 @@ impl TryFrom<ALCH> for Alchemy {
             (Err(_), _) => None,
         };
 
-        let keyword_count: Option<u32> = KSIZ::read(&mut cursor)
+        let _: Option<u32> = KSIZ::read(&mut cursor)
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
         let mut keywords = Vec::new();
-
-        if let Some(kc) = keyword_count {
-            for _ in 0..kc {
-                // It's actually only up to keyword count
-                if let Ok(kwda) = KWDA::read(&mut cursor) {
-                    keywords.push(FormID::read_le(&mut Cursor::new(kwda.data)).unwrap());
-                }
-            }
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
         }
         let model_filename = MODL::read(&mut cursor)
             .ok()
@@ -166,3 +176,68 @@ impl TryFrom<ALCH> for Alchemy {
         })
     }
 }
+
+impl TryFrom<Alchemy> for ALCH {
+    type Error = Error;
+
+    fn try_from(obj: Alchemy) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.model_filename
+            .map(MODL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.model_textures.is_empty() {
+            MODT::try_from(obj.model_textures)?.write(&mut data)?;
+        }
+        obj.alternate_textures
+            .map(MODS::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.icon.map(ICON::try_from).transpose()?.write(&mut data)?;
+        obj.message_icon
+            .map(MICO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.weight)?.write(&mut data)?;
+        ENIT::try_from(obj.item)?.write(&mut data)?;
+        for (efid, efit, ctdas) in obj.effects {
+            EFID::try_from(efid)?.write(&mut data)?;
+            EFIT::try_from(efit)?.write(&mut data)?;
+            for ctda in ctdas {
+                CTDA::try_from(ctda)?.write(&mut data)?;
+            }
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
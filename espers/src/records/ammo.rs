@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     DestructionData, Model, ObjectBounds, DATA, DESC, EDID, FULL, ICON, KSIZ, KWDA, MICO, MODL,
     MODS, MODT, OBND, ONAM, YNAM, ZNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -90,19 +90,14 @@ impl TryFrom<AMMO> for Ammo {
             LocalizedString::ZString(DESC::read(&mut cursor)?.try_into()?)
         };
 
-        let keyword_count: Option<u32> = KSIZ::read(&mut cursor)
+        let _: Option<u32> = KSIZ::read(&mut cursor)
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
         let mut keywords = Vec::new();
-
-        if let Some(kc) = keyword_count {
-            for _ in 0..kc {
-                // It's actually only up to keyword count
-                if let Ok(kwda) = KWDA::read(&mut cursor) {
-                    keywords.push(FormID::read_le(&mut Cursor::new(kwda.data)).unwrap());
-                }
-            }
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
         }
 
         let data = DATA::read(&mut cursor)?;
@@ -133,3 +128,60 @@ impl TryFrom<AMMO> for Ammo {
         })
     }
 }
+
+impl TryFrom<Ammo> for AMMO {
+    type Error = Error;
+
+    fn try_from(obj: Ammo) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.icon.map(ICON::try_from).transpose()?.write(&mut data)?;
+        obj.message_icon
+            .map(MICO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DESC::try_from(obj.description)?.write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.data.write(&mut data)?;
+        obj.short_name
+            .map(ONAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{Model, BNAM, EDID, MODL, MODS, MODT};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -55,3 +55,30 @@ impl TryFrom<ANIO> for AnimatedObjectInfo {
         })
     }
 }
+
+impl TryFrom<AnimatedObjectInfo> for ANIO {
+    type Error = Error;
+
+    fn try_from(obj: AnimatedObjectInfo) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.model.write::<MODL, MODT, MODS>(&mut data)?;
+        obj.unload_event
+            .map(BNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     DestructionData, Model, ObjectBounds, ScriptList, DATA, DESC, EDID, FULL, ICON, MICO, MODL,
     MODS, MODT, OBND, QUAL, VMAD, YNAM, ZNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -44,6 +44,21 @@ impl TryFrom<DATA> for ApparatusData {
     }
 }
 
+impl TryFrom<ApparatusData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: ApparatusData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Apparatus {
     pub header: RecordHeader,
@@ -133,3 +148,54 @@ impl TryFrom<APPA> for Apparatus {
         })
     }
 }
+
+impl TryFrom<Apparatus> for APPA {
+    type Error = Error;
+
+    fn try_from(obj: Apparatus) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        FULL::try_from(obj.full_name)?.write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.icon.map(ICON::try_from).transpose()?.write(&mut data)?;
+        obj.message_icon
+            .map(MICO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        QUAL::try_from(obj.quality)?.write(&mut data)?;
+        DESC::try_from(obj.description)?.write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{
     BodyTemplate, BodyTemplate2, Model, BOD2, BODT, DNAM, EDID, MO2S, MO2T, MO3S, MO3T, MO4S, MO4T,
     MO5S, MO5T, MOD2, MOD3, MOD4, MOD5, MODL, NAM0, NAM1, NAM2, NAM3, ONAM, RNAM, SNDD,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -128,3 +128,74 @@ impl TryFrom<ARMA> for ArmorAddon {
         })
     }
 }
+
+impl TryFrom<ArmorAddon> for ARMA {
+    type Error = Error;
+
+    fn try_from(obj: ArmorAddon) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.body_template
+            .map(BODT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.body_template_2
+            .map(BOD2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        RNAM::try_from(obj.primary_race)?.write(&mut data)?;
+        DNAM::try_from(obj.unknown)?.write(&mut data)?;
+        if let Some(m) = obj.male_model {
+            m.write::<MOD2, MO2T, MO2S>(&mut data)?;
+        }
+        if let Some(m) = obj.female_model {
+            m.write::<MOD3, MO3T, MO3S>(&mut data)?;
+        }
+        if let Some(m) = obj.male_3p_model {
+            m.write::<MOD4, MO4T, MO4S>(&mut data)?;
+        }
+        if let Some(m) = obj.female_3p_model {
+            m.write::<MOD5, MO5T, MO5S>(&mut data)?;
+        }
+        obj.base_male_texture
+            .map(NAM0::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.base_female_texture
+            .map(NAM1::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.base_male_1p_texture
+            .map(NAM2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.base_female_1p_texture
+            .map(NAM3::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for x in obj.races {
+            MODL::try_from(x)?.write(&mut data)?;
+        }
+        obj.footstep_sound
+            .map(SNDD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.art_object
+            .map(ONAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
@@ -6,7 +6,7 @@ use crate::fields::{
     BMCT, BOD2, BODT, DATA, DESC, DNAM, EAMT, EDID, EITM, ETYP, FULL, ICO2, ICON, KSIZ, KWDA, MIC2,
     MICO, MO2S, MO2T, MO4S, MO4T, MOD2, MOD4, MODL, MODS, MODT, OBND, RNAM, TNAM, VMAD, YNAM, ZNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -45,6 +45,21 @@ impl TryFrom<DATA> for ArmorData {
     }
 }
 
+impl TryFrom<ArmorData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: ArmorData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Armor {
     pub header: RecordHeader,
@@ -169,18 +184,14 @@ impl TryFrom<ARMO> for Armor {
             .map(TryInto::try_into)
             .transpose()?;
         let rnam = RNAM::read(&mut cursor)?.try_into()?;
-        let keyword_count: Option<u32> = KSIZ::read(&mut cursor)
+        let _: Option<u32> = KSIZ::read(&mut cursor)
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
         let mut keywords = Vec::new();
-        if let Some(kc) = keyword_count {
-            for _ in 0..kc {
-                // It's actually only up to keyword count
-                if let Ok(kwda) = KWDA::read(&mut cursor) {
-                    keywords.push(FormID::read_le(&mut Cursor::new(kwda.data)).unwrap());
-                }
-            }
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
         }
         let description = match (DESC::read(&mut cursor), raw.localized) {
             (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
@@ -234,3 +245,124 @@ impl TryFrom<ARMO> for Armor {
         })
     }
 }
+
+impl TryFrom<Armor> for ARMO {
+    type Error = Error;
+
+    fn try_from(obj: Armor) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.bounds
+            .map(OBND::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.enchantment
+            .map(EITM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.enchantment_amount
+            .map(EAMT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        if let Some(m) = obj.male_model {
+            m.write::<MOD2, MO2T, MO2S>(&mut data)?;
+        }
+        obj.male_inventory_image
+            .map(ICON::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.male_message_image
+            .map(MICO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.female_model {
+            m.write::<MOD4, MO4T, MO4S>(&mut data)?;
+        }
+        obj.female_inventory_image
+            .map(ICO2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.female_message_image
+            .map(MIC2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.body_template
+            .map(BODT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.body_template_2
+            .map(BOD2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.ragdoll
+            .map(BMCT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.equip_slot
+            .map(ETYP::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.bash_impact_data_set
+            .map(BIDS::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.bash_material
+            .map(BAMT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        RNAM::try_from(obj.rnam)?.write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.description
+            .map(DESC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for x in obj.armatures {
+            MODL::try_from(x)?.write(&mut data)?;
+        }
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        DNAM::try_from(obj.armor_rating)?.write(&mut data)?;
+        obj.template
+            .map(TNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
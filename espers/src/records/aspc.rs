@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{ObjectBounds, BNAM, EDID, OBND, RDAT, SNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -67,3 +67,38 @@ impl TryFrom<ASPC> for AcousticSpace {
         })
     }
 }
+
+impl TryFrom<AcousticSpace> for ASPC {
+    type Error = Error;
+
+    fn try_from(obj: AcousticSpace) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.ambient
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.region_data
+            .map(RDAT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.reverb
+            .map(BNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{DATA, EDID, FCHT, FPRT, MCHT, MPRT};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -67,3 +67,36 @@ impl TryFrom<ASTP> for AssociationType {
         })
     }
 }
+
+impl TryFrom<AssociationType> for ASTP {
+    type Error = Error;
+
+    fn try_from(obj: AssociationType) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        MPRT::try_from(obj.male_parent_label)?.write(&mut data)?;
+        FPRT::try_from(obj.female_parent_label)?.write(&mut data)?;
+        obj.male_child_label
+            .map(MCHT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.female_child_label
+            .map(FCHT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.flags)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,10 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     ANAM, AVSK, CNAM, DESC, EDID, FNAM, FULL, HNAM, INAM, PNAM, SNAM, VNAM, XNAM, YNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -130,3 +130,55 @@ impl TryFrom<AVIF> for ActorValue {
         })
     }
 }
+
+impl TryFrom<ActorValue> for AVIF {
+    type Error = Error;
+
+    fn try_from(obj: ActorValue) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DESC::try_from(obj.description)?.write(&mut data)?;
+        obj.abbreviation
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for x in obj.data {
+            CNAM::try_from(x)?.write(&mut data)?;
+        }
+        obj.av_data
+            .map(AVSK::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for p in obj.perk_sections {
+            PNAM::try_from(p.perk)?.write(&mut data)?;
+            FNAM::try_from(p.flag)?.write(&mut data)?;
+            XNAM::try_from(p.xcoord)?.write(&mut data)?;
+            YNAM::try_from(p.ycoord)?.write(&mut data)?;
+            HNAM::try_from(p.horizontal_position)?.write(&mut data)?;
+            VNAM::try_from(p.vertical_position)?.write(&mut data)?;
+            SNAM::try_from(p.skill)?.write(&mut data)?;
+            for l in p.lines {
+                CNAM::try_from(l)?.write(&mut data)?;
+            }
+            INAM::try_from(p.index_number)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
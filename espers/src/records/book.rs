@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     DestructionData, Model, ObjectBounds, ScriptList, CNAM, DATA, DESC, EDID, FULL, ICON, INAM,
     KSIZ, KWDA, MICO, MODL, MODS, MODT, OBND, VMAD, YNAM, ZNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -46,6 +46,21 @@ impl TryFrom<DATA> for BookData {
     }
 }
 
+impl TryFrom<BookData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: BookData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// [BOOK](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/BOOK) record
 #[binrw]
 #[br(import(localized: bool))]
@@ -175,3 +190,68 @@ impl TryFrom<BOOK> for Book {
         })
     }
 }
+
+impl TryFrom<Book> for BOOK {
+    type Error = Error;
+
+    fn try_from(obj: Book) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.inventory_image
+            .map(ICON::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.message_image
+            .map(MICO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DESC::try_from(obj.text)?.write(&mut data)?;
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        obj.inventory_art
+            .map(INAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        CNAM::try_from(obj.description)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,10 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     Model, BPND, BPNI, BPNN, BPNT, BPTN, EDID, MODL, MODS, MODT, NAM1, NAM4, NAM5, RAGA,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -103,3 +103,43 @@ impl TryFrom<BPTD> for BodyPartData {
         })
     }
 }
+
+impl TryFrom<BodyPartData> for BPTD {
+    type Error = Error;
+
+    fn try_from(obj: BodyPartData) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        for p in obj.body_parts {
+            BPTN::try_from(p.name)?.write(&mut data)?;
+            BPNN::try_from(p.node_name)?.write(&mut data)?;
+            BPNT::try_from(p.node_title)?.write(&mut data)?;
+            BPNI::try_from(p.node_info)?.write(&mut data)?;
+            BPND::try_from(p.node_data)?.write(&mut data)?;
+            NAM1::try_from(p.limb_replacement_model)?.write(&mut data)?;
+            NAM4::try_from(p.gore_effects)?.write(&mut data)?;
+            NAM5::try_from(p.hashes)?.write(&mut data)?;
+            p.ragdoll
+                .map(RAGA::try_from)
+                .transpose()?
+                .write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     DATA, EDID, FULL, LNAM, LTMP, MHDT, TVDT, XCAS, XCCM, XCIM, XCLC, XCLL, XCLR, XCLW, XCMO, XCWT,
     XEZN, XILL, XLCN, XNAM, XOWN, XWCN, XWCS, XWCU, XWEM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -45,13 +45,28 @@ impl TryFrom<DATA> for CellFlags {
     }
 }
 
+impl TryFrom<CellFlags> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: CellFlags) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CellLocation {
-    x: i32,
-    y: i32,
-    flags: u32,
+    pub x: i32,
+    pub y: i32,
+    pub flags: u32,
 }
 
 impl TryFrom<XCLC> for CellLocation {
@@ -65,6 +80,21 @@ impl TryFrom<XCLC> for CellLocation {
     }
 }
 
+impl TryFrom<CellLocation> for XCLC {
+    type Error = Error;
+
+    fn try_from(obj: CellLocation) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -112,6 +142,21 @@ impl TryFrom<XCLL> for CellLighting {
     }
 }
 
+impl TryFrom<CellLighting> for XCLL {
+    type Error = Error;
+
+    fn try_from(obj: CellLighting) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 impl TryFrom<TVDT> for Vec<u8> {
     type Error = Error;
 
@@ -151,6 +196,21 @@ impl TryFrom<XWCU> for Vec<WaterCurrent> {
     }
 }
 
+impl TryFrom<Vec<WaterCurrent>> for XWCU {
+    type Error = Error;
+
+    fn try_from(obj: Vec<WaterCurrent>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [CELL] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Cell {
@@ -340,3 +400,108 @@ impl TryFrom<CELL> for Cell {
         })
     }
 }
+
+impl TryFrom<Cell> for CELL {
+    type Error = Error;
+
+    fn try_from(obj: Cell) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.flags)?.write(&mut data)?;
+        obj.location
+            .map(XCLC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.lighting
+            .map(XCLL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.occlusion_data
+            .map(TVDT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.max_height_data
+            .map(MHDT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        LTMP::try_from(obj.lighting_template)?.write(&mut data)?;
+        obj.lighting_template_flags
+            .map(LNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        XCLW::try_from(obj.water_height)?.write(&mut data)?;
+        obj.xnam.map(XNAM::try_from).transpose()?.write(&mut data)?;
+        if !obj.containing_regions.is_empty() {
+            XCLR::try_from(obj.containing_regions)?.write(&mut data)?;
+        }
+        obj.exit_location
+            .map(XLCN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.water_current_count_3
+            .map(XWCS::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.water_current_count_4
+            .map(XWCN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.water_current.is_empty() {
+            XWCU::try_from(obj.water_current)?.write(&mut data)?;
+        }
+        obj.water
+            .map(XCWT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.owner
+            .map(XOWN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.lock_list
+            .map(XILL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.water_environment_map
+            .map(XWEM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.climate
+            .map(XCCM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.acoustic_space
+            .map(XCAS::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.encounter_zone
+            .map(XEZN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.music_type
+            .map(XCMO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.image_space
+            .map(XCIM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, LocalizedString};
 use crate::error::Error;
 use crate::fields::{DATA, DESC, EDID, FULL, ICON};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -49,6 +49,21 @@ impl TryFrom<DATA> for ClassData {
     }
 }
 
+impl TryFrom<ClassData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: ClassData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Class {
     pub header: RecordHeader,
@@ -101,3 +116,30 @@ impl TryFrom<CLAS> for Class {
         })
     }
 }
+
+impl TryFrom<Class> for CLAS {
+    type Error = Error;
+
+    fn try_from(obj: Class) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        FULL::try_from(obj.full_name)?.write(&mut data)?;
+        DESC::try_from(obj.description)?.write(&mut data)?;
+        obj.icon.map(ICON::try_from).transpose()?.write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, LocalizedString};
 use crate::error::Error;
 use crate::fields::{CNAM, EDID, FNAM, FULL};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -65,3 +65,32 @@ impl TryFrom<CLFM> for Color {
         })
     }
 }
+
+impl TryFrom<Color> for CLFM {
+    type Error = Error;
+
+    fn try_from(obj: Color) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        CNAM::try_from(obj.color)?.write(&mut data)?;
+        FNAM::try_from(obj.playable)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
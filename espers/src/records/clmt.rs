@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{ModelTextures, Weather, EDID, FNAM, GNAM, MODL, MODT, TNAM, WLST};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -40,6 +40,21 @@ impl TryFrom<TNAM> for SunAndMoons {
     }
 }
 
+impl TryFrom<SunAndMoons> for TNAM {
+    type Error = Error;
+
+    fn try_from(obj: SunAndMoons) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Climate {
     pub header: RecordHeader,
@@ -96,3 +111,40 @@ impl TryFrom<CLMT> for Climate {
         })
     }
 }
+
+impl TryFrom<Climate> for CLMT {
+    type Error = Error;
+
+    fn try_from(obj: Climate) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        WLST::try_from(obj.weather_list)?.write(&mut data)?;
+        obj.sun_texture
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.glare_texture
+            .map(GNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        MODL::try_from(obj.model_filename)?.write(&mut data)?;
+        obj.model_textures
+            .map(MODT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        TNAM::try_from(obj.sun_and_moons)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{EffectCondition, BNAM, CNAM, CNTO, COCT, COED, EDID, NAM1};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -78,3 +78,42 @@ impl TryFrom<COBJ> for ConstructibleObj {
         })
     }
 }
+
+impl TryFrom<ConstructibleObj> for COBJ {
+    type Error = Error;
+
+    fn try_from(obj: ConstructibleObj) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.object_count
+            .map(COCT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for x in obj.objects {
+            CNTO::try_from(x)?.write(&mut data)?;
+        }
+        obj.unknown.write(&mut data)?;
+        for c in obj.conditions {
+            c.write(&mut data)?;
+        }
+        obj.output
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        BNAM::try_from(obj.bench)?.write(&mut data)?;
+        NAM1::try_from(obj.quantity)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
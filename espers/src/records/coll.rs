@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{BNAM, CNAM, DESC, EDID, FNAM, GNAM, INTV, MNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -80,3 +80,35 @@ impl TryFrom<COLL> for CollisionLayer {
         })
     }
 }
+
+impl TryFrom<CollisionLayer> for COLL {
+    type Error = Error;
+
+    fn try_from(obj: CollisionLayer) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        DESC::try_from(obj.description)?.write(&mut data)?;
+        BNAM::try_from(obj.unique_id)?.write(&mut data)?;
+        FNAM::try_from(obj.debug_color)?.write(&mut data)?;
+        GNAM::try_from(obj.flags)?.write(&mut data)?;
+        MNAM::try_from(obj.name)?.write(&mut data)?;
+        INTV::try_from(obj.interactables.len() as u32)?.write(&mut data)?;
+        if !obj.interactables.is_empty() {
+            CNAM::try_from(obj.interactables)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
-    Model, ObjectBounds, ScriptList, CNTO, COCT, COED, DATA, EDID, FULL, MODL, MODS, MODT, OBND,
+    ContainerItem, Model, ObjectBounds, ScriptList, COCT, DATA, EDID, FULL, MODL, MODS, MODT, OBND,
     QNAM, SNAM, VMAD,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -36,8 +36,7 @@ pub struct Container {
     pub full_name: Option<LocalizedString>,
     pub model: Option<Model>,
     pub object_count: Option<u32>,
-    pub objects: Vec<(FormID, u32)>,
-    pub unknown: Option<(FormID, FormID, f32)>,
+    pub objects: Vec<ContainerItem>,
     pub flags: DATA,
     pub open_sound: Option<FormID>,
     pub close_sound: Option<FormID>,
@@ -72,20 +71,7 @@ impl TryFrom<CONT> for Container {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
-        let mut objects = Vec::new();
-        let mut unknown = None;
-        loop {
-            if let Ok(c) = CNTO::read(&mut cursor) {
-                objects.push(c.try_into()?);
-                continue;
-            }
-            if let Ok(c) = COED::read(&mut cursor) {
-                unknown = Some(c.try_into()?);
-                continue;
-            }
-
-            break;
-        }
+        let objects = ContainerItem::load_multiple(&mut cursor)?;
         let flags = DATA::read(&mut cursor)?;
         let open_sound = SNAM::read(&mut cursor)
             .ok()
@@ -107,10 +93,60 @@ impl TryFrom<CONT> for Container {
             model,
             object_count,
             objects,
-            unknown,
             flags,
             open_sound,
             close_sound,
         })
     }
 }
+
+impl TryFrom<Container> for CONT {
+    type Error = Error;
+
+    fn try_from(obj: Container) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.object_count
+            .map(COCT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for o in obj.objects {
+            o.write(&mut data)?;
+        }
+        obj.flags.write(&mut data)?;
+        obj.open_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.close_sound
+            .map(QNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
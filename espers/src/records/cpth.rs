@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{EffectCondition, ANAM, DATA, EDID, SNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -70,3 +70,33 @@ impl TryFrom<CPTH> for CameraPath {
         })
     }
 }
+
+impl TryFrom<CameraPath> for CPTH {
+    type Error = Error;
+
+    fn try_from(obj: CameraPath) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        for x in obj.conditions {
+            x.write(&mut data)?;
+        }
+        ANAM::try_from(obj.paths)?.write(&mut data)?;
+        DATA::try_from(obj.flags)?.write(&mut data)?;
+        for x in obj.cameras {
+            SNAM::try_from(x)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
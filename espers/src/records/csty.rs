@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{CSCR, CSFL, CSGD, CSLR, CSMD, CSME, DATA, EDID};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -53,6 +53,21 @@ impl TryFrom<CSGD> for GeneralStyle {
     }
 }
 
+impl TryFrom<GeneralStyle> for CSGD {
+    type Error = Error;
+
+    fn try_from(obj: GeneralStyle) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,6 +94,21 @@ impl TryFrom<CSME> for MeleeStyle {
     }
 }
 
+impl TryFrom<MeleeStyle> for CSME {
+    type Error = Error;
+
+    fn try_from(obj: MeleeStyle) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -102,6 +132,21 @@ impl TryFrom<CSCR> for CloseRangeStyle {
     }
 }
 
+impl TryFrom<CloseRangeStyle> for CSCR {
+    type Error = Error;
+
+    fn try_from(obj: CloseRangeStyle) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -134,6 +179,21 @@ impl TryFrom<CSFL> for FlightStyle {
     }
 }
 
+impl TryFrom<FlightStyle> for CSFL {
+    type Error = Error;
+
+    fn try_from(obj: FlightStyle) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [CSTY] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CombatStyle {
@@ -206,3 +266,50 @@ impl TryFrom<CSTY> for CombatStyle {
         })
     }
 }
+
+impl TryFrom<CombatStyle> for CSTY {
+    type Error = Error;
+
+    fn try_from(obj: CombatStyle) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        CSGD::try_from(obj.general)?.write(&mut data)?;
+        obj.melee
+            .map(CSME::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.close_range
+            .map(CSCR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.long_range
+            .map(CSLR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flight
+            .map(CSFL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flags
+            .map(DATA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown
+            .map(CSMD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
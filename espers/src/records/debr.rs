@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{DATA, EDID, MODT};
-use binrw::{binrw, BinRead, NullString};
+use binrw::{binrw, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -42,6 +42,23 @@ impl TryFrom<DATA> for DirectionalData {
     }
 }
 
+impl TryFrom<DirectionalData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: DirectionalData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.percentage.write(&mut cursor)?;
+        NullString::from(obj.model_path).write(&mut cursor)?;
+        obj.flags.write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 impl TryFrom<MODT> for Vec<(u32, u32, u32)> {
     type Error = Error;
 
@@ -56,12 +73,30 @@ impl TryFrom<MODT> for Vec<(u32, u32, u32)> {
     }
 }
 
+impl TryFrom<Vec<(u32, u32, u32)>> for MODT {
+    type Error = Error;
+
+    fn try_from(obj: Vec<(u32, u32, u32)>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+/// Texture hashes for a debris model
+pub type TextureHashes = Vec<(u32, u32, u32)>;
+
 /// Parsed [DEBR] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Debris {
     pub header: RecordHeader,
     pub edid: Option<String>,
-    pub data: Vec<(DirectionalData, Option<Vec<(u32, u32, u32)>>)>,
+    pub data: Vec<(DirectionalData, Option<TextureHashes>)>,
 }
 
 impl fmt::Display for Debris {
@@ -100,3 +135,29 @@ impl TryFrom<DEBR> for Debris {
         })
     }
 }
+
+impl TryFrom<Debris> for DEBR {
+    type Error = Error;
+
+    fn try_from(obj: Debris) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        for (d, modt) in obj.data {
+            DATA::try_from(d)?.write(&mut data)?;
+            modt.map(MODT::try_from).transpose()?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{BNAM, DATA, EDID, FULL, PNAM, QNAM, SNAM, TIFC};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -44,6 +44,21 @@ impl TryFrom<DATA> for DialogTopicData {
     }
 }
 
+impl TryFrom<DialogTopicData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: DialogTopicData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 impl TryFrom<SNAM> for [u8; 4] {
     type Error = Error;
 
@@ -55,6 +70,21 @@ impl TryFrom<SNAM> for [u8; 4] {
     }
 }
 
+impl TryFrom<[u8; 4]> for SNAM {
+    type Error = Error;
+
+    fn try_from(obj: [u8; 4]) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [DIAL] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DialogueTopic {
@@ -116,3 +146,39 @@ impl TryFrom<DIAL> for DialogueTopic {
         })
     }
 }
+
+impl TryFrom<DialogueTopic> for DIAL {
+    type Error = Error;
+
+    fn try_from(obj: DialogueTopic) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        PNAM::try_from(obj.priority)?.write(&mut data)?;
+        obj.owning_branch
+            .map(BNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        QNAM::try_from(obj.owning_quest)?.write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        SNAM::try_from(obj.subtype)?.write(&mut data)?;
+        TIFC::try_from(obj.info_count)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
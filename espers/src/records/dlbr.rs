@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{DNAM, EDID, QNAM, SNAM, TNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -58,3 +58,29 @@ impl TryFrom<DLBR> for DialogueBranch {
         })
     }
 }
+
+impl TryFrom<DialogueBranch> for DLBR {
+    type Error = Error;
+
+    fn try_from(obj: DialogueBranch) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        QNAM::try_from(obj.quest_id)?.write(&mut data)?;
+        TNAM::try_from(obj.unknown)?.write(&mut data)?;
+        DNAM::try_from(obj.flags)?.write(&mut data)?;
+        SNAM::try_from(obj.start_dialog)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{BNAM, DNAM, EDID, ENAM, QNAM, TNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -73,3 +73,40 @@ impl TryFrom<DLVW> for DialogueView {
         })
     }
 }
+
+impl TryFrom<DialogueView> for DLVW {
+    type Error = Error;
+
+    fn try_from(obj: DialogueView) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        QNAM::try_from(obj.parent_quest)?.write(&mut data)?;
+        for x in obj.branches {
+            BNAM::try_from(x)?.write(&mut data)?;
+        }
+        for x in obj.topics {
+            TNAM::try_from(x)?.write(&mut data)?;
+        }
+        obj.unknown
+            .map(ENAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.show_all_text
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::DNAM;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -35,6 +35,21 @@ impl TryFrom<DNAM> for Vec<([u8; 4], FormID)> {
     }
 }
 
+impl TryFrom<Vec<([u8; 4], FormID)>> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: Vec<([u8; 4], FormID)>) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [DOBJ] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DefaultObjectManager {
@@ -65,3 +80,25 @@ impl TryFrom<DOBJ> for DefaultObjectManager {
         })
     }
 }
+
+impl TryFrom<DefaultObjectManager> for DOBJ {
+    type Error = Error;
+
+    fn try_from(obj: DefaultObjectManager) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        DNAM::try_from(obj.items)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     Model, ObjectBounds, ScriptList, ANAM, BNAM, EDID, FNAM, FULL, MODL, MODS, MODT, OBND, SNAM,
     TNAM, VMAD,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -103,3 +103,54 @@ impl TryFrom<DOOR> for Door {
         })
     }
 }
+
+impl TryFrom<Door> for DOOR {
+    type Error = Error;
+
+    fn try_from(obj: Door) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.open_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.close_sound
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.loop_sound
+            .map(BNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        FNAM::try_from(obj.flags)?.write(&mut data)?;
+        for x in obj.random_teleports {
+            TNAM::try_from(x)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{ObjectBounds, DATA, EDID, OBND};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -40,6 +40,21 @@ impl TryFrom<DATA> for DualCastData {
     }
 }
 
+impl TryFrom<DualCastData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: DualCastData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DualCastArt {
     pub header: RecordHeader,
@@ -75,3 +90,27 @@ impl TryFrom<DUAL> for DualCastArt {
         })
     }
 }
+
+impl TryFrom<DualCastArt> for DUAL {
+    type Error = Error;
+
+    fn try_from(obj: DualCastArt) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{DATA, EDID};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -50,6 +50,26 @@ impl TryFrom<DATA> for EncounterZoneData {
     }
 }
 
+impl TryFrom<EncounterZoneData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: EncounterZoneData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let mut data = cursor.into_inner();
+
+        // An unset encounter zone is stored as the short, zeroed form
+        if data.iter().all(|b| *b == 0) {
+            data.truncate(8);
+        }
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EncounterZone {
     pub header: RecordHeader,
@@ -82,3 +102,26 @@ impl TryFrom<ECZN> for EncounterZone {
         })
     }
 }
+
+impl TryFrom<EncounterZone> for ECZN {
+    type Error = Error;
+
+    fn try_from(obj: EncounterZone) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
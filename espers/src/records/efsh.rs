@@ -1,8 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
-use crate::common::check_done_reading;
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, rgba_from_bytes, rgba_to_bytes, FormID};
 use crate::error::Error;
 use crate::fields::{DATA, EDID, ICO2, ICON, NAM7, NAM8, NAM9};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -17,6 +19,145 @@ pub struct EFSH {
     pub data: Vec<u8>,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct EffectShaderFlags: u32 {
+        const NO_MEMBRANE_SHADER = 0x00000001;
+        const MEMBRANE_GRAYSCALE_COLOR = 0x00000002;
+        const MEMBRANE_GRAYSCALE_ALPHA = 0x00000004;
+        const NO_PARTICLE_SHADER = 0x00000008;
+        const EDGE_EFFECT_INVERSE = 0x00000010;
+        const AFFECT_SKIN_ONLY = 0x00000020;
+        const IGNORE_ALPHA = 0x00000040;
+        const PROJECT_UVS = 0x00000080;
+        const IGNORE_BASE_GEOMETRY_ALPHA = 0x00000100;
+        const LIGHTING = 0x00000200;
+        const NO_WEAPONS = 0x00000400;
+        const PARTICLE_ANIMATED = 0x00008000;
+        const PARTICLE_GRAYSCALE_COLOR = 0x00010000;
+        const PARTICLE_GRAYSCALE_ALPHA = 0x00020000;
+        const USE_BLOOD_GEOMETRY = 0x01000000;
+    }
+}
+
+/// Membrane, edge, particle and addon model settings (DATA).
+/// Blend modes, blend operations and z test functions are kept as the raw
+/// D3D enumeration values.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectShaderData {
+    pub unknown1: [u8; 4],
+    pub membrane_source_blend_mode: u32,
+    pub membrane_blend_operation: u32,
+    pub membrane_z_test_function: u32,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub fill_color_key1: RGBA8,
+    pub fill_alpha_fade_in_time: f32,
+    pub fill_full_alpha_time: f32,
+    pub fill_alpha_fade_out_time: f32,
+    pub fill_persistent_alpha_ratio: f32,
+    pub fill_alpha_pulse_amplitude: f32,
+    pub fill_alpha_pulse_frequency: f32,
+    pub fill_texture_animation_speed_u: f32,
+    pub fill_texture_animation_speed_v: f32,
+    pub edge_falloff: f32,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub edge_color: RGBA8,
+    pub edge_alpha_fade_in_time: f32,
+    pub edge_full_alpha_time: f32,
+    pub edge_alpha_fade_out_time: f32,
+    pub edge_persistent_alpha_ratio: f32,
+    pub edge_alpha_pulse_amplitude: f32,
+    pub edge_alpha_pulse_frequency: f32,
+    pub fill_full_alpha_ratio: f32,
+    pub edge_full_alpha_ratio: f32,
+    pub membrane_dest_blend_mode: u32,
+    pub particle_source_blend_mode: u32,
+    pub particle_blend_operation: u32,
+    pub particle_z_test_function: u32,
+    pub particle_dest_blend_mode: u32,
+    pub particle_birth_ramp_up_time: f32,
+    pub particle_full_birth_time: f32,
+    pub particle_birth_ramp_down_time: f32,
+    pub particle_full_birth_ratio: f32,
+    pub particle_persistent_count: f32,
+    pub particle_lifetime: f32,
+    pub particle_lifetime_variance: f32,
+    pub particle_initial_speed_along_normal: f32,
+    pub particle_acceleration_along_normal: f32,
+    pub particle_initial_velocity: [f32; 3],
+    pub particle_acceleration: [f32; 3],
+    pub particle_scale_key1: f32,
+    pub particle_scale_key2: f32,
+    pub particle_scale_key1_time: f32,
+    pub particle_scale_key2_time: f32,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub color_key1: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub color_key2: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub color_key3: RGBA8,
+    pub color_key_alphas: [f32; 3],
+    pub color_key_times: [f32; 3],
+    pub particle_initial_speed_along_normal_variance: f32,
+    pub particle_initial_rotation: f32,
+    pub particle_initial_rotation_variance: f32,
+    pub particle_rotation_speed: f32,
+    pub particle_rotation_speed_variance: f32,
+    pub addon_models: FormID,
+    pub holes_start_time: f32,
+    pub holes_end_time: f32,
+    pub holes_start_value: f32,
+    pub holes_end_value: f32,
+    pub edge_width: f32,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub edge_width_color: RGBA8,
+    pub explosion_wind_speed: f32,
+    pub texture_count_u: u32,
+    pub texture_count_v: u32,
+    pub addon_models_fade_in_time: f32,
+    pub addon_models_fade_out_time: f32,
+    pub addon_models_scale_start: f32,
+    pub addon_models_scale_end: f32,
+    pub addon_models_scale_in_time: f32,
+    pub addon_models_scale_out_time: f32,
+    pub ambient_sound: FormID,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub fill_color_key2: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub fill_color_key3: RGBA8,
+    pub fill_color_key_scales: [f32; 3],
+    pub fill_color_key_times: [f32; 3],
+    pub color_scale: f32,
+    pub birth_position_offset: f32,
+    pub birth_position_offset_variance: f32,
+    pub particle_animated_start_frame: u32,
+    pub particle_animated_start_frame_variation: u32,
+    pub particle_animated_end_frame: u32,
+    pub particle_animated_loop_start_frame: u32,
+    pub particle_animated_loop_start_variation: u32,
+    pub particle_animated_frame_count: u32,
+    pub particle_animated_frame_count_variation: u32,
+    pub flags: EffectShaderFlags,
+    pub fill_texture_scale_u: f32,
+    pub fill_texture_scale_v: f32,
+    pub scene_graph_emit_depth_limit: u16,
+    pub unknown2: [u8; 2],
+}
+
+impl_field_struct!(EffectShaderData, DATA);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EffectShader {
     pub header: RecordHeader,
@@ -26,7 +167,7 @@ pub struct EffectShader {
     pub post_effect: String,
     pub looped_gradient: Option<String>,
     pub end_gradient: Option<String>,
-    pub shader_data: Vec<u8>,
+    pub shader_data: EffectShaderData,
 }
 
 impl fmt::Display for EffectShader {
@@ -70,3 +211,37 @@ impl TryFrom<EFSH> for EffectShader {
         })
     }
 }
+
+impl TryFrom<EffectShader> for EFSH {
+    type Error = Error;
+
+    fn try_from(obj: EffectShader) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        ICON::try_from(obj.start_effect)?.write(&mut data)?;
+        ICO2::try_from(obj.looped_effect)?.write(&mut data)?;
+        NAM7::try_from(obj.post_effect)?.write(&mut data)?;
+        obj.looped_gradient
+            .map(NAM8::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.end_gradient
+            .map(NAM9::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.shader_data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
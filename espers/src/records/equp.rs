@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{DATA, EDID, PNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -56,3 +56,29 @@ impl TryFrom<EQUP> for EquipSlot {
         })
     }
 }
+
+impl TryFrom<EquipSlot> for EQUP {
+    type Error = Error;
+
+    fn try_from(obj: EquipSlot) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        if !obj.equip_slots.is_empty() {
+            PNAM::try_from(obj.equip_slots)?.write(&mut data)?;
+        }
+        DATA::try_from(obj.use_all_parents)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{Model, ObjectBounds, DATA, EDID, EITM, FULL, MNAM, MODL, MODS, MODT, OBND};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -79,3 +79,43 @@ impl TryFrom<EXPL> for Explosion {
         })
     }
 }
+
+impl TryFrom<Explosion> for EXPL {
+    type Error = Error;
+
+    fn try_from(obj: Explosion) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.enchantment
+            .map(EITM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.modifier
+            .map(MNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, LocalizedString};
 use crate::error::Error;
 use crate::fields::{DATA, EDID, FULL, ICON};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -45,6 +45,21 @@ impl TryFrom<DATA> for EyesFlags {
     }
 }
 
+impl TryFrom<EyesFlags> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: EyesFlags) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Eyes {
     pub header: RecordHeader,
@@ -87,3 +102,29 @@ impl TryFrom<EYES> for Eyes {
         })
     }
 }
+
+impl TryFrom<Eyes> for EYES {
+    type Error = Error;
+
+    fn try_from(obj: Eyes) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        FULL::try_from(obj.full_name)?.write(&mut data)?;
+        ICON::try_from(obj.icon)?.write(&mut data)?;
+        DATA::try_from(obj.flags)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     CrimeGold, EffectCondition, CRGR, CRVA, DATA, EDID, FNAM, FULL, JAIL, JOUT, MNAM, PLCN, PLVD,
     RNAM, STOL, VENC, VEND, VENV, WAIT, XNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -47,6 +47,21 @@ impl TryFrom<XNAM> for Relations {
     }
 }
 
+impl TryFrom<Relations> for XNAM {
+    type Error = Error;
+
+    fn try_from(obj: Relations) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 bitflags! {
     #[binrw]
     #[brw(little)]
@@ -79,6 +94,21 @@ impl TryFrom<DATA> for FactionFlags {
     }
 }
 
+impl TryFrom<FactionFlags> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: FactionFlags) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Rank {
     pub id: u32,
@@ -106,6 +136,19 @@ impl Rank {
             female_title,
         })
     }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        RNAM::try_from(self.id)?.write(cursor)?;
+        self.male_title
+            .map(MNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.female_title
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        Ok(())
+    }
 }
 
 #[binrw]
@@ -131,6 +174,21 @@ impl TryFrom<VENV> for VendorInfo {
     }
 }
 
+impl TryFrom<VendorInfo> for VENV {
+    type Error = Error;
+
+    fn try_from(obj: VendorInfo) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[brw(little)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -151,6 +209,21 @@ impl TryFrom<PLVD> for VendorPlace {
     }
 }
 
+impl TryFrom<VendorPlace> for PLVD {
+    type Error = Error;
+
+    fn try_from(obj: VendorPlace) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [FACT] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Faction {
@@ -276,3 +349,81 @@ impl TryFrom<FACT> for Faction {
         })
     }
 }
+
+impl TryFrom<Faction> for FACT {
+    type Error = Error;
+
+    fn try_from(obj: Faction) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for x in obj.relations {
+            XNAM::try_from(x)?.write(&mut data)?;
+        }
+        DATA::try_from(obj.flags)?.write(&mut data)?;
+        obj.jail.map(JAIL::try_from).transpose()?.write(&mut data)?;
+        obj.follower_wait_marker
+            .map(WAIT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.evidence_chest
+            .map(STOL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.belongings_chest
+            .map(PLCN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.crime_group
+            .map(CRGR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.jail_outfit
+            .map(JOUT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.crime_gold
+            .map(CRVA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for r in obj.ranks {
+            r.write(&mut data)?;
+        }
+        obj.merchandise
+            .map(VEND::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.chest
+            .map(VENC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.vendor
+            .map(VENV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.place
+            .map(PLVD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for x in obj.conditions {
+            x.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
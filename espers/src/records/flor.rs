@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     DestructionData, Model, ObjectBounds, ScriptList, EDID, FNAM, FULL, KSIZ, KWDA, MODL, MODS,
     MODT, OBND, PFIG, PFPC, PNAM, RNAM, SNAM, VMAD,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -68,19 +68,14 @@ impl TryFrom<FLOR> for Flora {
         };
         let model = Model::load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
         let destruction_data = DestructionData::load(&mut cursor)?;
-        let keyword_count: Option<u32> = KSIZ::read(&mut cursor)
+        let _: Option<u32> = KSIZ::read(&mut cursor)
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
         let mut keywords = Vec::new();
-
-        if let Some(kc) = keyword_count {
-            for _ in 0..kc {
-                // It's actually only up to keyword count
-                if let Ok(kwda) = KWDA::read(&mut cursor) {
-                    keywords.push(FormID::read_le(&mut Cursor::new(kwda.data)).unwrap());
-                }
-            }
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
         }
         let unknown = PNAM::read(&mut cursor)?.try_into()?;
         let activate_text = match (RNAM::read(&mut cursor), raw.localized) {
@@ -119,3 +114,55 @@ impl TryFrom<FLOR> for Flora {
         })
     }
 }
+
+impl TryFrom<Flora> for FLOR {
+    type Error = Error;
+
+    fn try_from(obj: Flora) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        FULL::try_from(obj.full_name)?.write(&mut data)?;
+        obj.model.write::<MODL, MODT, MODS>(&mut data)?;
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        PNAM::try_from(obj.unknown)?.write(&mut data)?;
+        obj.activate_text
+            .map(RNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        FNAM::try_from(obj.flags)?.write(&mut data)?;
+        obj.ingredient
+            .map(PFIG::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.pickup_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        PFPC::try_from(obj.percent_chance)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
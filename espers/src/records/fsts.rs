@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{DATA, EDID, XCNT};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -40,6 +40,21 @@ impl TryFrom<XCNT> for SetCount {
     }
 }
 
+impl TryFrom<SetCount> for XCNT {
+    type Error = Error;
+
+    fn try_from(obj: SetCount) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [FSTS] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FootstepSet {
@@ -76,3 +91,27 @@ impl TryFrom<FSTS> for FootstepSet {
         })
     }
 }
+
+impl TryFrom<FootstepSet> for FSTS {
+    type Error = Error;
+
+    fn try_from(obj: FootstepSet) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        XCNT::try_from(obj.counts)?.write(&mut data)?;
+        DATA::try_from(obj.sets)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     DestructionData, Model, ObjectBounds, ScriptList, EDID, ENAM, FNAM, FNMK, FNPR, FULL, KNAM,
     KSIZ, KWDA, MNAM, MODL, MODS, MODT, NAM0, OBND, PNAM, VMAD, WBDT, XMRK,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -45,6 +45,21 @@ impl TryFrom<WBDT> for WorkbenchData {
     }
 }
 
+impl TryFrom<WorkbenchData> for WBDT {
+    type Error = Error;
+
+    fn try_from(obj: WorkbenchData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Marker {
     pub index: u32,
@@ -72,6 +87,16 @@ impl Marker {
         }
         Ok(markers)
     }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        ENAM::try_from(self.index)?.write(cursor)?;
+        self.flags.map(NAM0::try_from).transpose()?.write(cursor)?;
+        self.keyword
+            .map(FNMK::try_from)
+            .transpose()?
+            .write(cursor)?;
+        Ok(())
+    }
 }
 
 /// Parsed [FURN] record
@@ -170,3 +195,63 @@ impl TryFrom<FURN> for Furniture {
         })
     }
 }
+
+impl TryFrom<Furniture> for FURN {
+    type Error = Error;
+
+    fn try_from(obj: Furniture) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        PNAM::try_from(obj.color)?.write(&mut data)?;
+        FNAM::try_from(obj.flags)?.write(&mut data)?;
+        obj.interaction_keyword
+            .map(KNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        MNAM::try_from(obj.marker_flags)?.write(&mut data)?;
+        WBDT::try_from(obj.workbench_data)?.write(&mut data)?;
+        for m in obj.markers {
+            m.write(&mut data)?;
+        }
+        for x in obj.marker_flags_2 {
+            FNPR::try_from(x)?.write(&mut data)?;
+        }
+        obj.marker_model
+            .map(XMRK::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
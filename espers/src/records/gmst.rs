@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, LocalizedString};
 use crate::error::Error;
 use crate::fields::{DATA, EDID};
-use binrw::{binrw, BinRead, NullString};
+use binrw::{binrw, BinRead, BinWrite, NullString};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -31,6 +31,28 @@ pub enum Value {
     Unknown([u8; 4]),
 }
 
+impl TryFrom<Value> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: Value) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        match obj {
+            Value::Bool(b) => b.write_le(&mut cursor)?,
+            Value::Int(i) => i.write_le(&mut cursor)?,
+            Value::Float(f) => f.write_le(&mut cursor)?,
+            Value::Str(LocalizedString::Localized(l)) => l.write_le(&mut cursor)?,
+            Value::Str(LocalizedString::ZString(z)) => NullString::from(z).write(&mut cursor)?,
+            Value::Unknown(u) => u.write_le(&mut cursor)?,
+        }
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameSetting {
     pub header: RecordHeader,
@@ -75,3 +97,27 @@ impl TryFrom<GMST> for GameSetting {
         })
     }
 }
+
+impl TryFrom<GameSetting> for GMST {
+    type Error = Error;
+
+    fn try_from(obj: GameSetting) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        DATA::try_from(obj.value)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{Model, ObjectBounds, DATA, EDID, MODL, MODS, MODT, OBND};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -61,6 +61,21 @@ impl TryFrom<DATA> for GrassData {
     }
 }
 
+impl TryFrom<GrassData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: GrassData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [GRAS] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Grass {
@@ -98,3 +113,30 @@ impl TryFrom<GRAS> for Grass {
         })
     }
 }
+
+impl TryFrom<Grass> for GRAS {
+    type Error = Error;
+
+    fn try_from(obj: Grass) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,7 +1,7 @@
-use crate::common::check_done_reading;
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::records::{RawRecord, Record};
-use binrw::{binrw, io::Cursor, until_eof, Endian};
+use crate::records::{from_raw, to_raw, RawRecord, Record};
+use binrw::{binrw, io::Cursor, until_eof, BinWrite, Endian};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -69,6 +69,15 @@ impl Group {
 
         magics
     }
+
+    /// FormID of the worldspace owning this group, if it is a world
+    /// children group (the exterior cells of a [WRLD](super::World))
+    pub fn world(&self) -> Option<FormID> {
+        match self.header.kind {
+            1 => Some(FormID(self.header.label)),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Group {
@@ -84,16 +93,7 @@ impl TryFrom<GRUP> for Group {
         let mut cursor = Cursor::new(&raw.data);
         let args = (raw.localized,);
         let recs: Vec<RawRecord> = until_eof(&mut cursor, Endian::Little, args)?;
-        let records: Vec<_> = recs
-            .into_iter()
-            .map(|r| {
-                r.clone().try_into().map_err(|err| match err {
-                    Error::BinaryParseError(e) => Error::BinaryParseErrorExtra(e, r),
-                    Error::ExtraBytes(e) => Error::ExtraBytesRaw(e, r),
-                    other => other,
-                })
-            })
-            .collect();
+        let records: Vec<_> = recs.into_iter().map(from_raw).collect();
 
         check_done_reading(&mut cursor)?;
 
@@ -108,11 +108,20 @@ impl TryFrom<Group> for GRUP {
     type Error = Error;
 
     fn try_from(obj: Group) -> Result<Self, Self::Error> {
-        let data = Cursor::new(Vec::new());
+        let mut data = Cursor::new(Vec::new());
+
+        for rec in obj.records {
+            to_raw(rec)?.write(&mut data)?;
+        }
+
+        let data = data.into_inner();
 
         Ok(Self {
-            header: obj.header,
-            data: data.into_inner(),
+            header: GroupHeader {
+                size: data.len() as u32 + 24,
+                ..obj.header
+            },
+            data,
             localized: false,
         })
     }
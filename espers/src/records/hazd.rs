@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{Model, ObjectBounds, DATA, EDID, FULL, MNAM, MODL, MODS, MODT, OBND};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -64,6 +64,21 @@ impl TryFrom<DATA> for HazardData {
     }
 }
 
+impl TryFrom<HazardData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: HazardData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [HAZD] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hazard {
@@ -115,3 +130,39 @@ impl TryFrom<HAZD> for Hazard {
         })
     }
 }
+
+impl TryFrom<Hazard> for HAZD {
+    type Error = Error;
+
+    fn try_from(obj: Hazard) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.image_space_mod
+            .map(MNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
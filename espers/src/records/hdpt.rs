@@ -1,10 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     Model, CNAM, DATA, EDID, FULL, HNAM, MODL, MODS, MODT, NAM0, NAM1, PNAM, RNAM, TNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -50,6 +50,21 @@ impl TryFrom<DATA> for HeadPartFlags {
     }
 }
 
+impl TryFrom<HeadPartFlags> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: HeadPartFlags) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[brw(little, repr = u32)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +89,21 @@ impl TryFrom<PNAM> for HeadPartType {
     }
 }
 
+impl TryFrom<HeadPartType> for PNAM {
+    type Error = Error;
+
+    fn try_from(obj: HeadPartType) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [HDPT] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeadPart {
@@ -151,3 +181,54 @@ impl TryFrom<HDPT> for HeadPart {
         })
     }
 }
+
+impl TryFrom<HeadPart> for HDPT {
+    type Error = Error;
+
+    fn try_from(obj: HeadPart) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        DATA::try_from(obj.flags)?.write(&mut data)?;
+        PNAM::try_from(obj.kind)?.write(&mut data)?;
+        for x in obj.additional_parts {
+            HNAM::try_from(x)?.write(&mut data)?;
+        }
+        for (x, y) in obj.options {
+            NAM0::try_from(x)?.write(&mut data)?;
+            NAM1::try_from(y)?.write(&mut data)?;
+        }
+        obj.base_texture
+            .map(TNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.color
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.resources
+            .map(RNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
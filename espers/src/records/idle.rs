@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{EffectCondition, ANAM, DATA, DNAM, EDID, ENAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -53,6 +53,21 @@ impl TryFrom<DATA> for IdleData {
     }
 }
 
+impl TryFrom<IdleData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: IdleData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 /// Parsed [IDLE] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IdleAnimation {
@@ -107,3 +122,38 @@ impl TryFrom<IDLE> for IdleAnimation {
         })
     }
 }
+
+impl TryFrom<IdleAnimation> for IDLE {
+    type Error = Error;
+
+    fn try_from(obj: IdleAnimation) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        for c in obj.conditions {
+            c.write(&mut data)?;
+        }
+        obj.havok_file
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.animation_event
+            .map(ENAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        ANAM::try_from(obj.animations)?.write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{ObjectBounds, EDID, IDLA, IDLC, IDLF, IDLT, OBND};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -41,6 +41,21 @@ impl TryFrom<IDLF> for IdleMarkerFlags {
     }
 }
 
+impl TryFrom<IdleMarkerFlags> for IDLF {
+    type Error = Error;
+
+    fn try_from(obj: IdleMarkerFlags) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IdleMarker {
     pub header: RecordHeader,
@@ -97,3 +112,41 @@ impl TryFrom<IDLM> for IdleMarker {
         })
     }
 }
+
+impl TryFrom<IdleMarker> for IDLM {
+    type Error = Error;
+
+    fn try_from(obj: IdleMarker) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.flags
+            .map(IDLF::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.count
+            .map(IDLC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.timer
+            .map(IDLT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.animations.is_empty() {
+            IDLA::try_from(obj.animations)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
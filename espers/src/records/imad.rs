@@ -1,10 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{
     BNAM, DNAM, EDID, NAM1, NAM2, NAM3, NAM4, RNAM, SNAM, TNAM, UNAM, VNAM, WNAM, XNAM, YNAM,
 };
-use binrw::{binrw, io::Cursor, until_eof, BinRead, Endian};
+use binrw::{binrw, io::Cursor, until_eof, BinRead, BinWrite, Endian};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -40,6 +40,16 @@ impl TryFrom<Vec<u8>> for Timestamps {
     }
 }
 
+impl TryFrom<Timestamps> for Vec<u8> {
+    type Error = Error;
+
+    fn try_from(obj: Timestamps) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.0.write_le(&mut cursor)?;
+        Ok(cursor.into_inner())
+    }
+}
+
 fn read_attr(cursor: &mut Cursor<&Vec<u8>>) -> Result<Timestamps, Error> {
     <[u8; 4]>::read_le(cursor)
         .map_err(Error::BinaryParseError)
@@ -51,6 +61,15 @@ fn read_attr(cursor: &mut Cursor<&Vec<u8>>) -> Result<Timestamps, Error> {
         })
 }
 
+/// The `*IAD` attributes are keyed by index, with `0x40` set on the "add" half of each pair.
+fn write_attr(cursor: &mut Cursor<Vec<u8>>, magic: u8, attr: Timestamps) -> Result<(), Error> {
+    let data: Vec<u8> = attr.try_into()?;
+    [magic, b'I', b'A', b'D'].write_le(cursor)?;
+    (data.len() as u16).write_le(cursor)?;
+    data.write_le(cursor)?;
+    Ok(())
+}
+
 bitflags! {
     #[binrw]
     #[brw(little)]
@@ -86,6 +105,23 @@ impl TryFrom<DNAM> for ImageSpaceAdapterData {
     }
 }
 
+impl TryFrom<ImageSpaceAdapterData> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: ImageSpaceAdapterData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.flags.write(&mut cursor)?;
+        obj.duration.write_le(&mut cursor)?;
+        obj.entry_counts.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageSpaceAdapter {
     pub header: RecordHeader,
@@ -93,6 +129,8 @@ pub struct ImageSpaceAdapter {
     pub data: ImageSpaceAdapterData,
     pub blur_radius: Timestamps,
     pub double_vision_strength: Timestamps,
+    pub tint_color: TNAM,
+    pub fade_color: NAM3,
     pub radial_blur_strength: Timestamps,
     pub radial_blur_rampup: Timestamps,
     pub radial_blur_start: Timestamps,
@@ -163,8 +201,8 @@ impl TryFrom<IMAD> for ImageSpaceAdapter {
         let data = DNAM::read(&mut cursor)?.try_into()?;
         let blur_radius = BNAM::read(&mut cursor)?.data.try_into()?;
         let double_vision_strength = VNAM::read(&mut cursor)?.data.try_into()?;
-        let _ = TNAM::read(&mut cursor)?;
-        let _ = NAM3::read(&mut cursor)?;
+        let tint_color = TNAM::read(&mut cursor)?;
+        let fade_color = NAM3::read(&mut cursor)?;
         let radial_blur_strength = RNAM::read(&mut cursor)?.data.try_into()?;
         let radial_blur_rampup = SNAM::read(&mut cursor)?.data.try_into()?;
         let radial_blur_start = UNAM::read(&mut cursor)?.data.try_into()?;
@@ -226,6 +264,8 @@ impl TryFrom<IMAD> for ImageSpaceAdapter {
             data,
             blur_radius,
             double_vision_strength,
+            tint_color,
+            fade_color,
             radial_blur_strength,
             radial_blur_rampup,
             radial_blur_start,
@@ -280,3 +320,81 @@ impl TryFrom<IMAD> for ImageSpaceAdapter {
         })
     }
 }
+
+impl TryFrom<ImageSpaceAdapter> for IMAD {
+    type Error = Error;
+
+    fn try_from(obj: ImageSpaceAdapter) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        DNAM::try_from(obj.data)?.write(&mut data)?;
+        BNAM::try_from(Vec::<u8>::try_from(obj.blur_radius)?)?.write(&mut data)?;
+        VNAM::try_from(Vec::<u8>::try_from(obj.double_vision_strength)?)?.write(&mut data)?;
+        obj.tint_color.write(&mut data)?;
+        obj.fade_color.write(&mut data)?;
+        RNAM::try_from(Vec::<u8>::try_from(obj.radial_blur_strength)?)?.write(&mut data)?;
+        SNAM::try_from(Vec::<u8>::try_from(obj.radial_blur_rampup)?)?.write(&mut data)?;
+        UNAM::try_from(Vec::<u8>::try_from(obj.radial_blur_start)?)?.write(&mut data)?;
+        NAM1::try_from(Vec::<u8>::try_from(obj.radial_blur_rampdown)?)?.write(&mut data)?;
+        NAM2::try_from(Vec::<u8>::try_from(obj.radial_blur_downstart)?)?.write(&mut data)?;
+        WNAM::try_from(Vec::<u8>::try_from(obj.depth_of_field_strength)?)?.write(&mut data)?;
+        XNAM::try_from(Vec::<u8>::try_from(obj.depth_of_field_distance)?)?.write(&mut data)?;
+        YNAM::try_from(Vec::<u8>::try_from(obj.depth_of_field_range)?)?.write(&mut data)?;
+        NAM4::try_from(Vec::<u8>::try_from(obj.fullscreen_motion_blur)?)?.write(&mut data)?;
+        write_attr(&mut data, 0x00, obj.eye_adapt_speed_multiply)?;
+        write_attr(&mut data, 0x40, obj.eye_adapt_speed_add)?;
+        write_attr(&mut data, 0x01, obj.bloom_blur_radius_multiply)?;
+        write_attr(&mut data, 0x41, obj.bloom_blur_radius_add)?;
+        write_attr(&mut data, 0x02, obj.bloom_threshold_multiply)?;
+        write_attr(&mut data, 0x42, obj.bloom_threshold_add)?;
+        write_attr(&mut data, 0x03, obj.bloom_scale_multiply)?;
+        write_attr(&mut data, 0x43, obj.bloom_scale_add)?;
+        write_attr(&mut data, 0x04, obj.target_lum_min_multiply)?;
+        write_attr(&mut data, 0x44, obj.target_lum_min_add)?;
+        write_attr(&mut data, 0x05, obj.target_lum_max_multiply)?;
+        write_attr(&mut data, 0x45, obj.target_lum_max_add)?;
+        write_attr(&mut data, 0x06, obj.sunlight_scale_multiply)?;
+        write_attr(&mut data, 0x46, obj.sunlight_scale_add)?;
+        write_attr(&mut data, 0x07, obj.sky_scale_multiply)?;
+        write_attr(&mut data, 0x47, obj.sky_scale_add)?;
+        write_attr(&mut data, 0x08, obj.unknown_1)?;
+        write_attr(&mut data, 0x48, obj.unknown_2)?;
+        write_attr(&mut data, 0x09, obj.unknown_3)?;
+        write_attr(&mut data, 0x49, obj.unknown_4)?;
+        write_attr(&mut data, 0x0a, obj.unknown_5)?;
+        write_attr(&mut data, 0x4a, obj.unknown_6)?;
+        write_attr(&mut data, 0x0b, obj.unknown_7)?;
+        write_attr(&mut data, 0x4b, obj.unknown_8)?;
+        write_attr(&mut data, 0x0c, obj.unknown_9)?;
+        write_attr(&mut data, 0x4c, obj.unknown_10)?;
+        write_attr(&mut data, 0x0d, obj.unknown_11)?;
+        write_attr(&mut data, 0x4d, obj.unknown_12)?;
+        write_attr(&mut data, 0x0e, obj.unknown_13)?;
+        write_attr(&mut data, 0x4e, obj.unknown_14)?;
+        write_attr(&mut data, 0x0f, obj.unknown_15)?;
+        write_attr(&mut data, 0x4f, obj.unknown_16)?;
+        write_attr(&mut data, 0x10, obj.unknown_17)?;
+        write_attr(&mut data, 0x50, obj.unknown_18)?;
+        write_attr(&mut data, 0x11, obj.saturation_multiply)?;
+        write_attr(&mut data, 0x51, obj.saturation_add)?;
+        write_attr(&mut data, 0x12, obj.brightness_multiply)?;
+        write_attr(&mut data, 0x52, obj.brightness_add)?;
+        write_attr(&mut data, 0x13, obj.contrast_multiply)?;
+        write_attr(&mut data, 0x53, obj.contrast_add)?;
+        write_attr(&mut data, 0x14, obj.unknown_19)?;
+        write_attr(&mut data, 0x54, obj.unknown_20)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,8 +1,9 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{CNAM, DNAM, EDID, ENAM, HNAM, TNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -17,10 +18,85 @@ pub struct IMGS {
     pub data: Vec<u8>,
 }
 
+/// High dynamic range settings (HNAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageSpaceHdr {
+    pub eye_adapt_speed: f32,
+    pub bloom_blur_radius: f32,
+    pub bloom_threshold: f32,
+    pub bloom_scale: f32,
+    pub receive_bloom_threshold: f32,
+    pub white: f32,
+    pub sunlight_scale: f32,
+    pub sky_scale: f32,
+    pub eye_adapt_strength: f32,
+}
+
+impl_field_struct!(ImageSpaceHdr, HNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageSpaceCinematic {
+    pub saturation: f32,
+    pub brightness: f32,
+    pub contrast: f32,
+}
+
+impl_field_struct!(ImageSpaceCinematic, CNAM);
+
+/// Screen tint (TNAM). The colour channels are stored as floats from 0 to 1.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageSpaceTint {
+    pub amount: f32,
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+impl_field_struct!(ImageSpaceTint, TNAM);
+
+impl ImageSpaceTint {
+    /// The tint colour as 8 bit channels, with the tint amount as alpha
+    pub fn color(&self) -> RGBA8 {
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        RGBA8::new(
+            channel(self.red),
+            channel(self.green),
+            channel(self.blue),
+            channel(self.amount),
+        )
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageSpaceDepthOfField {
+    pub strength: f32,
+    pub distance: f32,
+    pub range: f32,
+    #[br(try)]
+    pub unknown: Option<[u8; 2]>,
+    #[br(try)]
+    pub sky_blur_radius: Option<u16>,
+}
+
+impl_field_struct!(ImageSpaceDepthOfField, DNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageSpace {
     pub header: RecordHeader,
     pub edid: String,
+    pub unknown: Option<Vec<u8>>,
+    pub hdr: Option<ImageSpaceHdr>,
+    pub cinematic: Option<ImageSpaceCinematic>,
+    pub tint: Option<ImageSpaceTint>,
+    pub depth_of_field: Option<ImageSpaceDepthOfField>,
 }
 
 impl fmt::Display for ImageSpace {
@@ -37,17 +113,72 @@ impl TryFrom<IMGS> for ImageSpace {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
-        let _ = ENAM::read(&mut cursor).ok();
-        let _ = HNAM::read(&mut cursor).ok();
-        let _ = CNAM::read(&mut cursor).ok();
-        let _ = TNAM::read(&mut cursor).ok();
-        let _ = DNAM::read(&mut cursor).ok();
+        let unknown = ENAM::read(&mut cursor).ok().map(|x| x.data);
+        let hdr = HNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let cinematic = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let tint = TNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let depth_of_field = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
 
         check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            unknown,
+            hdr,
+            cinematic,
+            tint,
+            depth_of_field,
+        })
+    }
+}
+
+impl TryFrom<ImageSpace> for IMGS {
+    type Error = Error;
+
+    fn try_from(obj: ImageSpace) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.unknown
+            .map(|d| ENAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        obj.hdr.map(HNAM::try_from).transpose()?.write(&mut data)?;
+        obj.cinematic
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.tint.map(TNAM::try_from).transpose()?.write(&mut data)?;
+        obj.depth_of_field
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
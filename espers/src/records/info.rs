@@ -1,12 +1,19 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    EffectCondition, ScriptList, ANAM, CNAM, DATA, DNAM, EDID, ENAM, LNAM, NAM1, NAM2, NAM3, ONAM,
+    PNAM, RNAM, SNAM, TCLT, TPIC, TRDT, TWAT, VMAD,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [INFO](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/INFO) record
 #[binrw]
+#[br(import(localized: bool))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"INFO")]
 pub struct INFO {
@@ -14,12 +21,131 @@ pub struct INFO {
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct InfoFlags: u16 {
+        const GOODBYE = 0x0001;
+        const RANDOM = 0x0002;
+        const SAY_ONCE = 0x0004;
+        const REQUIRES_PLAYER_ACTIVATION = 0x0008;
+        const INFO_REFUSAL = 0x0010;
+        const RANDOM_END = 0x0020;
+        const INVISIBLE_CONTINUE = 0x0040;
+        const WALK_AWAY = 0x0080;
+        const WALK_AWAY_INVISIBLE_IN_MENU = 0x0100;
+        const FORCE_SUBTITLE = 0x0200;
+        const CAN_MOVE_WHILE_GREETING = 0x0400;
+        const HAS_NO_LIP_FILE = 0x0800;
+        const REQUIRES_POST_PROCESSING = 0x1000;
+        const AUDIO_OUTPUT_OVERRIDE = 0x2000;
+        const SPENDS_FAVOR_POINTS = 0x4000;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseFlags {
+    pub flags: InfoFlags,
+    pub reset_hours: u16,
+}
+
+impl_field_struct!(ResponseFlags, ENAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseData {
+    pub emotion_type: u32,
+    pub emotion_value: u32,
+    pub unknown1: i32,
+    pub response_number: u8,
+    pub unknown2: [u8; 3],
+    pub sound: FormID,
+    pub use_emotion_animation: u8,
+    pub unknown3: [u8; 3],
+}
+
+impl_field_struct!(ResponseData, TRDT);
+
+/// A single line of dialogue (TRDT through LNAM)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub data: ResponseData,
+    pub text: LocalizedString,
+    pub notes: Option<String>,
+    pub edits: Option<String>,
+    pub speaker_idle: Option<FormID>,
+    pub listener_idle: Option<FormID>,
+}
+
+impl Response {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>, localized: bool) -> Result<Self, Error> {
+        let data = TRDT::read(cursor)?.try_into()?;
+        let text = NAM1::read(cursor)?;
+        let text = if localized {
+            LocalizedString::Localized(text.try_into()?)
+        } else {
+            LocalizedString::ZString(text.try_into()?)
+        };
+        let notes = NAM2::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let edits = NAM3::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let speaker_idle = SNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let listener_idle = LNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self {
+            data,
+            text,
+            notes,
+            edits,
+            speaker_idle,
+            listener_idle,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        TRDT::try_from(self.data)?.write(cursor)?;
+        NAM1::try_from(self.text)?.write(cursor)?;
+        self.notes.map(NAM2::try_from).transpose()?.write(cursor)?;
+        self.edits.map(NAM3::try_from).transpose()?.write(cursor)?;
+        self.speaker_idle
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.listener_idle
+            .map(LNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DialogueTopicInfo {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub scripts: Option<ScriptList>,
+    pub unused: Option<Vec<u8>>,
+    pub flags: Option<ResponseFlags>,
+    pub topic: Option<FormID>,
+    pub previous_info: Option<FormID>,
+    pub favor_level: Option<u8>,
+    pub linked_topics: Vec<FormID>,
+    pub shared_info: Option<FormID>,
+    pub responses: Vec<Response>,
+    pub conditions: Vec<EffectCondition>,
+    pub prompt: Option<LocalizedString>,
+    pub speaker: Option<FormID>,
+    pub walk_away_topic: Option<FormID>,
+    pub audio_output_override: Option<FormID>,
 }
 
 impl fmt::Display for DialogueTopicInfo {
@@ -43,10 +169,156 @@ impl TryFrom<INFO> for DialogueTopicInfo {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(|v| v.parse(b"INFO"))
+            .transpose()?;
+        let unused = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let flags = ENAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let topic = TPIC::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let previous_info = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let favor_level = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut linked_topics = Vec::new();
+        while let Ok(t) = TCLT::read(&mut cursor) {
+            linked_topics.push(t.try_into()?);
+        }
+        let shared_info = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut responses = Vec::new();
+        while let Ok(r) = Response::load(&mut cursor, raw.localized) {
+            responses.push(r);
+        }
+        let conditions = EffectCondition::load_multiple(&mut cursor)?;
+        let prompt = match (RNAM::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let speaker = ANAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let walk_away_topic = TWAT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let audio_output_override = ONAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            unused,
+            flags,
+            topic,
+            previous_info,
+            favor_level,
+            linked_topics,
+            shared_info,
+            responses,
+            conditions,
+            prompt,
+            speaker,
+            walk_away_topic,
+            audio_output_override,
+        })
+    }
+}
+
+impl TryFrom<DialogueTopicInfo> for INFO {
+    type Error = Error;
+
+    fn try_from(obj: DialogueTopicInfo) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unused
+            .map(DATA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flags
+            .map(ENAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.topic
+            .map(TPIC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.previous_info
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.favor_level
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for t in obj.linked_topics {
+            TCLT::try_from(t)?.write(&mut data)?;
+        }
+        obj.shared_info
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for r in obj.responses {
+            r.write(&mut data)?;
+        }
+        for c in obj.conditions {
+            c.write(&mut data)?;
+        }
+        obj.prompt
+            .map(RNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.speaker
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.walk_away_topic
+            .map(TWAT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.audio_output_override
+            .map(ONAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
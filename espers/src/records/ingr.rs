@@ -1,11 +1,11 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
 use crate::fields::{
     Condition, EffectItem, Model, ObjectBounds, ScriptList, CTDA, DATA, EDID, EFID, EFIT, ENIT,
     FULL, ICON, KSIZ, KWDA, MODL, MODS, MODT, OBND, VMAD, YNAM, ZNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -45,6 +45,21 @@ impl TryFrom<DATA> for IngredientData {
     }
 }
 
+impl TryFrom<IngredientData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: IngredientData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 bitflags! {
     #[binrw]
     #[brw(little)]
@@ -75,6 +90,21 @@ impl TryFrom<ENIT> for EffectData {
     }
 }
 
+impl TryFrom<EffectData> for ENIT {
+    type Error = Error;
+
+    fn try_from(obj: EffectData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ingredient {
     pub header: RecordHeader,
@@ -115,19 +145,14 @@ impl TryFrom<INGR> for Ingredient {
             (f, true) => LocalizedString::Localized(f.try_into()?),
             (z, false) => LocalizedString::ZString(z.try_into()?),
         };
-        let keyword_count: Option<u32> = KSIZ::read(&mut cursor)
+        let _: Option<u32> = KSIZ::read(&mut cursor)
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
         let mut keywords = Vec::new();
-
-        if let Some(kc) = keyword_count {
-            for _ in 0..kc {
-                // It's actually only up to keyword count
-                if let Ok(kwda) = KWDA::read(&mut cursor) {
-                    keywords.push(FormID::read_le(&mut Cursor::new(kwda.data)).unwrap());
-                }
-            }
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
         }
         let model = Model::load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
         let inventory_image = ICON::read(&mut cursor)
@@ -176,3 +201,58 @@ impl TryFrom<INGR> for Ingredient {
         })
     }
 }
+
+impl TryFrom<Ingredient> for INGR {
+    type Error = Error;
+
+    fn try_from(obj: Ingredient) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        FULL::try_from(obj.full_name)?.write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.model.write::<MODL, MODT, MODS>(&mut data)?;
+        obj.inventory_image
+            .map(ICON::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        ENIT::try_from(obj.effect_data)?.write(&mut data)?;
+        for (efid, efit, ctdas) in obj.effects {
+            EFID::try_from(efid)?.write(&mut data)?;
+            EFIT::try_from(efit)?.write(&mut data)?;
+            for ctda in ctdas {
+                CTDA::try_from(ctda)?.write(&mut data)?;
+            }
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
+        })
+    }
+}
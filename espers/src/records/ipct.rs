@@ -1,10 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{
     DecalData, Model, DATA, DNAM, DODT, EDID, ENAM, MODL, MODS, MODT, NAM1, NAM2, SNAM,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -42,6 +42,21 @@ impl TryFrom<DATA> for ImpactDataData {
     }
 }
 
+impl TryFrom<ImpactDataData> for DATA {
+    type Error = Error;
+
+    fn try_from(obj: ImpactDataData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImpactData {
     pub header: RecordHeader,
@@ -113,3 +128,53 @@ impl TryFrom<IPCT> for ImpactData {
         })
     }
 }
+
+impl TryFrom<ImpactData> for IPCT {
+    type Error = Error;
+
+    fn try_from(obj: ImpactData) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        obj.decal_data
+            .map(DODT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.texture_set
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.secondary_texture_set
+            .map(ENAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.impact_sound_1
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.impact_sound_2
+            .map(NAM1::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.effect_hazard
+            .map(NAM2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
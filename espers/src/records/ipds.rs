@@ -1,8 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
 use crate::fields::{EDID, PNAM};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -36,6 +36,21 @@ impl TryFrom<PNAM> for ImpactDataItem {
     }
 }
 
+impl TryFrom<ImpactDataItem> for PNAM {
+    type Error = Error;
+
+    fn try_from(obj: ImpactDataItem) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImpactDataSet {
     pub header: RecordHeader,
@@ -71,3 +86,28 @@ impl TryFrom<IPDS> for ImpactDataSet {
         })
     }
 }
+
+impl TryFrom<ImpactDataSet> for IPDS {
+    type Error = Error;
+
+    fn try_from(obj: ImpactDataSet) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        for x in obj.items {
+            PNAM::try_from(x)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,11 +1,19 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{ATXT, BTXT, DATA, EDID, VCLR, VHGT, VNML, VTEX, VTXT};
+use binrw::{binrw, helpers::count, until_eof, BinRead, BinWrite, Endian};
+use rgb::RGB8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// Number of vertices along each side of a cell
+pub const GRID_SIZE: usize = 33;
+
+/// Game units per step of the height map
+pub const HEIGHT_SCALE: f32 = 8.0;
+
 #[binrw]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"LAND")]
@@ -16,10 +24,96 @@ pub struct LAND {
     pub data: Vec<u8>,
 }
 
+/// Vertex heights, stored as an offset followed by per-vertex gradients
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeightMap {
+    pub offset: f32,
+    #[br(parse_with = count(GRID_SIZE * GRID_SIZE))]
+    pub gradients: Vec<i8>,
+    pub unused: [u8; 3],
+}
+
+impl HeightMap {
+    /// Absolute vertex heights in game units, indexed as `[row][column]`.
+    ///
+    /// The first gradient of each row is relative to the start of the
+    /// previous row, the others to the previous vertex in the same row.
+    pub fn heights(&self) -> [[f32; GRID_SIZE]; GRID_SIZE] {
+        let mut heights = [[0.0; GRID_SIZE]; GRID_SIZE];
+        let mut row_start = self.offset;
+        for (row, gradients) in heights.iter_mut().zip(self.gradients.chunks(GRID_SIZE)) {
+            let mut offset = row_start;
+            for (col, (height, gradient)) in row.iter_mut().zip(gradients).enumerate() {
+                offset += *gradient as f32;
+                if col == 0 {
+                    row_start = offset;
+                }
+                *height = offset * HEIGHT_SCALE;
+            }
+        }
+        heights
+    }
+}
+
+impl_field_struct!(HeightMap, VHGT);
+
+/// Texture applied to one quadrant of the cell (0 = bottom left,
+/// 1 = bottom right, 2 = top left, 3 = top right)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerHeader {
+    pub texture: FormID,
+    pub quadrant: u8,
+    pub unknown: u8,
+    pub layer: u16,
+}
+
+impl_field_struct!(LayerHeader, BTXT);
+
+impl_field_struct!(LayerHeader, ATXT);
+
+/// Opacity of an alpha layer at one vertex of its quadrant (`position`
+/// indexes a 17×17 grid)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlphaPoint {
+    pub position: u16,
+    pub unknown: [u8; 2],
+    pub opacity: f32,
+}
+
+impl_field_vec!(AlphaPoint, VTXT);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TextureLayer {
+    Base(LayerHeader),
+    Alpha {
+        header: LayerHeader,
+        points: Option<Vec<AlphaPoint>>,
+    },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Landscape {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub flags: Option<u32>,
+    pub normals: Option<Vec<[i8; 3]>>,
+    pub height_map: Option<HeightMap>,
+    pub colors: Option<Vec<RGB8>>,
+    pub layers: Vec<TextureLayer>,
+    pub textures: Vec<FormID>,
+}
+
+impl Landscape {
+    /// Absolute vertex heights of the cell, see [HeightMap::heights]
+    pub fn heights(&self) -> Option<[[f32; GRID_SIZE]; GRID_SIZE]> {
+        self.height_map.as_ref().map(HeightMap::heights)
+    }
 }
 
 impl fmt::Display for Landscape {
@@ -39,10 +133,108 @@ impl TryFrom<LAND> for Landscape {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let flags = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let normals = VNML::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let height_map = VHGT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let colors = VCLR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        let mut layers = Vec::new();
+        loop {
+            if let Ok(b) = BTXT::read(&mut cursor) {
+                layers.push(TextureLayer::Base(b.try_into()?));
+            } else if let Ok(a) = ATXT::read(&mut cursor) {
+                let points = VTXT::read(&mut cursor)
+                    .ok()
+                    .map(TryInto::try_into)
+                    .transpose()?;
+                layers.push(TextureLayer::Alpha {
+                    header: a.try_into()?,
+                    points,
+                });
+            } else {
+                break;
+            }
+        }
+
+        let textures = VTEX::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            flags,
+            normals,
+            height_map,
+            colors,
+            layers,
+            textures,
+        })
+    }
+}
+
+impl TryFrom<Landscape> for LAND {
+    type Error = Error;
+
+    fn try_from(obj: Landscape) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.flags
+            .map(DATA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.normals
+            .map(VNML::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.height_map
+            .map(VHGT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.colors
+            .map(VCLR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for layer in obj.layers {
+            match layer {
+                TextureLayer::Base(b) => BTXT::try_from(b)?.write(&mut data)?,
+                TextureLayer::Alpha { header, points } => {
+                    ATXT::try_from(header)?.write(&mut data)?;
+                    points.map(VTXT::try_from).transpose()?.write(&mut data)?;
+                }
+            }
+        }
+        if !obj.textures.is_empty() {
+            VTEX::try_from(obj.textures)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
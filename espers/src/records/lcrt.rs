@@ -1,7 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{CNAM, EDID};
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -43,6 +44,8 @@ impl TryFrom<LCRT> for LocationRef {
             .map(TryInto::try_into)
             .transpose()?;
 
+        check_done_reading(&mut cursor)?;
+
         Ok(Self {
             header: raw.header,
             edid,
@@ -50,3 +53,29 @@ impl TryFrom<LCRT> for LocationRef {
         })
     }
 }
+
+impl TryFrom<LocationRef> for LCRT {
+    type Error = Error;
+
+    fn try_from(obj: LocationRef) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.color
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
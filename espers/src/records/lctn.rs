@@ -1,25 +1,146 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    ACEC, ACEP, ACID, ACPR, ACSR, ACUN, CNAM, EDID, FNAM, FULL, KSIZ, KWDA, LCEC, LCEP, LCID, LCPR,
+    LCSR, LCUN, MNAM, NAM0, NAM1, PNAM, RCEC, RCPR, RCSR, RCUN, RNAM,
+};
+use binrw::{binrw, until_eof, BinRead, BinWrite, Endian};
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"LCTN")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LCTN {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+/// A persistent reference in the location (ACPR, LCPR)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistentRef {
+    pub reference: FormID,
+    /// Worldspace or cell containing the reference
+    pub location: FormID,
+    pub grid_y: i16,
+    pub grid_x: i16,
+}
+
+impl_field_vec!(PersistentRef, ACPR);
+impl_field_vec!(PersistentRef, LCPR);
+
+/// A unique actor in the location (ACUN, LCUN)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UniqueRef {
+    pub actor: FormID,
+    pub reference: FormID,
+    pub location: FormID,
+}
+
+impl_field_vec!(UniqueRef, ACUN);
+impl_field_vec!(UniqueRef, LCUN);
+
+/// A reference with a location ref type, e.g. a boss marker (ACSR, LCSR)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaticRef {
+    pub ref_type: FormID,
+    pub marker: FormID,
+    /// Worldspace or cell containing the marker
+    pub location: FormID,
+    pub grid_y: i16,
+    pub grid_x: i16,
+}
+
+impl_field_vec!(StaticRef, ACSR);
+impl_field_vec!(StaticRef, LCSR);
+
+/// An actor enabled by another reference (ACEP, LCEP)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnablePoint {
+    pub actor: FormID,
+    pub reference: FormID,
+    pub grid_y: i16,
+    pub grid_x: i16,
+}
+
+impl_field_vec!(EnablePoint, ACEP);
+impl_field_vec!(EnablePoint, LCEP);
+
+/// Cells of a worldspace or interior used for encounters (ACEC, LCEC, RCEC)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterCells {
+    pub location: FormID,
+    /// Grid coordinates as (y, x)
+    #[br(parse_with = until_eof)]
+    pub cells: Vec<(i16, i16)>,
+}
+
+impl_field_struct!(EncounterCells, ACEC);
+impl_field_struct!(EncounterCells, LCEC);
+impl_field_struct!(EncounterCells, RCEC);
+
+impl_field_vec!(FormID, RCPR);
+impl_field_vec!(FormID, RCUN);
+impl_field_vec!(FormID, RCSR);
+impl_field_vec!(FormID, ACID);
+impl_field_vec!(FormID, LCID);
+
+/// References in the location. Each kind is split into those added by this
+/// plugin (AC*), those copied from its masters (LC*) and those it removes (RC*).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocationRefs {
+    pub added_persistent: Vec<PersistentRef>,
+    pub master_persistent: Vec<PersistentRef>,
+    pub removed_persistent: Vec<FormID>,
+    pub added_unique: Vec<UniqueRef>,
+    pub master_unique: Vec<UniqueRef>,
+    pub removed_unique: Vec<FormID>,
+    pub added_static: Vec<StaticRef>,
+    pub master_static: Vec<StaticRef>,
+    pub removed_static: Vec<FormID>,
+    pub added_encounter_cells: Vec<EncounterCells>,
+    pub master_encounter_cells: Vec<EncounterCells>,
+    pub removed_encounter_cells: Vec<EncounterCells>,
+    pub added_markers: Vec<FormID>,
+    pub master_markers: Vec<FormID>,
+    pub added_enable_points: Vec<EnablePoint>,
+    pub master_enable_points: Vec<EnablePoint>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Location {
     pub header: RecordHeader,
     pub edid: String,
+    pub refs: LocationRefs,
+    pub full_name: Option<LocalizedString>,
+    pub keywords: Vec<FormID>,
+    pub parent: Option<FormID>,
+    pub music: Option<FormID>,
+    pub unreported_crime_faction: Option<FormID>,
+    pub world_location_marker: Option<FormID>,
+    pub world_location_radius: Option<f32>,
+    pub horse_marker: Option<FormID>,
+    pub color: Option<RGBA8>,
 }
 
 impl fmt::Display for Location {
@@ -28,6 +149,101 @@ impl fmt::Display for Location {
     }
 }
 
+/// Reads an optional array subrecord, defaulting to an empty list
+macro_rules! read_vec {
+    ($field: ident, $cursor: expr) => {
+        $field::read($cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default()
+    };
+}
+
+/// Writes an array subrecord, unless the list is empty
+macro_rules! write_vec {
+    ($field: ident, $list: expr, $cursor: expr) => {
+        if !$list.is_empty() {
+            $field::try_from($list)?.write($cursor)?;
+        }
+    };
+}
+
+impl LocationRefs {
+    fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let added_persistent = read_vec!(ACPR, cursor);
+        let master_persistent = read_vec!(LCPR, cursor);
+        let removed_persistent = read_vec!(RCPR, cursor);
+        let added_unique = read_vec!(ACUN, cursor);
+        let master_unique = read_vec!(LCUN, cursor);
+        let removed_unique = read_vec!(RCUN, cursor);
+        let added_static = read_vec!(ACSR, cursor);
+        let master_static = read_vec!(LCSR, cursor);
+        let removed_static = read_vec!(RCSR, cursor);
+        let mut added_encounter_cells = Vec::new();
+        while let Ok(a) = ACEC::read(cursor) {
+            added_encounter_cells.push(a.try_into()?);
+        }
+        let mut master_encounter_cells = Vec::new();
+        while let Ok(l) = LCEC::read(cursor) {
+            master_encounter_cells.push(l.try_into()?);
+        }
+        let mut removed_encounter_cells = Vec::new();
+        while let Ok(r) = RCEC::read(cursor) {
+            removed_encounter_cells.push(r.try_into()?);
+        }
+        let added_markers = read_vec!(ACID, cursor);
+        let master_markers = read_vec!(LCID, cursor);
+        let added_enable_points = read_vec!(ACEP, cursor);
+        let master_enable_points = read_vec!(LCEP, cursor);
+
+        Ok(Self {
+            added_persistent,
+            master_persistent,
+            removed_persistent,
+            added_unique,
+            master_unique,
+            removed_unique,
+            added_static,
+            master_static,
+            removed_static,
+            added_encounter_cells,
+            master_encounter_cells,
+            removed_encounter_cells,
+            added_markers,
+            master_markers,
+            added_enable_points,
+            master_enable_points,
+        })
+    }
+
+    fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        write_vec!(ACPR, self.added_persistent, cursor);
+        write_vec!(LCPR, self.master_persistent, cursor);
+        write_vec!(RCPR, self.removed_persistent, cursor);
+        write_vec!(ACUN, self.added_unique, cursor);
+        write_vec!(LCUN, self.master_unique, cursor);
+        write_vec!(RCUN, self.removed_unique, cursor);
+        write_vec!(ACSR, self.added_static, cursor);
+        write_vec!(LCSR, self.master_static, cursor);
+        write_vec!(RCSR, self.removed_static, cursor);
+        for a in self.added_encounter_cells {
+            ACEC::try_from(a)?.write(cursor)?;
+        }
+        for l in self.master_encounter_cells {
+            LCEC::try_from(l)?.write(cursor)?;
+        }
+        for r in self.removed_encounter_cells {
+            RCEC::try_from(r)?.write(cursor)?;
+        }
+        write_vec!(ACID, self.added_markers, cursor);
+        write_vec!(LCID, self.master_markers, cursor);
+        write_vec!(ACEP, self.added_enable_points, cursor);
+        write_vec!(LCEP, self.master_enable_points, cursor);
+        Ok(())
+    }
+}
+
 impl TryFrom<LCTN> for Location {
     type Error = Error;
 
@@ -36,10 +252,125 @@ impl TryFrom<LCTN> for Location {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
+        let refs = LocationRefs::load(&mut cursor)?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let _: Option<u32> = KSIZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+        let parent = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let music = NAM1::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unreported_crime_faction = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let world_location_marker = MNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let world_location_radius = RNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let horse_marker = NAM0::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let color = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            refs,
+            full_name,
+            keywords,
+            parent,
+            music,
+            unreported_crime_faction,
+            world_location_marker,
+            world_location_radius,
+            horse_marker,
+            color,
+        })
+    }
+}
+
+impl TryFrom<Location> for LCTN {
+    type Error = Error;
+
+    fn try_from(obj: Location) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.refs.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.parent
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.music
+            .map(NAM1::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unreported_crime_faction
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.world_location_marker
+            .map(MNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.world_location_radius
+            .map(RNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.horse_marker
+            .map(NAM0::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.color
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,7 +1,9 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, DirectionalAmbient, Flags, RecordHeader};
+use crate::common::{check_done_reading, rgba_from_bytes, rgba_to_bytes};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{DALC, DATA, EDID};
+use binrw::{binrw, BinRead, BinWrite};
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +18,46 @@ pub struct LGTM {
     pub data: Vec<u8>,
 }
 
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LightingTemplateData {
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub ambient: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub directional: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub fog_near_color: RGBA8,
+    pub fog_near: f32,
+    pub fog_far: f32,
+    pub rotation_xy: i32,
+    pub rotation_z: i32,
+    pub directional_fade: f32,
+    pub fog_clip_distance: f32,
+    pub fog_pow: f32,
+    /// Directional ambient colours, superseded by DALC
+    pub unknown1: [u8; 32],
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub fog_far_color: RGBA8,
+    pub fog_max: f32,
+    pub light_fade_dist_start: f32,
+    pub light_fade_dist_end: f32,
+    #[br(try)]
+    pub unknown2: Option<[u8; 4]>,
+}
+
+impl_field_struct!(LightingTemplateData, DATA);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LightingTemplate {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub data: Option<LightingTemplateData>,
+    pub directional_ambient: Option<DirectionalAmbient>,
 }
 
 impl fmt::Display for LightingTemplate {
@@ -43,10 +81,49 @@ impl TryFrom<LGTM> for LightingTemplate {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let lighting = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let directional_ambient = DALC::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            data: lighting,
+            directional_ambient,
+        })
+    }
+}
+
+impl TryFrom<LightingTemplate> for LGTM {
+    type Error = Error;
+
+    fn try_from(obj: LightingTemplate) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.data.map(DATA::try_from).transpose()?.write(&mut data)?;
+        obj.directional_ambient
+            .map(DALC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
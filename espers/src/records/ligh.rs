@@ -1,25 +1,98 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, rgba_from_bytes, rgba_to_bytes, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    DestructionData, Model, ObjectBounds, ScriptList, DATA, EDID, FNAM, FULL, ICON, MICO, MODL,
+    MODS, MODT, OBND, SNAM, VMAD,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"LIGH")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LIGH {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct LightFlags: u32 {
+        const DYNAMIC = 0x00000001;
+        const CAN_BE_CARRIED = 0x00000002;
+        const NEGATIVE = 0x00000004;
+        const FLICKER = 0x00000008;
+        const UNKNOWN = 0x00000010;
+        const OFF_BY_DEFAULT = 0x00000020;
+        const FLICKER_SLOW = 0x00000040;
+        const PULSE = 0x00000080;
+        const PULSE_SLOW = 0x00000100;
+        const SPOT_LIGHT = 0x00000200;
+        const SHADOW_SPOTLIGHT = 0x00000400;
+        const SHADOW_HEMISPHERE = 0x00000800;
+        const SHADOW_OMNIDIRECTIONAL = 0x00001000;
+        const PORTAL_STRICT = 0x00002000;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlickerEffect {
+    pub period: f32,
+    pub intensity_amplitude: f32,
+    pub movement_amplitude: f32,
 }
 
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LightData {
+    /// Duration in hours for carried lights, -1 for infinite
+    pub time: i32,
+    pub radius: u32,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub color: RGBA8,
+    pub flags: LightFlags,
+    pub falloff_exponent: f32,
+    pub fov: f32,
+    pub near_clip: f32,
+    pub flicker: FlickerEffect,
+    pub value: u32,
+    pub weight: f32,
+}
+
+impl_field_struct!(LightData, DATA);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Light {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub scripts: Option<ScriptList>,
+    pub bounds: ObjectBounds,
+    pub model: Option<Model>,
+    pub destruction_data: Option<DestructionData>,
+    pub full_name: Option<LocalizedString>,
+    pub icon: Option<String>,
+    pub message_icon: Option<String>,
+    pub data: LightData,
+    pub fade: Option<f32>,
+    pub sound: Option<FormID>,
 }
 
 impl fmt::Display for Light {
@@ -39,10 +112,100 @@ impl TryFrom<LIGH> for Light {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let bounds = OBND::read(&mut cursor)?.try_into()?;
+        let model = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let destruction_data = DestructionData::load(&mut cursor)?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let icon = ICON::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let message_icon = MICO::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let light_data = DATA::read(&mut cursor)?.try_into()?;
+        let fade = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let sound = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            bounds,
+            model,
+            destruction_data,
+            full_name,
+            icon,
+            message_icon,
+            data: light_data,
+            fade,
+            sound,
+        })
+    }
+}
+
+impl TryFrom<Light> for LIGH {
+    type Error = Error;
+
+    fn try_from(obj: Light) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.icon.map(ICON::try_from).transpose()?.write(&mut data)?;
+        obj.message_icon
+            .map(MICO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        obj.fade.map(FNAM::try_from).transpose()?.write(&mut data)?;
+        obj.sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
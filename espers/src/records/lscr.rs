@@ -1,10 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::error::Error;
 use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
-use std::io::Cursor;
+use std::io::{Cursor, Read};
 
 #[binrw]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +20,7 @@ pub struct LSCR {
 pub struct LoadScreen {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub unparsed: Vec<u8>,
 }
 
 impl fmt::Display for LoadScreen {
@@ -39,10 +40,36 @@ impl TryFrom<LSCR> for LoadScreen {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let mut unparsed = Vec::new();
+        cursor.read_to_end(&mut unparsed)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            unparsed,
+        })
+    }
+}
+
+impl TryFrom<LoadScreen> for LSCR {
+    type Error = Error;
+
+    fn try_from(obj: LoadScreen) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.unparsed.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
@@ -1,7 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::check_done_reading;
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{LeveledEntry, LeveledFlags, ObjectBounds, EDID, LLCT, LVLD, LVLF, OBND};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -20,6 +21,10 @@ pub struct LVSP {
 pub struct LeveledSpell {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub bounds: Option<ObjectBounds>,
+    pub chance_none: u8,
+    pub flags: LeveledFlags,
+    pub entries: Vec<LeveledEntry>,
 }
 
 impl fmt::Display for LeveledSpell {
@@ -39,10 +44,61 @@ impl TryFrom<LVSP> for LeveledSpell {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let bounds = OBND::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let chance_none = LVLD::read(&mut cursor)?.try_into()?;
+        let flags = LVLF::read(&mut cursor)?.try_into()?;
+        let _: Option<u8> = LLCT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let entries = LeveledEntry::load_multiple(&mut cursor)?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            bounds,
+            chance_none,
+            flags,
+            entries,
+        })
+    }
+}
+
+impl TryFrom<LeveledSpell> for LVSP {
+    type Error = Error;
+
+    fn try_from(obj: LeveledSpell) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.bounds
+            .map(OBND::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        LVLD::try_from(obj.chance_none)?.write(&mut data)?;
+        LVLF::try_from(obj.flags)?.write(&mut data)?;
+        if !obj.entries.is_empty() {
+            LLCT::try_from(obj.entries.len() as u8)?.write(&mut data)?;
+        }
+        for e in obj.entries {
+            e.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
@@ -1,9 +1,13 @@
-use super::{get_cursor, Flags, RecordHeader};
-use crate::common::LocalizedString;
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::{ScriptList, EDID, FULL, VMAD};
+use crate::fields::{
+    CastType, Delivery, EffectCondition, ScriptList, DATA, DNAM, EDID, ESCE, FULL, KSIZ, KWDA,
+    MDOB, SNDD, VMAD,
+};
 use binrw::binrw;
-use binrw::BinRead;
+use binrw::{until_eof, BinRead, BinWrite, Endian};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -23,12 +27,168 @@ pub struct MGEF {
     pub localized: bool,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct MagicEffectFlags: u32 {
+        const HOSTILE = 0x00000001;
+        const RECOVER = 0x00000002;
+        const DETRIMENTAL = 0x00000004;
+        const SNAP_TO_NAVMESH = 0x00000008;
+        const NO_HIT_EVENT = 0x00000010;
+        const DISPEL_WITH_KEYWORDS = 0x00000100;
+        const NO_DURATION = 0x00000200;
+        const NO_MAGNITUDE = 0x00000400;
+        const NO_AREA = 0x00000800;
+        const FX_PERSIST = 0x00001000;
+        const GORY_VISUALS = 0x00004000;
+        const HIDE_IN_UI = 0x00008000;
+        const NO_RECAST = 0x00020000;
+        const POWER_AFFECTS_MAGNITUDE = 0x00200000;
+        const POWER_AFFECTS_DURATION = 0x00400000;
+        const PAINLESS = 0x04000000;
+        const NO_HIT_EFFECT = 0x08000000;
+        const NO_DEATH_DISPEL = 0x10000000;
+    }
+}
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EffectArchetype {
+    ValueModifier = 0,
+    Script,
+    Dispel,
+    CureDisease,
+    Absorb,
+    DualValueModifier,
+    Calm,
+    Demoralize,
+    Frenzy,
+    Disarm,
+    CommandSummoned,
+    Invisibility,
+    Light,
+    Darkness,
+    NightEye,
+    Lock,
+    Open,
+    BoundWeapon,
+    SummonCreature,
+    DetectLife,
+    Telekinesis,
+    Paralysis,
+    Reanimate,
+    SoulTrap,
+    TurnUndead,
+    Guide,
+    WerewolfFeed,
+    CureParalysis,
+    CureAddiction,
+    CurePoison,
+    Concussion,
+    ValueAndParts,
+    AccumulateMagnitude,
+    Stagger,
+    PeakValueModifier,
+    Cloak,
+    Werewolf,
+    SlowTime,
+    Rally,
+    EnhanceWeapon,
+    SpawnHazard,
+    Etherealize,
+    Banish,
+    SpawnScriptedRef,
+    Disguise,
+    GrabActor,
+    VampireLord,
+}
+
+/// Magic effect data (DATA)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MagicEffectData {
+    pub flags: MagicEffectFlags,
+    pub base_cost: f32,
+    pub associated_item: FormID,
+    pub magic_skill: i32,
+    pub resist_value: i32,
+    pub counter_effect_count: u16,
+    pub unused: [u8; 2],
+    pub casting_light: FormID,
+    pub taper_weight: f32,
+    pub hit_shader: FormID,
+    pub enchant_shader: FormID,
+    pub minimum_skill_level: u32,
+    pub spellmaking_area: u32,
+    pub spellmaking_casting_time: f32,
+    pub taper_curve: f32,
+    pub taper_duration: f32,
+    pub second_av_weight: f32,
+    pub archetype: EffectArchetype,
+    pub actor_value: i32,
+    pub projectile: FormID,
+    pub explosion: FormID,
+    pub cast_type: CastType,
+    pub delivery: Delivery,
+    pub second_actor_value: i32,
+    pub casting_art: FormID,
+    pub hit_effect_art: FormID,
+    pub impact_data: FormID,
+    pub skill_usage_multiplier: f32,
+    pub dual_casting_art: FormID,
+    pub dual_casting_scale: f32,
+    pub enchant_art: FormID,
+    pub unknown1: u32,
+    pub unknown2: u32,
+    pub equip_ability: FormID,
+    pub image_space_modifier: FormID,
+    pub perk_to_apply: FormID,
+    pub casting_sound_level: u32,
+    pub script_effect_ai_score: f32,
+    pub script_effect_ai_delay_time: f32,
+}
+
+impl_field_struct!(MagicEffectData, DATA);
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MagicEffectSoundType {
+    SheatheDraw = 0,
+    Charge,
+    Ready,
+    Release,
+    ConcentrationCastLoop,
+    OnHit,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MagicEffectSound {
+    pub kind: MagicEffectSoundType,
+    pub sound: FormID,
+}
+
+impl_field_vec!(MagicEffectSound, SNDD);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MagicEffect {
     pub header: RecordHeader,
     pub edid: String,
     pub scripts: Option<ScriptList>,
     pub full_name: Option<LocalizedString>,
+    pub menu_display_object: Option<FormID>,
+    pub keywords: Vec<FormID>,
+    pub data: MagicEffectData,
+    pub counter_effects: Vec<FormID>,
+    pub sounds: Vec<MagicEffectSound>,
+    pub description: Option<LocalizedString>,
+    pub conditions: Vec<EffectCondition>,
 }
 
 impl fmt::Display for MagicEffect {
@@ -54,12 +214,103 @@ impl TryFrom<MGEF> for MagicEffect {
             (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
             (Err(_), _) => None,
         };
+        let menu_display_object = MDOB::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let _: Option<u32> = KSIZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+        let data = DATA::read(&mut cursor)?.try_into()?;
+        let mut counter_effects = Vec::new();
+        while let Ok(e) = ESCE::read(&mut cursor) {
+            counter_effects.push(e.try_into()?);
+        }
+        let sounds = SNDD::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let description = match (DNAM::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let conditions = EffectCondition::load_multiple(&mut cursor)?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
             scripts,
             full_name,
+            menu_display_object,
+            keywords,
+            data,
+            counter_effects,
+            sounds,
+            description,
+            conditions,
+        })
+    }
+}
+
+impl TryFrom<MagicEffect> for MGEF {
+    type Error = Error;
+
+    fn try_from(obj: MagicEffect) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.menu_display_object
+            .map(MDOB::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        for e in obj.counter_effects {
+            ESCE::try_from(e)?.write(&mut data)?;
+        }
+        if !obj.sounds.is_empty() {
+            SNDD::try_from(obj.sounds)?.write(&mut data)?;
+        }
+        obj.description
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for c in obj.conditions {
+            c.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,3 +1,65 @@
+/// Implements the conversions between a fixed-size struct and its subrecord
+macro_rules! impl_field_struct {
+    ($name: ident, $field: ident) => {
+        impl TryFrom<$field> for $name {
+            type Error = Error;
+
+            fn try_from(raw: $field) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(&raw.data);
+                let result = Self::read_le(&mut cursor)?;
+                check_done_reading(&mut cursor)?;
+                Ok(result)
+            }
+        }
+
+        impl TryFrom<$name> for $field {
+            type Error = Error;
+
+            fn try_from(obj: $name) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+    };
+}
+
+/// Implements the conversions between a subrecord and a list of fixed-size structs
+macro_rules! impl_field_vec {
+    ($name: ident, $field: ident) => {
+        impl TryFrom<$field> for Vec<$name> {
+            type Error = Error;
+
+            fn try_from(raw: $field) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(&raw.data);
+                let result = until_eof(&mut cursor, Endian::Little, ())?;
+                check_done_reading(&mut cursor)?;
+                Ok(result)
+            }
+        }
+
+        impl TryFrom<Vec<$name>> for $field {
+            type Error = Error;
+
+            fn try_from(obj: Vec<$name>) -> Result<Self, Self::Error> {
+                let mut cursor = Cursor::new(Vec::new());
+                obj.write_le(&mut cursor)?;
+                let data = cursor.into_inner();
+
+                Ok(Self {
+                    size: data.len() as u16,
+                    data,
+                })
+            }
+        }
+    };
+}
+
 pub mod aact;
 pub mod achr;
 pub mod acti;
@@ -138,7 +200,7 @@ pub use avif::{ActorValue, AVIF};
 pub use book::{Book, BookData, BookFlags, BOOK};
 pub use bptd::{BodyPartData, BPTD};
 pub use cams::{CameraShot, CAMS};
-pub use cell::{Cell, CELL};
+pub use cell::{Cell, CellLocation, CELL};
 pub use clas::{Class, CLAS};
 pub use clfm::{Color, CLFM};
 pub use clmt::{Climate, CLMT};
@@ -155,7 +217,7 @@ pub use dobj::{DefaultObjectManager, DOBJ};
 pub use door::{Door, DOOR};
 pub use dual::{DualCastArt, DUAL};
 pub use eczn::{EncounterZone, ECZN};
-pub use efsh::{EffectShader, EFSH};
+pub use efsh::{EffectShader, EffectShaderData, EffectShaderFlags, EFSH};
 pub use ench::{Enchantment, ENCH};
 pub use equp::{EquipSlot, EQUP};
 pub use expl::{Explosion, EXPL};
@@ -174,7 +236,9 @@ pub use hdpt::{HeadPart, HDPT};
 pub use idle::{IdleAnimation, IDLE};
 pub use idlm::{IdleMarker, IDLM};
 pub use imad::{ImageSpaceAdapter, IMAD};
-pub use imgs::{ImageSpace, IMGS};
+pub use imgs::{
+    ImageSpace, ImageSpaceCinematic, ImageSpaceDepthOfField, ImageSpaceHdr, ImageSpaceTint, IMGS,
+};
 pub use info::{DialogueTopicInfo, INFO};
 pub use ingr::{Ingredient, INGR};
 pub use ipct::{ImpactData, IPCT};
@@ -183,9 +247,9 @@ pub use keym::{Key, KEYM};
 pub use kywd::{Keyword, KYWD};
 pub use land::{Landscape, LAND};
 pub use lcrt::{LocationRef, LCRT};
-pub use lctn::{Location, LCTN};
-pub use lgtm::{LightingTemplate, LGTM};
-pub use ligh::{Light, LIGH};
+pub use lctn::{Location, LocationRefs, LCTN};
+pub use lgtm::{LightingTemplate, LightingTemplateData, LGTM};
+pub use ligh::{Light, LightData, LightFlags, LIGH};
 pub use lscr::{LoadScreen, LSCR};
 pub use ltex::{LandTexture, LTEX};
 pub use lvli::{LeveledItem, LVLI};
@@ -194,62 +258,62 @@ pub use lvsp::{LeveledSpell, LVSP};
 pub use mato::{MaterialObject, MATO};
 pub use matt::{MaterialType, MATT};
 pub use mesg::{Message, MESG};
-pub use mgef::{MagicEffect, MGEF};
+pub use mgef::{MagicEffect, MagicEffectData, MGEF};
 pub use misc::{MiscItem, MISC};
 pub use movt::{MovementType, MOVT};
-pub use mstt::{MovableStatic, MSTT};
-pub use musc::{MusicType, MUSC};
-pub use must::{MusicTrack, MUST};
+pub use mstt::{MovableStatic, MovableStaticFlags, MSTT};
+pub use musc::{MusicType, MusicTypeFlags, MUSC};
+pub use must::{MusicTrack, MusicTrackType, MUST};
 pub use navi::{Navigation, NAVI};
 pub use navm::{NavMesh, NAVM};
 pub use note::{Note, NOTE};
 pub use npc_::{NPC, NPC_};
 pub use otft::{Outfit, OTFT};
 pub use pack::{AIPackage, PACK};
-pub use perk::{Perk, PERK};
+pub use perk::{EntryPoint, EntryPointData, Perk, PerkEffect, PerkEffectData, PERK};
 pub use pgre::{Placedgrenade, PGRE};
 pub use phzd::{Placedhazard, PHZD};
 pub use proj::{Projectile, PROJ};
 pub use qust::{Quest, QUST};
-pub use race::{Race, RACE};
+pub use race::{HeadData, Race, RaceData, RaceFlags, RACE};
 pub use refr::{ObjectRef, REFR};
-pub use regn::{Region, REGN};
+pub use regn::{Region, RegionData, RegionDataType, REGN};
 pub use rela::{Relationship, RELA};
 pub use revb::{ReverbParameters, REVB};
 pub use rfct::{VisualEffect, RFCT};
-pub use scen::{Scene, SCEN};
+pub use scen::{Scene, SceneAction, SceneActor, ScenePhase, SCEN};
 pub use scrl::{Scroll, SCRL};
-pub use shou::{Shout, SHOU};
+pub use shou::{Shout, ShoutWord, SHOU};
 pub use slgm::{SoulGem, SLGM};
-pub use smbn::{StoryManagerBranchNode, SMBN};
+pub use smbn::{StoryManagerBranchNode, StoryNodeFlags, SMBN};
 pub use smen::{StoryManagerEventNode, SMEN};
-pub use smqn::{StoryManagerQuestNode, SMQN};
-pub use snct::{SoundCategory, SNCT};
-pub use sndr::{SoundReference, SNDR};
-pub use sopm::{SoundOutputModel, SOPM};
+pub use smqn::{QuestNodeFlags, StoryManagerQuestNode, StoryQuest, StoryQuestFlags, SMQN};
+pub use snct::{SoundCategory, SoundCategoryFlags, SNCT};
+pub use sndr::{SoundReference, SoundValues, SNDR};
+pub use sopm::{OutputChannels, SoundOutputModel, SOPM};
 pub use soun::{Sound, SOUN};
 pub use spel::{Spell, SPEL};
 pub use spgd::{ShaderParticleGeometry, SPGD};
-pub use stat::{Static, STAT};
+pub use stat::{DistantLod, Static, STAT};
 pub use tact::{TalkingActivator, TACT};
 pub use tes4::{Header, TES4};
-pub use tree::{Tree, TREE};
+pub use tree::{Tree, TreeData, TREE};
 pub use txst::{TextureSet, TXST};
 pub use vtyp::{VoiceType, VTYP};
-pub use watr::{WaterType, WATR};
-pub use weap::{Weapon, WEAP};
+pub use watr::{WaterFlags, WaterType, WaterVelocity, WaterVisualData, WATR};
+pub use weap::{Weapon, WeaponData, WeaponStats, WEAP};
 pub use woop::{WordOfPower, WOOP};
-pub use wrld::{World, WRLD};
-pub use wthr::{Weather, WTHR};
+pub use wrld::{MapData, ParentFlags, World, WorldFlags, WRLD};
+pub use wthr::{DirectionalAmbient, Weather, WeatherData, WeatherFlags, WTHR};
 
 use crate::error::Error;
 
 use binrw::binrw;
 use bitflags::bitflags;
-use flate2::read::ZlibDecoder;
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Write};
 
 bitflags! {
     #[binrw]
@@ -337,7 +401,7 @@ pub enum RawRecord {
     IDLM(IDLM),
     IMAD(IMAD),
     IMGS(IMGS),
-    INFO(INFO),
+    INFO(#[br(args(localized))] INFO),
     INGR(#[br(args(localized))] INGR),
     IPCT(IPCT),
     IPDS(IPDS),
@@ -345,9 +409,9 @@ pub enum RawRecord {
     KYWD(KYWD),
     LAND(LAND),
     LCRT(LCRT),
-    LCTN(LCTN),
+    LCTN(#[br(args(localized))] LCTN),
     LGTM(LGTM),
-    LIGH(LIGH),
+    LIGH(#[br(args(localized))] LIGH),
     LSCR(LSCR),
     LTEX(LTEX),
     LVLI(LVLI),
@@ -359,52 +423,53 @@ pub enum RawRecord {
     MGEF(#[br(args(localized))] MGEF),
     MISC(MISC),
     MOVT(MOVT),
-    MSTT(MSTT),
+    MSTT(#[br(args(localized))] MSTT),
     MUSC(MUSC),
     MUST(MUST),
     NAVI(NAVI),
     NAVM(NAVM),
     NOTE(NOTE),
-    NPC_(NPC_),
+    NPC_(#[br(args(localized))] NPC_),
     OTFT(OTFT),
     PACK(PACK),
     PERK(#[br(args(localized))] PERK),
     PGRE(PGRE),
     PHZD(PHZD),
     PROJ(PROJ),
-    QUST(QUST),
+    QUST(#[br(args(localized))] QUST),
     RACE(#[br(args(localized))] RACE),
-    REFR(REFR),
-    REGN(REGN),
+    REFR(#[br(args(localized))] REFR),
+    REGN(#[br(args(localized))] REGN),
     RELA(RELA),
     REVB(REVB),
     RFCT(RFCT),
     SCEN(SCEN),
-    SCRL(SCRL),
-    SHOU(SHOU),
+    SCRL(#[br(args(localized))] SCRL),
+    SHOU(#[br(args(localized))] SHOU),
     SLGM(SLGM),
     SMBN(SMBN),
     SMEN(SMEN),
     SMQN(SMQN),
-    SNCT(SNCT),
+    SNCT(#[br(args(localized))] SNCT),
     SNDR(SNDR),
     SOPM(SOPM),
     SOUN(SOUN),
-    SPEL(SPEL),
+    SPEL(#[br(args(localized))] SPEL),
     SPGD(SPGD),
     STAT(STAT),
     TACT(TACT),
-    TREE(TREE),
+    TREE(#[br(args(localized))] TREE),
     TXST(TXST),
     VTYP(VTYP),
-    WATR(WATR),
-    WEAP(WEAP),
-    WOOP(WOOP),
-    WRLD(WRLD),
+    WATR(#[br(args(localized))] WATR),
+    WEAP(#[br(args(localized))] WEAP),
+    WOOP(#[br(args(localized))] WOOP),
+    WRLD(#[br(args(localized))] WRLD),
     WTHR(WTHR),
 }
 
-#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Serialize)]
 pub enum Record {
     AIPackage(AIPackage),
     AcousticSpace(AcousticSpace),
@@ -456,6 +521,7 @@ pub enum Record {
     GameSetting(GameSetting),
     GlobalVariable(GlobalVariable),
     Grass(Grass),
+    #[serde(skip_serializing)]
     Group(Group),
     Hazard(Hazard),
     HeadPart(HeadPart),
@@ -667,6 +733,135 @@ impl TryFrom<RawRecord> for Record {
     }
 }
 
+impl TryFrom<Record> for RawRecord {
+    type Error = Error;
+
+    fn try_from(r: Record) -> Result<Self, Self::Error> {
+        match r {
+            Record::Action(x) => Ok(RawRecord::AACT(AACT::try_from(x)?)),
+            Record::ActorRef(x) => Ok(RawRecord::ACHR(ACHR::try_from(x)?)),
+            Record::Activator(x) => Ok(RawRecord::ACTI(ACTI::try_from(x)?)),
+            Record::AddonNode(x) => Ok(RawRecord::ADDN(ADDN::try_from(x)?)),
+            Record::Alchemy(x) => Ok(RawRecord::ALCH(ALCH::try_from(x)?)),
+            Record::Ammo(x) => Ok(RawRecord::AMMO(AMMO::try_from(x)?)),
+            Record::AnimatedObjectInfo(x) => Ok(RawRecord::ANIO(ANIO::try_from(x)?)),
+            Record::Apparatus(x) => Ok(RawRecord::APPA(APPA::try_from(x)?)),
+            Record::ArmorAddon(x) => Ok(RawRecord::ARMA(ARMA::try_from(x)?)),
+            Record::Armor(x) => Ok(RawRecord::ARMO(ARMO::try_from(x)?)),
+            Record::ArtObject(x) => Ok(RawRecord::ARTO(ARTO::try_from(x)?)),
+            Record::AcousticSpace(x) => Ok(RawRecord::ASPC(ASPC::try_from(x)?)),
+            Record::AssociationType(x) => Ok(RawRecord::ASTP(ASTP::try_from(x)?)),
+            Record::ActorValue(x) => Ok(RawRecord::AVIF(AVIF::try_from(x)?)),
+            Record::Book(x) => Ok(RawRecord::BOOK(BOOK::try_from(x)?)),
+            Record::BodyPartData(x) => Ok(RawRecord::BPTD(BPTD::try_from(x)?)),
+            Record::CameraShot(x) => Ok(RawRecord::CAMS(CAMS::try_from(x)?)),
+            Record::Cell(x) => Ok(RawRecord::CELL(CELL::try_from(x)?)),
+            Record::Class(x) => Ok(RawRecord::CLAS(CLAS::try_from(x)?)),
+            Record::Color(x) => Ok(RawRecord::CLFM(CLFM::try_from(x)?)),
+            Record::Climate(x) => Ok(RawRecord::CLMT(CLMT::try_from(x)?)),
+            Record::ConstructibleObj(x) => Ok(RawRecord::COBJ(COBJ::try_from(x)?)),
+            Record::CollisionLayer(x) => Ok(RawRecord::COLL(COLL::try_from(x)?)),
+            Record::Container(x) => Ok(RawRecord::CONT(CONT::try_from(x)?)),
+            Record::CameraPath(x) => Ok(RawRecord::CPTH(CPTH::try_from(x)?)),
+            Record::CombatStyle(x) => Ok(RawRecord::CSTY(CSTY::try_from(x)?)),
+            Record::Debris(x) => Ok(RawRecord::DEBR(DEBR::try_from(x)?)),
+            Record::DialogueTopic(x) => Ok(RawRecord::DIAL(DIAL::try_from(x)?)),
+            Record::DialogueBranch(x) => Ok(RawRecord::DLBR(DLBR::try_from(x)?)),
+            Record::DialogueView(x) => Ok(RawRecord::DLVW(DLVW::try_from(x)?)),
+            Record::DefaultObjectManager(x) => Ok(RawRecord::DOBJ(DOBJ::try_from(x)?)),
+            Record::Door(x) => Ok(RawRecord::DOOR(DOOR::try_from(x)?)),
+            Record::DualCastArt(x) => Ok(RawRecord::DUAL(DUAL::try_from(x)?)),
+            Record::EncounterZone(x) => Ok(RawRecord::ECZN(ECZN::try_from(x)?)),
+            Record::EffectShader(x) => Ok(RawRecord::EFSH(EFSH::try_from(x)?)),
+            Record::Enchantment(x) => Ok(RawRecord::ENCH(ENCH::try_from(x)?)),
+            Record::EquipSlot(x) => Ok(RawRecord::EQUP(EQUP::try_from(x)?)),
+            Record::Explosion(x) => Ok(RawRecord::EXPL(EXPL::try_from(x)?)),
+            Record::Eyes(x) => Ok(RawRecord::EYES(EYES::try_from(x)?)),
+            Record::Faction(x) => Ok(RawRecord::FACT(FACT::try_from(x)?)),
+            Record::Flora(x) => Ok(RawRecord::FLOR(FLOR::try_from(x)?)),
+            Record::FormList(x) => Ok(RawRecord::FLST(FLST::try_from(x)?)),
+            Record::Footstep(x) => Ok(RawRecord::FSTP(FSTP::try_from(x)?)),
+            Record::FootstepSet(x) => Ok(RawRecord::FSTS(FSTS::try_from(x)?)),
+            Record::Furniture(x) => Ok(RawRecord::FURN(FURN::try_from(x)?)),
+            Record::GlobalVariable(x) => Ok(RawRecord::GLOB(GLOB::try_from(x)?)),
+            Record::GameSetting(x) => Ok(RawRecord::GMST(GMST::try_from(x)?)),
+            Record::Grass(x) => Ok(RawRecord::GRAS(GRAS::try_from(x)?)),
+            Record::Group(x) => Ok(RawRecord::GRUP(GRUP::try_from(x)?)),
+            Record::Hazard(x) => Ok(RawRecord::HAZD(HAZD::try_from(x)?)),
+            Record::HeadPart(x) => Ok(RawRecord::HDPT(HDPT::try_from(x)?)),
+            Record::IdleAnimation(x) => Ok(RawRecord::IDLE(IDLE::try_from(x)?)),
+            Record::IdleMarker(x) => Ok(RawRecord::IDLM(IDLM::try_from(x)?)),
+            Record::ImageSpaceAdapter(x) => Ok(RawRecord::IMAD(IMAD::try_from(x)?)),
+            Record::ImageSpace(x) => Ok(RawRecord::IMGS(IMGS::try_from(x)?)),
+            Record::DialogueTopicInfo(x) => Ok(RawRecord::INFO(INFO::try_from(x)?)),
+            Record::Ingredient(x) => Ok(RawRecord::INGR(INGR::try_from(x)?)),
+            Record::ImpactData(x) => Ok(RawRecord::IPCT(IPCT::try_from(x)?)),
+            Record::ImpactDataSet(x) => Ok(RawRecord::IPDS(IPDS::try_from(x)?)),
+            Record::Key(x) => Ok(RawRecord::KEYM(KEYM::try_from(x)?)),
+            Record::Keyword(x) => Ok(RawRecord::KYWD(KYWD::try_from(x)?)),
+            Record::Landscape(x) => Ok(RawRecord::LAND(LAND::try_from(x)?)),
+            Record::LocationRef(x) => Ok(RawRecord::LCRT(LCRT::try_from(x)?)),
+            Record::Location(x) => Ok(RawRecord::LCTN(LCTN::try_from(x)?)),
+            Record::LightingTemplate(x) => Ok(RawRecord::LGTM(LGTM::try_from(x)?)),
+            Record::Light(x) => Ok(RawRecord::LIGH(LIGH::try_from(x)?)),
+            Record::LoadScreen(x) => Ok(RawRecord::LSCR(LSCR::try_from(x)?)),
+            Record::LandTexture(x) => Ok(RawRecord::LTEX(LTEX::try_from(x)?)),
+            Record::LeveledItem(x) => Ok(RawRecord::LVLI(LVLI::try_from(x)?)),
+            Record::LeveledActor(x) => Ok(RawRecord::LVLN(LVLN::try_from(x)?)),
+            Record::LeveledSpell(x) => Ok(RawRecord::LVSP(LVSP::try_from(x)?)),
+            Record::MaterialObject(x) => Ok(RawRecord::MATO(MATO::try_from(x)?)),
+            Record::MaterialType(x) => Ok(RawRecord::MATT(MATT::try_from(x)?)),
+            Record::Message(x) => Ok(RawRecord::MESG(MESG::try_from(x)?)),
+            Record::MagicEffect(x) => Ok(RawRecord::MGEF(MGEF::try_from(x)?)),
+            Record::MiscItem(x) => Ok(RawRecord::MISC(MISC::try_from(x)?)),
+            Record::MovementType(x) => Ok(RawRecord::MOVT(MOVT::try_from(x)?)),
+            Record::MovableStatic(x) => Ok(RawRecord::MSTT(MSTT::try_from(x)?)),
+            Record::MusicType(x) => Ok(RawRecord::MUSC(MUSC::try_from(x)?)),
+            Record::MusicTrack(x) => Ok(RawRecord::MUST(MUST::try_from(x)?)),
+            Record::Navigation(x) => Ok(RawRecord::NAVI(NAVI::try_from(x)?)),
+            Record::NavMesh(x) => Ok(RawRecord::NAVM(NAVM::try_from(x)?)),
+            Record::Note(x) => Ok(RawRecord::NOTE(NOTE::try_from(x)?)),
+            Record::NPC(x) => Ok(RawRecord::NPC_(NPC_::try_from(x)?)),
+            Record::Outfit(x) => Ok(RawRecord::OTFT(OTFT::try_from(x)?)),
+            Record::AIPackage(x) => Ok(RawRecord::PACK(PACK::try_from(x)?)),
+            Record::Perk(x) => Ok(RawRecord::PERK(PERK::try_from(x)?)),
+            Record::Placedgrenade(x) => Ok(RawRecord::PGRE(PGRE::try_from(x)?)),
+            Record::Placedhazard(x) => Ok(RawRecord::PHZD(PHZD::try_from(x)?)),
+            Record::Projectile(x) => Ok(RawRecord::PROJ(PROJ::try_from(x)?)),
+            Record::Quest(x) => Ok(RawRecord::QUST(QUST::try_from(x)?)),
+            Record::Race(x) => Ok(RawRecord::RACE(RACE::try_from(x)?)),
+            Record::ObjectRef(x) => Ok(RawRecord::REFR(REFR::try_from(x)?)),
+            Record::Region(x) => Ok(RawRecord::REGN(REGN::try_from(x)?)),
+            Record::Relationship(x) => Ok(RawRecord::RELA(RELA::try_from(x)?)),
+            Record::ReverbParameters(x) => Ok(RawRecord::REVB(REVB::try_from(x)?)),
+            Record::VisualEffect(x) => Ok(RawRecord::RFCT(RFCT::try_from(x)?)),
+            Record::Scene(x) => Ok(RawRecord::SCEN(SCEN::try_from(x)?)),
+            Record::Scroll(x) => Ok(RawRecord::SCRL(SCRL::try_from(x)?)),
+            Record::Shout(x) => Ok(RawRecord::SHOU(SHOU::try_from(x)?)),
+            Record::SoulGem(x) => Ok(RawRecord::SLGM(SLGM::try_from(x)?)),
+            Record::StoryManagerBranchNode(x) => Ok(RawRecord::SMBN(SMBN::try_from(x)?)),
+            Record::StoryManagerEventNode(x) => Ok(RawRecord::SMEN(SMEN::try_from(x)?)),
+            Record::StoryManagerQuestNode(x) => Ok(RawRecord::SMQN(SMQN::try_from(x)?)),
+            Record::SoundCategory(x) => Ok(RawRecord::SNCT(SNCT::try_from(x)?)),
+            Record::SoundReference(x) => Ok(RawRecord::SNDR(SNDR::try_from(x)?)),
+            Record::SoundOutputModel(x) => Ok(RawRecord::SOPM(SOPM::try_from(x)?)),
+            Record::Sound(x) => Ok(RawRecord::SOUN(SOUN::try_from(x)?)),
+            Record::Spell(x) => Ok(RawRecord::SPEL(SPEL::try_from(x)?)),
+            Record::ShaderParticleGeometry(x) => Ok(RawRecord::SPGD(SPGD::try_from(x)?)),
+            Record::Static(x) => Ok(RawRecord::STAT(STAT::try_from(x)?)),
+            Record::TalkingActivator(x) => Ok(RawRecord::TACT(TACT::try_from(x)?)),
+            Record::Tree(x) => Ok(RawRecord::TREE(TREE::try_from(x)?)),
+            Record::TextureSet(x) => Ok(RawRecord::TXST(TXST::try_from(x)?)),
+            Record::VoiceType(x) => Ok(RawRecord::VTYP(VTYP::try_from(x)?)),
+            Record::WaterType(x) => Ok(RawRecord::WATR(WATR::try_from(x)?)),
+            Record::Weapon(x) => Ok(RawRecord::WEAP(WEAP::try_from(x)?)),
+            Record::WordOfPower(x) => Ok(RawRecord::WOOP(WOOP::try_from(x)?)),
+            Record::World(x) => Ok(RawRecord::WRLD(WRLD::try_from(x)?)),
+            Record::Weather(x) => Ok(RawRecord::WTHR(WTHR::try_from(x)?)),
+        }
+    }
+}
+
 impl fmt::Display for Record {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -1057,3 +1252,36 @@ fn get_cursor(data: &[u8], compressed: bool) -> Vec<u8> {
         data.into()
     }
 }
+
+fn make_data(data: Vec<u8>, compressed: bool) -> Result<Vec<u8>, Error> {
+    if compressed {
+        let size = (data.len() as u32).to_le_bytes().to_vec();
+        let mut e = ZlibEncoder::new(size, Compression::default());
+        e.write_all(&data)?;
+        Ok(e.finish()?)
+    } else {
+        Ok(data)
+    }
+}
+
+/// Parses a raw record, keeping the raw form in the error if it fails so
+/// that [to_raw] can write it back out unchanged
+pub(crate) fn from_raw(raw: RawRecord) -> Result<Record, Error> {
+    raw.clone().try_into().map_err(|err| match err {
+        Error::BinaryParseError(e) => Error::BinaryParseErrorExtra(e, raw),
+        Error::ExtraBytes(e) => Error::ExtraBytesRaw(e, raw),
+        other => Error::ParseErrorRaw(Box::new(other), raw),
+    })
+}
+
+/// Converts a parsed record back into its raw form. Records that failed to
+/// parse still carry their raw form in the error, so they are passed through.
+pub(crate) fn to_raw(rec: Result<Record, Error>) -> Result<RawRecord, Error> {
+    match rec {
+        Ok(r) => r.try_into(),
+        Err(Error::BinaryParseErrorExtra(_, r)) => Ok(r),
+        Err(Error::ExtraBytesRaw(_, r)) => Ok(r),
+        Err(Error::ParseErrorRaw(_, r)) => Ok(r),
+        Err(err) => Err(err),
+    }
+}
@@ -1,25 +1,54 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    DestructionData, Model, ObjectBounds, ScriptList, DATA, EDID, FULL, MODL, MODS, MODT, OBND,
+    SNAM, VMAD,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"MSTT")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MSTT {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct MovableStaticFlags: u8 {
+        const ON_LOCAL_MAP = 0x01;
+        const UNKNOWN_2 = 0x02;
+    }
+}
+
+impl_field_struct!(MovableStaticFlags, DATA);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MovableStatic {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub scripts: Option<ScriptList>,
+    pub bounds: ObjectBounds,
+    pub full_name: Option<LocalizedString>,
+    pub model: Option<Model>,
+    pub destruction_data: Option<DestructionData>,
+    pub flags: Option<MovableStaticFlags>,
+    pub loop_sound: Option<FormID>,
 }
 
 impl fmt::Display for MovableStatic {
@@ -39,10 +68,85 @@ impl TryFrom<MSTT> for MovableStatic {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let bounds = OBND::read(&mut cursor)?.try_into()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let model = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let destruction_data = DestructionData::load(&mut cursor)?;
+        let flags = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let loop_sound = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            bounds,
+            full_name,
+            model,
+            destruction_data,
+            flags,
+            loop_sound,
+        })
+    }
+}
+
+impl TryFrom<MovableStatic> for MSTT {
+    type Error = Error;
+
+    fn try_from(obj: MovableStatic) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.flags
+            .map(DATA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.loop_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,7 +1,9 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EDID, FNAM, PNAM, TNAM, WNAM};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +18,40 @@ pub struct MUSC {
     pub data: Vec<u8>,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct MusicTypeFlags: u32 {
+        const PLAYS_ONE_SELECTION = 0x00000001;
+        const ABRUPT_TRANSITION = 0x00000002;
+        const CYCLE_TRACKS = 0x00000004;
+        const MAINTAIN_TRACK_ORDER = 0x00000008;
+        const DUCKS_CURRENT_TRACK = 0x00000020;
+    }
+}
+
+impl_field_struct!(MusicTypeFlags, FNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MusicTypeData {
+    pub priority: u16,
+    /// In hundredths of a decibel
+    pub ducking: u16,
+}
+
+impl_field_struct!(MusicTypeData, PNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MusicType {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub flags: Option<MusicTypeFlags>,
+    pub data: Option<MusicTypeData>,
+    pub fade_duration: Option<f32>,
+    pub tracks: Vec<FormID>,
 }
 
 impl fmt::Display for MusicType {
@@ -39,10 +71,67 @@ impl TryFrom<MUSC> for MusicType {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let flags = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let music_data = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let fade_duration = WNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let tracks = TNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            flags,
+            data: music_data,
+            fade_duration,
+            tracks,
+        })
+    }
+}
+
+impl TryFrom<MusicType> for MUSC {
+    type Error = Error;
+
+    fn try_from(obj: MusicType) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.flags
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.data.map(PNAM::try_from).transpose()?.write(&mut data)?;
+        obj.fade_duration
+            .map(WNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.tracks.is_empty() {
+            TNAM::try_from(obj.tracks)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
@@ -1,7 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EffectCondition, ANAM, BNAM, CNAM, DNAM, EDID, FLTV, FNAM, LNAM, SNAM};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +17,46 @@ pub struct MUST {
     pub data: Vec<u8>,
 }
 
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u32)]
+pub enum MusicTrackType {
+    Palette = 0x23F678C3,
+    SingleTrack = 0x6ED7E048,
+    SilentTrack = 0xA1A9C4D5,
+}
+
+impl_field_struct!(MusicTrackType, CNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoopData {
+    pub begins: f32,
+    pub ends: f32,
+    pub count: u32,
+}
+
+impl_field_struct!(LoopData, LNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MusicTrack {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub kind: MusicTrackType,
+    pub duration: Option<f32>,
+    pub fade_out: Option<f32>,
+    /// Relative to `Data/`
+    pub track: Option<String>,
+    /// Relative to `Data/`
+    pub finale: Option<String>,
+    /// Seconds into the track at which it may be switched
+    pub cue_points: Vec<f32>,
+    pub loop_data: Option<LoopData>,
+    pub conditions: Vec<EffectCondition>,
+    /// Tracks played by a palette
+    pub tracks: Vec<FormID>,
 }
 
 impl fmt::Display for MusicTrack {
@@ -39,10 +76,103 @@ impl TryFrom<MUST> for MusicTrack {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let kind = CNAM::read(&mut cursor)?.try_into()?;
+        let duration = FLTV::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let fade_out = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let track = ANAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let finale = BNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let cue_points = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let loop_data = LNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let conditions = EffectCondition::load_counted(&mut cursor)?;
+        let tracks = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            kind,
+            duration,
+            fade_out,
+            track,
+            finale,
+            cue_points,
+            loop_data,
+            conditions,
+            tracks,
+        })
+    }
+}
+
+impl TryFrom<MusicTrack> for MUST {
+    type Error = Error;
+
+    fn try_from(obj: MusicTrack) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        CNAM::try_from(obj.kind)?.write(&mut data)?;
+        obj.duration
+            .map(FLTV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.fade_out
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.track
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.finale
+            .map(BNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.cue_points.is_empty() {
+            FNAM::try_from(obj.cue_points)?.write(&mut data)?;
+        }
+        obj.loop_data
+            .map(LNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        EffectCondition::write_counted(obj.conditions, &mut data)?;
+        if !obj.tracks.is_empty() {
+            SNAM::try_from(obj.tracks)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
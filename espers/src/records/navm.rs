@@ -1,7 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EDID, NNAM, NVNM, ONAM, PNAM};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +17,168 @@ pub struct NAVM {
     pub data: Vec<u8>,
 }
 
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Vertex {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// Triangle of the navmesh; `edges` hold the index of the triangle on the
+/// other side of each edge (0-1, 1-2, 2-0), or -1 if there is none
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Triangle {
+    pub vertices: [u16; 3],
+    pub edges: [i16; 3],
+    pub flags: u16,
+    pub cover_flags: u16,
+}
+
+/// Connection from a triangle edge to a triangle in another navmesh
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeLink {
+    pub kind: u32,
+    pub navmesh: FormID,
+    pub triangle: i16,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DoorTriangle {
+    pub triangle: u16,
+    pub unknown: u32,
+    pub door: FormID,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridCell {
+    pub y: i16,
+    pub x: i16,
+}
+
+/// Triangles overlapping one cell of the navmesh search grid
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NavMeshGridCell {
+    #[br(temp)]
+    #[bw(calc = triangles.len() as u32)]
+    triangle_count: u32,
+    #[br(count = triangle_count)]
+    pub triangles: Vec<u16>,
+}
+
+/// Navmesh geometry (NVNM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NavMeshGeometry {
+    pub version: u32,
+    pub unknown: u32,
+    pub worldspace: FormID,
+    /// Parent cell, for navmeshes outside of a worldspace
+    #[br(if(worldspace.0 == 0))]
+    pub cell: Option<FormID>,
+    /// Parent grid cell, for navmeshes in a worldspace
+    #[br(if(worldspace.0 != 0))]
+    pub grid: Option<GridCell>,
+
+    #[br(temp)]
+    #[bw(calc = vertices.len() as u32)]
+    vertex_count: u32,
+    #[br(count = vertex_count)]
+    pub vertices: Vec<Vertex>,
+
+    #[br(temp)]
+    #[bw(calc = triangles.len() as u32)]
+    triangle_count: u32,
+    #[br(count = triangle_count)]
+    pub triangles: Vec<Triangle>,
+
+    #[br(temp)]
+    #[bw(calc = edge_links.len() as u32)]
+    edge_link_count: u32,
+    #[br(count = edge_link_count)]
+    pub edge_links: Vec<EdgeLink>,
+
+    #[br(temp)]
+    #[bw(calc = door_triangles.len() as u32)]
+    door_triangle_count: u32,
+    #[br(count = door_triangle_count)]
+    pub door_triangles: Vec<DoorTriangle>,
+
+    #[br(temp)]
+    #[bw(calc = cover_triangles.len() as u32)]
+    cover_triangle_count: u32,
+    #[br(count = cover_triangle_count)]
+    pub cover_triangles: Vec<u16>,
+
+    pub grid_size: u32,
+    pub max_x_distance: f32,
+    pub max_y_distance: f32,
+    pub min: Vertex,
+    pub max: Vertex,
+    #[br(temp, try_calc(grid_size.checked_mul(grid_size).ok_or("navmesh grid size overflows")))]
+    #[bw(ignore)]
+    grid_cell_count: u32,
+    #[br(count = grid_cell_count)]
+    pub grid_cells: Vec<NavMeshGridCell>,
+}
+
+impl NavMeshGeometry {
+    pub fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+
+    pub fn triangles(&self) -> &[Triangle] {
+        &self.triangles
+    }
+
+    pub fn vertex(&self, index: u16) -> Option<&Vertex> {
+        self.vertices.get(index as usize)
+    }
+
+    /// Corners of `triangle`, or `None` if any index is out of range
+    pub fn triangle_vertices(&self, triangle: &Triangle) -> Option<[Vertex; 3]> {
+        let [a, b, c] = triangle.vertices;
+        Some([*self.vertex(a)?, *self.vertex(b)?, *self.vertex(c)?])
+    }
+
+    /// Indices of triangles referencing vertices past the end of the vertex
+    /// list, which crash the game when the navmesh is loaded
+    pub fn invalid_triangles(&self) -> Vec<usize> {
+        self.triangles
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| self.triangle_vertices(t).is_none())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.invalid_triangles().is_empty()
+    }
+}
+
+impl_field_struct!(NavMeshGeometry, NVNM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NavMesh {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub geometry: Option<NavMeshGeometry>,
+    pub unknown1: Option<ONAM>,
+    pub unknown2: Option<PNAM>,
+    pub unknown3: Option<NNAM>,
 }
 
 impl fmt::Display for NavMesh {
@@ -39,10 +198,52 @@ impl TryFrom<NAVM> for NavMesh {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let geometry = NVNM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown1 = ONAM::read(&mut cursor).ok();
+        let unknown2 = PNAM::read(&mut cursor).ok();
+        let unknown3 = NNAM::read(&mut cursor).ok();
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            geometry,
+            unknown1,
+            unknown2,
+            unknown3,
+        })
+    }
+}
+
+impl TryFrom<NavMesh> for NAVM {
+    type Error = Error;
+
+    fn try_from(obj: NavMesh) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.geometry
+            .map(NVNM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown1.write(&mut data)?;
+        obj.unknown2.write(&mut data)?;
+        obj.unknown3.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
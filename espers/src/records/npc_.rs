@@ -1,25 +1,313 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    Attack, ContainerItem, DestructionData, ObjectBounds, ScriptList, ACBS, AIDT, ANAM, ATKR, CNAM,
+    COCT, CRIF, CSCR, CSDC, CSDI, CSDT, DATA, DNAM, DOFT, DPLT, ECOR, EDID, FTST, FULL, GNAM, GWOR,
+    HCLF, INAM, KSIZ, KWDA, NAM5, NAM6, NAM7, NAM8, NAM9, NAMA, OBND, OCOR, PKID, PNAM, PRKR, PRKZ,
+    QNAM, RNAM, SHRT, SNAM, SOFT, SPCT, SPLO, SPOR, TIAS, TINC, TINI, TINV, TPLT, VMAD, VTCK, WNAM,
+    ZNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [NPC_](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/NPC_) record
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"NPC_")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NPC_ {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct NPCFlags: u32 {
+        const FEMALE = 0x1;
+        const ESSENTIAL = 0x2;
+        const IS_CHARGEN_FACE_PRESET = 0x4;
+        const RESPAWN = 0x8;
+        const AUTO_CALC_STATS = 0x10;
+        const UNIQUE = 0x20;
+        const DOES_NOT_AFFECT_STEALTH = 0x40;
+        const PC_LEVEL_MULT = 0x80;
+        const AUDIO_TEMPLATE = 0x100;
+        const PROTECTED = 0x800;
+        const SUMMONABLE = 0x4000;
+        const DOES_NOT_BLEED = 0x10000;
+        const BLEEDOUT_OVERRIDE = 0x40000;
+        const OPPOSITE_GENDER_ANIMS = 0x80000;
+        const SIMPLE_ACTOR = 0x100000;
+        const LOOPED_SCRIPT = 0x200000;
+        const LOOPED_AUDIO = 0x10000000;
+        const IS_GHOST = 0x20000000;
+        const INVULNERABLE = 0x80000000;
+    }
+}
+
+bitflags! {
+    /// Which parts of the NPC are taken from its template (TPLT)
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct TemplateFlags: u16 {
+        const TRAITS = 0x1;
+        const STATS = 0x2;
+        const FACTIONS = 0x4;
+        const SPELL_LIST = 0x8;
+        const AI_DATA = 0x10;
+        const AI_PACKAGES = 0x20;
+        const MODEL_ANIMATION = 0x40;
+        const BASE_DATA = 0x80;
+        const INVENTORY = 0x100;
+        const SCRIPT = 0x200;
+        const DEFAULT_PACKAGE_LIST = 0x400;
+        const ATTACK_DATA = 0x800;
+        const KEYWORDS = 0x1000;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActorStats {
+    pub flags: NPCFlags,
+    pub magicka_offset: i16,
+    pub stamina_offset: i16,
+    pub level: u16,
+    pub calc_min_level: u16,
+    pub calc_max_level: u16,
+    pub speed_multiplier: u16,
+    pub disposition_base: i16,
+    pub template_flags: TemplateFlags,
+    pub health_offset: i16,
+    pub bleedout_override: u16,
+}
+
+impl_field_struct!(ActorStats, ACBS);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionRank {
+    pub faction: FormID,
+    pub rank: i8,
+    pub unused: [u8; 3],
+}
+
+impl_field_struct!(FactionRank, SNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerkRank {
+    pub perk: FormID,
+    pub rank: u8,
+    pub unused: [u8; 3],
+}
+
+impl_field_struct!(PerkRank, PRKR);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIData {
+    pub aggression: u8,
+    pub confidence: u8,
+    pub energy: u8,
+    pub morality: u8,
+    pub mood: u8,
+    pub assistance: u8,
+    pub aggro_radius_behavior: u8,
+    pub unknown: u8,
+    pub warn: u32,
+    pub warn_attack: u32,
+    pub attack: u32,
+}
+
+impl_field_struct!(AIData, AIDT);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSkills {
+    pub skill_values: [u8; 18],
+    pub skill_offsets: [u8; 18],
+    pub health: u16,
+    pub magicka: u16,
+    pub stamina: u16,
+    pub unused: u16,
+    pub far_away_model_distance: f32,
+    pub geared_up_weapons: u8,
+    pub unused2: [u8; 3],
+}
+
+impl_field_struct!(PlayerSkills, DNAM);
+
+/// Sounds (CSDI/CSDC) played for one type of action (CSDT)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActorSound {
+    pub kind: u32,
+    pub sounds: Vec<(FormID, u8)>,
+}
+
+impl ActorSound {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let kind = CSDT::read(cursor)?.try_into()?;
+        let mut sounds = Vec::new();
+        while let Ok(sound) = CSDI::read(cursor) {
+            let chance = CSDC::read(cursor)?.try_into()?;
+            sounds.push((sound.try_into()?, chance));
+        }
+
+        Ok(Self { kind, sounds })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        CSDT::try_from(self.kind)?.write(cursor)?;
+        for (sound, chance) in self.sounds {
+            CSDI::try_from(sound)?.write(cursor)?;
+            CSDC::try_from(chance)?.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaceMorph {
+    pub nose_long_short: f32,
+    pub nose_up_down: f32,
+    pub jaw_up_down: f32,
+    pub jaw_narrow_wide: f32,
+    pub jaw_forward_back: f32,
+    pub cheeks_up_down: f32,
+    pub cheeks_forward_back: f32,
+    pub eyes_up_down: f32,
+    pub eyes_in_out: f32,
+    pub brows_up_down: f32,
+    pub brows_in_out: f32,
+    pub brows_forward_back: f32,
+    pub lips_up_down: f32,
+    pub lips_in_out: f32,
+    pub chin_narrow_wide: f32,
+    pub chin_up_down: f32,
+    pub chin_underbite_overbite: f32,
+    pub eyes_forward_back: f32,
+    pub unknown: f32,
+}
+
+impl_field_struct!(FaceMorph, NAM9);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaceParts {
+    pub nose: u32,
+    pub unknown: u32,
+    pub eyes: u32,
+    pub mouth: u32,
+}
+
+impl_field_struct!(FaceParts, NAMA);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TintLayer {
+    pub index: u16,
+    pub color: Option<RGBA8>,
+    pub value: Option<i32>,
+    pub preset: Option<i16>,
+}
+
+impl TintLayer {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let index = TINI::read(cursor)?.try_into()?;
+        let color = TINC::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let value = TINV::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let preset = TIAS::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self {
+            index,
+            color,
+            value,
+            preset,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        TINI::try_from(self.index)?.write(cursor)?;
+        self.color.map(TINC::try_from).transpose()?.write(cursor)?;
+        self.value.map(TINV::try_from).transpose()?.write(cursor)?;
+        self.preset.map(TIAS::try_from).transpose()?.write(cursor)?;
+        Ok(())
+    }
 }
 
+/// Parsed [NPC_] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NPC {
     pub header: RecordHeader,
     pub edid: String,
+    pub scripts: Option<ScriptList>,
+    pub bounds: ObjectBounds,
+    pub stats: ActorStats,
+    pub factions: Vec<FactionRank>,
+    pub death_item: Option<FormID>,
+    pub voice_type: Option<FormID>,
+    pub template: Option<FormID>,
+    pub race: FormID,
+    pub spells: Vec<FormID>,
+    pub destruction_data: Option<DestructionData>,
+    pub skin: Option<FormID>,
+    pub far_away_model: Option<FormID>,
+    pub attack_race: Option<FormID>,
+    pub attacks: Vec<Attack>,
+    pub spectator_override: Option<FormID>,
+    pub observe_dead_override: Option<FormID>,
+    pub guard_warn_override: Option<FormID>,
+    pub combat_override: Option<FormID>,
+    pub perks: Vec<PerkRank>,
+    pub items: Vec<ContainerItem>,
+    pub ai_data: AIData,
+    pub packages: Vec<FormID>,
+    pub keywords: Vec<FormID>,
+    pub class: FormID,
+    pub full_name: Option<LocalizedString>,
+    pub short_name: Option<LocalizedString>,
+    pub player_skills: PlayerSkills,
+    pub head_parts: Vec<FormID>,
+    pub hair_color: Option<FormID>,
+    pub combat_style: Option<FormID>,
+    pub gift_filter: Option<FormID>,
+    pub unknown: Option<u16>,
+    pub height: f32,
+    pub weight: f32,
+    pub sound_level: u32,
+    pub sounds: Vec<ActorSound>,
+    pub inherits_sounds_from: Option<FormID>,
+    pub default_outfit: Option<FormID>,
+    pub sleeping_outfit: Option<FormID>,
+    pub default_package_list: Option<FormID>,
+    pub crime_faction: Option<FormID>,
+    pub face_texture: Option<FormID>,
+    pub texture_lighting: Option<(f32, f32, f32)>,
+    pub face_morph: Option<FaceMorph>,
+    pub face_parts: Option<FaceParts>,
+    pub tint_layers: Vec<TintLayer>,
 }
 
 impl fmt::Display for NPC {
@@ -36,10 +324,414 @@ impl TryFrom<NPC_> for NPC {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let bounds = OBND::read(&mut cursor)?.try_into()?;
+        let stats = ACBS::read(&mut cursor)?.try_into()?;
+        let mut factions = Vec::new();
+        while let Ok(s) = SNAM::read(&mut cursor) {
+            factions.push(s.try_into()?);
+        }
+        let death_item = INAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let voice_type = VTCK::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let template = TPLT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let race = RNAM::read(&mut cursor)?.try_into()?;
+
+        let _: Option<u32> = SPCT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut spells = Vec::new();
+        while let Ok(s) = SPLO::read(&mut cursor) {
+            spells.push(s.try_into()?);
+        }
+
+        let destruction_data = DestructionData::load(&mut cursor)?;
+        let skin = WNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let far_away_model = ANAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attack_race = ATKR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attacks = Attack::load_multiple(&mut cursor)?;
+        let spectator_override = SPOR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let observe_dead_override = OCOR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let guard_warn_override = GWOR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let combat_override = ECOR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        let _: Option<u32> = PRKZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut perks = Vec::new();
+        while let Ok(p) = PRKR::read(&mut cursor) {
+            perks.push(p.try_into()?);
+        }
+
+        let _: Option<u32> = COCT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let items = ContainerItem::load_multiple(&mut cursor)?;
+
+        let ai_data = AIDT::read(&mut cursor)?.try_into()?;
+        let mut packages = Vec::new();
+        while let Ok(p) = PKID::read(&mut cursor) {
+            packages.push(p.try_into()?);
+        }
+
+        let _: Option<u32> = KSIZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+
+        let class = CNAM::read(&mut cursor)?.try_into()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let short_name = match (SHRT::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+
+        // Always empty
+        DATA::read(&mut cursor)?;
+
+        let player_skills = DNAM::read(&mut cursor)?.try_into()?;
+        let mut head_parts = Vec::new();
+        while let Ok(p) = PNAM::read(&mut cursor) {
+            head_parts.push(p.try_into()?);
+        }
+        let hair_color = HCLF::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let combat_style = ZNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let gift_filter = GNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown = NAM5::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let height = NAM6::read(&mut cursor)?.try_into()?;
+        let weight = NAM7::read(&mut cursor)?.try_into()?;
+        let sound_level = NAM8::read(&mut cursor)?.try_into()?;
+
+        let mut sounds = Vec::new();
+        while let Ok(s) = ActorSound::load(&mut cursor) {
+            sounds.push(s);
+        }
+        let inherits_sounds_from = CSCR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let default_outfit = DOFT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let sleeping_outfit = SOFT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let default_package_list = DPLT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let crime_faction = CRIF::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let face_texture = FTST::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let texture_lighting = QNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let face_morph = NAM9::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let face_parts = NAMA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut tint_layers = Vec::new();
+        while let Ok(t) = TintLayer::load(&mut cursor) {
+            tint_layers.push(t);
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            bounds,
+            stats,
+            factions,
+            death_item,
+            voice_type,
+            template,
+            race,
+            spells,
+            destruction_data,
+            skin,
+            far_away_model,
+            attack_race,
+            attacks,
+            spectator_override,
+            observe_dead_override,
+            guard_warn_override,
+            combat_override,
+            perks,
+            items,
+            ai_data,
+            packages,
+            keywords,
+            class,
+            full_name,
+            short_name,
+            player_skills,
+            head_parts,
+            hair_color,
+            combat_style,
+            gift_filter,
+            unknown,
+            height,
+            weight,
+            sound_level,
+            sounds,
+            inherits_sounds_from,
+            default_outfit,
+            sleeping_outfit,
+            default_package_list,
+            crime_faction,
+            face_texture,
+            texture_lighting,
+            face_morph,
+            face_parts,
+            tint_layers,
+        })
+    }
+}
+
+impl TryFrom<NPC> for NPC_ {
+    type Error = Error;
+
+    fn try_from(obj: NPC) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        ACBS::try_from(obj.stats)?.write(&mut data)?;
+        for f in obj.factions {
+            SNAM::try_from(f)?.write(&mut data)?;
+        }
+        obj.death_item
+            .map(INAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.voice_type
+            .map(VTCK::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.template
+            .map(TPLT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        RNAM::try_from(obj.race)?.write(&mut data)?;
+        if !obj.spells.is_empty() {
+            SPCT::try_from(obj.spells.len() as u32)?.write(&mut data)?;
+            for s in obj.spells {
+                SPLO::try_from(s)?.write(&mut data)?;
+            }
+        }
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.skin.map(WNAM::try_from).transpose()?.write(&mut data)?;
+        obj.far_away_model
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.attack_race
+            .map(ATKR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for a in obj.attacks {
+            a.write(&mut data)?;
+        }
+        obj.spectator_override
+            .map(SPOR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.observe_dead_override
+            .map(OCOR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.guard_warn_override
+            .map(GWOR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.combat_override
+            .map(ECOR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.perks.is_empty() {
+            PRKZ::try_from(obj.perks.len() as u32)?.write(&mut data)?;
+            for p in obj.perks {
+                PRKR::try_from(p)?.write(&mut data)?;
+            }
+        }
+        if !obj.items.is_empty() {
+            COCT::try_from(obj.items.len() as u32)?.write(&mut data)?;
+            for i in obj.items {
+                i.write(&mut data)?;
+            }
+        }
+        AIDT::try_from(obj.ai_data)?.write(&mut data)?;
+        for p in obj.packages {
+            PKID::try_from(p)?.write(&mut data)?;
+        }
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        CNAM::try_from(obj.class)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.short_name
+            .map(SHRT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DATA::try_from(Vec::<u8>::new())?.write(&mut data)?;
+        DNAM::try_from(obj.player_skills)?.write(&mut data)?;
+        for p in obj.head_parts {
+            PNAM::try_from(p)?.write(&mut data)?;
+        }
+        obj.hair_color
+            .map(HCLF::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.combat_style
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.gift_filter
+            .map(GNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown
+            .map(NAM5::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        NAM6::try_from(obj.height)?.write(&mut data)?;
+        NAM7::try_from(obj.weight)?.write(&mut data)?;
+        NAM8::try_from(obj.sound_level)?.write(&mut data)?;
+        for s in obj.sounds {
+            s.write(&mut data)?;
+        }
+        obj.inherits_sounds_from
+            .map(CSCR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.default_outfit
+            .map(DOFT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.sleeping_outfit
+            .map(SOFT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.default_package_list
+            .map(DPLT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.crime_faction
+            .map(CRIF::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.face_texture
+            .map(FTST::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.texture_lighting
+            .map(QNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.face_morph
+            .map(NAM9::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.face_parts
+            .map(NAMA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for t in obj.tint_layers {
+            t.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,11 +1,17 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    EffectCondition, ScriptList, ANAM, BNAM, CNAM, EDID, FNAM, IDLA, IDLB, IDLC, IDLF, IDLT, INAM,
+    PDTO, PFO2, PFOR, PKC2, PKCU, PKDT, PLDT, PNAM, POBA, POCA, POEA, PRCB, PSDT, PTDA, QNAM, SCHR,
+    SCTX, TNAM, TPIC, UNAM, VMAD, XNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [PACK](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/PACK) record
 #[binrw]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"PACK")]
@@ -16,10 +22,380 @@ pub struct PACK {
     pub data: Vec<u8>,
 }
 
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageData {
+    pub flags: u32,
+    pub kind: u8,
+    pub interrupt_override: u8,
+    pub preferred_speed: u8,
+    pub unknown1: u8,
+    pub interrupt_flags: u16,
+    pub unknown2: u16,
+}
+
+impl_field_struct!(PackageData, PKDT);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageSchedule {
+    pub month: i8,
+    pub day_of_week: i8,
+    pub date: u8,
+    pub hour: i8,
+    pub minute: i8,
+    pub unused: [u8; 3],
+    /// Duration in minutes
+    pub duration: i32,
+}
+
+impl_field_struct!(PackageSchedule, PSDT);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageTemplate {
+    pub data_input_count: u32,
+    pub template: FormID,
+    pub version: u32,
+}
+
+impl_field_struct!(PackageTemplate, PKCU);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PackageTopic {
+    #[brw(magic = 0u32)]
+    Reference(FormID),
+    #[brw(magic = 1u32)]
+    Subtype([u8; 4]),
+}
+
+impl_field_struct!(PackageTopic, PDTO);
+
+/// `value` is a FormID for near reference (0), in cell (1) and object ID (4)
+/// locations
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageLocation {
+    pub kind: i32,
+    pub value: u32,
+    pub radius: i32,
+}
+
+impl_field_struct!(PackageLocation, PLDT);
+
+/// `value` is a FormID for specific reference (0) and object ID (1) targets
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageTarget {
+    pub kind: i32,
+    pub value: u32,
+    pub count_or_distance: i32,
+}
+
+impl_field_struct!(PackageTarget, PTDA);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchRoot {
+    pub branch_count: u32,
+    pub flags: u32,
+}
+
+impl_field_struct!(BranchRoot, PRCB);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdleAnimations {
+    pub flags: u8,
+    pub unknown1: Vec<u8>,
+    pub timer: Option<f32>,
+    pub animations: Vec<FormID>,
+    pub unknown2: Option<IDLB>,
+}
+
+impl IdleAnimations {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let flags = IDLF::read(cursor)?.try_into()?;
+        // IDLC holds the animation count, optionally followed by padding
+        let unknown1 = IDLC::read(cursor)
+            .map(|c| c.data.into_iter().skip(1).collect())
+            .unwrap_or_default();
+        let timer = IDLT::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let animations = IDLA::read(cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let unknown2 = IDLB::read(cursor).ok();
+
+        Ok(Self {
+            flags,
+            unknown1,
+            timer,
+            animations,
+            unknown2,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        IDLF::try_from(self.flags)?.write(cursor)?;
+        let mut count = vec![self.animations.len() as u8];
+        count.extend(self.unknown1);
+        IDLC {
+            size: count.len() as u16,
+            data: count,
+        }
+        .write(cursor)?;
+        self.timer.map(IDLT::try_from).transpose()?.write(cursor)?;
+        if !self.animations.is_empty() {
+            IDLA::try_from(self.animations)?.write(cursor)?;
+        }
+        self.unknown2.write(cursor)?;
+        Ok(())
+    }
+}
+
+/// Value of a package data input (CNAM), interpreted according to its type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PackageValueData {
+    Bool(bool),
+    Int(i32),
+    Float(f32),
+    Unknown(Vec<u8>),
+}
+
+/// Value of a package data input, typed by ANAM
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageValue {
+    pub kind: String,
+    pub value: Option<PackageValueData>,
+    pub unknown1: Option<BNAM>,
+    pub topics: Vec<PackageTopic>,
+    pub location: Option<PackageLocation>,
+    pub target: Option<PackageTarget>,
+    pub unknown2: Option<TPIC>,
+}
+
+impl PackageValue {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let kind: String = ANAM::read(cursor)?.try_into()?;
+        let value = match CNAM::read(cursor) {
+            Ok(c) => Some(match kind.as_str() {
+                "Bool" => PackageValueData::Bool(TryInto::<u8>::try_into(c)? != 0),
+                "Int" => PackageValueData::Int(c.try_into()?),
+                "Float" | "ObjectList" => PackageValueData::Float(c.try_into()?),
+                _ => PackageValueData::Unknown(c.data),
+            }),
+            Err(_) => None,
+        };
+        let unknown1 = BNAM::read(cursor).ok();
+        let mut topics = Vec::new();
+        while let Ok(t) = PDTO::read(cursor) {
+            topics.push(t.try_into()?);
+        }
+        let location = PLDT::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let target = PTDA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let unknown2 = TPIC::read(cursor).ok();
+
+        Ok(Self {
+            kind,
+            value,
+            unknown1,
+            topics,
+            location,
+            target,
+            unknown2,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        ANAM::try_from(self.kind)?.write(cursor)?;
+        match self.value {
+            Some(PackageValueData::Bool(b)) => CNAM::try_from(b as u8)?.write(cursor)?,
+            Some(PackageValueData::Int(i)) => CNAM::try_from(i)?.write(cursor)?,
+            Some(PackageValueData::Float(f)) => CNAM::try_from(f)?.write(cursor)?,
+            Some(PackageValueData::Unknown(data)) => CNAM {
+                size: data.len() as u16,
+                data,
+            }
+            .write(cursor)?,
+            None => (),
+        }
+        self.unknown1.write(cursor)?;
+        for t in self.topics {
+            PDTO::try_from(t)?.write(cursor)?;
+        }
+        self.location
+            .map(PLDT::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.target.map(PTDA::try_from).transpose()?.write(cursor)?;
+        self.unknown2.write(cursor)?;
+        Ok(())
+    }
+}
+
+/// Named data input, referencing a [PackageValue] by index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageInput {
+    pub index: i8,
+    pub name: Option<String>,
+    pub flags: Option<u32>,
+}
+
+impl PackageInput {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let index = UNAM::read(cursor)?.try_into()?;
+        let name = BNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let flags = PNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self { index, name, flags })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        UNAM::try_from(self.index)?.write(cursor)?;
+        self.name.map(BNAM::try_from).transpose()?.write(cursor)?;
+        self.flags.map(PNAM::try_from).transpose()?.write(cursor)?;
+        Ok(())
+    }
+}
+
+/// Node of the package procedure tree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcedureBranch {
+    pub kind: String,
+    pub conditions: Vec<EffectCondition>,
+    pub root: Option<BranchRoot>,
+    pub procedure: Option<String>,
+    pub flags: Option<u32>,
+    pub data_inputs: Vec<u8>,
+    pub flags_overrides: Vec<PFO2>,
+    pub unknown: Vec<PFOR>,
+}
+
+impl ProcedureBranch {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let kind = ANAM::read(cursor)?.try_into()?;
+        let conditions = EffectCondition::load_counted(cursor)?;
+        let root = PRCB::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let procedure = PNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let flags = FNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let mut data_inputs = Vec::new();
+        while let Ok(p) = PKC2::read(cursor) {
+            data_inputs.push(p.try_into()?);
+        }
+        let mut flags_overrides = Vec::new();
+        while let Ok(p) = PFO2::read(cursor) {
+            flags_overrides.push(p);
+        }
+        let mut unknown = Vec::new();
+        while let Ok(p) = PFOR::read(cursor) {
+            unknown.push(p);
+        }
+
+        Ok(Self {
+            kind,
+            conditions,
+            root,
+            procedure,
+            flags,
+            data_inputs,
+            flags_overrides,
+            unknown,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        ANAM::try_from(self.kind)?.write(cursor)?;
+        EffectCondition::write_counted(self.conditions, cursor)?;
+        self.root.map(PRCB::try_from).transpose()?.write(cursor)?;
+        self.procedure
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.flags.map(FNAM::try_from).transpose()?.write(cursor)?;
+        for d in self.data_inputs {
+            PKC2::try_from(d)?.write(cursor)?;
+        }
+        self.flags_overrides.write(cursor)?;
+        self.unknown.write(cursor)?;
+        Ok(())
+    }
+}
+
+/// Idle and topics for the OnBegin (POBA), OnEnd (POEA) or OnChange (POCA)
+/// events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageEvent {
+    pub idle: Option<FormID>,
+    pub unused1: Option<SCHR>,
+    pub unused2: Option<SCTX>,
+    pub unused3: Option<QNAM>,
+    pub unused4: Option<TNAM>,
+    pub topics: Vec<PackageTopic>,
+}
+
+impl PackageEvent {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let idle = INAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let unused1 = SCHR::read(cursor).ok();
+        let unused2 = SCTX::read(cursor).ok();
+        let unused3 = QNAM::read(cursor).ok();
+        let unused4 = TNAM::read(cursor).ok();
+        let mut topics = Vec::new();
+        while let Ok(t) = PDTO::read(cursor) {
+            topics.push(t.try_into()?);
+        }
+
+        Ok(Self {
+            idle,
+            unused1,
+            unused2,
+            unused3,
+            unused4,
+            topics,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        self.idle.map(INAM::try_from).transpose()?.write(cursor)?;
+        self.unused1.write(cursor)?;
+        self.unused2.write(cursor)?;
+        self.unused3.write(cursor)?;
+        self.unused4.write(cursor)?;
+        for t in self.topics {
+            PDTO::try_from(t)?.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AIPackage {
     pub header: RecordHeader,
     pub edid: String,
+    pub scripts: Option<ScriptList>,
+    pub data: PackageData,
+    pub schedule: PackageSchedule,
+    pub conditions: Vec<EffectCondition>,
+    pub idle_animations: Option<IdleAnimations>,
+    pub combat_style: Option<FormID>,
+    pub owner_quest: Option<FormID>,
+    pub template: Option<PackageTemplate>,
+    pub values: Vec<PackageValue>,
+    pub inputs: Vec<PackageInput>,
+    pub marker: XNAM,
+    pub procedure_tree: Vec<ProcedureBranch>,
+    pub on_begin: Option<PackageEvent>,
+    pub on_end: Option<PackageEvent>,
+    pub on_change: Option<PackageEvent>,
 }
 
 impl fmt::Display for AIPackage {
@@ -36,10 +412,150 @@ impl TryFrom<PACK> for AIPackage {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(|v| v.parse(b"PACK"))
+            .transpose()?;
+        let data = PKDT::read(&mut cursor)?.try_into()?;
+        let schedule = PSDT::read(&mut cursor)?.try_into()?;
+        let conditions = EffectCondition::load_counted(&mut cursor)?;
+        let idle_animations = IdleAnimations::load(&mut cursor).ok();
+        let combat_style = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let owner_quest = QNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let template = PKCU::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut values = Vec::new();
+        while let Ok(v) = PackageValue::load(&mut cursor) {
+            values.push(v);
+        }
+        let mut inputs = Vec::new();
+        while let Ok(i) = PackageInput::load(&mut cursor) {
+            inputs.push(i);
+        }
+        let marker = XNAM::read(&mut cursor)?;
+        let mut procedure_tree = Vec::new();
+        while let Ok(b) = ProcedureBranch::load(&mut cursor) {
+            procedure_tree.push(b);
+        }
+        let on_begin = POBA::read(&mut cursor)
+            .ok()
+            .map(|_| PackageEvent::load(&mut cursor))
+            .transpose()?;
+        let on_end = POEA::read(&mut cursor)
+            .ok()
+            .map(|_| PackageEvent::load(&mut cursor))
+            .transpose()?;
+        let on_change = POCA::read(&mut cursor)
+            .ok()
+            .map(|_| PackageEvent::load(&mut cursor))
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            data,
+            schedule,
+            conditions,
+            idle_animations,
+            combat_style,
+            owner_quest,
+            template,
+            values,
+            inputs,
+            marker,
+            procedure_tree,
+            on_begin,
+            on_end,
+            on_change,
+        })
+    }
+}
+
+impl TryFrom<AIPackage> for PACK {
+    type Error = Error;
+
+    fn try_from(obj: AIPackage) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        PKDT::try_from(obj.data)?.write(&mut data)?;
+        PSDT::try_from(obj.schedule)?.write(&mut data)?;
+        EffectCondition::write_counted(obj.conditions, &mut data)?;
+        if let Some(i) = obj.idle_animations {
+            i.write(&mut data)?;
+        }
+        obj.combat_style
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.owner_quest
+            .map(QNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.template
+            .map(PKCU::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for v in obj.values {
+            v.write(&mut data)?;
+        }
+        for i in obj.inputs {
+            i.write(&mut data)?;
+        }
+        obj.marker.write(&mut data)?;
+        for b in obj.procedure_tree {
+            b.write(&mut data)?;
+        }
+        if let Some(e) = obj.on_begin {
+            POBA {
+                size: 0,
+                data: Vec::new(),
+            }
+            .write(&mut data)?;
+            e.write(&mut data)?;
+        }
+        if let Some(e) = obj.on_end {
+            POEA {
+                size: 0,
+                data: Vec::new(),
+            }
+            .write(&mut data)?;
+            e.write(&mut data)?;
+        }
+        if let Some(e) = obj.on_change {
+            POCA {
+                size: 0,
+                data: Vec::new(),
+            }
+            .write(&mut data)?;
+            e.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
@@ -1,8 +1,12 @@
-use super::{get_cursor, Flags, RecordHeader};
-use crate::common::LocalizedString;
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::{ScriptList, DESC, EDID, FULL, ICON, VMAD};
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    EffectCondition, ScriptList, DATA, DESC, EDID, EPF2, EPF3, EPFD, EPFT, FULL, ICON, NNAM, PRKC,
+    PRKE, PRKF, VMAD,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -22,6 +26,309 @@ pub struct PERK {
     pub localized: bool,
 }
 
+/// Perk data (DATA)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerkData {
+    pub trainable: u8,
+    pub level: u8,
+    pub ranks: u8,
+    pub playable: u8,
+    pub hidden: u8,
+}
+
+impl_field_struct!(PerkData, DATA);
+
+#[binrw]
+#[brw(little, repr = u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PerkEffectType {
+    QuestStage = 0,
+    Ability,
+    EntryPoint,
+}
+
+/// Header of a perk effect section (PRKE)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerkEffectHeader {
+    pub kind: PerkEffectType,
+    pub rank: u8,
+    pub priority: u8,
+}
+
+impl_field_struct!(PerkEffectHeader, PRKE);
+
+/// Quest stage set by a quest stage effect (DATA)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerkQuestStage {
+    pub quest: FormID,
+    pub stage: u8,
+    pub unused: [u8; 3],
+}
+
+impl_field_struct!(PerkQuestStage, DATA);
+
+#[binrw]
+#[brw(little, repr = u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntryPointFunction {
+    SetValue = 1,
+    AddValue,
+    MultiplyValue,
+    AddRangeToValue,
+    AddActorValueMult,
+    AbsoluteValue,
+    NegativeAbsoluteValue,
+    AddLeveledList,
+    AddActivateChoice,
+    SelectSpell,
+    SelectText,
+    SetToActorValueMult,
+    MultiplyActorValueMult,
+    MultiplyOnePlusActorValueMult,
+    SetText,
+}
+
+/// Entry point an effect modifies, and how (DATA)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryPointHeader {
+    pub entry_point: u8,
+    pub function: EntryPointFunction,
+    pub condition_tab_count: u8,
+}
+
+impl_field_struct!(EntryPointHeader, DATA);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct EntryPointScriptFlags: u16 {
+        const RUN_IMMEDIATELY = 0x0001;
+        const REPLACE_DEFAULT = 0x0002;
+    }
+}
+
+/// Script fragment run by an activate choice entry point (EPF3)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryPointScript {
+    pub flags: EntryPointScriptFlags,
+    pub fragment_index: u16,
+}
+
+impl_field_struct!(EntryPointScript, EPF3);
+
+/// Function parameter of an entry point (EPFD), whose layout is given by
+/// the preceding EPFT
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EntryPointData {
+    None,
+    Float(f32),
+    FloatPair(f32, f32),
+    LeveledItem(FormID),
+    ActivateSpell(FormID),
+    Spell(FormID),
+    GameSetting(String),
+    Text(LocalizedString),
+    Unknown(u8, Option<Vec<u8>>),
+}
+
+impl EntryPointData {
+    fn load(cursor: &mut Cursor<&Vec<u8>>, kind: u8, localized: bool) -> Result<Self, Error> {
+        Ok(match (kind, EPFD::read(cursor).ok()) {
+            (0, None) => Self::None,
+            (1, Some(d)) if d.size == 4 => Self::Float(d.try_into()?),
+            (2, Some(d)) if d.size == 8 => {
+                let (a, b) = d.try_into()?;
+                Self::FloatPair(a, b)
+            }
+            (3, Some(d)) if d.size == 4 => Self::LeveledItem(d.try_into()?),
+            (4, Some(d)) if d.size == 4 => Self::ActivateSpell(d.try_into()?),
+            (5, Some(d)) if d.size == 4 => Self::Spell(d.try_into()?),
+            (6, Some(d)) => Self::GameSetting(d.try_into()?),
+            (7, Some(d)) if localized => Self::Text(LocalizedString::Localized(d.try_into()?)),
+            (7, Some(d)) => Self::Text(LocalizedString::ZString(d.try_into()?)),
+            (k, d) => Self::Unknown(k, d.map(|d| d.data)),
+        })
+    }
+
+    fn kind(&self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Float(_) => 1,
+            Self::FloatPair(..) => 2,
+            Self::LeveledItem(_) => 3,
+            Self::ActivateSpell(_) => 4,
+            Self::Spell(_) => 5,
+            Self::GameSetting(_) => 6,
+            Self::Text(_) => 7,
+            Self::Unknown(k, _) => *k,
+        }
+    }
+
+    fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        match self {
+            Self::None => None,
+            Self::Float(x) => Some(EPFD::try_from(x)?),
+            Self::FloatPair(a, b) => Some(EPFD::try_from((a, b))?),
+            Self::LeveledItem(x) | Self::ActivateSpell(x) | Self::Spell(x) => {
+                Some(EPFD::try_from(x)?)
+            }
+            Self::GameSetting(x) => Some(EPFD::try_from(x)?),
+            Self::Text(x) => Some(EPFD::try_from(x)?),
+            Self::Unknown(_, x) => x.map(|data| EPFD {
+                size: data.len() as u16,
+                data,
+            }),
+        }
+        .write(cursor)?;
+        Ok(())
+    }
+}
+
+/// Conditions on one tab of an entry point, run against the subject given
+/// by PRKC
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerkConditionTab {
+    pub run_on: i8,
+    pub conditions: Vec<EffectCondition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryPoint {
+    pub entry_point: u8,
+    pub function: EntryPointFunction,
+    pub condition_tab_count: u8,
+    pub condition_tabs: Vec<PerkConditionTab>,
+    pub data: Option<EntryPointData>,
+    pub button_label: Option<LocalizedString>,
+    pub script: Option<EntryPointScript>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PerkEffectData {
+    QuestStage(PerkQuestStage),
+    Ability(FormID),
+    EntryPoint(EntryPoint),
+}
+
+/// One PRKE ... PRKF effect section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerkEffect {
+    pub rank: u8,
+    pub priority: u8,
+    pub data: PerkEffectData,
+}
+
+impl PerkEffect {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>, localized: bool) -> Result<Self, Error> {
+        let header: PerkEffectHeader = PRKE::read(cursor)?.try_into()?;
+        let data = match header.kind {
+            PerkEffectType::QuestStage => {
+                PerkEffectData::QuestStage(DATA::read(cursor)?.try_into()?)
+            }
+            PerkEffectType::Ability => PerkEffectData::Ability(DATA::read(cursor)?.try_into()?),
+            PerkEffectType::EntryPoint => {
+                let ep: EntryPointHeader = DATA::read(cursor)?.try_into()?;
+                let mut condition_tabs = Vec::new();
+                while let Ok(prkc) = PRKC::read(cursor) {
+                    condition_tabs.push(PerkConditionTab {
+                        run_on: prkc.try_into()?,
+                        conditions: EffectCondition::load_multiple(cursor)?,
+                    });
+                }
+                let kind: Option<u8> =
+                    EPFT::read(cursor).ok().map(TryInto::try_into).transpose()?;
+                let button_label = match (EPF2::read(cursor), localized) {
+                    (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+                    (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+                    (Err(_), _) => None,
+                };
+                let script = EPF3::read(cursor).ok().map(TryInto::try_into).transpose()?;
+                let data = kind
+                    .map(|k| EntryPointData::load(cursor, k, localized))
+                    .transpose()?;
+                PerkEffectData::EntryPoint(EntryPoint {
+                    entry_point: ep.entry_point,
+                    function: ep.function,
+                    condition_tab_count: ep.condition_tab_count,
+                    condition_tabs,
+                    data,
+                    button_label,
+                    script,
+                })
+            }
+        };
+        PRKF::read(cursor)?;
+
+        Ok(Self {
+            rank: header.rank,
+            priority: header.priority,
+            data,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        let kind = match self.data {
+            PerkEffectData::QuestStage(_) => PerkEffectType::QuestStage,
+            PerkEffectData::Ability(_) => PerkEffectType::Ability,
+            PerkEffectData::EntryPoint(_) => PerkEffectType::EntryPoint,
+        };
+        PRKE::try_from(PerkEffectHeader {
+            kind,
+            rank: self.rank,
+            priority: self.priority,
+        })?
+        .write(cursor)?;
+        match self.data {
+            PerkEffectData::QuestStage(q) => DATA::try_from(q)?.write(cursor)?,
+            PerkEffectData::Ability(s) => DATA::try_from(s)?.write(cursor)?,
+            PerkEffectData::EntryPoint(ep) => {
+                DATA::try_from(EntryPointHeader {
+                    entry_point: ep.entry_point,
+                    function: ep.function,
+                    condition_tab_count: ep.condition_tab_count,
+                })?
+                .write(cursor)?;
+                for tab in ep.condition_tabs {
+                    PRKC::try_from(tab.run_on)?.write(cursor)?;
+                    for c in tab.conditions {
+                        c.write(cursor)?;
+                    }
+                }
+                ep.data
+                    .as_ref()
+                    .map(|d| EPFT::try_from(d.kind()))
+                    .transpose()?
+                    .write(cursor)?;
+                ep.button_label
+                    .map(EPF2::try_from)
+                    .transpose()?
+                    .write(cursor)?;
+                ep.script.map(EPF3::try_from).transpose()?.write(cursor)?;
+                if let Some(d) = ep.data {
+                    d.write(cursor)?;
+                }
+            }
+        }
+        PRKF {
+            size: 0,
+            data: Vec::new(),
+        }
+        .write(cursor)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Perk {
     pub header: RecordHeader,
@@ -30,6 +337,10 @@ pub struct Perk {
     pub full_name: Option<LocalizedString>,
     pub description: LocalizedString,
     pub icon: Option<String>,
+    pub conditions: Vec<EffectCondition>,
+    pub data: PerkData,
+    pub next_perk: Option<FormID>,
+    pub effects: Vec<PerkEffect>,
 }
 
 impl fmt::Display for Perk {
@@ -46,9 +357,9 @@ impl TryFrom<PERK> for Perk {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
-        let scripts: Option<ScriptList> = VMAD::read(&mut cursor)
+        let scripts = VMAD::read(&mut cursor)
             .ok()
-            .map(TryInto::try_into)
+            .map(|v| v.parse(b"PERK"))
             .transpose()?;
         let full_name = match (FULL::read(&mut cursor), raw.localized) {
             (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
@@ -64,6 +375,18 @@ impl TryFrom<PERK> for Perk {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let conditions = EffectCondition::load_multiple(&mut cursor)?;
+        let data = DATA::read(&mut cursor)?.try_into()?;
+        let next_perk = NNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut effects = Vec::new();
+        while let Ok(e) = PerkEffect::load(&mut cursor, raw.localized) {
+            effects.push(e);
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
@@ -72,6 +395,54 @@ impl TryFrom<PERK> for Perk {
             full_name,
             description,
             icon,
+            conditions,
+            data,
+            next_perk,
+            effects,
+        })
+    }
+}
+
+impl TryFrom<Perk> for PERK {
+    type Error = Error;
+
+    fn try_from(obj: Perk) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DESC::try_from(obj.description)?.write(&mut data)?;
+        obj.icon.map(ICON::try_from).transpose()?.write(&mut data)?;
+        for c in obj.conditions {
+            c.write(&mut data)?;
+        }
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        obj.next_perk
+            .map(NNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for e in obj.effects {
+            e.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
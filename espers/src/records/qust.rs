@@ -1,25 +1,485 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    ContainerItem, EffectCondition, ScriptList, ALCA, ALCL, ALCO, ALDN, ALEA, ALED, ALEQ, ALFA,
+    ALFC, ALFD, ALFE, ALFI, ALFL, ALFR, ALID, ALLS, ALNA, ALNT, ALPC, ALRT, ALSP, ALST, ALUA, ANAM,
+    CNAM, COCT, DNAM, ECOR, EDID, ENAM, FLTR, FNAM, FULL, GWOR, INDX, KNAM, KSIZ, KWDA, NAM0, NEXT,
+    NNAM, OCOR, QOBJ, QSDT, QSTA, QTGL, SPOR, VMAD, VTCK,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [QUST](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/QUST) record
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"QUST")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QUST {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct QuestFlags: u16 {
+        const START_GAME_ENABLED = 0x0001;
+        const COMPLETED = 0x0002;
+        const ADD_IDLE_TOPIC_TO_HELLO = 0x0004;
+        const ALLOW_REPEATED_STAGES = 0x0008;
+        const STARTS_ENABLED = 0x0010;
+        const DISPLAYED_IN_HUD = 0x0020;
+        const FAILED = 0x0040;
+        const STAGE_WAIT = 0x0080;
+        const RUN_ONCE = 0x0100;
+        const EXCLUDE_FROM_DIALOGUE_EXPORT = 0x0200;
+        const WARN_ON_ALIAS_FILL_FAILURE = 0x0400;
+        const ACTIVE = 0x0800;
+        const REPEATS_CONDITIONS = 0x1000;
+        const KEEP_INSTANCE = 0x2000;
+        const WANT_DORMANT = 0x4000;
+        const HAS_DIALOGUE_DATA = 0x8000;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestData {
+    pub flags: QuestFlags,
+    pub priority: u8,
+    pub form_version: u8,
+    pub unknown: u32,
+    pub kind: u32,
+}
+
+impl_field_struct!(QuestData, DNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageIndex {
+    pub index: u16,
+    pub flags: u8,
+    pub unknown: u8,
+}
+
+impl_field_struct!(StageIndex, INDX);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub flags: u8,
+    pub conditions: Vec<EffectCondition>,
+    pub entry: Option<LocalizedString>,
+    pub next_quest: Option<FormID>,
+}
+
+impl LogEntry {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>, localized: bool) -> Result<Self, Error> {
+        let flags = QSDT::read(cursor)?.try_into()?;
+        let conditions = EffectCondition::load_multiple(cursor)?;
+        let entry = match (CNAM::read(cursor), localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let next_quest = NAM0::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self {
+            flags,
+            conditions,
+            entry,
+            next_quest,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        QSDT::try_from(self.flags)?.write(cursor)?;
+        for c in self.conditions {
+            c.write(cursor)?;
+        }
+        self.entry.map(CNAM::try_from).transpose()?.write(cursor)?;
+        self.next_quest
+            .map(NAM0::try_from)
+            .transpose()?
+            .write(cursor)?;
+        Ok(())
+    }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stage {
+    pub index: StageIndex,
+    pub log_entries: Vec<LogEntry>,
+}
+
+impl Stage {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>, localized: bool) -> Result<Self, Error> {
+        let index = INDX::read(cursor)?.try_into()?;
+        let mut log_entries = Vec::new();
+        while let Ok(l) = LogEntry::load(cursor, localized) {
+            log_entries.push(l);
+        }
+
+        Ok(Self { index, log_entries })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        INDX::try_from(self.index)?.write(cursor)?;
+        for l in self.log_entries {
+            l.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetData {
+    pub alias: i32,
+    pub flags: u32,
+}
+
+impl_field_struct!(TargetData, QSTA);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectiveTarget {
+    pub target: TargetData,
+    pub conditions: Vec<EffectCondition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Objective {
+    pub index: u16,
+    pub flags: Option<u32>,
+    pub display_text: Option<LocalizedString>,
+    pub targets: Vec<ObjectiveTarget>,
+}
+
+impl Objective {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>, localized: bool) -> Result<Self, Error> {
+        let index = QOBJ::read(cursor)?.try_into()?;
+        let flags = FNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let display_text = match (NNAM::read(cursor), localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let mut targets = Vec::new();
+        while let Ok(t) = QSTA::read(cursor) {
+            targets.push(ObjectiveTarget {
+                target: t.try_into()?,
+                conditions: EffectCondition::load_multiple(cursor)?,
+            });
+        }
+
+        Ok(Self {
+            index,
+            flags,
+            display_text,
+            targets,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        QOBJ::try_from(self.index)?.write(cursor)?;
+        self.flags.map(FNAM::try_from).transpose()?.write(cursor)?;
+        self.display_text
+            .map(NNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        for t in self.targets {
+            QSTA::try_from(t.target)?.write(cursor)?;
+            for c in t.conditions {
+                c.write(cursor)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether an alias holds a reference (ALST) or a location (ALLS)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AliasID {
+    Reference(u32),
+    Location(u32),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alias {
+    pub id: AliasID,
+    pub name: Option<String>,
+    pub flags: Option<u32>,
+    pub force_into_alias: Option<i32>,
+    pub specific_location: Option<FormID>,
+    pub forced_reference: Option<FormID>,
+    pub unique_actor: Option<FormID>,
+    pub location_alias: Option<i32>,
+    pub location_keyword: Option<FormID>,
+    pub location_ref_type: Option<FormID>,
+    pub external_quest: Option<FormID>,
+    pub external_alias: Option<i32>,
+    pub created_object: Option<FormID>,
+    pub create_at: Option<u32>,
+    pub create_level: Option<u32>,
+    pub near_alias: Option<i32>,
+    pub near_type: Option<u32>,
+    pub event: Option<[u8; 4]>,
+    pub event_data: Option<ALFD>,
+    pub conditions: Vec<EffectCondition>,
+    pub keywords: Vec<FormID>,
+    pub items: Vec<ContainerItem>,
+    pub spectator_override: Option<FormID>,
+    pub observe_dead_override: Option<FormID>,
+    pub guard_warn_override: Option<FormID>,
+    pub combat_override: Option<FormID>,
+    pub display_name: Option<FormID>,
+    pub spells: Vec<FormID>,
+    pub factions: Vec<FormID>,
+    pub packages: Vec<FormID>,
+    pub voice_types: Option<FormID>,
+}
+
+impl Alias {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let id = match ALST::read(cursor) {
+            Ok(r) => AliasID::Reference(r.try_into()?),
+            Err(_) => AliasID::Location(ALLS::read(cursor)?.try_into()?),
+        };
+        let name = ALID::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let flags = FNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let force_into_alias = ALFI::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let specific_location = ALFL::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let forced_reference = ALFR::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let unique_actor = ALUA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let location_alias = ALFA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let location_keyword = KNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let location_ref_type = ALRT::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let external_quest = ALEQ::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let external_alias = ALEA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let created_object = ALCO::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let create_at = ALCA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let create_level = ALCL::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let near_alias = ALNA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let near_type = ALNT::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let event = ALFE::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let event_data = ALFD::read(cursor).ok();
+        let conditions = EffectCondition::load_multiple(cursor)?;
+
+        let _: Option<u32> = KSIZ::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+
+        let _: Option<u32> = COCT::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let items = ContainerItem::load_multiple(cursor)?;
+
+        let spectator_override = SPOR::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let observe_dead_override = OCOR::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let guard_warn_override = GWOR::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let combat_override = ECOR::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let display_name = ALDN::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let mut spells = Vec::new();
+        while let Ok(s) = ALSP::read(cursor) {
+            spells.push(s.try_into()?);
+        }
+        let mut factions = Vec::new();
+        while let Ok(f) = ALFC::read(cursor) {
+            factions.push(f.try_into()?);
+        }
+        let mut packages = Vec::new();
+        while let Ok(p) = ALPC::read(cursor) {
+            packages.push(p.try_into()?);
+        }
+        let voice_types = VTCK::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        ALED::read(cursor)?;
+
+        Ok(Self {
+            id,
+            name,
+            flags,
+            force_into_alias,
+            specific_location,
+            forced_reference,
+            unique_actor,
+            location_alias,
+            location_keyword,
+            location_ref_type,
+            external_quest,
+            external_alias,
+            created_object,
+            create_at,
+            create_level,
+            near_alias,
+            near_type,
+            event,
+            event_data,
+            conditions,
+            keywords,
+            items,
+            spectator_override,
+            observe_dead_override,
+            guard_warn_override,
+            combat_override,
+            display_name,
+            spells,
+            factions,
+            packages,
+            voice_types,
+        })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        match self.id {
+            AliasID::Reference(r) => ALST::try_from(r)?.write(cursor)?,
+            AliasID::Location(l) => ALLS::try_from(l)?.write(cursor)?,
+        }
+        self.name.map(ALID::try_from).transpose()?.write(cursor)?;
+        self.flags.map(FNAM::try_from).transpose()?.write(cursor)?;
+        self.force_into_alias
+            .map(ALFI::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.specific_location
+            .map(ALFL::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.forced_reference
+            .map(ALFR::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.unique_actor
+            .map(ALUA::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.location_alias
+            .map(ALFA::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.location_keyword
+            .map(KNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.location_ref_type
+            .map(ALRT::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.external_quest
+            .map(ALEQ::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.external_alias
+            .map(ALEA::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.created_object
+            .map(ALCO::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.create_at
+            .map(ALCA::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.create_level
+            .map(ALCL::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.near_alias
+            .map(ALNA::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.near_type
+            .map(ALNT::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.event.map(ALFE::try_from).transpose()?.write(cursor)?;
+        self.event_data.write(cursor)?;
+        for c in self.conditions {
+            c.write(cursor)?;
+        }
+        if !self.keywords.is_empty() {
+            KSIZ::try_from(self.keywords.len() as u32)?.write(cursor)?;
+            KWDA::try_from(self.keywords)?.write(cursor)?;
+        }
+        if !self.items.is_empty() {
+            COCT::try_from(self.items.len() as u32)?.write(cursor)?;
+            for i in self.items {
+                i.write(cursor)?;
+            }
+        }
+        self.spectator_override
+            .map(SPOR::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.observe_dead_override
+            .map(OCOR::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.guard_warn_override
+            .map(GWOR::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.combat_override
+            .map(ECOR::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.display_name
+            .map(ALDN::try_from)
+            .transpose()?
+            .write(cursor)?;
+        for s in self.spells {
+            ALSP::try_from(s)?.write(cursor)?;
+        }
+        for f in self.factions {
+            ALFC::try_from(f)?.write(cursor)?;
+        }
+        for p in self.packages {
+            ALPC::try_from(p)?.write(cursor)?;
+        }
+        self.voice_types
+            .map(VTCK::try_from)
+            .transpose()?
+            .write(cursor)?;
+        ALED {
+            size: 0,
+            data: Vec::new(),
+        }
+        .write(cursor)?;
+        Ok(())
+    }
+}
+
+/// Parsed [QUST] record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Quest {
     pub header: RecordHeader,
     pub edid: String,
+    pub scripts: Option<ScriptList>,
+    pub full_name: Option<LocalizedString>,
+    pub data: QuestData,
+    pub event: Option<[u8; 4]>,
+    pub text_display_globals: Vec<FormID>,
+    pub filter: Option<String>,
+    pub dialogue_conditions: Vec<EffectCondition>,
+    pub event_conditions: Vec<EffectCondition>,
+    pub stages: Vec<Stage>,
+    pub objectives: Vec<Objective>,
+    pub next_alias_id: Option<u32>,
+    pub aliases: Vec<Alias>,
 }
 
 impl fmt::Display for Quest {
@@ -36,10 +496,133 @@ impl TryFrom<QUST> for Quest {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(|v| v.parse(b"QUST"))
+            .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let data = DNAM::read(&mut cursor)?.try_into()?;
+        let event = ENAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut text_display_globals = Vec::new();
+        while let Ok(q) = QTGL::read(&mut cursor) {
+            text_display_globals.push(q.try_into()?);
+        }
+        let filter = FLTR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let dialogue_conditions = EffectCondition::load_multiple(&mut cursor)?;
+        NEXT::read(&mut cursor)?;
+        let event_conditions = EffectCondition::load_multiple(&mut cursor)?;
+
+        let mut stages = Vec::new();
+        while let Ok(s) = Stage::load(&mut cursor, raw.localized) {
+            stages.push(s);
+        }
+        let mut objectives = Vec::new();
+        while let Ok(o) = Objective::load(&mut cursor, raw.localized) {
+            objectives.push(o);
+        }
+        let next_alias_id = ANAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut aliases = Vec::new();
+        while let Ok(a) = Alias::load(&mut cursor) {
+            aliases.push(a);
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            full_name,
+            data,
+            event,
+            text_display_globals,
+            filter,
+            dialogue_conditions,
+            event_conditions,
+            stages,
+            objectives,
+            next_alias_id,
+            aliases,
+        })
+    }
+}
+
+impl TryFrom<Quest> for QUST {
+    type Error = Error;
+
+    fn try_from(obj: Quest) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DNAM::try_from(obj.data)?.write(&mut data)?;
+        obj.event
+            .map(ENAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for q in obj.text_display_globals {
+            QTGL::try_from(q)?.write(&mut data)?;
+        }
+        obj.filter
+            .map(FLTR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for c in obj.dialogue_conditions {
+            c.write(&mut data)?;
+        }
+        NEXT {
+            size: 0,
+            data: Vec::new(),
+        }
+        .write(&mut data)?;
+        for c in obj.event_conditions {
+            c.write(&mut data)?;
+        }
+        for s in obj.stages {
+            s.write(&mut data)?;
+        }
+        for o in obj.objectives {
+            o.write(&mut data)?;
+        }
+        obj.next_alias_id
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for a in obj.aliases {
+            a.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,9 +1,16 @@
-use super::{get_cursor, Flags, RecordHeader};
-use crate::common::LocalizedString;
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::{DESC, EDID, FULL};
-use binrw::binrw;
-use binrw::BinRead;
+use crate::fields::{
+    Attack, BodyTemplate, BodyTemplate2, Model, ReadTextures, WriteTextures, AHCF, AHCM, ANAM,
+    ATKR, BOD2, BODT, DATA, DESC, DFTF, DFTM, DNAM, EDID, ENAM, FLMV, FNAM, FTSF, FTSM, FULL, GNAM,
+    HCLF, HEAD, HNAM, INDX, KSIZ, KWDA, LNAM, MNAM, MODL, MODS, MODT, MPAI, MPAV, MTNM, MTYP, NAM0,
+    NAM1, NAM2, NAM3, NAM4, NAM5, NAM7, NAM8, NAME, ONAM, PHTN, PHWT, PNAM, QNAM, RNAM, RNMV, RPRF,
+    RPRM, SNMV, SPCT, SPED, SPLO, SPMV, SWMV, TINC, TIND, TINI, TINL, TINP, TINT, TINV, TIRS, UNAM,
+    UNES, VNAM, VTCK, WKMV, WNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -23,12 +30,472 @@ pub struct RACE {
     pub localized: bool,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct RaceFlags: u32 {
+        const PLAYABLE = 0x00000001;
+        const FACEGEN_HEAD = 0x00000002;
+        const CHILD = 0x00000004;
+        const TILT_FRONT_BACK = 0x00000008;
+        const TILT_LEFT_RIGHT = 0x00000010;
+        const NO_SHADOW = 0x00000020;
+        const SWIMS = 0x00000040;
+        const FLIES = 0x00000080;
+        const WALKS = 0x00000100;
+        const IMMOBILE = 0x00000200;
+        const NOT_PUSHABLE = 0x00000400;
+        const NO_COMBAT_IN_WATER = 0x00000800;
+        const NO_ROTATING_TO_HEAD_TRACK = 0x00001000;
+        const DONT_SHOW_BLOOD_SPRAY = 0x00002000;
+        const DONT_SHOW_BLOOD_DECAL = 0x00004000;
+        const USES_HEAD_TRACK_ANIMS = 0x00008000;
+        const SPELLS_ALIGN_WITH_MAGIC_NODE = 0x00010000;
+        const USE_WORLD_RAYCASTS_FOR_FOOT_IK = 0x00020000;
+        const ALLOW_RAGDOLL_COLLISION = 0x00040000;
+        const REGEN_HP_IN_COMBAT = 0x00080000;
+        const CANT_OPEN_DOORS = 0x00100000;
+        const ALLOW_PC_DIALOGUE = 0x00200000;
+        const NO_KNOCKDOWNS = 0x00400000;
+        const ALLOW_PICKPOCKET = 0x00800000;
+        const ALWAYS_USE_PROXY_CONTROLLER = 0x01000000;
+        const DONT_SHOW_WEAPON_BLOOD = 0x02000000;
+        const OVERLAY_HEAD_PART_LIST = 0x04000000;
+        const OVERRIDE_HEAD_PART_LIST = 0x08000000;
+        const CAN_PICKUP_ITEMS = 0x10000000;
+        const ALLOW_MULTIPLE_MEMBERSHIP = 0x20000000;
+        const CAN_DUAL_WIELD = 0x40000000;
+        const AVOIDS_ROADS = 0x80000000;
+    }
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct RaceFlags2: u32 {
+        const USE_ADVANCED_AVOIDANCE = 0x00000001;
+        const NON_HOSTILE = 0x00000002;
+        const ALLOW_MOUNTED_COMBAT = 0x00000010;
+    }
+}
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RaceSize {
+    Small = 0,
+    Medium,
+    Large,
+    ExtraLarge,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillBoost {
+    pub skill: i8,
+    pub boost: i8,
+}
+
+/// Race data (DATA). Mount data is only present in newer plugins.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaceData {
+    pub skill_boosts: [SkillBoost; 7],
+    pub unknown: [u8; 2],
+    pub male_height: f32,
+    pub female_height: f32,
+    pub male_weight: f32,
+    pub female_weight: f32,
+    pub flags: RaceFlags,
+    pub starting_health: f32,
+    pub starting_magicka: f32,
+    pub starting_stamina: f32,
+    pub base_carry_weight: f32,
+    pub base_mass: f32,
+    pub acceleration_rate: f32,
+    pub deceleration_rate: f32,
+    pub size: RaceSize,
+    pub head_biped_object: i32,
+    pub hair_biped_object: i32,
+    pub injured_health_percent: f32,
+    pub shield_biped_object: i32,
+    pub health_regen: f32,
+    pub magicka_regen: f32,
+    pub stamina_regen: f32,
+    pub unarmed_damage: f32,
+    pub unarmed_reach: f32,
+    pub body_biped_object: i32,
+    pub aim_angle_tolerance: f32,
+    pub flight_radius: f32,
+    pub angular_acceleration_rate: f32,
+    pub angular_tolerance: f32,
+    pub flags2: RaceFlags2,
+    #[br(try)]
+    pub mount_data: Option<[f32; 9]>,
+}
+
+impl_field_struct!(RaceData, DATA);
+
+/// Movement speed overrides (SPED)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovementSpeeds {
+    pub left_walk: f32,
+    pub left_run: f32,
+    pub right_walk: f32,
+    pub right_run: f32,
+    pub forward_walk: f32,
+    pub forward_run: f32,
+    pub back_walk: f32,
+    pub back_run: f32,
+    pub rotate_in_place_walk: f32,
+    pub rotate_in_place_run: f32,
+    pub rotate_while_moving_run: f32,
+}
+
+impl_field_struct!(MovementSpeeds, SPED);
+
+/// A movement type (MTYP), with optional speed overrides
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovementType {
+    pub movement_type: FormID,
+    pub speeds: Option<MovementSpeeds>,
+}
+
+/// Phoneme target weights (PHWT), one per phoneme target name
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhonemeWeights {
+    pub weights: [f32; 16],
+}
+
+impl_field_struct!(PhonemeWeights, PHWT);
+
+/// A body part model, keyed by body part index (INDX)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BodyPart {
+    pub index: u32,
+    pub model: Option<Model>,
+}
+
+impl BodyPart {
+    fn load_multiple(cursor: &mut Cursor<&Vec<u8>>, version: u16) -> Result<Vec<Self>, Error> {
+        let mut parts = Vec::new();
+        while let Ok(i) = INDX::read(cursor) {
+            parts.push(Self {
+                index: i.try_into()?,
+                model: Model::try_load::<MODL, MODT, MODS>(cursor, version)?,
+            });
+        }
+        Ok(parts)
+    }
+
+    fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        INDX::try_from(self.index)?.write(cursor)?;
+        if let Some(m) = self.model {
+            m.write::<MODL, MODT, MODS>(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+/// A head part slot (INDX) and its default head part (HEAD)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaceHeadPart {
+    pub index: u32,
+    pub head_part: Option<FormID>,
+}
+
+/// FaceGen morph values (MPAV)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MorphValues {
+    pub flags: u32,
+    pub unknown: [u32; 7],
+}
+
+impl_field_struct!(MorphValues, MPAV);
+
+/// A FaceGen morph preset (MPAI) with its values (MPAV)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MorphPreset {
+    pub index: u32,
+    pub values: Option<MorphValues>,
+}
+
+/// Colour preset of a tint mask
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TintPreset {
+    pub color: FormID,
+    pub default_value: Option<f32>,
+    pub index: Option<u16>,
+}
+
+/// A face tint layer and the colours it may be given
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TintMask {
+    pub index: u16,
+    pub texture: Option<String>,
+    pub mask_type: Option<u16>,
+    pub default_preset: Option<FormID>,
+    pub presets: Vec<TintPreset>,
+}
+
+impl TintMask {
+    fn load_multiple(cursor: &mut Cursor<&Vec<u8>>) -> Result<Vec<Self>, Error> {
+        let mut masks = Vec::new();
+        while let Ok(i) = TINI::read(cursor) {
+            let index = i.try_into()?;
+            let texture = TINT::read(cursor).ok().map(TryInto::try_into).transpose()?;
+            let mask_type = TINP::read(cursor).ok().map(TryInto::try_into).transpose()?;
+            let default_preset = TIND::read(cursor).ok().map(TryInto::try_into).transpose()?;
+            let mut presets = Vec::new();
+            while let Ok(c) = TINC::read(cursor) {
+                presets.push(TintPreset {
+                    color: c.try_into()?,
+                    default_value: TINV::read(cursor).ok().map(TryInto::try_into).transpose()?,
+                    index: TIRS::read(cursor).ok().map(TryInto::try_into).transpose()?,
+                });
+            }
+            masks.push(Self {
+                index,
+                texture,
+                mask_type,
+                default_preset,
+                presets,
+            });
+        }
+        Ok(masks)
+    }
+
+    fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        TINI::try_from(self.index)?.write(cursor)?;
+        self.texture
+            .map(TINT::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.mask_type
+            .map(TINP::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.default_preset
+            .map(TIND::try_from)
+            .transpose()?
+            .write(cursor)?;
+        for p in self.presets {
+            TINC::try_from(p.color)?.write(cursor)?;
+            p.default_value
+                .map(TINV::try_from)
+                .transpose()?
+                .write(cursor)?;
+            p.index.map(TIRS::try_from).transpose()?.write(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+/// Head parts, FaceGen presets and tint masks for one sex
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadData {
+    pub head_parts: Vec<RaceHeadPart>,
+    pub morphs: Vec<MorphPreset>,
+    pub race_presets: Vec<FormID>,
+    pub hair_colors: Vec<FormID>,
+    pub face_details: Vec<FormID>,
+    pub default_face_texture: Option<FormID>,
+    pub tint_masks: Vec<TintMask>,
+    pub model: Option<Model>,
+}
+
+/// Male and female head data differ only in the subrecords used for their
+/// presets, hair colours and face textures
+macro_rules! impl_head_data {
+    ($load:ident, $write:ident, $presets:ident, $colors:ident, $details:ident, $default:ident) => {
+        impl HeadData {
+            fn $load(cursor: &mut Cursor<&Vec<u8>>, version: u16) -> Result<Self, Error> {
+                let mut head_parts = Vec::new();
+                while let Ok(i) = INDX::read(cursor) {
+                    head_parts.push(RaceHeadPart {
+                        index: i.try_into()?,
+                        head_part: HEAD::read(cursor).ok().map(TryInto::try_into).transpose()?,
+                    });
+                }
+                let mut morphs = Vec::new();
+                while let Ok(i) = MPAI::read(cursor) {
+                    morphs.push(MorphPreset {
+                        index: i.try_into()?,
+                        values: MPAV::read(cursor).ok().map(TryInto::try_into).transpose()?,
+                    });
+                }
+                let mut race_presets = Vec::new();
+                while let Ok(p) = $presets::read(cursor) {
+                    race_presets.push(p.try_into()?);
+                }
+                let mut hair_colors = Vec::new();
+                while let Ok(c) = $colors::read(cursor) {
+                    hair_colors.push(c.try_into()?);
+                }
+                let mut face_details = Vec::new();
+                while let Ok(d) = $details::read(cursor) {
+                    face_details.push(d.try_into()?);
+                }
+                let default_face_texture = $default::read(cursor)
+                    .ok()
+                    .map(TryInto::try_into)
+                    .transpose()?;
+                let tint_masks = TintMask::load_multiple(cursor)?;
+                let model = Model::try_load::<MODL, MODT, MODS>(cursor, version)?;
+
+                Ok(Self {
+                    head_parts,
+                    morphs,
+                    race_presets,
+                    hair_colors,
+                    face_details,
+                    default_face_texture,
+                    tint_masks,
+                    model,
+                })
+            }
+
+            fn $write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+                for h in self.head_parts {
+                    INDX::try_from(h.index)?.write(cursor)?;
+                    h.head_part.map(HEAD::try_from).transpose()?.write(cursor)?;
+                }
+                for m in self.morphs {
+                    MPAI::try_from(m.index)?.write(cursor)?;
+                    m.values.map(MPAV::try_from).transpose()?.write(cursor)?;
+                }
+                for p in self.race_presets {
+                    $presets::try_from(p)?.write(cursor)?;
+                }
+                for c in self.hair_colors {
+                    $colors::try_from(c)?.write(cursor)?;
+                }
+                for d in self.face_details {
+                    $details::try_from(d)?.write(cursor)?;
+                }
+                self.default_face_texture
+                    .map($default::try_from)
+                    .transpose()?
+                    .write(cursor)?;
+                for t in self.tint_masks {
+                    t.write(cursor)?;
+                }
+                if let Some(m) = self.model {
+                    m.write::<MODL, MODT, MODS>(cursor)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_head_data!(load_male, write_male, RPRM, AHCM, FTSM, DFTM);
+impl_head_data!(load_female, write_female, RPRF, AHCF, FTSF, DFTF);
+
+fn empty_mnam() -> MNAM {
+    MNAM {
+        size: 0,
+        data: Vec::new(),
+    }
+}
+
+fn empty_fnam() -> FNAM {
+    FNAM {
+        size: 0,
+        data: Vec::new(),
+    }
+}
+
+/// Skeleton models are stored as ANAM followed by the usual MODT
+fn load_skeleton(cursor: &mut Cursor<&Vec<u8>>, version: u16) -> Result<Option<Model>, Error> {
+    let model = ANAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+    let textures = MODT::try_read_textures(cursor, version)?;
+    Ok(model.map(|model| Model {
+        model,
+        textures,
+        alternate_textures: None,
+    }))
+}
+
+fn write_skeleton(skeleton: Option<Model>, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+    if let Some(m) = skeleton {
+        ANAM::try_from(m.model)?.write(cursor)?;
+        if let Some(t) = m.textures {
+            MODT::write_textures(t, cursor)?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Race {
     pub header: RecordHeader,
     pub edid: String,
     pub full_name: Option<LocalizedString>,
     pub description: LocalizedString,
+    pub spells: Vec<FormID>,
+    pub skin: Option<FormID>,
+    pub body_template: Option<BodyTemplate>,
+    pub body_template2: Option<BodyTemplate2>,
+    pub keywords: Vec<FormID>,
+    pub data: RaceData,
+    pub male_skeleton: Option<Model>,
+    pub female_skeleton: Option<Model>,
+    /// Whether the empty NAM2 marker before the movement type names is present
+    pub movement_marker: bool,
+    pub movement_type_names: Vec<String>,
+    /// (male, female)
+    pub voices: Option<(FormID, FormID)>,
+    /// (male, female)
+    pub decapitate_armors: Option<(FormID, FormID)>,
+    /// (male, female)
+    pub default_hair_colors: Option<(FormID, FormID)>,
+    pub tint_count: Option<u16>,
+    pub facegen_main_clamp: Option<f32>,
+    pub facegen_face_clamp: Option<f32>,
+    pub attack_race: Option<FormID>,
+    pub attacks: Vec<Attack>,
+    pub male_body_parts: Vec<BodyPart>,
+    pub female_body_parts: Vec<BodyPart>,
+    pub hairs: Vec<FormID>,
+    pub eyes: Vec<FormID>,
+    pub body_part_data: Option<FormID>,
+    /// Whether the empty NAM2 marker before the behavior graphs is present
+    pub behavior_marker: bool,
+    pub male_behavior_graph: Option<Model>,
+    pub female_behavior_graph: Option<Model>,
+    pub material_type: Option<FormID>,
+    pub impact_data_set: Option<FormID>,
+    pub decapitation_fx: Option<FormID>,
+    pub open_loot_sound: Option<FormID>,
+    pub close_loot_sound: Option<FormID>,
+    pub biped_object_names: Vec<String>,
+    pub movement_types: Vec<MovementType>,
+    pub equip_type_flags: Option<u32>,
+    pub equip_slots: Vec<FormID>,
+    pub unarmed_equip_slot: Option<FormID>,
+    pub phoneme_target_names: Vec<String>,
+    pub phoneme_weights: Vec<PhonemeWeights>,
+    pub walk_movement: Option<FormID>,
+    pub run_movement: Option<FormID>,
+    pub swim_movement: Option<FormID>,
+    pub fly_movement: Option<FormID>,
+    pub sneak_movement: Option<FormID>,
+    pub sprint_movement: Option<FormID>,
+    pub male_head_data: Option<HeadData>,
+    pub female_head_data: Option<HeadData>,
+    pub morph_race: Option<FormID>,
+    pub armor_race: Option<FormID>,
 }
 
 impl fmt::Display for Race {
@@ -43,6 +510,7 @@ impl TryFrom<RACE> for Race {
     fn try_from(raw: RACE) -> Result<Self, Self::Error> {
         let data = get_cursor(&raw.data, raw.header.flags.contains(Flags::COMPRESSED));
         let mut cursor = Cursor::new(&data);
+        let version = raw.header.internal_version;
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
         let full_name = match (FULL::read(&mut cursor), raw.localized) {
@@ -55,12 +523,462 @@ impl TryFrom<RACE> for Race {
         } else {
             LocalizedString::ZString(DESC::read(&mut cursor)?.try_into()?)
         };
+        let _: Option<u32> = SPCT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut spells = Vec::new();
+        while let Ok(s) = SPLO::read(&mut cursor) {
+            spells.push(s.try_into()?);
+        }
+        let skin = WNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let body_template = BODT::read(&mut cursor)
+            .ok()
+            .map(|bodt| BodyTemplate::load(bodt, version))
+            .transpose()?;
+        let body_template2 = BOD2::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let _: Option<u32> = KSIZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+        let data = DATA::read(&mut cursor)?.try_into()?;
+        MNAM::read(&mut cursor)?;
+        let male_skeleton = load_skeleton(&mut cursor, version)?;
+        FNAM::read(&mut cursor)?;
+        let female_skeleton = load_skeleton(&mut cursor, version)?;
+        let movement_marker = NAM2::read(&mut cursor).is_ok();
+        let mut movement_type_names = Vec::new();
+        while let Ok(m) = MTNM::read(&mut cursor) {
+            movement_type_names.push(m.try_into()?);
+        }
+        let voices = VTCK::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let decapitate_armors = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let default_hair_colors = HCLF::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let tint_count = TINL::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let facegen_main_clamp = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let facegen_face_clamp = UNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attack_race = ATKR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attacks = Attack::load_multiple(&mut cursor)?;
+        NAM1::read(&mut cursor)?;
+        MNAM::read(&mut cursor)?;
+        let male_body_parts = BodyPart::load_multiple(&mut cursor, version)?;
+        FNAM::read(&mut cursor)?;
+        let female_body_parts = BodyPart::load_multiple(&mut cursor, version)?;
+        let hairs = HNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let eyes = ENAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let body_part_data = GNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let behavior_marker = NAM2::read(&mut cursor).is_ok();
+        NAM3::read(&mut cursor)?;
+        MNAM::read(&mut cursor)?;
+        let male_behavior_graph = Model::try_load::<MODL, MODT, MODS>(&mut cursor, version)?;
+        FNAM::read(&mut cursor)?;
+        let female_behavior_graph = Model::try_load::<MODL, MODT, MODS>(&mut cursor, version)?;
+        let material_type = NAM4::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let impact_data_set = NAM5::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let decapitation_fx = NAM7::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let open_loot_sound = ONAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let close_loot_sound = LNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut biped_object_names = Vec::new();
+        while let Ok(n) = NAME::read(&mut cursor) {
+            biped_object_names.push(n.try_into()?);
+        }
+        let mut movement_types = Vec::new();
+        while let Ok(m) = MTYP::read(&mut cursor) {
+            movement_types.push(MovementType {
+                movement_type: m.try_into()?,
+                speeds: SPED::read(&mut cursor)
+                    .ok()
+                    .map(TryInto::try_into)
+                    .transpose()?,
+            });
+        }
+        let equip_type_flags = VNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut equip_slots = Vec::new();
+        while let Ok(q) = QNAM::read(&mut cursor) {
+            equip_slots.push(q.try_into()?);
+        }
+        let unarmed_equip_slot = UNES::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut phoneme_target_names = Vec::new();
+        while let Ok(p) = PHTN::read(&mut cursor) {
+            phoneme_target_names.push(p.try_into()?);
+        }
+        let mut phoneme_weights = Vec::new();
+        while let Ok(p) = PHWT::read(&mut cursor) {
+            phoneme_weights.push(p.try_into()?);
+        }
+        let walk_movement = WKMV::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let run_movement = RNMV::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let swim_movement = SWMV::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let fly_movement = FLMV::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let sneak_movement = SNMV::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let sprint_movement = SPMV::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let (male_head_data, female_head_data) = match NAM0::read(&mut cursor) {
+            Ok(_) => {
+                let male = match MNAM::read(&mut cursor) {
+                    Ok(_) => Some(HeadData::load_male(&mut cursor, version)?),
+                    Err(_) => None,
+                };
+                let female = match FNAM::read(&mut cursor) {
+                    Ok(_) => Some(HeadData::load_female(&mut cursor, version)?),
+                    Err(_) => None,
+                };
+                (male, female)
+            }
+            Err(_) => (None, None),
+        };
+        let morph_race = NAM8::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let armor_race = RNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
             full_name,
             description,
+            spells,
+            skin,
+            body_template,
+            body_template2,
+            keywords,
+            data,
+            male_skeleton,
+            female_skeleton,
+            movement_marker,
+            movement_type_names,
+            voices,
+            decapitate_armors,
+            default_hair_colors,
+            tint_count,
+            facegen_main_clamp,
+            facegen_face_clamp,
+            attack_race,
+            attacks,
+            male_body_parts,
+            female_body_parts,
+            hairs,
+            eyes,
+            body_part_data,
+            behavior_marker,
+            male_behavior_graph,
+            female_behavior_graph,
+            material_type,
+            impact_data_set,
+            decapitation_fx,
+            open_loot_sound,
+            close_loot_sound,
+            biped_object_names,
+            movement_types,
+            equip_type_flags,
+            equip_slots,
+            unarmed_equip_slot,
+            phoneme_target_names,
+            phoneme_weights,
+            walk_movement,
+            run_movement,
+            swim_movement,
+            fly_movement,
+            sneak_movement,
+            sprint_movement,
+            male_head_data,
+            female_head_data,
+            morph_race,
+            armor_race,
+        })
+    }
+}
+
+impl TryFrom<Race> for RACE {
+    type Error = Error;
+
+    fn try_from(obj: Race) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        DESC::try_from(obj.description)?.write(&mut data)?;
+        if !obj.spells.is_empty() {
+            SPCT::try_from(obj.spells.len() as u32)?.write(&mut data)?;
+        }
+        for s in obj.spells {
+            SPLO::try_from(s)?.write(&mut data)?;
+        }
+        obj.skin.map(WNAM::try_from).transpose()?.write(&mut data)?;
+        obj.body_template
+            .map(BODT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.body_template2
+            .map(BOD2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        DATA::try_from(obj.data)?.write(&mut data)?;
+        empty_mnam().write(&mut data)?;
+        write_skeleton(obj.male_skeleton, &mut data)?;
+        empty_fnam().write(&mut data)?;
+        write_skeleton(obj.female_skeleton, &mut data)?;
+        if obj.movement_marker {
+            NAM2::try_from(Vec::new())?.write(&mut data)?;
+        }
+        for m in obj.movement_type_names {
+            MTNM::try_from(m)?.write(&mut data)?;
+        }
+        obj.voices
+            .map(VTCK::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.decapitate_armors
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.default_hair_colors
+            .map(HCLF::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.tint_count
+            .map(TINL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.facegen_main_clamp
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.facegen_face_clamp
+            .map(UNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.attack_race
+            .map(ATKR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for a in obj.attacks {
+            a.write(&mut data)?;
+        }
+        NAM1::try_from(Vec::new())?.write(&mut data)?;
+        empty_mnam().write(&mut data)?;
+        for p in obj.male_body_parts {
+            p.write(&mut data)?;
+        }
+        empty_fnam().write(&mut data)?;
+        for p in obj.female_body_parts {
+            p.write(&mut data)?;
+        }
+        if !obj.hairs.is_empty() {
+            HNAM::try_from(obj.hairs)?.write(&mut data)?;
+        }
+        if !obj.eyes.is_empty() {
+            ENAM::try_from(obj.eyes)?.write(&mut data)?;
+        }
+        obj.body_part_data
+            .map(GNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if obj.behavior_marker {
+            NAM2::try_from(Vec::new())?.write(&mut data)?;
+        }
+        NAM3::try_from(Vec::new())?.write(&mut data)?;
+        empty_mnam().write(&mut data)?;
+        if let Some(m) = obj.male_behavior_graph {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        empty_fnam().write(&mut data)?;
+        if let Some(m) = obj.female_behavior_graph {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.material_type
+            .map(NAM4::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.impact_data_set
+            .map(NAM5::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.decapitation_fx
+            .map(NAM7::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.open_loot_sound
+            .map(ONAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.close_loot_sound
+            .map(LNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for n in obj.biped_object_names {
+            NAME::try_from(n)?.write(&mut data)?;
+        }
+        for m in obj.movement_types {
+            MTYP::try_from(m.movement_type)?.write(&mut data)?;
+            m.speeds.map(SPED::try_from).transpose()?.write(&mut data)?;
+        }
+        obj.equip_type_flags
+            .map(VNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for q in obj.equip_slots {
+            QNAM::try_from(q)?.write(&mut data)?;
+        }
+        obj.unarmed_equip_slot
+            .map(UNES::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for p in obj.phoneme_target_names {
+            PHTN::try_from(p)?.write(&mut data)?;
+        }
+        for p in obj.phoneme_weights {
+            PHWT::try_from(p)?.write(&mut data)?;
+        }
+        obj.walk_movement
+            .map(WKMV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.run_movement
+            .map(RNMV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.swim_movement
+            .map(SWMV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.fly_movement
+            .map(FLMV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.sneak_movement
+            .map(SNMV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.sprint_movement
+            .map(SPMV::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if obj.male_head_data.is_some() || obj.female_head_data.is_some() {
+            NAM0::try_from(Vec::<u8>::new())?.write(&mut data)?;
+            if let Some(h) = obj.male_head_data {
+                empty_mnam().write(&mut data)?;
+                h.write_male(&mut data)?;
+            }
+            if let Some(h) = obj.female_head_data {
+                empty_fnam().write(&mut data)?;
+                h.write_female(&mut data)?;
+            }
+        }
+        obj.morph_race
+            .map(NAM8::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.armor_race
+            .map(RNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,13 +1,22 @@
-use super::{get_cursor, Flags, RecordHeader};
-use crate::common::FormID;
+use super::{achr::Coords, get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::{ScriptList, EDID, NAME, VMAD};
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    ScriptList, DATA, EDID, FNAM, FULL, INAM, LNAM, NAME, ONAM, PDTO, TNAM, VMAD, XACT, XALP, XAPD,
+    XAPR, XATR, XCHG, XCNT, XCVL, XCVR, XCZA, XCZC, XCZR, XEMI, XESP, XEZN, XFVC, XHOR, XHTW, XIBS,
+    XIS2, XLCM, XLCN, XLIB, XLIG, XLKR, XLOC, XLRL, XLRM, XLRT, XLTW, XMBO, XMBR, XMRK, XNDP, XOCP,
+    XOWN, XPOD, XPPA, XPRD, XPRM, XPWR, XRDS, XRGB, XRGD, XRMR, XRNK, XSCL, XSPC, XTEL, XTNM, XTRI,
+    XWCN, XWCS, XWCU,
+};
+use binrw::{binrw, until_eof, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
-use std::io::Cursor;
+use std::io::{Cursor, Write};
 
+/// [REFR](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/REFR) record
 #[binrw]
+#[br(import(localized: bool))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"REFR")]
 pub struct REFR {
@@ -15,6 +24,230 @@ pub struct REFR {
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum XRec {
+    INAM(INAM),
+    LNAM(LNAM),
+    ONAM(ONAM),
+    PDTO(PDTO),
+    XACT(XACT),
+    XALP(XALP),
+    XAPD(XAPD),
+    XAPR(XAPR),
+    XATR(XATR),
+    XCHG(XCHG),
+    XCNT(XCNT),
+    XCVL(XCVL),
+    XCVR(XCVR),
+    XCZA(XCZA),
+    XCZC(XCZC),
+    XCZR(XCZR),
+    XEMI(XEMI),
+    XESP(XESP),
+    XEZN(XEZN),
+    XFVC(XFVC),
+    XHOR(XHOR),
+    XHTW(XHTW),
+    XIBS(XIBS),
+    XIS2(XIS2),
+    XLCM(XLCM),
+    XLCN(XLCN),
+    XLIB(XLIB),
+    XLIG(XLIG),
+    XLKR(XLKR),
+    XLOC(XLOC),
+    XLRL(XLRL),
+    XLRM(XLRM),
+    XLRT(XLRT),
+    XLTW(XLTW),
+    XMBO(XMBO),
+    XMBR(XMBR),
+    XMRK(XMRK),
+    XNDP(XNDP),
+    XOCP(XOCP),
+    XOWN(XOWN),
+    XPOD(XPOD),
+    XPPA(XPPA),
+    XPRD(XPRD),
+    XPRM(XPRM),
+    XPWR(XPWR),
+    XRDS(XRDS),
+    XRGB(XRGB),
+    XRGD(XRGD),
+    XRMR(XRMR),
+    XRNK(XRNK),
+    XSCL(XSCL),
+    XSPC(XSPC),
+    XTEL(XTEL),
+    XTNM(XTNM),
+    XTRI(XTRI),
+    XWCN(XWCN),
+    XWCS(XWCS),
+    XWCU(XWCU),
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct EnableParentFlags: u8 {
+        const SET_OPPOSITE_OF_PARENT = 0x01;
+        const POP_IN = 0x02;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnableParent {
+    pub parent: FormID,
+    pub flags: EnableParentFlags,
+    pub unused: [u8; 3],
+}
+
+impl_field_struct!(EnableParent, XESP);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedRef {
+    pub keyword: FormID,
+    pub reference: FormID,
+}
+
+impl_field_struct!(LinkedRef, XLKR);
+
+/// Destination of a load door
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Teleport {
+    pub door: FormID,
+    pub position: Coords,
+    pub flags: u32,
+}
+
+impl_field_struct!(Teleport, XTEL);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct LockFlags: u8 {
+        const LEVELED = 0x04;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockData {
+    pub level: u8,
+    pub unused1: [u8; 3],
+    pub key: FormID,
+    pub flags: LockFlags,
+    pub unused2: [u8; 3],
+    #[br(parse_with = until_eof)]
+    pub unknown: Vec<u8>,
+}
+
+impl_field_struct!(LockData, XLOC);
+
+/// Navmesh triangle a door is attached to
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DoorNavMesh {
+    pub navmesh: FormID,
+    pub triangle: u16,
+    pub unused: [u8; 2],
+}
+
+impl_field_struct!(DoorNavMesh, XNDP);
+
+/// Shape of a trigger, occlusion plane or other primitive
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Primitive {
+    pub bounds: [f32; 3],
+    pub color: [f32; 3],
+    pub unknown: f32,
+    pub kind: u32,
+}
+
+impl_field_struct!(Primitive, XPRM);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct MapMarkerFlags: u8 {
+        const VISIBLE = 0x01;
+        const CAN_TRAVEL_TO = 0x02;
+        const SHOW_ALL_HIDDEN = 0x04;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkerType {
+    pub kind: u8,
+    pub unused: u8,
+}
+
+impl_field_struct!(MarkerType, TNAM);
+
+/// Map marker (XMRK through TNAM)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapMarker {
+    pub flags: Option<MapMarkerFlags>,
+    pub name: Option<LocalizedString>,
+    pub kind: Option<MarkerType>,
+}
+
+impl MapMarker {
+    /// Reads the fields following an XMRK marker
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>, localized: bool) -> Result<Self, Error> {
+        let flags = FNAM::read(cursor)
+            .ok()
+            .map(|f| -> Result<MapMarkerFlags, Error> {
+                Ok(MapMarkerFlags::from_bits_truncate(f.try_into()?))
+            })
+            .transpose()?;
+        let name = match (FULL::read(cursor), localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let kind = TNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+
+        Ok(Self { flags, name, kind })
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        XMRK {
+            size: 0,
+            data: Vec::new(),
+        }
+        .write(cursor)?;
+        self.flags
+            .map(|f| FNAM::try_from(f.bits()))
+            .transpose()?
+            .write(cursor)?;
+        self.name.map(FULL::try_from).transpose()?.write(cursor)?;
+        self.kind.map(TNAM::try_from).transpose()?.write(cursor)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +256,68 @@ pub struct ObjectRef {
     pub edid: Option<String>,
     pub scripts: Option<ScriptList>,
     pub name: FormID,
+    pub bound_half_extents: Option<[f32; 3]>,
+    pub primitive: Option<Primitive>,
+    pub unknown1: Option<XPOD>,
+    pub room_portal: Option<XRMR>,
+    pub lighting_template: Option<FormID>,
+    pub linked_rooms: Vec<FormID>,
+    pub multibound: Option<FormID>,
+    pub ragdoll: Option<XRGD>,
+    pub unknown2: Option<XRGB>,
+    pub radius: Option<f32>,
+    pub emittance: Option<FormID>,
+    pub light_data: Option<XLIG>,
+    pub alpha: Option<XALP>,
+    pub teleport: Option<Teleport>,
+    pub teleport_message: Option<FormID>,
+    pub water_current_count: Option<u32>,
+    pub water_current_velocity: Option<u32>,
+    pub water_currents: Option<XWCU>,
+    pub unknown3: Option<XCVL>,
+    pub unknown4: Option<XCZR>,
+    pub unknown5: Option<XCZA>,
+    pub unknown6: Option<XCZC>,
+    pub unknown7: Option<XCVR>,
+    pub occlusion_planes: Option<XOCP>,
+    pub scale: Option<f32>,
+    pub spawn_container: Option<FormID>,
+    pub activation_parent_flags: Option<u8>,
+    pub activate_parent: Vec<XAPR>,
+    pub leveled_item_base: Option<FormID>,
+    pub leveled_creature_data: Option<u32>,
+    pub location: Option<FormID>,
+    pub collision_layer: Option<u32>,
+    pub lock: Option<LockData>,
+    pub encounter_zone: Option<FormID>,
+    pub door_navmesh: Option<DoorNavMesh>,
+    pub location_ref_type: Vec<FormID>,
+    pub ignored_by_sandbox: bool,
+    pub xis2: Option<XIS2>,
+    pub linked_refs: Vec<LinkedRef>,
+    pub xlrl: Option<FormID>,
+    pub count: Option<i32>,
+    pub owner: Option<FormID>,
+    pub owner_rank: Option<i32>,
+    pub charge: Option<f32>,
+    pub head_tracking_weight: Option<f32>,
+    pub favor_cost: Option<f32>,
+    pub power_grid: Vec<XPWR>,
+    pub lit_water: Vec<FormID>,
+    pub action_flags: Option<u32>,
+    pub attach_ref: Option<FormID>,
+    pub map_marker: Option<MapMarker>,
+    pub patrol_data: Option<()>,
+    pub patrol_idle: Option<f32>,
+    pub patrol_idle_object: Option<FormID>,
+    pub topic_data: Vec<PDTO>,
+    pub horse_id: Option<FormID>,
+    pub enable_parent: Option<EnableParent>,
+    pub open_by_default: bool,
+    pub coords: Option<Coords>,
+    /// Magics of the subrecords between NAME and DATA in the order they
+    /// were read, so that they are written back the same way
+    pub subrecord_order: Vec<[u8; 4]>,
 }
 
 impl fmt::Display for ObjectRef {
@@ -35,6 +330,27 @@ impl fmt::Display for ObjectRef {
     }
 }
 
+/// Stores `value` in `slot`, failing if it was already filled by an earlier
+/// subrecord
+fn set_once<T: fmt::Debug>(slot: &mut Option<T>, value: T) -> Result<(), Error> {
+    match slot {
+        Some(x) => Err(Error::DuplicateField(format!("{:?}", x))),
+        None => {
+            *slot = Some(value);
+            Ok(())
+        }
+    }
+}
+
+/// Records that a subrecord ending at the cursor's position was just
+/// written, unless nothing was written since the last mark
+fn mark(ends: &mut Vec<([u8; 4], usize)>, magic: &[u8; 4], cursor: &Cursor<Vec<u8>>) {
+    let end = cursor.position() as usize;
+    if ends.last().map_or(0, |(_, e)| *e) < end {
+        ends.push((*magic, end));
+    }
+}
+
 impl TryFrom<REFR> for ObjectRef {
     type Error = Error;
 
@@ -52,11 +368,518 @@ impl TryFrom<REFR> for ObjectRef {
             .transpose()?;
         let name = NAME::read(&mut cursor)?.try_into()?;
 
+        let mut bound_half_extents = None;
+        let mut primitive = None;
+        let mut unknown1 = None;
+        let mut room_portal = None;
+        let mut lighting_template = None;
+        let mut linked_rooms = Vec::new();
+        let mut multibound = None;
+        let mut ragdoll = None;
+        let mut unknown2 = None;
+        let mut radius = None;
+        let mut emittance = None;
+        let mut light_data = None;
+        let mut alpha = None;
+        let mut teleport = None;
+        let mut teleport_message = None;
+        let mut water_current_count = None;
+        let mut water_current_velocity = None;
+        let mut water_currents = None;
+        let mut unknown3 = None;
+        let mut unknown4 = None;
+        let mut unknown5 = None;
+        let mut unknown6 = None;
+        let mut unknown7 = None;
+        let mut occlusion_planes = None;
+        let mut scale = None;
+        let mut spawn_container = None;
+        let mut activation_parent_flags = None;
+        let mut activate_parent = Vec::new();
+        let mut leveled_item_base = None;
+        let mut leveled_creature_data = None;
+        let mut location = None;
+        let mut collision_layer = None;
+        let mut lock = None;
+        let mut encounter_zone = None;
+        let mut door_navmesh = None;
+        let mut location_ref_type = Vec::new();
+        let mut ignored_by_sandbox = false;
+        let mut xis2 = None;
+        let mut linked_refs = Vec::new();
+        let mut xlrl = None;
+        let mut count = None;
+        let mut owner = None;
+        let mut owner_rank = None;
+        let mut charge = None;
+        let mut head_tracking_weight = None;
+        let mut favor_cost = None;
+        let mut power_grid = Vec::new();
+        let mut lit_water = Vec::new();
+        let mut action_flags = None;
+        let mut attach_ref = None;
+        let mut map_marker = None;
+        let mut patrol_data = None;
+        let mut patrol_idle = None;
+        let mut patrol_idle_object = None;
+        let mut topic_data = Vec::new();
+        let mut horse_id = None;
+        let mut enable_parent = None;
+        let mut open_by_default = false;
+        let mut subrecord_order = Vec::new();
+
+        // As with ACHR, there is no reliable order for these, so parse them
+        // as they come.
+        let mut start = cursor.position() as usize;
+        while let Ok(rec) = XRec::read(&mut cursor) {
+            let mut magic = [0; 4];
+            magic.copy_from_slice(&data[start..start + 4]);
+            subrecord_order.push(magic);
+            match rec {
+                XRec::INAM(r) => set_once(&mut patrol_idle_object, r.try_into()?)?,
+                XRec::LNAM(r) => set_once(&mut lighting_template, r.try_into()?)?,
+                XRec::ONAM(_) => open_by_default = true,
+                XRec::PDTO(r) => topic_data.push(r),
+                XRec::XACT(r) => set_once(&mut action_flags, r.try_into()?)?,
+                XRec::XALP(r) => set_once(&mut alpha, r)?,
+                XRec::XAPD(r) => set_once(&mut activation_parent_flags, r.try_into()?)?,
+                XRec::XAPR(r) => activate_parent.push(r),
+                XRec::XATR(r) => set_once(&mut attach_ref, r.try_into()?)?,
+                XRec::XCHG(r) => set_once(&mut charge, r.try_into()?)?,
+                XRec::XCNT(r) => set_once(&mut count, r.try_into()?)?,
+                XRec::XCVL(r) => set_once(&mut unknown3, r)?,
+                XRec::XCVR(r) => set_once(&mut unknown7, r)?,
+                XRec::XCZA(r) => set_once(&mut unknown5, r)?,
+                XRec::XCZC(r) => set_once(&mut unknown6, r)?,
+                XRec::XCZR(r) => set_once(&mut unknown4, r)?,
+                XRec::XEMI(r) => set_once(&mut emittance, r.try_into()?)?,
+                XRec::XESP(r) => set_once(&mut enable_parent, r.try_into()?)?,
+                XRec::XEZN(r) => set_once(&mut encounter_zone, r.try_into()?)?,
+                XRec::XFVC(r) => set_once(&mut favor_cost, r.try_into()?)?,
+                XRec::XHOR(r) => set_once(&mut horse_id, r.try_into()?)?,
+                XRec::XHTW(r) => set_once(&mut head_tracking_weight, r.try_into()?)?,
+                XRec::XIBS(_) => ignored_by_sandbox = true,
+                XRec::XIS2(r) => set_once(&mut xis2, r)?,
+                XRec::XLCM(r) => set_once(&mut leveled_creature_data, r.try_into()?)?,
+                XRec::XLCN(r) => set_once(&mut location, r.try_into()?)?,
+                XRec::XLIB(r) => set_once(&mut leveled_item_base, r.try_into()?)?,
+                XRec::XLIG(r) => set_once(&mut light_data, r)?,
+                XRec::XLKR(r) => linked_refs.push(r.try_into()?),
+                XRec::XLOC(r) => set_once(&mut lock, r.try_into()?)?,
+                XRec::XLRL(r) => set_once(&mut xlrl, r.try_into()?)?,
+                XRec::XLRM(r) => linked_rooms.push(r.try_into()?),
+                XRec::XLRT(r) => location_ref_type.push(r.try_into()?),
+                XRec::XLTW(r) => lit_water.push(r.try_into()?),
+                XRec::XMBO(r) => set_once(&mut bound_half_extents, r.try_into()?)?,
+                XRec::XMBR(r) => set_once(&mut multibound, r.try_into()?)?,
+                XRec::XMRK(_) => set_once(
+                    &mut map_marker,
+                    MapMarker::load(&mut cursor, raw.localized)?,
+                )?,
+                XRec::XNDP(r) => set_once(&mut door_navmesh, r.try_into()?)?,
+                XRec::XOCP(r) => set_once(&mut occlusion_planes, r)?,
+                XRec::XOWN(r) => set_once(&mut owner, r.try_into()?)?,
+                XRec::XPOD(r) => set_once(&mut unknown1, r)?,
+                XRec::XPPA(r) => set_once(&mut patrol_data, r.try_into()?)?,
+                XRec::XPRD(r) => set_once(&mut patrol_idle, r.try_into()?)?,
+                XRec::XPRM(r) => set_once(&mut primitive, r.try_into()?)?,
+                XRec::XPWR(r) => power_grid.push(r),
+                XRec::XRDS(r) => set_once(&mut radius, r.try_into()?)?,
+                XRec::XRGB(r) => set_once(&mut unknown2, r)?,
+                XRec::XRGD(r) => set_once(&mut ragdoll, r)?,
+                XRec::XRMR(r) => set_once(&mut room_portal, r)?,
+                XRec::XRNK(r) => set_once(&mut owner_rank, r.try_into()?)?,
+                XRec::XSCL(r) => set_once(&mut scale, r.try_into()?)?,
+                XRec::XSPC(r) => set_once(&mut spawn_container, r.try_into()?)?,
+                XRec::XTEL(r) => set_once(&mut teleport, r.try_into()?)?,
+                XRec::XTNM(r) => set_once(&mut teleport_message, r.try_into()?)?,
+                XRec::XTRI(r) => set_once(&mut collision_layer, r.try_into()?)?,
+                XRec::XWCN(r) => set_once(&mut water_current_count, r.try_into()?)?,
+                XRec::XWCS(r) => set_once(&mut water_current_velocity, r.try_into()?)?,
+                XRec::XWCU(r) => set_once(&mut water_currents, r)?,
+            }
+            start = cursor.position() as usize;
+        }
+
+        let coords = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
+
         Ok(Self {
             header: raw.header,
             edid,
             scripts,
             name,
+            bound_half_extents,
+            primitive,
+            unknown1,
+            room_portal,
+            lighting_template,
+            linked_rooms,
+            multibound,
+            ragdoll,
+            unknown2,
+            radius,
+            emittance,
+            light_data,
+            alpha,
+            teleport,
+            teleport_message,
+            water_current_count,
+            water_current_velocity,
+            water_currents,
+            unknown3,
+            unknown4,
+            unknown5,
+            unknown6,
+            unknown7,
+            occlusion_planes,
+            scale,
+            spawn_container,
+            activation_parent_flags,
+            activate_parent,
+            leveled_item_base,
+            leveled_creature_data,
+            location,
+            collision_layer,
+            lock,
+            encounter_zone,
+            door_navmesh,
+            location_ref_type,
+            ignored_by_sandbox,
+            xis2,
+            linked_refs,
+            xlrl,
+            count,
+            owner,
+            owner_rank,
+            charge,
+            head_tracking_weight,
+            favor_cost,
+            power_grid,
+            lit_water,
+            action_flags,
+            attach_ref,
+            map_marker,
+            patrol_data,
+            patrol_idle,
+            patrol_idle_object,
+            topic_data,
+            horse_id,
+            enable_parent,
+            open_by_default,
+            coords,
+            subrecord_order,
+        })
+    }
+}
+
+impl TryFrom<ObjectRef> for REFR {
+    type Error = Error;
+
+    fn try_from(obj: ObjectRef) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        NAME::try_from(obj.name)?.write(&mut data)?;
+
+        // Write each subrecord in the order the Creation Kit emits them,
+        // noting where it ends, then put them back in the order they were
+        // read.
+        let mut fields = Cursor::new(Vec::new());
+        let mut ends = Vec::new();
+        obj.bound_half_extents
+            .map(XMBO::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XMBO", &fields);
+        obj.primitive
+            .map(XPRM::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XPRM", &fields);
+        obj.unknown1.write(&mut fields)?;
+        mark(&mut ends, b"XPOD", &fields);
+        obj.room_portal.write(&mut fields)?;
+        mark(&mut ends, b"XRMR", &fields);
+        obj.lighting_template
+            .map(LNAM::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"LNAM", &fields);
+        for r in obj.linked_rooms {
+            XLRM::try_from(r)?.write(&mut fields)?;
+            mark(&mut ends, b"XLRM", &fields);
+        }
+        obj.multibound
+            .map(XMBR::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XMBR", &fields);
+        obj.ragdoll.write(&mut fields)?;
+        mark(&mut ends, b"XRGD", &fields);
+        obj.unknown2.write(&mut fields)?;
+        mark(&mut ends, b"XRGB", &fields);
+        obj.radius
+            .map(XRDS::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XRDS", &fields);
+        obj.emittance
+            .map(XEMI::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XEMI", &fields);
+        obj.light_data.write(&mut fields)?;
+        mark(&mut ends, b"XLIG", &fields);
+        obj.alpha.write(&mut fields)?;
+        mark(&mut ends, b"XALP", &fields);
+        obj.teleport
+            .map(XTEL::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XTEL", &fields);
+        obj.teleport_message
+            .map(XTNM::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XTNM", &fields);
+        obj.water_current_count
+            .map(XWCN::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XWCN", &fields);
+        obj.water_current_velocity
+            .map(XWCS::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XWCS", &fields);
+        obj.water_currents.write(&mut fields)?;
+        mark(&mut ends, b"XWCU", &fields);
+        obj.unknown3.write(&mut fields)?;
+        mark(&mut ends, b"XCVL", &fields);
+        obj.unknown4.write(&mut fields)?;
+        mark(&mut ends, b"XCZR", &fields);
+        obj.unknown5.write(&mut fields)?;
+        mark(&mut ends, b"XCZA", &fields);
+        obj.unknown6.write(&mut fields)?;
+        mark(&mut ends, b"XCZC", &fields);
+        obj.unknown7.write(&mut fields)?;
+        mark(&mut ends, b"XCVR", &fields);
+        obj.occlusion_planes.write(&mut fields)?;
+        mark(&mut ends, b"XOCP", &fields);
+        obj.scale
+            .map(XSCL::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XSCL", &fields);
+        obj.spawn_container
+            .map(XSPC::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XSPC", &fields);
+        obj.activation_parent_flags
+            .map(XAPD::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XAPD", &fields);
+        for r in obj.activate_parent {
+            r.write(&mut fields)?;
+            mark(&mut ends, b"XAPR", &fields);
+        }
+        obj.leveled_item_base
+            .map(XLIB::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XLIB", &fields);
+        obj.leveled_creature_data
+            .map(XLCM::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XLCM", &fields);
+        obj.location
+            .map(XLCN::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XLCN", &fields);
+        obj.collision_layer
+            .map(XTRI::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XTRI", &fields);
+        obj.lock
+            .map(XLOC::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XLOC", &fields);
+        obj.encounter_zone
+            .map(XEZN::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XEZN", &fields);
+        obj.door_navmesh
+            .map(XNDP::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XNDP", &fields);
+        for r in obj.location_ref_type {
+            XLRT::try_from(r)?.write(&mut fields)?;
+            mark(&mut ends, b"XLRT", &fields);
+        }
+        if obj.ignored_by_sandbox {
+            XIBS {
+                size: 0,
+                data: Vec::new(),
+            }
+            .write(&mut fields)?;
+            mark(&mut ends, b"XIBS", &fields);
+        }
+        obj.xis2.write(&mut fields)?;
+        mark(&mut ends, b"XIS2", &fields);
+        for r in obj.linked_refs {
+            XLKR::try_from(r)?.write(&mut fields)?;
+            mark(&mut ends, b"XLKR", &fields);
+        }
+        obj.xlrl
+            .map(XLRL::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XLRL", &fields);
+        obj.count
+            .map(XCNT::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XCNT", &fields);
+        obj.owner
+            .map(XOWN::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XOWN", &fields);
+        obj.owner_rank
+            .map(XRNK::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XRNK", &fields);
+        obj.charge
+            .map(XCHG::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XCHG", &fields);
+        obj.head_tracking_weight
+            .map(XHTW::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XHTW", &fields);
+        obj.favor_cost
+            .map(XFVC::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XFVC", &fields);
+        for r in obj.power_grid {
+            r.write(&mut fields)?;
+            mark(&mut ends, b"XPWR", &fields);
+        }
+        for r in obj.lit_water {
+            XLTW::try_from(r)?.write(&mut fields)?;
+            mark(&mut ends, b"XLTW", &fields);
+        }
+        obj.action_flags
+            .map(XACT::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XACT", &fields);
+        obj.attach_ref
+            .map(XATR::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XATR", &fields);
+        if let Some(m) = obj.map_marker {
+            m.write(&mut fields)?;
+            mark(&mut ends, b"XMRK", &fields);
+        }
+        obj.patrol_data
+            .map(XPPA::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XPPA", &fields);
+        obj.patrol_idle
+            .map(XPRD::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XPRD", &fields);
+        obj.patrol_idle_object
+            .map(INAM::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"INAM", &fields);
+        for r in obj.topic_data {
+            r.write(&mut fields)?;
+            mark(&mut ends, b"PDTO", &fields);
+        }
+        obj.horse_id
+            .map(XHOR::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XHOR", &fields);
+        obj.enable_parent
+            .map(XESP::try_from)
+            .transpose()?
+            .write(&mut fields)?;
+        mark(&mut ends, b"XESP", &fields);
+        if obj.open_by_default {
+            ONAM {
+                size: 0,
+                data: Vec::new(),
+            }
+            .write(&mut fields)?;
+            mark(&mut ends, b"ONAM", &fields);
+        }
+
+        let fields = fields.into_inner();
+        let mut start = 0;
+        let mut chunks: Vec<_> = ends
+            .into_iter()
+            .map(|(magic, end)| {
+                let chunk = (magic, &fields[start..end]);
+                start = end;
+                Some(chunk)
+            })
+            .collect();
+        for magic in obj.subrecord_order {
+            let chunk = chunks
+                .iter_mut()
+                .find(|c| matches!(c, Some((m, _)) if *m == magic));
+            if let Some((_, bytes)) = chunk.and_then(Option::take) {
+                data.write_all(bytes)?;
+            }
+        }
+        for (_, bytes) in chunks.into_iter().flatten() {
+            data.write_all(bytes)?;
+        }
+
+        obj.coords
+            .map(DATA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,25 +1,275 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    EDID, ICON, RCLR, RDAT, RDGS, RDID, RDMO, RDMP, RDOT, RDSA, RDWT, RPLD, RPLI, WNAM,
+};
+use binrw::{binrw, until_eof, BinRead, BinWrite, Endian};
+use bitflags::bitflags;
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"REGN")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct REGN {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl_field_vec!(RegionPoint, RPLD);
+
+/// A polygon of the worldspace covered by the region
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionArea {
+    pub edge_falloff: Option<u32>,
+    pub points: Vec<RegionPoint>,
+}
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegionDataType {
+    Unknown0 = 0,
+    Unknown1,
+    Objects,
+    Weather,
+    Map,
+    Land,
+    Grass,
+    Sound,
+    Imposter,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct RegionDataFlags: u8 {
+        const OVERRIDE = 0x01;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionDataHeader {
+    pub kind: RegionDataType,
+    pub flags: RegionDataFlags,
+    pub priority: u8,
+    pub unknown: [u8; 2],
+}
+
+impl_field_struct!(RegionDataHeader, RDAT);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct RegionObjectFlags: u8 {
+        const CONFORM_TO_SLOPE = 0x01;
+        const PAINT_VERTICES = 0x02;
+        const SIZE_VARIANCE = 0x04;
+        const X_VARIANCE = 0x08;
+        const Y_VARIANCE = 0x10;
+        const Z_VARIANCE = 0x20;
+        const TREE = 0x40;
+        const HUGE_ROCK = 0x80;
+    }
+}
+
+/// An object scattered over the region (RDOT)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionObject {
+    pub object: FormID,
+    /// 0xFFFF if the object has no parent
+    pub parent_index: u16,
+    pub unknown1: [u8; 2],
+    pub density: f32,
+    pub clustering: u8,
+    pub min_slope: u8,
+    pub max_slope: u8,
+    pub flags: RegionObjectFlags,
+    pub radius_wrt_parent: u16,
+    pub radius: u16,
+    pub min_height: f32,
+    pub max_height: f32,
+    pub sink: f32,
+    pub sink_variance: f32,
+    pub size_variance: f32,
+    pub angle_variance: [u16; 3],
+    pub unknown2: [u8; 2],
+    pub unknown3: [u8; 4],
+}
+
+impl_field_vec!(RegionObject, RDOT);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionGrass {
+    pub grass: FormID,
+    pub unknown: [u8; 4],
+}
+
+impl_field_vec!(RegionGrass, RDGS);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct RegionSoundFlags: u32 {
+        const PLEASANT = 0x00000001;
+        const CLOUDY = 0x00000002;
+        const RAINY = 0x00000004;
+        const SNOWY = 0x00000008;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionSound {
+    pub sound: FormID,
+    pub flags: RegionSoundFlags,
+    pub chance: f32,
+}
+
+impl_field_vec!(RegionSound, RDSA);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionWeather {
+    pub weather: FormID,
+    pub chance: u32,
+    pub global: FormID,
+}
+
+impl_field_vec!(RegionWeather, RDWT);
+impl_field_vec!(FormID, RDID);
+
+/// A region data entry. Which of the lists are filled depends on
+/// [RegionDataHeader::kind].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionData {
+    pub header: RegionDataHeader,
+    pub icon: Option<String>,
+    pub objects: Vec<RegionObject>,
+    pub map_name: Option<LocalizedString>,
+    pub grasses: Vec<RegionGrass>,
+    pub music: Option<FormID>,
+    pub sounds: Vec<RegionSound>,
+    pub weathers: Vec<RegionWeather>,
+    pub imposters: Vec<FormID>,
+}
+
+impl RegionData {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>, localized: bool) -> Result<Option<Self>, Error> {
+        let header = match RDAT::read(cursor) {
+            Ok(r) => r.try_into()?,
+            Err(_) => return Ok(None),
+        };
+        let icon = ICON::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let objects = RDOT::read(cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let map_name = match (RDMP::read(cursor), localized) {
+            (Ok(m), true) => Some(LocalizedString::Localized(m.try_into()?)),
+            (Ok(m), false) => Some(LocalizedString::ZString(m.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let grasses = RDGS::read(cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let music = RDMO::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let sounds = RDSA::read(cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let weathers = RDWT::read(cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let imposters = RDID::read(cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Some(Self {
+            header,
+            icon,
+            objects,
+            map_name,
+            grasses,
+            music,
+            sounds,
+            weathers,
+            imposters,
+        }))
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        RDAT::try_from(self.header)?.write(cursor)?;
+        self.icon.map(ICON::try_from).transpose()?.write(cursor)?;
+        if !self.objects.is_empty() {
+            RDOT::try_from(self.objects)?.write(cursor)?;
+        }
+        self.map_name
+            .map(RDMP::try_from)
+            .transpose()?
+            .write(cursor)?;
+        if !self.grasses.is_empty() {
+            RDGS::try_from(self.grasses)?.write(cursor)?;
+        }
+        self.music.map(RDMO::try_from).transpose()?.write(cursor)?;
+        if !self.sounds.is_empty() {
+            RDSA::try_from(self.sounds)?.write(cursor)?;
+        }
+        if !self.weathers.is_empty() {
+            RDWT::try_from(self.weathers)?.write(cursor)?;
+        }
+        if !self.imposters.is_empty() {
+            RDID::try_from(self.imposters)?.write(cursor)?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Region {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub map_color: Option<RGBA8>,
+    pub worldspace: Option<FormID>,
+    pub areas: Vec<RegionArea>,
+    pub data: Vec<RegionData>,
 }
 
 impl fmt::Display for Region {
@@ -39,10 +289,87 @@ impl TryFrom<REGN> for Region {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let map_color = RCLR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let worldspace = WNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut areas = Vec::new();
+        loop {
+            let edge_falloff = RPLI::read(&mut cursor)
+                .ok()
+                .map(TryInto::try_into)
+                .transpose()?;
+            let points = match RPLD::read(&mut cursor) {
+                Ok(p) => p.try_into()?,
+                Err(_) if edge_falloff.is_some() => Vec::new(),
+                Err(_) => break,
+            };
+            areas.push(RegionArea {
+                edge_falloff,
+                points,
+            });
+        }
+        let mut region_data = Vec::new();
+        while let Some(d) = RegionData::load(&mut cursor, raw.localized)? {
+            region_data.push(d);
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            map_color,
+            worldspace,
+            areas,
+            data: region_data,
+        })
+    }
+}
+
+impl TryFrom<Region> for REGN {
+    type Error = Error;
+
+    fn try_from(obj: Region) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.map_color
+            .map(RCLR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.worldspace
+            .map(WNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for a in obj.areas {
+            a.edge_falloff
+                .map(RPLI::try_from)
+                .transpose()?
+                .write(&mut data)?;
+            if !a.points.is_empty() {
+                RPLD::try_from(a.points)?.write(&mut data)?;
+            }
+        }
+        for d in obj.data {
+            d.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
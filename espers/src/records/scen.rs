@@ -1,7 +1,12 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    EffectCondition, ScriptList, ALID, ANAM, DATA, DEMO, DEVA, DMAX, DMIN, DNAM, EDID, ENAM, FNAM,
+    HNAM, HTID, INAM, LNAM, NAM0, NEXT, PNAM, SNAM, VMAD, VNAM, WNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +21,309 @@ pub struct SCEN {
     pub data: Vec<u8>,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct SceneFlags: u32 {
+        const BEGIN_ON_QUEST_START = 0x00000001;
+        const STOP_ON_QUEST_END = 0x00000002;
+        const REPEAT_CONDITIONS_WHILE_TRUE = 0x00000008;
+        const INTERRUPTIBLE = 0x00000010;
+    }
+}
+
+impl_field_struct!(SceneFlags, FNAM);
+
+/// A phase of a scene, bracketed by HNAM markers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenePhase {
+    pub name: Option<String>,
+    pub start_conditions: Vec<EffectCondition>,
+    pub completion_conditions: Vec<EffectCondition>,
+    pub editor_width: Option<u32>,
+    /// Whether a NEXT followed the completion conditions
+    pub completion_next: bool,
+}
+
+impl ScenePhase {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        HNAM::read(cursor)?;
+        let name = NAM0::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let start_conditions = EffectCondition::load_multiple(cursor)?;
+        NEXT::read(cursor)?;
+        let completion_conditions = EffectCondition::load_multiple(cursor)?;
+        let completion_next = NEXT::read(cursor).is_ok();
+        let editor_width = WNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        HNAM::read(cursor)?;
+
+        Ok(Self {
+            name,
+            start_conditions,
+            completion_conditions,
+            editor_width,
+            completion_next,
+        })
+    }
+
+    pub fn load_multiple(cursor: &mut Cursor<&Vec<u8>>) -> Result<Vec<Self>, Error> {
+        let mut phases = Vec::new();
+        while let Ok(p) = Self::load(cursor) {
+            phases.push(p);
+        }
+        Ok(phases)
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        empty_hnam().write(cursor)?;
+        self.name.map(NAM0::try_from).transpose()?.write(cursor)?;
+        for c in self.start_conditions {
+            c.write(cursor)?;
+        }
+        empty_next().write(cursor)?;
+        for c in self.completion_conditions {
+            c.write(cursor)?;
+        }
+        if self.completion_next {
+            empty_next().write(cursor)?;
+        }
+        self.editor_width
+            .map(WNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        empty_hnam().write(cursor)?;
+        Ok(())
+    }
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct SceneActorFlags: u32 {
+        const NO_PLAYER_ACTIVATION = 0x00000001;
+        const OPTIONAL = 0x00000002;
+    }
+}
+
+impl_field_struct!(SceneActorFlags, LNAM);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct SceneActorBehaviour: u32 {
+        const DEATH_PAUSE = 0x00000001;
+        const DEATH_END = 0x00000002;
+        const COMBAT_PAUSE = 0x00000004;
+        const COMBAT_END = 0x00000008;
+        const DIALOGUE_PAUSE = 0x00000010;
+        const DIALOGUE_END = 0x00000020;
+        const OBSERVE_COMBAT_PAUSE = 0x00000040;
+        const OBSERVE_COMBAT_END = 0x00000080;
+    }
+}
+
+impl_field_struct!(SceneActorBehaviour, DNAM);
+
+/// A quest alias taking part in a scene
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneActor {
+    pub alias: i32,
+    pub flags: Option<SceneActorFlags>,
+    pub behaviour: Option<SceneActorBehaviour>,
+}
+
+#[binrw]
+#[brw(little, repr = u16)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SceneActionType {
+    Dialogue = 0,
+    Package,
+    Timer,
+}
+
+impl_field_struct!(SceneActionType, ANAM);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct SceneActionFlags: u32 {
+        const FACE_TARGET = 0x00008000;
+        const LOOPING = 0x00010000;
+        const HEADTRACK_PLAYER = 0x00020000;
+    }
+}
+
+impl_field_struct!(SceneActionFlags, FNAM);
+
+/// Something an actor does during a range of phases, terminated by an
+/// empty ANAM
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneAction {
+    pub kind: SceneActionType,
+    pub name: Option<String>,
+    pub actor: Option<i32>,
+    pub unknown: Option<Vec<u8>>,
+    pub index: Option<u32>,
+    pub flags: Option<SceneActionFlags>,
+    pub start_phase: Option<u32>,
+    pub end_phase: Option<u32>,
+    pub timer: Option<f32>,
+    pub packages: Vec<FormID>,
+    pub topic: Option<FormID>,
+    pub headtrack_actor: Option<i32>,
+    pub looping_max: Option<f32>,
+    pub looping_min: Option<f32>,
+    pub emotion_type: Option<u32>,
+    pub emotion_value: Option<u32>,
+}
+
+impl SceneAction {
+    pub fn load(cursor: &mut Cursor<&Vec<u8>>) -> Result<Self, Error> {
+        let kind: SceneActionType = ANAM::read(cursor)?.try_into()?;
+        let name = NAM0::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let actor = ALID::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let unknown = LNAM::read(cursor).ok().map(|l| l.data);
+        let index = INAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let flags = FNAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let first_snam = SNAM::read(cursor).ok();
+        let end_phase: Option<u32> = ENAM::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        // A timer's SNAM after the phases holds its length in seconds. If a
+        // timer has a lone SNAM and no phases, that SNAM is the timer.
+        let (start_phase, timer) = match kind {
+            SceneActionType::Timer => match (first_snam, SNAM::read(cursor).ok()) {
+                (Some(t), None) if end_phase.is_none() => (None, Some(t)),
+                (s, t) => (s, t),
+            },
+            _ => (first_snam, None),
+        };
+        let start_phase = start_phase.map(TryInto::try_into).transpose()?;
+        let timer = timer.map(TryInto::try_into).transpose()?;
+        let mut packages = Vec::new();
+        while let Ok(p) = PNAM::read(cursor) {
+            packages.push(p.try_into()?);
+        }
+        let topic = DATA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let headtrack_actor = HTID::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let looping_max = DMAX::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let looping_min = DMIN::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let emotion_type = DEMO::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        let emotion_value = DEVA::read(cursor).ok().map(TryInto::try_into).transpose()?;
+        ANAM::read(cursor)?;
+
+        Ok(Self {
+            kind,
+            name,
+            actor,
+            unknown,
+            index,
+            flags,
+            start_phase,
+            end_phase,
+            timer,
+            packages,
+            topic,
+            headtrack_actor,
+            looping_max,
+            looping_min,
+            emotion_type,
+            emotion_value,
+        })
+    }
+
+    pub fn load_multiple(cursor: &mut Cursor<&Vec<u8>>) -> Result<Vec<Self>, Error> {
+        let mut actions = Vec::new();
+        while let Ok(a) = Self::load(cursor) {
+            actions.push(a);
+        }
+        Ok(actions)
+    }
+
+    pub fn write(self, cursor: &mut Cursor<Vec<u8>>) -> Result<(), Error> {
+        ANAM::try_from(self.kind)?.write(cursor)?;
+        self.name.map(NAM0::try_from).transpose()?.write(cursor)?;
+        self.actor.map(ALID::try_from).transpose()?.write(cursor)?;
+        self.unknown
+            .map(|d| LNAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(cursor)?;
+        self.index.map(INAM::try_from).transpose()?.write(cursor)?;
+        self.flags.map(FNAM::try_from).transpose()?.write(cursor)?;
+        self.start_phase
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.end_phase
+            .map(ENAM::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.timer.map(SNAM::try_from).transpose()?.write(cursor)?;
+        for p in self.packages {
+            PNAM::try_from(p)?.write(cursor)?;
+        }
+        self.topic.map(DATA::try_from).transpose()?.write(cursor)?;
+        self.headtrack_actor
+            .map(HTID::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.looping_max
+            .map(DMAX::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.looping_min
+            .map(DMIN::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.emotion_type
+            .map(DEMO::try_from)
+            .transpose()?
+            .write(cursor)?;
+        self.emotion_value
+            .map(DEVA::try_from)
+            .transpose()?
+            .write(cursor)?;
+        ANAM {
+            size: 0,
+            data: Vec::new(),
+        }
+        .write(cursor)?;
+        Ok(())
+    }
+}
+
+fn empty_hnam() -> HNAM {
+    HNAM {
+        size: 0,
+        data: Vec::new(),
+    }
+}
+
+fn empty_next() -> NEXT {
+    NEXT {
+        size: 0,
+        data: Vec::new(),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Scene {
     pub header: RecordHeader,
     pub edid: String,
+    pub scripts: Option<ScriptList>,
+    pub flags: Option<SceneFlags>,
+    pub phases: Vec<ScenePhase>,
+    pub actors: Vec<SceneActor>,
+    pub actions: Vec<SceneAction>,
+    pub quest: Option<FormID>,
+    pub last_action_index: Option<u32>,
+    pub unknown: Option<Vec<u8>>,
+    pub conditions: Vec<EffectCondition>,
+    /// Whether a NEXT followed the actions
+    pub actions_next: bool,
 }
 
 impl fmt::Display for Scene {
@@ -36,10 +340,119 @@ impl TryFrom<SCEN> for Scene {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(|v| v.parse(b"SCEN"))
+            .transpose()?;
+        let flags = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let phases = ScenePhase::load_multiple(&mut cursor)?;
+        let mut actors = Vec::new();
+        while let Ok(a) = ALID::read(&mut cursor) {
+            actors.push(SceneActor {
+                alias: a.try_into()?,
+                flags: LNAM::read(&mut cursor)
+                    .ok()
+                    .map(TryInto::try_into)
+                    .transpose()?,
+                behaviour: DNAM::read(&mut cursor)
+                    .ok()
+                    .map(TryInto::try_into)
+                    .transpose()?,
+            });
+        }
+        let actions = SceneAction::load_multiple(&mut cursor)?;
+        let actions_next = NEXT::read(&mut cursor).is_ok();
+        let quest = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let last_action_index = INAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown = VNAM::read(&mut cursor).ok().map(|v| v.data);
+        let conditions = EffectCondition::load_multiple(&mut cursor)?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            flags,
+            phases,
+            actors,
+            actions,
+            quest,
+            last_action_index,
+            unknown,
+            conditions,
+            actions_next,
+        })
+    }
+}
+
+impl TryFrom<Scene> for SCEN {
+    type Error = Error;
+
+    fn try_from(obj: Scene) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flags
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for p in obj.phases {
+            p.write(&mut data)?;
+        }
+        for a in obj.actors {
+            ALID::try_from(a.alias)?.write(&mut data)?;
+            a.flags.map(LNAM::try_from).transpose()?.write(&mut data)?;
+            a.behaviour
+                .map(DNAM::try_from)
+                .transpose()?
+                .write(&mut data)?;
+        }
+        for a in obj.actions {
+            a.write(&mut data)?;
+        }
+        if obj.actions_next {
+            empty_next().write(&mut data)?;
+        }
+        obj.quest
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.last_action_index
+            .map(INAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown
+            .map(VNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for c in obj.conditions {
+            c.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
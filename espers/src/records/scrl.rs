@@ -1,12 +1,18 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    DestructionData, Effect, Model, ObjectBounds, SpellItem, DATA, DESC, EDID, ETYP, FULL, KSIZ,
+    KWDA, MDOB, MODL, MODS, MODT, OBND, SPIT, YNAM, ZNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [SCRL](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/SCRL) record
 #[binrw]
+#[br(import(localized: bool))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"SCRL")]
 pub struct SCRL {
@@ -14,12 +20,39 @@ pub struct SCRL {
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrollData {
+    pub value: u32,
+    pub weight: f32,
 }
 
+impl_field_struct!(ScrollData, DATA);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Scroll {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub bounds: Option<ObjectBounds>,
+    pub full_name: Option<LocalizedString>,
+    pub keywords: Vec<FormID>,
+    pub menu_display_object: Option<FormID>,
+    pub equip_slot: Option<FormID>,
+    pub description: Option<LocalizedString>,
+    pub model: Option<Model>,
+    pub destruction_data: Option<DestructionData>,
+    pub pickup_sound: Option<FormID>,
+    pub drop_sound: Option<FormID>,
+    pub data: Option<ScrollData>,
+    pub spell: Option<SpellItem>,
+    pub effects: Vec<Effect>,
 }
 
 impl fmt::Display for Scroll {
@@ -39,10 +72,147 @@ impl TryFrom<SCRL> for Scroll {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let bounds = OBND::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let _: Option<u32> = KSIZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+        let menu_display_object = MDOB::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let equip_slot = ETYP::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let description = match (DESC::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let model = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let destruction_data = DestructionData::load(&mut cursor)?;
+        let pickup_sound = YNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let drop_sound = ZNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let data = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let spell = SPIT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut effects = Vec::new();
+        while let Ok(e) = Effect::load(&mut cursor) {
+            effects.push(e);
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            bounds,
+            full_name,
+            keywords,
+            menu_display_object,
+            equip_slot,
+            description,
+            model,
+            destruction_data,
+            pickup_sound,
+            drop_sound,
+            data,
+            spell,
+            effects,
+        })
+    }
+}
+
+impl TryFrom<Scroll> for SCRL {
+    type Error = Error;
+
+    fn try_from(obj: Scroll) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.bounds
+            .map(OBND::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.menu_display_object
+            .map(MDOB::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.equip_slot
+            .map(ETYP::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.description
+            .map(DESC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.data.map(DATA::try_from).transpose()?.write(&mut data)?;
+        obj.spell
+            .map(SPIT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for x in obj.effects {
+            x.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
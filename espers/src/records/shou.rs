@@ -1,12 +1,15 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{DESC, EDID, FULL, MDOB, SNAM};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [SHOU](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/SHOU) record
 #[binrw]
+#[br(import(localized: bool))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"SHOU")]
 pub struct SHOU {
@@ -14,12 +17,32 @@ pub struct SHOU {
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+/// One word of a shout, with the spell it casts and its recharge time
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShoutWord {
+    pub word: FormID,
+    pub spell: FormID,
+    pub recharge: f32,
 }
 
+impl_field_struct!(ShoutWord, SNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Shout {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub full_name: Option<LocalizedString>,
+    pub menu_display_object: Option<FormID>,
+    pub description: Option<LocalizedString>,
+    pub words: Vec<ShoutWord>,
 }
 
 impl fmt::Display for Shout {
@@ -39,10 +62,72 @@ impl TryFrom<SHOU> for Shout {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let menu_display_object = MDOB::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let description = match (DESC::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let mut words = Vec::new();
+        while let Ok(w) = SNAM::read(&mut cursor) {
+            words.push(w.try_into()?);
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            full_name,
+            menu_display_object,
+            description,
+            words,
+        })
+    }
+}
+
+impl TryFrom<Shout> for SHOU {
+    type Error = Error;
+
+    fn try_from(obj: Shout) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.menu_display_object
+            .map(MDOB::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.description
+            .map(DESC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for w in obj.words {
+            SNAM::try_from(w)?.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
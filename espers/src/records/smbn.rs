@@ -1,7 +1,9 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EffectCondition, DNAM, EDID, PNAM, SNAM, XNAM};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +18,27 @@ pub struct SMBN {
     pub data: Vec<u8>,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct StoryNodeFlags: u32 {
+        const RANDOM = 0x00000001;
+        const WARN_IF_NO_CHILD_QUEST_STARTED = 0x00000002;
+    }
+}
+
+impl_field_struct!(StoryNodeFlags, DNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryManagerBranchNode {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub parent: Option<FormID>,
+    pub previous_sibling: Option<FormID>,
+    pub conditions: Vec<EffectCondition>,
+    pub flags: Option<StoryNodeFlags>,
+    pub unknown: Option<Vec<u8>>,
 }
 
 impl fmt::Display for StoryManagerBranchNode {
@@ -43,10 +62,70 @@ impl TryFrom<SMBN> for StoryManagerBranchNode {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let parent = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let previous_sibling = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let conditions = EffectCondition::load_counted(&mut cursor)?;
+        let flags = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown = XNAM::read(&mut cursor).ok().map(|x| x.data);
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            parent,
+            previous_sibling,
+            conditions,
+            flags,
+            unknown,
+        })
+    }
+}
+
+impl TryFrom<StoryManagerBranchNode> for SMBN {
+    type Error = Error;
+
+    fn try_from(obj: StoryManagerBranchNode) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.parent
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.previous_sibling
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        EffectCondition::write_counted(obj.conditions, &mut data)?;
+        obj.flags
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown
+            .map(XNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
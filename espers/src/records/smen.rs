@@ -1,7 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader, StoryNodeFlags};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EffectCondition, DNAM, EDID, ENAM, PNAM, SNAM, XNAM};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -20,6 +21,13 @@ pub struct SMEN {
 pub struct StoryManagerEventNode {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub parent: Option<FormID>,
+    pub previous_sibling: Option<FormID>,
+    pub conditions: Vec<EffectCondition>,
+    pub flags: Option<StoryNodeFlags>,
+    pub unknown: Option<Vec<u8>>,
+    /// Story event code, e.g. `ADIA` or `CHRR`
+    pub event: Option<[u8; 4]>,
 }
 
 impl fmt::Display for StoryManagerEventNode {
@@ -43,10 +51,79 @@ impl TryFrom<SMEN> for StoryManagerEventNode {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let parent = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let previous_sibling = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let conditions = EffectCondition::load_counted(&mut cursor)?;
+        let flags = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown = XNAM::read(&mut cursor).ok().map(|x| x.data);
+        let event = ENAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            parent,
+            previous_sibling,
+            conditions,
+            flags,
+            unknown,
+            event,
+        })
+    }
+}
+
+impl TryFrom<StoryManagerEventNode> for SMEN {
+    type Error = Error;
+
+    fn try_from(obj: StoryManagerEventNode) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.parent
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.previous_sibling
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        EffectCondition::write_counted(obj.conditions, &mut data)?;
+        obj.flags
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown
+            .map(XNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.event
+            .map(ENAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
@@ -1,7 +1,9 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EffectCondition, DNAM, EDID, FNAM, MNAM, NNAM, PNAM, QNAM, RNAM, SNAM, XNAM};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +18,48 @@ pub struct SMQN {
     pub data: Vec<u8>,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct StoryQuestFlags: u16 {
+        const DO_ALL_BEFORE_REPEATING = 0x0001;
+        const SHARES_EVENT = 0x0002;
+        const NUM_QUESTS_TO_RUN = 0x0004;
+    }
+}
+
+/// Quest node flags (DNAM). The node flags are those of
+/// [super::StoryNodeFlags], truncated to 16 bits.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestNodeFlags {
+    pub node_flags: u16,
+    pub quest_flags: StoryQuestFlags,
+}
+
+impl_field_struct!(QuestNodeFlags, DNAM);
+
+/// A quest started by the node (NNAM)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryQuest {
+    pub quest: FormID,
+    pub unknown: Option<u32>,
+    pub hours_until_reset: Option<f32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryManagerQuestNode {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub parent: Option<FormID>,
+    pub previous_sibling: Option<FormID>,
+    pub conditions: Vec<EffectCondition>,
+    pub flags: Option<QuestNodeFlags>,
+    pub max_concurrent_quests: Option<u32>,
+    pub quests_to_run: Option<u32>,
+    pub quests: Vec<StoryQuest>,
 }
 
 impl fmt::Display for StoryManagerQuestNode {
@@ -43,10 +83,115 @@ impl TryFrom<SMQN> for StoryManagerQuestNode {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let parent = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let previous_sibling = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let conditions = EffectCondition::load_counted(&mut cursor)?;
+        let flags = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let max_concurrent_quests = XNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let quests_to_run = MNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let _: Option<u32> = QNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut quests = Vec::new();
+        while let Ok(n) = NNAM::read(&mut cursor) {
+            quests.push(StoryQuest {
+                quest: n.try_into()?,
+                unknown: FNAM::read(&mut cursor)
+                    .ok()
+                    .map(TryInto::try_into)
+                    .transpose()?,
+                hours_until_reset: RNAM::read(&mut cursor)
+                    .ok()
+                    .map(TryInto::try_into)
+                    .transpose()?,
+            });
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            parent,
+            previous_sibling,
+            conditions,
+            flags,
+            max_concurrent_quests,
+            quests_to_run,
+            quests,
+        })
+    }
+}
+
+impl TryFrom<StoryManagerQuestNode> for SMQN {
+    type Error = Error;
+
+    fn try_from(obj: StoryManagerQuestNode) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.parent
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.previous_sibling
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        EffectCondition::write_counted(obj.conditions, &mut data)?;
+        obj.flags
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.max_concurrent_quests
+            .map(XNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.quests_to_run
+            .map(MNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.quests.is_empty() {
+            QNAM::try_from(obj.quests.len() as u32)?.write(&mut data)?;
+        }
+        for q in obj.quests {
+            NNAM::try_from(q.quest)?.write(&mut data)?;
+            q.unknown
+                .map(FNAM::try_from)
+                .transpose()?
+                .write(&mut data)?;
+            q.hours_until_reset
+                .map(RNAM::try_from)
+                .transpose()?
+                .write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
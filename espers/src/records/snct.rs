@@ -1,25 +1,64 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EDID, FNAM, FULL, PNAM, UNAM, VNAM};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"SNCT")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SNCT {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct SoundCategoryFlags: u32 {
+        const MUTE_WHEN_SUBMERGED = 0x00000001;
+        const SHOULD_APPEAR_ON_MENU = 0x00000002;
+    }
+}
+
+impl_field_struct!(SoundCategoryFlags, FNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SoundCategory {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub full_name: Option<LocalizedString>,
+    pub flags: Option<SoundCategoryFlags>,
+    pub parent: Option<FormID>,
+    /// Fraction of 65535
+    pub static_volume_multiplier: Option<u16>,
+    /// Fraction of 65535
+    pub default_menu_volume: Option<u16>,
+}
+
+impl SoundCategory {
+    /// Static volume multiplier, from 0 to 1
+    pub fn static_volume(&self) -> Option<f32> {
+        self.static_volume_multiplier
+            .map(|v| v as f32 / u16::MAX as f32)
+    }
+
+    /// Default menu volume, from 0 to 1
+    pub fn menu_volume(&self) -> Option<f32> {
+        self.default_menu_volume.map(|v| v as f32 / u16::MAX as f32)
+    }
 }
 
 impl fmt::Display for SoundCategory {
@@ -39,10 +78,81 @@ impl TryFrom<SNCT> for SoundCategory {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let flags = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let parent = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let static_volume_multiplier = VNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let default_menu_volume = UNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            full_name,
+            flags,
+            parent,
+            static_volume_multiplier,
+            default_menu_volume,
+        })
+    }
+}
+
+impl TryFrom<SoundCategory> for SNCT {
+    type Error = Error;
+
+    fn try_from(obj: SoundCategory) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flags
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.parent
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.static_volume_multiplier
+            .map(VNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.default_menu_volume
+            .map(UNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,7 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{EffectCondition, ANAM, BNAM, CNAM, EDID, FNAM, GNAM, LNAM, ONAM, SNAM};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +17,61 @@ pub struct SNDR {
     pub data: Vec<u8>,
 }
 
+#[binrw]
+#[brw(little, repr = u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SoundLooping {
+    None = 0x00,
+    Loop = 0x08,
+    EnvelopeFast = 0x10,
+    EnvelopeSlow = 0x20,
+}
+
+/// Looping and rumble values (LNAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundLoopAndRumble {
+    pub unknown1: u8,
+    pub looping: SoundLooping,
+    pub unknown2: u8,
+    pub rumble_send_value: u8,
+}
+
+impl_field_struct!(SoundLoopAndRumble, LNAM);
+
+/// Playback values (BNAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundValues {
+    pub frequency_shift_pct: i8,
+    pub frequency_variance_pct: i8,
+    pub priority: u8,
+    pub db_variance: u8,
+    /// In hundredths of a decibel
+    pub static_attenuation: u16,
+}
+
+impl_field_struct!(SoundValues, BNAM);
+
+/// A sound descriptor, picking one of its files to play through a
+/// category and output model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SoundReference {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    /// Hash of the descriptor type; `Standard` is `0x1EEF540A`
+    pub kind: Option<u32>,
+    pub category: Option<FormID>,
+    pub alternate_sound_for: Option<FormID>,
+    /// `.wav` or `.xwm` files, relative to `Data/Sound/`
+    pub files: Vec<String>,
+    pub output_model: Option<FormID>,
+    pub unknown: Option<String>,
+    pub conditions: Vec<EffectCondition>,
+    pub loop_and_rumble: Option<SoundLoopAndRumble>,
+    pub values: Option<SoundValues>,
 }
 
 impl fmt::Display for SoundReference {
@@ -43,10 +95,107 @@ impl TryFrom<SNDR> for SoundReference {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let kind = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let category = GNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let alternate_sound_for = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut files = Vec::new();
+        while let Ok(a) = ANAM::read(&mut cursor) {
+            files.push(a.try_into()?);
+        }
+        let output_model = ONAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let conditions = EffectCondition::load_multiple(&mut cursor)?;
+        let loop_and_rumble = LNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let values = BNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            kind,
+            category,
+            alternate_sound_for,
+            files,
+            output_model,
+            unknown,
+            conditions,
+            loop_and_rumble,
+            values,
+        })
+    }
+}
+
+impl TryFrom<SoundReference> for SNDR {
+    type Error = Error;
+
+    fn try_from(obj: SoundReference) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.kind.map(CNAM::try_from).transpose()?.write(&mut data)?;
+        obj.category
+            .map(GNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.alternate_sound_for
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for f in obj.files {
+            ANAM::try_from(f)?.write(&mut data)?;
+        }
+        obj.output_model
+            .map(ONAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for c in obj.conditions {
+            c.write(&mut data)?;
+        }
+        obj.loop_and_rumble
+            .map(LNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.values
+            .map(BNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
@@ -1,7 +1,9 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::check_done_reading;
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{ANAM, CNAM, EDID, FNAM, MNAM, NAM1, ONAM, SNAM};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +18,86 @@ pub struct SOPM {
     pub data: Vec<u8>,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct OutputModelFlags: u8 {
+        const ATTENUATES_WITH_DISTANCE = 0x01;
+        const ALLOWS_RUMBLE = 0x02;
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputModelData {
+    pub flags: OutputModelFlags,
+    pub unknown: [u8; 2],
+    pub reverb_send_pct: u8,
+}
+
+impl_field_struct!(OutputModelData, NAM1);
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputModelType {
+    UsesHrtf = 0,
+    DefinedSpeakerOutput,
+}
+
+impl_field_struct!(OutputModelType, MNAM);
+
+/// Output level of a channel on each speaker, as a percentage
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeakerLevels {
+    pub front_left: u8,
+    pub front_right: u8,
+    pub center: u8,
+    pub lfe: u8,
+    pub rear_left: u8,
+    pub rear_right: u8,
+    pub back_left: u8,
+    pub back_right: u8,
+}
+
+/// Speaker levels for each input channel (ONAM). The third channel is unused.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputChannels {
+    pub channels: [SpeakerLevels; 3],
+}
+
+impl_field_struct!(OutputChannels, ONAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attenuation {
+    pub unknown1: [u8; 4],
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub curve: [u8; 5],
+    pub unknown2: [u8; 3],
+}
+
+impl_field_struct!(Attenuation, ANAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SoundOutputModel {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub data: Option<OutputModelData>,
+    pub unknown_fnam: Option<Vec<u8>>,
+    pub kind: Option<OutputModelType>,
+    pub unknown_cnam: Option<Vec<u8>>,
+    pub unknown_snam: Option<Vec<u8>>,
+    pub output: Option<OutputChannels>,
+    pub attenuation: Option<Attenuation>,
 }
 
 impl fmt::Display for SoundOutputModel {
@@ -43,10 +121,86 @@ impl TryFrom<SOPM> for SoundOutputModel {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let output_data = NAM1::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown_fnam = FNAM::read(&mut cursor).ok().map(|f| f.data);
+        let kind = MNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown_cnam = CNAM::read(&mut cursor).ok().map(|c| c.data);
+        let unknown_snam = SNAM::read(&mut cursor).ok().map(|s| s.data);
+        let output = ONAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attenuation = ANAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            data: output_data,
+            unknown_fnam,
+            kind,
+            unknown_cnam,
+            unknown_snam,
+            output,
+            attenuation,
+        })
+    }
+}
+
+impl TryFrom<SoundOutputModel> for SOPM {
+    type Error = Error;
+
+    fn try_from(obj: SoundOutputModel) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.data.map(NAM1::try_from).transpose()?.write(&mut data)?;
+        obj.unknown_fnam
+            .map(|d| FNAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        obj.kind.map(MNAM::try_from).transpose()?.write(&mut data)?;
+        obj.unknown_cnam
+            .map(|d| CNAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        obj.unknown_snam
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.output
+            .map(ONAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.attenuation
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
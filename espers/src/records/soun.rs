@@ -1,7 +1,8 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{ObjectBounds, EDID, FNAM, OBND, SDSC, SNDD};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +17,16 @@ pub struct SOUN {
     pub data: Vec<u8>,
 }
 
+/// A placeable sound marker, which plays its sound descriptor
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sound {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub bounds: ObjectBounds,
+    /// Unused by Skyrim, left over from the Oblivion format
+    pub filename: Option<String>,
+    pub unknown: Option<Vec<u8>>,
+    pub descriptor: Option<FormID>,
 }
 
 impl fmt::Display for Sound {
@@ -39,10 +46,63 @@ impl TryFrom<SOUN> for Sound {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let bounds = OBND::read(&mut cursor)?.try_into()?;
+        let filename = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown = SNDD::read(&mut cursor).ok().map(|s| s.data);
+        let descriptor = SDSC::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            bounds,
+            filename,
+            unknown,
+            descriptor,
+        })
+    }
+}
+
+impl TryFrom<Sound> for SOUN {
+    type Error = Error;
+
+    fn try_from(obj: Sound) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        obj.filename
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown
+            .map(|d| SNDD {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        obj.descriptor
+            .map(SDSC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
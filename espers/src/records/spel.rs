@@ -1,13 +1,18 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
+use crate::fields::{
+    Effect, ObjectBounds, SpellItem, DESC, EDID, ETYP, FULL, KSIZ, KWDA, MDOB, OBND, SPIT,
+};
 use binrw::binrw;
-use binrw::BinRead;
+use binrw::{BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [SPEL](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/SPEL) record
 #[binrw]
+#[br(import(localized: bool))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"SPEL")]
 pub struct SPEL {
@@ -15,12 +20,24 @@ pub struct SPEL {
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Spell {
     pub header: RecordHeader,
     pub edid: String,
+    pub bounds: Option<ObjectBounds>,
+    pub full_name: Option<LocalizedString>,
+    pub keywords: Vec<FormID>,
+    pub menu_display_object: Option<FormID>,
+    pub equip_slot: Option<FormID>,
+    pub description: Option<LocalizedString>,
+    pub data: SpellItem,
+    pub effects: Vec<Effect>,
 }
 
 impl fmt::Display for Spell {
@@ -37,10 +54,107 @@ impl TryFrom<SPEL> for Spell {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
+        let bounds = OBND::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let _: Option<u32> = KSIZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+        let menu_display_object = MDOB::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let equip_slot = ETYP::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let description = match (DESC::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let data = SPIT::read(&mut cursor)?.try_into()?;
+        let mut effects = Vec::new();
+        while let Ok(e) = Effect::load(&mut cursor) {
+            effects.push(e);
+        }
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            bounds,
+            full_name,
+            keywords,
+            menu_display_object,
+            equip_slot,
+            description,
+            data,
+            effects,
+        })
+    }
+}
+
+impl TryFrom<Spell> for SPEL {
+    type Error = Error;
+
+    fn try_from(obj: Spell) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        obj.bounds
+            .map(OBND::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.menu_display_object
+            .map(MDOB::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.equip_slot
+            .map(ETYP::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.description
+            .map(DESC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        SPIT::try_from(obj.data)?.write(&mut data)?;
+        for x in obj.effects {
+            x.write(&mut data)?;
+        }
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,7 +1,9 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{Model, ObjectBounds, DNAM, EDID, MNAM, MODL, MODS, MODT, OBND};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +18,80 @@ pub struct STAT {
     pub data: Vec<u8>,
 }
 
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct StaticFlags: u8 {
+        const CONSIDERED_SNOW = 0x01;
+    }
+}
+
+/// Directional material (DNAM). The flags are only present in Skyrim SE.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionalMaterial {
+    /// In degrees, from 30 to 120
+    pub max_angle: f32,
+    pub material: FormID,
+    #[br(try)]
+    pub flags: Option<StaticFlags>,
+    #[br(try)]
+    pub unknown: Option<[u8; 3]>,
+}
+
+impl_field_struct!(DirectionalMaterial, DNAM);
+
+/// A mesh path in a fixed 260 byte buffer
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LodMesh {
+    #[br(count = 260)]
+    pub data: Vec<u8>,
+}
+
+impl LodMesh {
+    /// Returns the path up to its null terminator, if it is not empty
+    pub fn path(&self) -> Option<String> {
+        let end = self
+            .data
+            .iter()
+            .position(|b| *b == 0)
+            .unwrap_or(self.data.len());
+        if end == 0 {
+            None
+        } else {
+            Some(String::from_utf8_lossy(&self.data[..end]).into_owned())
+        }
+    }
+}
+
+/// Distant LOD meshes (MNAM), one per level
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistantLod {
+    pub levels: [LodMesh; 4],
+}
+
+impl DistantLod {
+    pub fn meshes(&self) -> Vec<String> {
+        self.levels.iter().filter_map(LodMesh::path).collect()
+    }
+}
+
+impl_field_struct!(DistantLod, MNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Static {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub bounds: ObjectBounds,
+    pub model: Option<Model>,
+    pub material: Option<DirectionalMaterial>,
+    pub lod: Option<DistantLod>,
 }
 
 impl fmt::Display for Static {
@@ -39,10 +111,57 @@ impl TryFrom<STAT> for Static {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let bounds = OBND::read(&mut cursor)?.try_into()?;
+        let model = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let material = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let lod = MNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            bounds,
+            model,
+            material,
+            lod,
+        })
+    }
+}
+
+impl TryFrom<Static> for STAT {
+    type Error = Error;
+
+    fn try_from(obj: Static) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.material
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.lod.map(MNAM::try_from).transpose()?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
@@ -132,9 +132,15 @@ impl TryFrom<Header> for TES4 {
         if let Some(c) = obj.counter {
             INCC::try_from(c)?.write(&mut data)?;
         }
+
+        let data = data.into_inner();
+
         Ok(Self {
-            header: obj.header,
-            data: data.into_inner(),
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
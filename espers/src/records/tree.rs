@@ -1,25 +1,55 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    Model, ObjectBounds, CNAM, EDID, FULL, MODL, MODS, MODT, OBND, PFIG, PFPC, SNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"TREE")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TREE {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+/// How the tree sways in the wind (CNAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreeData {
+    pub trunk_flexibility: f32,
+    pub branch_flexibility: f32,
+    pub unknown: [u8; 32],
+    pub leaf_amplitude: f32,
+    pub leaf_frequency: f32,
 }
 
+impl_field_struct!(TreeData, CNAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tree {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub bounds: ObjectBounds,
+    pub model: Option<Model>,
+    pub ingredient: Option<FormID>,
+    pub harvest_sound: Option<FormID>,
+    /// Harvest chance in spring, summer, fall and winter
+    pub percent_chance: Option<[u8; 4]>,
+    pub full_name: Option<LocalizedString>,
+    pub data: Option<TreeData>,
 }
 
 impl fmt::Display for Tree {
@@ -39,10 +69,86 @@ impl TryFrom<TREE> for Tree {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let bounds = OBND::read(&mut cursor)?.try_into()?;
+        let model = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let ingredient = PFIG::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let harvest_sound = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let percent_chance = PFPC::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let tree_data = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            bounds,
+            model,
+            ingredient,
+            harvest_sound,
+            percent_chance,
+            full_name,
+            data: tree_data,
+        })
+    }
+}
+
+impl TryFrom<Tree> for TREE {
+    type Error = Error;
+
+    fn try_from(obj: Tree) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        OBND::try_from(obj.bounds)?.write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.ingredient
+            .map(PFIG::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.harvest_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.percent_chance
+            .map(PFPC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.data.map(CNAM::try_from).transpose()?.write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
@@ -1,10 +1,10 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
 use crate::common::check_done_reading;
 use crate::error::Error;
 use crate::fields::{
     DecalData, ObjectBounds, DNAM, DODT, EDID, OBND, TX00, TX01, TX02, TX03, TX04, TX05, TX06, TX07,
 };
-use binrw::{binrw, BinRead};
+use binrw::{binrw, BinRead, BinWrite};
 use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -32,6 +32,21 @@ impl TryFrom<DNAM> for TypeFlags {
     }
 }
 
+impl TryFrom<TypeFlags> for DNAM {
+    type Error = Error;
+
+    fn try_from(obj: TypeFlags) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
 #[binrw]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"TXST")]
@@ -129,3 +144,57 @@ impl TryFrom<TXST> for TextureSet {
         })
     }
 }
+
+impl TryFrom<TextureSet> for TXST {
+    type Error = Error;
+
+    fn try_from(obj: TextureSet) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        OBND::try_from(obj.obnd)?.write(&mut data)?;
+        TX00::try_from(obj.color_map)?.write(&mut data)?;
+        obj.normal_map
+            .map(TX01::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.mask.map(TX02::try_from).transpose()?.write(&mut data)?;
+        obj.tone_map
+            .map(TX03::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.detail_map
+            .map(TX04::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.environment_map
+            .map(TX05::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.multilayer
+            .map(TX06::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.specularity_map
+            .map(TX07::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.dodt.map(DODT::try_from).transpose()?.write(&mut data)?;
+        obj.flags
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+        })
+    }
+}
@@ -1,25 +1,141 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, rgba_from_bytes, rgba_to_bytes, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    ANAM, DATA, DNAM, EDID, FNAM, FULL, GNAM, INAM, MNAM, NAM0, NAM1, NAM2, NAM3, NAM4, NAM5, NNAM,
+    SNAM, TNAM, XNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
+use rgb::RGBA8;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
 #[binrw]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[br(import(localized: bool))]
 #[brw(little, magic = b"WATR")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WATR {
     pub header: RecordHeader,
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct WaterFlags: u8 {
+        const CAUSES_DAMAGE = 0x01;
+    }
+}
+
+impl_field_struct!(WaterFlags, FNAM);
+
+/// Shader properties of the water surface (DNAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaterVisualData {
+    pub unknown1: [f32; 4],
+    pub sun_specular_power: f32,
+    pub reflectivity_amount: f32,
+    pub fresnel_amount: f32,
+    pub unknown2: [u8; 4],
+    pub fog_near: f32,
+    pub fog_far: f32,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub shallow_color: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub deep_color: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub reflection_color: RGBA8,
+    pub unknown3: [u8; 4],
+    pub unknown4: [f32; 4],
+    pub displacement_starting_size: f32,
+    pub displacement_force: f32,
+    pub displacement_velocity: f32,
+    pub displacement_falloff: f32,
+    pub displacement_dampener: f32,
+    pub unknown5: f32,
+    pub noise_falloff: f32,
+    /// One per noise layer
+    pub noise_wind_direction: [f32; 3],
+    /// One per noise layer
+    pub noise_wind_speed: [f32; 3],
+    pub unknown6: [f32; 2],
+    pub fog_amount: f32,
+    pub unknown7: f32,
+    pub underwater_fog_amount: f32,
+    pub underwater_fog_near: f32,
+    pub underwater_fog_far: f32,
+    pub refraction_magnitude: f32,
+    pub specular_power: f32,
+    pub unknown8: f32,
+    pub specular_radius: f32,
+    pub specular_brightness: f32,
+    /// One per noise layer
+    pub noise_uv_scale: [f32; 3],
+    /// One per noise layer
+    pub noise_amplitude_scale: [f32; 3],
+    pub reflection_magnitude: f32,
+    pub sun_sparkle_magnitude: f32,
+    pub sun_specular_magnitude: f32,
+    pub depth_reflections: f32,
+    pub depth_refraction: f32,
+    pub depth_normals: f32,
+    pub depth_specular_lighting: f32,
+    pub sun_sparkle_power: f32,
+    /// SSE only
+    #[br(try)]
+    pub flowmap_scale: Option<f32>,
 }
 
+impl_field_struct!(WaterVisualData, DNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaterVelocity {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl_field_struct!(WaterVelocity, NAM0);
+impl_field_struct!(WaterVelocity, NAM1);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaterType {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub full_name: Option<LocalizedString>,
+    pub noise_maps: Vec<String>,
+    pub opacity: Option<u8>,
+    pub flags: Option<WaterFlags>,
+    pub unknown_mnam: Option<Vec<u8>>,
+    pub material: Option<FormID>,
+    pub open_sound: Option<FormID>,
+    pub spell: Option<FormID>,
+    pub image_space: Option<FormID>,
+    pub damage_per_second: Option<u16>,
+    pub visual_data: Option<WaterVisualData>,
+    pub unknown_gnam: Option<Vec<u8>>,
+    pub linear_velocity: Option<WaterVelocity>,
+    pub angular_velocity: Option<WaterVelocity>,
+    pub noise_layer_one: Option<String>,
+    pub noise_layer_two: Option<String>,
+    pub noise_layer_three: Option<String>,
+    pub flow_normals: Option<String>,
 }
 
 impl fmt::Display for WaterType {
@@ -39,10 +155,195 @@ impl TryFrom<WATR> for WaterType {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let mut noise_maps = Vec::new();
+        while let Ok(n) = NNAM::read(&mut cursor) {
+            noise_maps.push(n.try_into()?);
+        }
+        let opacity = ANAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let flags = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown_mnam = MNAM::read(&mut cursor).ok().map(|x| x.data);
+        let material = TNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let open_sound = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let spell = XNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let image_space = INAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let damage_per_second = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let visual_data = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown_gnam = GNAM::read(&mut cursor).ok().map(|x| x.data);
+        let linear_velocity = NAM0::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let angular_velocity = NAM1::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let noise_layer_one = NAM2::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let noise_layer_two = NAM3::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let noise_layer_three = NAM4::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let flow_normals = NAM5::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            full_name,
+            noise_maps,
+            opacity,
+            flags,
+            unknown_mnam,
+            material,
+            open_sound,
+            spell,
+            image_space,
+            damage_per_second,
+            visual_data,
+            unknown_gnam,
+            linear_velocity,
+            angular_velocity,
+            noise_layer_one,
+            noise_layer_two,
+            noise_layer_three,
+            flow_normals,
+        })
+    }
+}
+
+impl TryFrom<WaterType> for WATR {
+    type Error = Error;
+
+    fn try_from(obj: WaterType) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for n in obj.noise_maps {
+            NNAM::try_from(n)?.write(&mut data)?;
+        }
+        obj.opacity
+            .map(ANAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flags
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown_mnam
+            .map(|d| MNAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        obj.material
+            .map(TNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.open_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.spell
+            .map(XNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.image_space
+            .map(INAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.damage_per_second
+            .map(DATA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.visual_data
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown_gnam
+            .map(|d| GNAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        obj.linear_velocity
+            .map(NAM0::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.angular_velocity
+            .map(NAM1::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.noise_layer_one
+            .map(NAM2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.noise_layer_two
+            .map(NAM3::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.noise_layer_three
+            .map(NAM4::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flow_normals
+            .map(NAM5::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
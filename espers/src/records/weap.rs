@@ -1,12 +1,20 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    DestructionData, Model, ObjectBounds, ScriptList, BAMT, BIDS, CNAM, CRDT, DATA, DESC, DNAM,
+    EAMT, EDID, EITM, ETYP, FULL, ICON, INAM, KSIZ, KWDA, MICO, MODL, MODS, MODT, NAM7, NAM8, NAM9,
+    NNAM, OBND, SNAM, TNAM, UNAM, VMAD, VNAM, WNAM, XNAM, YNAM, ZNAM,
+};
+use binrw::{binrw, until_eof, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// [WEAP](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/WEAP) record
 #[binrw]
+#[br(import(localized: bool))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"WEAP")]
 pub struct WEAP {
@@ -14,12 +22,198 @@ pub struct WEAP {
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeaponData {
+    pub value: u32,
+    pub weight: f32,
+    pub damage: u16,
+}
+
+impl_field_struct!(WeaponData, DATA);
+
+#[binrw]
+#[brw(little, repr = u8)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WeaponAnimationType {
+    HandToHand = 0,
+    OneHandSword,
+    OneHandDagger,
+    OneHandAxe,
+    OneHandMace,
+    TwoHandSword,
+    TwoHandAxe,
+    Bow,
+    Staff,
+    Crossbow,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct WeaponFlags: u16 {
+        const IGNORES_NORMAL_WEAPON_RESISTANCE = 0x0001;
+        const AUTOMATIC = 0x0002;
+        const HAS_SCOPE = 0x0004;
+        const CANNOT_DROP = 0x0008;
+        const HIDE_BACKPACK = 0x0010;
+        const EMBEDDED_WEAPON = 0x0020;
+        const NO_FIRST_PERSON_IS_ANIMATIONS = 0x0040;
+        const NON_PLAYABLE = 0x0080;
+    }
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct WeaponFlags2: u32 {
+        const PLAYER_ONLY = 0x0001;
+        const NPCS_USE_AMMO = 0x0002;
+        const NO_JAM_AFTER_RELOAD = 0x0004;
+        const MINOR_CRIME = 0x0010;
+        const RANGE_FIXED = 0x0020;
+        const NOT_USED_IN_NORMAL_COMBAT = 0x0040;
+        const NO_THIRD_PERSON_IS_ANIMATIONS = 0x0100;
+        const BURST_SHOT = 0x0200;
+        const RUMBLE_ALTERNATE = 0x0400;
+        const LONG_BURSTS = 0x0800;
+        const NON_HOSTILE = 0x1000;
+        const BOUND_WEAPON = 0x2000;
+    }
+}
+
+/// Weapon behaviour (DNAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeaponStats {
+    pub animation_type: WeaponAnimationType,
+    pub unused1: [u8; 3],
+    pub speed: f32,
+    pub reach: f32,
+    pub flags: WeaponFlags,
+    pub unused2: u16,
+    pub sight_fov: f32,
+    pub unknown1: u32,
+    pub vats_to_hit: u8,
+    pub unknown2: u8,
+    pub projectile_count: u8,
+    pub embedded_weapon_av: u8,
+    pub min_range: f32,
+    pub max_range: f32,
+    pub unknown3: u32,
+    pub flags2: WeaponFlags2,
+    pub animation_attack_mult: f32,
+    pub unknown4: f32,
+    pub rumble_left_motor_strength: f32,
+    pub rumble_right_motor_strength: f32,
+    pub rumble_duration: f32,
+    pub unknown5: [u8; 12],
+    pub skill: i32,
+    pub unknown6: [u8; 8],
+    pub resist: i32,
+    pub unknown7: u32,
+    pub stagger: f32,
+}
+
+impl_field_struct!(WeaponStats, DNAM);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct CriticalFlags: u8 {
+        const ON_DEATH = 0x01;
+    }
+}
+
+/// Critical hit data (CRDT); Special Edition pads this from 16 to 24 bytes
+#[binrw]
+#[brw(little)]
+#[br(import(size: u16))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalData {
+    pub damage: u16,
+    pub unused1: [u8; 2],
+    pub percent_mult: f32,
+    pub flags: CriticalFlags,
+    #[br(count = if size == 24 { 7 } else { 3 })]
+    pub unused2: Vec<u8>,
+    pub spell_effect: FormID,
+    #[br(parse_with = until_eof)]
+    pub unused3: Vec<u8>,
+}
+
+impl TryFrom<CRDT> for CriticalData {
+    type Error = Error;
+
+    fn try_from(raw: CRDT) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let result = Self::read_args(&mut cursor, (raw.size,))?;
+        check_done_reading(&mut cursor)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<CriticalData> for CRDT {
+    type Error = Error;
+
+    fn try_from(obj: CriticalData) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        obj.write_le(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            size: data.len() as u16,
+            data,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Weapon {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub scripts: Option<ScriptList>,
+    pub bounds: Option<ObjectBounds>,
+    pub full_name: Option<LocalizedString>,
+    pub model: Option<Model>,
+    pub inventory_image: Option<String>,
+    pub message_image: Option<String>,
+    pub enchantment: Option<FormID>,
+    pub enchantment_amount: Option<u16>,
+    pub destruction_data: Option<DestructionData>,
+    pub equip_slot: Option<FormID>,
+    pub bash_impact_data_set: Option<FormID>,
+    pub bash_material: Option<FormID>,
+    pub pickup_sound: Option<FormID>,
+    pub drop_sound: Option<FormID>,
+    pub keywords: Vec<FormID>,
+    pub description: Option<LocalizedString>,
+    pub embedded_node: Option<String>,
+    pub impact_data_set: Option<FormID>,
+    pub first_person_model: Option<FormID>,
+    pub attack_sound: Option<FormID>,
+    pub attack_sound_2d: Option<FormID>,
+    pub attack_loop_sound: Option<FormID>,
+    pub attack_fail_sound: Option<FormID>,
+    pub idle_sound: Option<FormID>,
+    pub equip_sound: Option<FormID>,
+    pub unequip_sound: Option<FormID>,
+    pub data: Option<WeaponData>,
+    pub stats: Option<WeaponStats>,
+    pub critical: Option<CriticalData>,
+    pub detection_sound_level: Option<u32>,
+    pub template: Option<FormID>,
 }
 
 impl fmt::Display for Weapon {
@@ -39,10 +233,310 @@ impl TryFrom<WEAP> for Weapon {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let scripts = VMAD::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let bounds = OBND::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let model = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let inventory_image = ICON::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let message_image = MICO::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let enchantment = EITM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let enchantment_amount = EAMT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let destruction_data = DestructionData::load(&mut cursor)?;
+        let equip_slot = ETYP::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let bash_impact_data_set = BIDS::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let bash_material = BAMT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let pickup_sound = YNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let drop_sound = ZNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let _: Option<u32> = KSIZ::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut keywords = Vec::new();
+        while let Ok(kwda) = KWDA::read(&mut cursor) {
+            let items: Vec<_> = kwda.try_into()?;
+            keywords.extend(items);
+        }
+        let description = match (DESC::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let embedded_node = NNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let impact_data_set = INAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let first_person_model = WNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attack_sound = SNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attack_sound_2d = XNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attack_loop_sound = NAM7::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let attack_fail_sound = TNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let idle_sound = UNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let equip_sound = NAM9::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unequip_sound = NAM8::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let data = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let stats = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let critical = CRDT::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let detection_sound_level = VNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let template = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            scripts,
+            bounds,
+            full_name,
+            model,
+            inventory_image,
+            message_image,
+            enchantment,
+            enchantment_amount,
+            destruction_data,
+            equip_slot,
+            bash_impact_data_set,
+            bash_material,
+            pickup_sound,
+            drop_sound,
+            keywords,
+            description,
+            embedded_node,
+            impact_data_set,
+            first_person_model,
+            attack_sound,
+            attack_sound_2d,
+            attack_loop_sound,
+            attack_fail_sound,
+            idle_sound,
+            equip_sound,
+            unequip_sound,
+            data,
+            stats,
+            critical,
+            detection_sound_level,
+            template,
+        })
+    }
+}
+
+impl TryFrom<Weapon> for WEAP {
+    type Error = Error;
+
+    fn try_from(obj: Weapon) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        obj.scripts
+            .map(VMAD::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.bounds
+            .map(OBND::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.inventory_image
+            .map(ICON::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.message_image
+            .map(MICO::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.enchantment
+            .map(EITM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.enchantment_amount
+            .map(EAMT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(d) = obj.destruction_data {
+            d.write(&mut data)?;
+        }
+        obj.equip_slot
+            .map(ETYP::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.bash_impact_data_set
+            .map(BIDS::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.bash_material
+            .map(BAMT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.pickup_sound
+            .map(YNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.drop_sound
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if !obj.keywords.is_empty() {
+            KSIZ::try_from(obj.keywords.len() as u32)?.write(&mut data)?;
+            KWDA::try_from(obj.keywords)?.write(&mut data)?;
+        }
+        obj.description
+            .map(DESC::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.embedded_node
+            .map(NNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.impact_data_set
+            .map(INAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.first_person_model
+            .map(WNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.attack_sound
+            .map(SNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.attack_sound_2d
+            .map(XNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.attack_loop_sound
+            .map(NAM7::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.attack_fail_sound
+            .map(TNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.idle_sound
+            .map(UNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.equip_sound
+            .map(NAM9::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unequip_sound
+            .map(NAM8::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.data.map(DATA::try_from).transpose()?.write(&mut data)?;
+        obj.stats
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.critical
+            .map(CRDT::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.detection_sound_level
+            .map(VNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.template
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
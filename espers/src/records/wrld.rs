@@ -1,12 +1,23 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, CellLocation, Flags, RecordHeader};
+use crate::common::{check_done_reading, FormID, LocalizedString};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    Model, CNAM, DATA, DNAM, EDID, FULL, ICON, LTMP, MHDT, MNAM, MODL, MODS, MODT, NAM0, NAM2,
+    NAM3, NAM4, NAM9, NAMA, NNAM, OFST, ONAM, PNAM, RNAM, TNAM, UNAM, WCTR, WNAM, XEZN, XLCN, XNAM,
+    XWEM, ZNAM,
+};
+use binrw::{binrw, BinRead, BinWrite};
+use bitflags::bitflags;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
 
+/// Width of an exterior cell in game units
+pub const CELL_SIZE: f32 = 4096.0;
+
+/// [WRLD](https://en.uesp.net/wiki/Skyrim_Mod:Mod_File_Format/WRLD) record
 #[binrw]
+#[br(import(localized: bool))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[brw(little, magic = b"WRLD")]
 pub struct WRLD {
@@ -14,12 +25,156 @@ pub struct WRLD {
 
     #[br(count = header.size)]
     pub data: Vec<u8>,
+
+    #[br(calc(localized))]
+    #[bw(ignore)]
+    pub localized: bool,
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct WorldFlags: u8 {
+        const SMALL_WORLD = 0x01;
+        const CANNOT_FAST_TRAVEL = 0x02;
+        const UNKNOWN = 0x04;
+        const NO_LOD_WATER = 0x08;
+        const NO_LANDSCAPE = 0x10;
+        const NO_SKY = 0x20;
+        const FIXED_DIMENSIONS = 0x40;
+        const NO_GRASS = 0x80;
+    }
+}
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct ParentFlags: u16 {
+        const USE_LAND_DATA = 0x0001;
+        const USE_LOD_DATA = 0x0002;
+        const USE_MAP_DATA = 0x0004;
+        const USE_WATER_DATA = 0x0008;
+        const USE_CLIMATE_DATA = 0x0010;
+        const USE_IMAGE_SPACE_DATA = 0x0020;
+        const USE_SKY_CELL = 0x0040;
+    }
+}
+
+impl_field_struct!(ParentFlags, PNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LargeReference {
+    pub reference: FormID,
+    pub y: i16,
+    pub x: i16,
+}
+
+/// Large references (RNAM) located in one cell of the worldspace
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LargeReferences {
+    pub y: i16,
+    pub x: i16,
+    pub count: u32,
+    #[br(count = count)]
+    pub references: Vec<LargeReference>,
+}
+
+impl_field_struct!(LargeReferences, RNAM);
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandData {
+    pub default_land_height: f32,
+    pub default_water_height: f32,
+}
+
+impl_field_struct!(LandData, DNAM);
+
+/// World map dimensions and camera (MNAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapData {
+    pub usable_width: i32,
+    pub usable_height: i32,
+    pub nw_cell_x: i16,
+    pub nw_cell_y: i16,
+    pub se_cell_x: i16,
+    pub se_cell_y: i16,
+    pub camera_min_height: f32,
+    pub camera_max_height: f32,
+    pub camera_initial_pitch: f32,
+}
+
+impl_field_struct!(MapData, MNAM);
+
+/// Placement of the worldspace on the world map (ONAM)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapOffset {
+    pub scale: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
 }
 
+impl_field_struct!(MapOffset, ONAM);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct World {
     pub header: RecordHeader,
     pub edid: String,
+    pub large_references: Vec<LargeReferences>,
+    pub max_height: Option<MHDT>,
+    pub full_name: Option<LocalizedString>,
+    pub fixed_center: Option<(i16, i16)>,
+    pub interior_lighting: Option<FormID>,
+    pub encounter_zone: Option<FormID>,
+    pub location: Option<FormID>,
+    pub parent: Option<FormID>,
+    pub parent_flags: Option<ParentFlags>,
+    pub climate: Option<FormID>,
+    pub water: Option<FormID>,
+    pub lod_water: Option<FormID>,
+    pub lod_water_height: Option<f32>,
+    pub land_data: Option<LandData>,
+    pub map_image: Option<String>,
+    pub cloud_model: Option<Model>,
+    pub map_data: Option<MapData>,
+    pub map_offset: Option<MapOffset>,
+    pub lod_multiplier: Option<f32>,
+    pub flags: Option<WorldFlags>,
+    pub min_bounds: Option<[f32; 2]>,
+    pub max_bounds: Option<[f32; 2]>,
+    pub music: Option<FormID>,
+    pub canopy_shadow: Option<String>,
+    pub water_noise_texture: Option<String>,
+    pub hd_lod_diffuse_texture: Option<String>,
+    pub hd_lod_normal_texture: Option<String>,
+    pub water_environment_map: Option<String>,
+    pub offset_data: Option<Vec<u32>>,
+}
+
+impl World {
+    /// Whether an exterior cell lies within the object bounds (NAM0/NAM9)
+    /// of this worldspace
+    pub fn contains(&self, location: &CellLocation) -> bool {
+        match (self.min_bounds, self.max_bounds) {
+            (Some([min_x, min_y]), Some([max_x, max_y])) => {
+                let (x, y) = (location.x as f32 * CELL_SIZE, location.y as f32 * CELL_SIZE);
+                x < max_x && x + CELL_SIZE > min_x && y < max_y && y + CELL_SIZE > min_y
+            }
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for World {
@@ -36,10 +191,289 @@ impl TryFrom<WRLD> for World {
         let mut cursor = Cursor::new(&data);
 
         let edid = EDID::read(&mut cursor)?.try_into()?;
+        let mut large_references = Vec::new();
+        while let Ok(r) = RNAM::read(&mut cursor) {
+            large_references.push(r.try_into()?);
+        }
+        let max_height = MHDT::read(&mut cursor).ok();
+        let full_name = match (FULL::read(&mut cursor), raw.localized) {
+            (Ok(f), true) => Some(LocalizedString::Localized(f.try_into()?)),
+            (Ok(z), false) => Some(LocalizedString::ZString(z.try_into()?)),
+            (Err(_), _) => None,
+        };
+        let fixed_center = WCTR::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let interior_lighting = LTMP::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let encounter_zone = XEZN::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let location = XLCN::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let parent = WNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let parent_flags = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let climate = CNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let water = NAM2::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let lod_water = NAM3::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let lod_water_height = NAM4::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let land_data = DNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let map_image = ICON::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let cloud_model =
+            Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let map_data = MNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let map_offset = ONAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let lod_multiplier = NAMA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let flags = DATA::read(&mut cursor)
+            .ok()
+            .map(|d| -> Result<WorldFlags, Error> {
+                Ok(WorldFlags::from_bits_truncate(d.try_into()?))
+            })
+            .transpose()?;
+        let min_bounds = NAM0::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let max_bounds = NAM9::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let music = ZNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let canopy_shadow = NNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let water_noise_texture = XNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let hd_lod_diffuse_texture = TNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let hd_lod_normal_texture = UNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let water_environment_map = XWEM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let offset_data = OFST::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            large_references,
+            max_height,
+            full_name,
+            fixed_center,
+            interior_lighting,
+            encounter_zone,
+            location,
+            parent,
+            parent_flags,
+            climate,
+            water,
+            lod_water,
+            lod_water_height,
+            land_data,
+            map_image,
+            cloud_model,
+            map_data,
+            map_offset,
+            lod_multiplier,
+            flags,
+            min_bounds,
+            max_bounds,
+            music,
+            canopy_shadow,
+            water_noise_texture,
+            hd_lod_diffuse_texture,
+            hd_lod_normal_texture,
+            water_environment_map,
+            offset_data,
+        })
+    }
+}
+
+impl TryFrom<World> for WRLD {
+    type Error = Error;
+
+    fn try_from(obj: World) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        EDID::try_from(obj.edid)?.write(&mut data)?;
+        for r in obj.large_references {
+            RNAM::try_from(r)?.write(&mut data)?;
+        }
+        obj.max_height.write(&mut data)?;
+        obj.full_name
+            .map(FULL::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.fixed_center
+            .map(WCTR::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.interior_lighting
+            .map(LTMP::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.encounter_zone
+            .map(XEZN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.location
+            .map(XLCN::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.parent
+            .map(WNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.parent_flags
+            .map(PNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.climate
+            .map(CNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.water
+            .map(NAM2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.lod_water
+            .map(NAM3::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.lod_water_height
+            .map(NAM4::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.land_data
+            .map(DNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.map_image
+            .map(ICON::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.cloud_model {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.map_data
+            .map(MNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.map_offset
+            .map(ONAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.lod_multiplier
+            .map(NAMA::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.flags
+            .map(|f| DATA::try_from(f.bits()))
+            .transpose()?
+            .write(&mut data)?;
+        obj.min_bounds
+            .map(NAM0::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.max_bounds
+            .map(NAM9::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.music
+            .map(ZNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.canopy_shadow
+            .map(NNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.water_noise_texture
+            .map(XNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.hd_lod_diffuse_texture
+            .map(TNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.hd_lod_normal_texture
+            .map(UNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.water_environment_map
+            .map(XWEM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.offset_data
+            .map(OFST::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
+            localized: false,
         })
     }
 }
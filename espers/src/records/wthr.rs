@@ -1,7 +1,15 @@
-use super::{get_cursor, Flags, RecordHeader};
+use super::{get_cursor, make_data, Flags, RecordHeader};
+use crate::common::{
+    check_done_reading, rgb_from_bytes, rgb_to_bytes, rgba_from_bytes, rgba_to_bytes, FormID,
+};
 use crate::error::Error;
-use crate::fields::EDID;
-use binrw::{binrw, BinRead};
+use crate::fields::{
+    Model, DALC, DATA, EDID, FNAM, GNAM, HNAM, IMSP, JNAM, LNAM, MNAM, MODL, MODS, MODT, NAM0,
+    NAM1, NAM2, NAM3, NNAM, ONAM, PNAM, QNAM, RNAM, SNAM, TNAM,
+};
+use binrw::{binrw, until_eof, BinRead, BinWrite, Endian, NullString};
+use bitflags::bitflags;
+use rgb::{RGB8, RGBA8};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Cursor;
@@ -16,10 +24,248 @@ pub struct WTHR {
     pub data: Vec<u8>,
 }
 
+/// Cloud textures use one subrecord per layer, `00TX` to `L0TX`
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone)]
+struct CloudTextureField {
+    #[br(assert(magic[1..] == *b"0TX"))]
+    magic: [u8; 4],
+    size: u16,
+
+    #[br(count = size)]
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudTexture {
+    pub layer: u8,
+    pub texture: String,
+}
+
+impl TryFrom<CloudTextureField> for CloudTexture {
+    type Error = Error;
+
+    fn try_from(raw: CloudTextureField) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(&raw.data);
+        let texture = NullString::read_le(&mut cursor)?.to_string();
+        check_done_reading(&mut cursor)?;
+        let layer = raw.magic[0].checked_sub(b'0').ok_or_else(|| {
+            Error::InvalidCloudLayer(String::from_utf8_lossy(&raw.magic).to_string())
+        })?;
+        Ok(Self { layer, texture })
+    }
+}
+
+impl TryFrom<CloudTexture> for CloudTextureField {
+    type Error = Error;
+
+    fn try_from(obj: CloudTexture) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        NullString::from(obj.texture).write(&mut cursor)?;
+        let data = cursor.into_inner();
+
+        Ok(Self {
+            magic: [obj.layer + b'0', b'0', b'T', b'X'],
+            size: data.len() as u16,
+            data,
+        })
+    }
+}
+
+/// A colour for each time of day
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeOfDayColors {
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub sunrise: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub day: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub sunset: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub night: RGBA8,
+}
+
+impl_field_vec!(TimeOfDayColors, PNAM);
+impl_field_vec!(TimeOfDayColors, NAM0);
+
+/// A cloud layer alpha for each time of day
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeOfDayAlphas {
+    pub sunrise: f32,
+    pub day: f32,
+    pub sunset: f32,
+    pub night: f32,
+}
+
+impl_field_vec!(TimeOfDayAlphas, JNAM);
+
+/// A form for each time of day
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeOfDayForms {
+    pub sunrise: FormID,
+    pub day: FormID,
+    pub sunset: FormID,
+    pub night: FormID,
+}
+
+impl_field_struct!(TimeOfDayForms, IMSP);
+impl_field_struct!(TimeOfDayForms, HNAM);
+
+/// Fog distances (FNAM). The max values are missing from older plugins.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FogDistance {
+    pub day_near: f32,
+    pub day_far: f32,
+    pub night_near: f32,
+    pub night_far: f32,
+    pub day_power: f32,
+    pub night_power: f32,
+    #[br(try)]
+    pub day_max: Option<f32>,
+    #[br(try)]
+    pub night_max: Option<f32>,
+}
+
+impl_field_struct!(FogDistance, FNAM);
+
+bitflags! {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Deserialize, Serialize)]
+    pub struct WeatherFlags: u8 {
+        const PLEASANT = 0x01;
+        const CLOUDY = 0x02;
+        const RAINY = 0x04;
+        const SNOW = 0x08;
+        const PERMANENT_AURORA = 0x10;
+        const AURORA_FOLLOWS_SUN = 0x20;
+    }
+}
+
+/// Weather data (DATA). Fade values are stored as fractions of 255.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherData {
+    pub wind_speed: u8,
+    pub unknown: [u8; 2],
+    pub trans_delta: u8,
+    pub sun_glare: u8,
+    pub sun_damage: u8,
+    pub precipitation_begin_fade_in: u8,
+    pub precipitation_end_fade_out: u8,
+    pub thunder_begin_fade_in: u8,
+    pub thunder_end_fade_out: u8,
+    pub thunder_frequency: u8,
+    pub flags: WeatherFlags,
+    #[br(map = rgb_from_bytes)]
+    #[bw(map = rgb_to_bytes)]
+    pub lightning_color: RGB8,
+    pub visual_effect_begin: u8,
+    pub visual_effect_end: u8,
+    pub wind_direction: u8,
+    pub wind_direction_range: u8,
+}
+
+impl_field_struct!(WeatherData, DATA);
+
+#[binrw]
+#[brw(little, repr = u32)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeatherSoundType {
+    Default = 0,
+    Precipitation,
+    Wind,
+    Thunder,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherSound {
+    pub sound: FormID,
+    pub kind: WeatherSoundType,
+}
+
+impl_field_struct!(WeatherSound, SNAM);
+
+/// Directional ambient lighting colours (DALC)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionalAmbient {
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub x_plus: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub x_minus: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub y_plus: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub y_minus: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub z_plus: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub z_minus: RGBA8,
+    #[br(map = rgba_from_bytes)]
+    #[bw(map = rgba_to_bytes)]
+    pub specular: RGBA8,
+    pub fresnel_power: f32,
+}
+
+impl_field_struct!(DirectionalAmbient, DALC);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Weather {
     pub header: RecordHeader,
     pub edid: Option<String>,
+    pub cloud_textures: Vec<CloudTexture>,
+    pub cloud_layer_count: Option<u32>,
+    pub precipitation: Option<FormID>,
+    pub visual_effect: Option<FormID>,
+    pub unknown_onam: Option<Vec<u8>>,
+    /// One byte per cloud layer, 127 being stationary
+    pub cloud_speeds_y: Option<Vec<u8>>,
+    /// One byte per cloud layer, 127 being stationary
+    pub cloud_speeds_x: Option<Vec<u8>>,
+    pub cloud_colors: Vec<TimeOfDayColors>,
+    pub cloud_alphas: Vec<TimeOfDayAlphas>,
+    /// Indexed by colour type: sky upper, fog near, unknown, ambient,
+    /// sunlight, sun, stars, sky lower, horizon, effect lighting,
+    /// cloud LOD diffuse, cloud LOD ambient, fog far, sky statics,
+    /// water multiplier, sun glare and moon glare
+    pub colors: Vec<TimeOfDayColors>,
+    pub fog_distance: Option<FogDistance>,
+    pub data: Option<WeatherData>,
+    pub disabled_cloud_layers: Option<u32>,
+    pub sounds: Vec<WeatherSound>,
+    pub sky_statics: Vec<FormID>,
+    pub image_spaces: Option<TimeOfDayForms>,
+    pub volumetric_lighting: Option<TimeOfDayForms>,
+    /// Sunrise, day, sunset and night
+    pub directional_ambient: Vec<DirectionalAmbient>,
+    pub unknown_nam2: Option<Vec<u8>>,
+    pub unknown_nam3: Option<Vec<u8>>,
+    pub aurora: Option<Model>,
+    pub sun_glare_lens_flare: Option<FormID>,
 }
 
 impl fmt::Display for Weather {
@@ -39,10 +285,210 @@ impl TryFrom<WTHR> for Weather {
             .ok()
             .map(TryInto::try_into)
             .transpose()?;
+        let mut cloud_textures = Vec::new();
+        while let Ok(t) = CloudTextureField::read(&mut cursor) {
+            cloud_textures.push(t.try_into()?);
+        }
+        let cloud_layer_count = LNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let precipitation = MNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let visual_effect = NNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let unknown_onam = ONAM::read(&mut cursor).ok().map(|o| o.data);
+        let cloud_speeds_y = RNAM::read(&mut cursor).ok().map(|r| r.data);
+        let cloud_speeds_x = QNAM::read(&mut cursor).ok().map(|q| q.data);
+        let cloud_colors = PNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let cloud_alphas = JNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let colors = NAM0::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let fog_distance = FNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let data = DATA::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let disabled_cloud_layers = NAM1::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut sounds = Vec::new();
+        while let Ok(s) = SNAM::read(&mut cursor) {
+            sounds.push(s.try_into()?);
+        }
+        let mut sky_statics = Vec::new();
+        while let Ok(t) = TNAM::read(&mut cursor) {
+            sky_statics.push(t.try_into()?);
+        }
+        let image_spaces = IMSP::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let volumetric_lighting = HNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+        let mut directional_ambient = Vec::new();
+        while let Ok(d) = DALC::read(&mut cursor) {
+            directional_ambient.push(d.try_into()?);
+        }
+        let unknown_nam2 = NAM2::read(&mut cursor).ok().map(|n| n.data);
+        let unknown_nam3 = NAM3::read(&mut cursor).ok().map(|n| n.data);
+        let aurora = Model::try_load::<MODL, MODT, MODS>(&mut cursor, raw.header.internal_version)?;
+        let sun_glare_lens_flare = GNAM::read(&mut cursor)
+            .ok()
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        check_done_reading(&mut cursor)?;
 
         Ok(Self {
             header: raw.header,
             edid,
+            cloud_textures,
+            cloud_layer_count,
+            precipitation,
+            visual_effect,
+            unknown_onam,
+            cloud_speeds_y,
+            cloud_speeds_x,
+            cloud_colors,
+            cloud_alphas,
+            colors,
+            fog_distance,
+            data,
+            disabled_cloud_layers,
+            sounds,
+            sky_statics,
+            image_spaces,
+            volumetric_lighting,
+            directional_ambient,
+            unknown_nam2,
+            unknown_nam3,
+            aurora,
+            sun_glare_lens_flare,
+        })
+    }
+}
+
+impl TryFrom<Weather> for WTHR {
+    type Error = Error;
+
+    fn try_from(obj: Weather) -> Result<Self, Self::Error> {
+        let mut data = Cursor::new(Vec::new());
+        obj.edid.map(EDID::try_from).transpose()?.write(&mut data)?;
+        for t in obj.cloud_textures {
+            CloudTextureField::try_from(t)?.write(&mut data)?;
+        }
+        obj.cloud_layer_count
+            .map(LNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.precipitation
+            .map(MNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.visual_effect
+            .map(NNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown_onam
+            .map(|d| ONAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        obj.cloud_speeds_y
+            .map(RNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.cloud_speeds_x
+            .map(|d| QNAM {
+                size: d.len() as u16,
+                data: d,
+            })
+            .write(&mut data)?;
+        if !obj.cloud_colors.is_empty() {
+            PNAM::try_from(obj.cloud_colors)?.write(&mut data)?;
+        }
+        if !obj.cloud_alphas.is_empty() {
+            JNAM::try_from(obj.cloud_alphas)?.write(&mut data)?;
+        }
+        if !obj.colors.is_empty() {
+            NAM0::try_from(obj.colors)?.write(&mut data)?;
+        }
+        obj.fog_distance
+            .map(FNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.data.map(DATA::try_from).transpose()?.write(&mut data)?;
+        obj.disabled_cloud_layers
+            .map(NAM1::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for s in obj.sounds {
+            SNAM::try_from(s)?.write(&mut data)?;
+        }
+        for t in obj.sky_statics {
+            TNAM::try_from(t)?.write(&mut data)?;
+        }
+        obj.image_spaces
+            .map(IMSP::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.volumetric_lighting
+            .map(HNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        for d in obj.directional_ambient {
+            DALC::try_from(d)?.write(&mut data)?;
+        }
+        obj.unknown_nam2
+            .map(NAM2::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        obj.unknown_nam3
+            .map(NAM3::try_from)
+            .transpose()?
+            .write(&mut data)?;
+        if let Some(m) = obj.aurora {
+            m.write::<MODL, MODT, MODS>(&mut data)?;
+        }
+        obj.sun_glare_lens_flare
+            .map(GNAM::try_from)
+            .transpose()?
+            .write(&mut data)?;
+
+        let data = make_data(
+            data.into_inner(),
+            obj.header.flags.contains(Flags::COMPRESSED),
+        )?;
+
+        Ok(Self {
+            header: RecordHeader {
+                size: data.len() as u32,
+                ..obj.header
+            },
+            data,
         })
     }
 }
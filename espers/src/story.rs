@@ -0,0 +1,232 @@
+use crate::common::FormID;
+use crate::game::Game;
+use crate::plugin::Plugin;
+use crate::records::Record;
+use std::collections::{HashMap, HashSet};
+
+/// What a node of the story manager tree does
+#[derive(Debug, Clone)]
+pub enum StoryNodeKind {
+    Branch,
+    /// Root of the nodes handling a story event, e.g. `ADIA` or `CHRR`
+    Event(String),
+    /// Quests started by the node
+    Quest(Vec<FormID>),
+}
+
+#[derive(Debug, Clone)]
+pub struct StoryNode {
+    pub form_id: FormID,
+    pub edid: Option<String>,
+    pub kind: StoryNodeKind,
+    /// Ordered by their previous sibling links
+    pub children: Vec<StoryNode>,
+}
+
+impl StoryNode {
+    /// Every quest started by this node or its descendants, in tree order
+    pub fn quests(&self) -> Vec<FormID> {
+        let mut quests = match &self.kind {
+            StoryNodeKind::Quest(q) => q.clone(),
+            _ => Vec::new(),
+        };
+        for c in &self.children {
+            quests.extend(c.quests());
+        }
+        quests
+    }
+}
+
+/// A story manager node before it is placed in the tree
+struct FlatNode {
+    form_id: FormID,
+    edid: Option<String>,
+    kind: StoryNodeKind,
+    parent: Option<FormID>,
+    previous_sibling: Option<FormID>,
+}
+
+impl FlatNode {
+    /// Returns the record as a node, with its links converted by `resolve`
+    fn load<F: Fn(&FormID) -> Option<FormID>>(record: &Record, resolve: F) -> Option<Self> {
+        let (edid, kind, parent, previous_sibling) = match record {
+            Record::StoryManagerBranchNode(n) => (
+                n.edid.clone(),
+                StoryNodeKind::Branch,
+                &n.parent,
+                &n.previous_sibling,
+            ),
+            Record::StoryManagerEventNode(n) => (
+                n.edid.clone(),
+                StoryNodeKind::Event(
+                    n.event
+                        .map(|e| String::from_utf8_lossy(&e).into_owned())
+                        .unwrap_or_default(),
+                ),
+                &n.parent,
+                &n.previous_sibling,
+            ),
+            Record::StoryManagerQuestNode(n) => (
+                n.edid.clone(),
+                StoryNodeKind::Quest(n.quests.iter().filter_map(|q| resolve(&q.quest)).collect()),
+                &n.parent,
+                &n.previous_sibling,
+            ),
+            _ => return None,
+        };
+
+        Some(Self {
+            form_id: FormID(record.form_id()?),
+            edid,
+            kind,
+            parent: parent.as_ref().and_then(&resolve),
+            previous_sibling: previous_sibling.as_ref().and_then(&resolve),
+        })
+    }
+}
+
+/// Labels of the top-level groups holding story manager nodes
+const NODE_GROUPS: [&[u8; 4]; 3] = [b"SMBN", b"SMQN", b"SMEN"];
+
+/// Whether `fid` is filed under one of the story manager node groups of
+/// `plugin`, checked without resolving the record itself
+fn is_node(plugin: &Plugin, fid: &FormID) -> bool {
+    plugin
+        .form_ids
+        .get(&fid.0)
+        .and_then(|key| plugin.records.get(*key.first()?))
+        .is_some_and(|r| match r {
+            Ok(Record::Group(g)) => NODE_GROUPS.contains(&&g.header.label.to_le_bytes()),
+            _ => false,
+        })
+}
+
+/// The story manager nodes of a plugin or game, arranged by their parent
+/// and sibling links
+#[derive(Debug, Clone, Default)]
+pub struct StoryManagerTree {
+    pub roots: Vec<StoryNode>,
+}
+
+impl StoryManagerTree {
+    pub fn from_plugin(plugin: &Plugin) -> Self {
+        let nodes = plugin
+            .form_ids
+            .keys()
+            .map(|fid| FormID(*fid))
+            .filter(|fid| is_node(plugin, fid))
+            .filter_map(|fid| plugin.get_record_by_form_id(&fid)?.as_ref().ok())
+            .filter_map(|r| FlatNode::load(r, |fid| Some(fid.clone())))
+            .collect();
+        Self::build(nodes)
+    }
+
+    /// Uses the winning version of every node, addressed by load order FormID
+    pub fn from_game(game: &Game) -> Self {
+        let nodes = game
+            .conflicts()
+            .iter()
+            .filter_map(|c| {
+                let plugin = &c.winner().plugin;
+                let local = game.local_form_id(plugin, &c.form_id)?;
+                if !is_node(&game.plugins()[plugin], &local) {
+                    return None;
+                }
+                let record = game.get_record_by_form_id(&c.form_id)?.as_ref().ok()?;
+                let mut node = FlatNode::load(record, |fid| game.global_form_id(plugin, fid))?;
+                node.form_id = c.form_id.clone();
+                Some(node)
+            })
+            .collect();
+        Self::build(nodes)
+    }
+
+    fn build(nodes: Vec<FlatNode>) -> Self {
+        let ids: HashSet<u32> = nodes.iter().map(|n| n.form_id.0).collect();
+        let mut children: HashMap<u32, Vec<FlatNode>> = HashMap::new();
+        let mut roots = Vec::new();
+        for n in nodes {
+            match n.parent.as_ref().filter(|p| ids.contains(&p.0)) {
+                Some(p) => children.entry(p.0).or_default().push(n),
+                None => roots.push(n),
+            }
+        }
+
+        let mut roots: Vec<_> = order(roots)
+            .into_iter()
+            .map(|n| attach(n, &mut children))
+            .collect();
+
+        // Nodes left over have parent links forming a cycle, so can't be
+        // reached from a root. Each cycle is broken at its lowest FormID.
+        while let Some(n) = take_lowest(&mut children) {
+            roots.push(attach(n, &mut children));
+        }
+
+        Self { roots }
+    }
+
+    /// Returns the event node handling `event`
+    pub fn event(&self, event: &str) -> Option<&StoryNode> {
+        self.roots
+            .iter()
+            .find(|n| matches!(&n.kind, StoryNodeKind::Event(e) if e == event))
+    }
+
+    /// Every quest that may be started by `event`, in the order the story
+    /// manager tries them
+    pub fn quests_for_event(&self, event: &str) -> Vec<FormID> {
+        self.event(event).map(StoryNode::quests).unwrap_or_default()
+    }
+}
+
+/// Sorts siblings by following their previous sibling links. Nodes with
+/// broken links are placed in FormID order.
+fn order(mut siblings: Vec<FlatNode>) -> Vec<FlatNode> {
+    siblings.sort_by_key(|n| n.form_id.0);
+    let ids: HashSet<u32> = siblings.iter().map(|n| n.form_id.0).collect();
+    let mut ordered = Vec::with_capacity(siblings.len());
+    let mut last = None;
+
+    while !siblings.is_empty() {
+        let i = siblings
+            .iter()
+            .position(|n| {
+                let previous = n
+                    .previous_sibling
+                    .as_ref()
+                    .map(|p| p.0)
+                    .filter(|p| ids.contains(p));
+                previous == last
+            })
+            .unwrap_or(0);
+        let n = siblings.remove(i);
+        last = Some(n.form_id.0);
+        ordered.push(n);
+    }
+    ordered
+}
+
+fn attach(node: FlatNode, children: &mut HashMap<u32, Vec<FlatNode>>) -> StoryNode {
+    let own = order(children.remove(&node.form_id.0).unwrap_or_default());
+    StoryNode {
+        form_id: node.form_id,
+        edid: node.edid,
+        kind: node.kind,
+        children: own.into_iter().map(|c| attach(c, children)).collect(),
+    }
+}
+
+/// Removes the node with the lowest FormID from `children`
+fn take_lowest(children: &mut HashMap<u32, Vec<FlatNode>>) -> Option<FlatNode> {
+    let (_, parent, i) = children
+        .iter()
+        .flat_map(|(p, c)| c.iter().enumerate().map(move |(i, n)| (n.form_id.0, *p, i)))
+        .min()?;
+    let siblings = children.get_mut(&parent)?;
+    let node = siblings.remove(i);
+    if siblings.is_empty() {
+        children.remove(&parent);
+    }
+    Some(node)
+}
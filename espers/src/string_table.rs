@@ -182,6 +182,7 @@ impl StringTable {
     }
 }
 
+#[derive(Default)]
 pub struct StringTables {
     pub tables: HashMap<(String, TableType), StringTable>,
 }
@@ -0,0 +1,148 @@
+#![allow(dead_code)]
+
+use espers::plugin::Plugin;
+use std::io::Cursor;
+
+pub mod records;
+
+pub const MASTER: u32 = 0x00000001;
+pub const LIGHT_MASTER: u32 = 0x00000200;
+
+pub fn subrecord(magic: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut bytes = magic.to_vec();
+    bytes.extend((data.len() as u16).to_le_bytes());
+    bytes.extend(data);
+    bytes
+}
+
+pub fn record(magic: &[u8; 4], flags: u32, form_id: u32, data: &[u8]) -> Vec<u8> {
+    let mut bytes = magic.to_vec();
+    bytes.extend((data.len() as u32).to_le_bytes());
+    bytes.extend(flags.to_le_bytes());
+    bytes.extend(form_id.to_le_bytes());
+    bytes.extend([0u8; 4]);
+    bytes.extend(44u16.to_le_bytes());
+    bytes.extend([0u8; 2]);
+    bytes.extend(data);
+    bytes
+}
+
+pub fn group(label: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut bytes = b"GRUP".to_vec();
+    bytes.extend((data.len() as u32 + 24).to_le_bytes());
+    bytes.extend(label);
+    bytes.extend(0i32.to_le_bytes());
+    bytes.extend([0u8; 8]);
+    bytes.extend(data);
+    bytes
+}
+
+fn zstring(s: &str) -> Vec<u8> {
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
+
+/// A KYWD record with an editor ID and colour
+pub fn keyword(form_id: u32, edid: &str, color: [u8; 4]) -> Vec<u8> {
+    let mut data = subrecord(b"EDID", &zstring(edid));
+    data.extend(subrecord(b"CNAM", &color));
+    record(b"KYWD", 0, form_id, &data)
+}
+
+/// A FLST record listing `objects`
+pub fn form_list(form_id: u32, edid: &str, objects: &[u32]) -> Vec<u8> {
+    let mut data = subrecord(b"EDID", &zstring(edid));
+    for object in objects {
+        data.extend(subrecord(b"LNAM", &object.to_le_bytes()));
+    }
+    record(b"FLST", 0, form_id, &data)
+}
+
+/// A LVLI record; each entry is a level, a reference and a count
+pub fn leveled_item(
+    form_id: u32,
+    chance_none: u8,
+    flags: u8,
+    global: Option<u32>,
+    entries: &[(u16, u32, u16)],
+) -> Vec<u8> {
+    let mut data = subrecord(b"LVLD", &[chance_none]);
+    data.extend(subrecord(b"LVLF", &[flags]));
+    if let Some(global) = global {
+        data.extend(subrecord(b"LVLG", &global.to_le_bytes()));
+    }
+    data.extend(subrecord(b"LLCT", &[entries.len() as u8]));
+    for (level, reference, count) in entries {
+        let mut entry = level.to_le_bytes().to_vec();
+        entry.extend([0u8; 2]);
+        entry.extend(reference.to_le_bytes());
+        entry.extend(count.to_le_bytes());
+        entry.extend([0u8; 2]);
+        data.extend(subrecord(b"LVLO", &entry));
+    }
+    record(b"LVLI", 0, form_id, &data)
+}
+
+/// A float GLOB record
+pub fn global(form_id: u32, edid: &str, value: f32) -> Vec<u8> {
+    let mut data = subrecord(b"EDID", &zstring(edid));
+    data.extend(subrecord(b"FNAM", b"f"));
+    data.extend(subrecord(b"FLTV", &value.to_le_bytes()));
+    record(b"GLOB", 0, form_id, &data)
+}
+
+/// A story manager node record (SMBN, SMEN or SMQN), with its type specific
+/// subrecords in `extra`
+pub fn story_node(
+    magic: &[u8; 4],
+    form_id: u32,
+    parent: Option<u32>,
+    previous_sibling: Option<u32>,
+    extra: &[u8],
+) -> Vec<u8> {
+    let mut data = Vec::new();
+    if let Some(parent) = parent {
+        data.extend(subrecord(b"PNAM", &parent.to_le_bytes()));
+    }
+    if let Some(previous) = previous_sibling {
+        data.extend(subrecord(b"SNAM", &previous.to_le_bytes()));
+    }
+    data.extend(extra);
+    record(magic, 0, form_id, &data)
+}
+
+/// Serializes a plugin, putting each record in a top level group for its
+/// record type
+pub fn plugin_bytes(flags: u32, masters: &[&str], records: &[Vec<u8>]) -> Vec<u8> {
+    let mut groups: Vec<([u8; 4], Vec<u8>)> = Vec::new();
+    for rec in records {
+        let magic: [u8; 4] = rec[..4].try_into().unwrap();
+        match groups.iter_mut().find(|(m, _)| *m == magic) {
+            Some((_, data)) => data.extend(rec),
+            None => groups.push((magic, rec.clone())),
+        }
+    }
+
+    let mut hedr = 1.71f32.to_le_bytes().to_vec();
+    hedr.extend(((records.len() + groups.len()) as u32).to_le_bytes());
+    hedr.extend(0x800u32.to_le_bytes());
+    let mut header = subrecord(b"HEDR", &hedr);
+    for master in masters {
+        header.extend(subrecord(b"MAST", &zstring(master)));
+        header.extend(subrecord(b"DATA", &[0u8; 8]));
+    }
+    header.extend(subrecord(b"INTV", &0u32.to_le_bytes()));
+
+    let mut bytes = record(b"TES4", flags, 0, &header);
+    for (magic, data) in groups {
+        bytes.extend(group(&magic, &data));
+    }
+
+    bytes
+}
+
+/// Builds a plugin in memory, as [plugin_bytes] would write it
+pub fn plugin(flags: u32, masters: &[&str], records: &[Vec<u8>]) -> Plugin {
+    Plugin::parse(&mut Cursor::new(plugin_bytes(flags, masters, records))).unwrap()
+}
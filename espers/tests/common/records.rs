@@ -0,0 +1,2947 @@
+//! Sample records for every decoded record type, built subrecord by
+//! subrecord in the order the readers expect them
+
+use super::{plugin_bytes, record, subrecord, zstring};
+use espers::plugin::Plugin;
+use espers::records::Record;
+use std::io::Cursor;
+
+pub const FORM_ID: u32 = 0x00000800;
+
+fn u32s(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn f32s(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn edid(name: &str) -> Vec<u8> {
+    subrecord(b"EDID", &zstring(name))
+}
+
+fn string(magic: &[u8; 4], value: &str) -> Vec<u8> {
+    subrecord(magic, &zstring(value))
+}
+
+fn form_id(magic: &[u8; 4], value: u32) -> Vec<u8> {
+    subrecord(magic, &value.to_le_bytes())
+}
+
+fn float(magic: &[u8; 4], value: f32) -> Vec<u8> {
+    subrecord(magic, &value.to_le_bytes())
+}
+
+fn bounds() -> Vec<u8> {
+    let bounds: Vec<u8> = [-8i16, -16, 0, 8, 16, 32]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    subrecord(b"OBND", &bounds)
+}
+
+/// Model texture hashes with one entry of each kind
+fn model_textures() -> Vec<u8> {
+    let mut textures = u32s(&[1, 1, 1]);
+    textures.extend(u32s(&[0x11, 0x22, 0x33]));
+    textures.extend(u32s(&[0x44]));
+    textures
+}
+
+/// A model with textures and an alternate texture, using the given magics
+/// for the file name, texture and alternate texture subrecords
+fn model_with(magics: [&[u8; 4]; 3], path: &str) -> Vec<u8> {
+    let mut alternate = u32s(&[1, 4]);
+    alternate.extend(b"Root");
+    alternate.extend(u32s(&[0x00000D01, 0]));
+
+    let mut data = string(magics[0], path);
+    data.extend(subrecord(magics[1], &model_textures()));
+    data.extend(subrecord(magics[2], &alternate));
+    data
+}
+
+fn model() -> Vec<u8> {
+    model_with([b"MODL", b"MODT", b"MODS"], "meshes\\test.nif")
+}
+
+fn keywords(keywords: &[u32]) -> Vec<u8> {
+    let mut data = form_id(b"KSIZ", keywords.len() as u32);
+    data.extend(subrecord(b"KWDA", &u32s(keywords)));
+    data
+}
+
+/// A script with one property of each simple type
+fn script() -> Vec<u8> {
+    fn property(name: &str, kind: u8, value: &[u8]) -> Vec<u8> {
+        let mut data = (name.len() as u16).to_le_bytes().to_vec();
+        data.extend(name.as_bytes());
+        data.push(kind);
+        data.push(1);
+        data.extend(value);
+        data
+    }
+
+    let mut data = 10u16.to_le_bytes().to_vec();
+    data.extend(b"TestScript");
+    data.push(0);
+    data.extend(5u16.to_le_bytes());
+    data.extend(property("Target", 1, &u32s(&[0xFFFF0000, 0x00000D02])));
+    let mut text = 5u16.to_le_bytes().to_vec();
+    text.extend(b"Hello");
+    data.extend(property("Text", 2, &text));
+    data.extend(property("Count", 3, &7i32.to_le_bytes()));
+    data.extend(property("Scale", 4, &1.5f32.to_le_bytes()));
+    data.extend(property("Enabled", 5, &[1]));
+    data
+}
+
+/// A script list (version 5, object format 2) holding [script]
+fn scripts_data() -> Vec<u8> {
+    let mut data: Vec<u8> = [5u16, 2, 1].iter().flat_map(|v| v.to_le_bytes()).collect();
+    data.extend(script());
+    data
+}
+
+fn wstring(value: &str) -> Vec<u8> {
+    let mut data = (value.len() as u16).to_le_bytes().to_vec();
+    data.extend(value.as_bytes());
+    data
+}
+
+fn scripts() -> Vec<u8> {
+    subrecord(b"VMAD", &scripts_data())
+}
+
+fn destruction() -> Vec<u8> {
+    let mut header = 100u32.to_le_bytes().to_vec();
+    header.extend([1, 0, 0, 0]);
+    let mut stage = vec![50, 0, 1, 0];
+    stage.extend(u32s(&[2, 0x00000D03, 0x00000D04, 3]));
+
+    let mut data = subrecord(b"DEST", &header);
+    data.extend(subrecord(b"DSTD", &stage));
+    data.extend(string(b"DMDL", "meshes\\destroyed.nif"));
+    data.extend(subrecord(b"DSTF", &[]));
+    data
+}
+
+fn condition_data(function: u16, reference: u32) -> Vec<u8> {
+    let mut data = vec![0x40, 0, 0, 0];
+    data.extend(1.0f32.to_le_bytes());
+    data.extend(function.to_le_bytes());
+    data.extend([0, 0]);
+    data.extend(u32s(&[0x00000D05, 0, 0, reference]));
+    data.extend((-1i32).to_le_bytes());
+    data
+}
+
+fn condition() -> Vec<u8> {
+    subrecord(b"CTDA", &condition_data(72, 0))
+}
+
+fn effect() -> Vec<u8> {
+    let mut data = form_id(b"EFID", 0x00000D06);
+    data.extend(subrecord(
+        b"EFIT",
+        &[f32s(&[25.0]), u32s(&[0, 30])].concat(),
+    ));
+    data.extend(condition());
+    data
+}
+
+fn sample(magic: &[u8; 4], parts: &[Vec<u8>]) -> Vec<u8> {
+    record(magic, 0, FORM_ID, &parts.concat())
+}
+
+pub fn aact() -> Vec<u8> {
+    sample(
+        b"AACT",
+        &[
+            edid("ActionTest"),
+            subrecord(b"CNAM", &[0x10, 0x20, 0x30, 0]),
+        ],
+    )
+}
+
+pub fn achr() -> Vec<u8> {
+    sample(
+        b"ACHR",
+        &[
+            edid("ActorRefTest"),
+            form_id(b"NAME", 0x00000D07),
+            form_id(b"XEZN", 0x00000D08),
+            form_id(b"XOWN", 0x00000D09),
+            float(b"XSCL", 1.25),
+            subrecord(b"DATA", &f32s(&[1.0, 2.0, 3.0, 0.0, 0.0, 1.5])),
+        ],
+    )
+}
+
+pub fn acti() -> Vec<u8> {
+    sample(
+        b"ACTI",
+        &[
+            edid("ActivatorTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Lever"),
+            model(),
+            destruction(),
+            keywords(&[0x00000D0A, 0x00000D0B]),
+            subrecord(b"PNAM", &[1, 2, 3, 0]),
+            form_id(b"SNAM", 0x00000D0C),
+            form_id(b"VNAM", 0x00000D0D),
+            form_id(b"WNAM", 0x00000D0E),
+            string(b"RNAM", "Pull"),
+            subrecord(b"FNAM", &3u16.to_le_bytes()),
+            form_id(b"KNAM", 0x00000D0F),
+        ],
+    )
+}
+
+pub fn addn() -> Vec<u8> {
+    sample(
+        b"ADDN",
+        &[
+            edid("AddonNodeTest"),
+            bounds(),
+            model(),
+            form_id(b"DATA", 4),
+            form_id(b"SNAM", 0x00000D10),
+            subrecord(b"DNAM", &u32s(&[0x0002_0010])),
+        ],
+    )
+}
+
+pub fn alch() -> Vec<u8> {
+    sample(
+        b"ALCH",
+        &[
+            edid("AlchemyTest"),
+            bounds(),
+            string(b"FULL", "Potion"),
+            keywords(&[0x00000D11]),
+            string(b"MODL", "meshes\\potion.nif"),
+            subrecord(b"MODT", &u32s(&[1, 2, 3])),
+            string(b"ICON", "potion.dds"),
+            form_id(b"YNAM", 0x00000D12),
+            form_id(b"ZNAM", 0x00000D13),
+            float(b"DATA", 0.5),
+            subrecord(b"ENIT", &u32s(&[25, 1, 0, 0, 0x00000D14])),
+            effect(),
+            effect(),
+        ],
+    )
+}
+
+pub fn ammo() -> Vec<u8> {
+    sample(
+        b"AMMO",
+        &[
+            edid("AmmoTest"),
+            bounds(),
+            string(b"FULL", "Arrow"),
+            model(),
+            destruction(),
+            form_id(b"YNAM", 0x00000D15),
+            form_id(b"ZNAM", 0x00000D16),
+            string(b"DESC", "Sharp"),
+            keywords(&[0x00000D17]),
+            subrecord(
+                b"DATA",
+                &[u32s(&[0x00000D18, 0]), f32s(&[10.0]), u32s(&[1])].concat(),
+            ),
+            string(b"ONAM", "Arr"),
+        ],
+    )
+}
+
+pub fn anio() -> Vec<u8> {
+    sample(
+        b"ANIO",
+        &[
+            edid("AnimatedObjectTest"),
+            model(),
+            string(b"BNAM", "Unload"),
+        ],
+    )
+}
+
+pub fn appa() -> Vec<u8> {
+    sample(
+        b"APPA",
+        &[
+            edid("ApparatusTest"),
+            bounds(),
+            string(b"FULL", "Mortar"),
+            model(),
+            string(b"ICON", "mortar.dds"),
+            destruction(),
+            form_id(b"YNAM", 0x00000D19),
+            form_id(b"ZNAM", 0x00000D1A),
+            form_id(b"QUAL", 2),
+            string(b"DESC", "Grinds"),
+            subrecord(b"DATA", &[u32s(&[10]), f32s(&[2.5])].concat()),
+        ],
+    )
+}
+
+pub fn arma() -> Vec<u8> {
+    sample(
+        b"ARMA",
+        &[
+            edid("ArmorAddonTest"),
+            subrecord(b"BOD2", &u32s(&[0x4, 2])),
+            form_id(b"RNAM", 0x00000D1B),
+            subrecord(b"DNAM", &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]),
+            model_with([b"MOD2", b"MO2T", b"MO2S"], "meshes\\male.nif"),
+            model_with([b"MOD3", b"MO3T", b"MO3S"], "meshes\\female.nif"),
+            form_id(b"NAM0", 0x00000D1C),
+            form_id(b"NAM1", 0x00000D1D),
+            form_id(b"MODL", 0x00000D1E),
+            form_id(b"MODL", 0x00000D1F),
+            form_id(b"SNDD", 0x00000D20),
+            form_id(b"ONAM", 0x00000D21),
+        ],
+    )
+}
+
+pub fn armo() -> Vec<u8> {
+    sample(
+        b"ARMO",
+        &[
+            edid("ArmorTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Cuirass"),
+            form_id(b"EITM", 0x00000D22),
+            subrecord(b"EAMT", &50u16.to_le_bytes()),
+            model_with([b"MOD2", b"MO2T", b"MO2S"], "meshes\\cuirass.nif"),
+            string(b"ICON", "cuirass.dds"),
+            subrecord(b"BOD2", &u32s(&[0x4, 1])),
+            destruction(),
+            form_id(b"YNAM", 0x00000D23),
+            form_id(b"ZNAM", 0x00000D24),
+            form_id(b"ETYP", 0x00000D25),
+            form_id(b"RNAM", 0x00000D26),
+            keywords(&[0x00000D27]),
+            string(b"DESC", "Sturdy"),
+            form_id(b"MODL", 0x00000D28),
+            subrecord(b"DATA", &[u32s(&[100]), f32s(&[20.0])].concat()),
+            form_id(b"DNAM", 2500),
+            form_id(b"TNAM", 0x00000D29),
+        ],
+    )
+}
+
+pub fn arto() -> Vec<u8> {
+    sample(
+        b"ARTO",
+        &[
+            edid("ArtObjectTest"),
+            bounds(),
+            model(),
+            form_id(b"DNAM", 2),
+        ],
+    )
+}
+
+pub fn aspc() -> Vec<u8> {
+    sample(
+        b"ASPC",
+        &[
+            edid("AcousticSpaceTest"),
+            bounds(),
+            form_id(b"SNAM", 0x00000D2A),
+            form_id(b"RDAT", 0x00000D2B),
+            form_id(b"BNAM", 0x00000D2C),
+        ],
+    )
+}
+
+pub fn astp() -> Vec<u8> {
+    sample(
+        b"ASTP",
+        &[
+            edid("AssociationTest"),
+            string(b"MPRT", "Father"),
+            string(b"FPRT", "Mother"),
+            string(b"MCHT", "Son"),
+            string(b"FCHT", "Daughter"),
+            form_id(b"DATA", 1),
+        ],
+    )
+}
+
+pub fn avif() -> Vec<u8> {
+    sample(
+        b"AVIF",
+        &[
+            edid("ActorValueTest"),
+            string(b"FULL", "Smithing"),
+            string(b"DESC", "Making things"),
+            string(b"ANAM", "SMT"),
+            form_id(b"CNAM", 4),
+            subrecord(b"AVSK", &f32s(&[1.0, 0.0, 1.5, 0.0])),
+            form_id(b"PNAM", 0x00000D2D),
+            form_id(b"FNAM", 0),
+            form_id(b"XNAM", 2),
+            form_id(b"YNAM", 3),
+            float(b"HNAM", 0.5),
+            float(b"VNAM", 0.25),
+            form_id(b"SNAM", 0x00000D2E),
+            form_id(b"CNAM", 1),
+            form_id(b"INAM", 0),
+        ],
+    )
+}
+
+pub fn book() -> Vec<u8> {
+    sample(
+        b"BOOK",
+        &[
+            edid("BookTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Journal"),
+            model(),
+            string(b"ICON", "book.dds"),
+            string(b"DESC", "Dear diary"),
+            destruction(),
+            form_id(b"YNAM", 0x00000D30),
+            form_id(b"ZNAM", 0x00000D31),
+            keywords(&[0x00000D32]),
+            subrecord(
+                b"DATA",
+                &[vec![0x01, 2, 0, 0], u32s(&[0x00000D33, 25]), f32s(&[1.0])].concat(),
+            ),
+            form_id(b"INAM", 0x00000D34),
+            string(b"CNAM", "A journal"),
+        ],
+    )
+}
+
+pub fn bptd() -> Vec<u8> {
+    let node_data: Vec<u32> = (0..21).collect();
+
+    sample(
+        b"BPTD",
+        &[
+            edid("BodyPartTest"),
+            model(),
+            string(b"BPTN", "Head"),
+            string(b"BPNN", "NPC Head [Head]"),
+            string(b"BPNT", "HeadTarget"),
+            string(b"BPNI", "HeadInfo"),
+            subrecord(b"BPND", &u32s(&node_data)),
+            string(b"NAM1", "meshes\\head_limb.nif"),
+            string(b"NAM4", "HeadGore"),
+            subrecord(b"NAM5", &[0x01, 0x02, 0x03, 0x04]),
+            form_id(b"RAGA", 0x00000F00),
+        ],
+    )
+}
+
+pub fn cams() -> Vec<u8> {
+    sample(
+        b"CAMS",
+        &[
+            edid("CameraShotTest"),
+            model(),
+            subrecord(b"DATA", &[u32s(&[1, 2, 3]), f32s(&[0.5, 1.5])].concat()),
+            form_id(b"MNAM", 0x00000D35),
+        ],
+    )
+}
+
+pub fn cell() -> Vec<u8> {
+    let mut lighting = [0x10u8; 12].to_vec();
+    lighting.extend(f32s(&[64.0, 1024.0]));
+    lighting.extend([0u8; 8]);
+    lighting.extend(f32s(&[1.0, 2048.0, 1.0]));
+    lighting.extend([0x20u8; 24]);
+    lighting.extend([0x30u8; 4]);
+    lighting.extend(u32s(&[1]));
+    lighting.extend([0x40u8; 4]);
+    lighting.extend(f32s(&[0.5, 100.0, 200.0]));
+    lighting.extend(u32s(&[0x3]));
+
+    sample(
+        b"CELL",
+        &[
+            edid("CellTest"),
+            string(b"FULL", "Cave"),
+            subrecord(b"DATA", &0x0001u16.to_le_bytes()),
+            subrecord(b"XCLL", &lighting),
+            form_id(b"LTMP", 0x00000D36),
+            form_id(b"LNAM", 0x1F),
+            float(b"XCLW", -200.0),
+            subrecord(b"XCLR", &u32s(&[0x00000D37, 0x00000D38])),
+            form_id(b"XLCN", 0x00000D39),
+            form_id(b"XOWN", 0x00000D3A),
+            form_id(b"XCCM", 0x00000D3B),
+            form_id(b"XCAS", 0x00000D3C),
+            form_id(b"XEZN", 0x00000D3D),
+            form_id(b"XCMO", 0x00000D3E),
+            form_id(b"XCIM", 0x00000D3F),
+        ],
+    )
+}
+
+pub fn clas() -> Vec<u8> {
+    let mut data = u32s(&[0]);
+    data.extend([7, 50]);
+    data.extend([1u8; 18]);
+    data.extend(f32s(&[0.1]));
+    data.extend(u32s(&[3]));
+    data.extend([1, 2, 3, 0]);
+
+    sample(
+        b"CLAS",
+        &[
+            edid("ClassTest"),
+            string(b"FULL", "Warrior"),
+            string(b"DESC", "Fights"),
+            string(b"ICON", "warrior.dds"),
+            subrecord(b"DATA", &data),
+        ],
+    )
+}
+
+pub fn clfm() -> Vec<u8> {
+    sample(
+        b"CLFM",
+        &[
+            edid("ColorTest"),
+            string(b"FULL", "Red"),
+            subrecord(b"CNAM", &[0xFF, 0, 0, 0]),
+            form_id(b"FNAM", 1),
+        ],
+    )
+}
+
+pub fn clmt() -> Vec<u8> {
+    sample(
+        b"CLMT",
+        &[
+            edid("ClimateTest"),
+            subrecord(
+                b"WLST",
+                &u32s(&[0x00000D40, 60, 0, 0x00000D41, 40, 0x00000D42]),
+            ),
+            string(b"FNAM", "sun.dds"),
+            string(b"GNAM", "glare.dds"),
+            string(b"MODL", "sky.nif"),
+            subrecord(b"MODT", &u32s(&[0, 0, 1, 0x55])),
+            subrecord(b"TNAM", &[30, 40, 110, 120, 10, 0x43]),
+        ],
+    )
+}
+
+pub fn cobj() -> Vec<u8> {
+    sample(
+        b"COBJ",
+        &[
+            edid("RecipeTest"),
+            form_id(b"COCT", 2),
+            subrecord(b"CNTO", &u32s(&[0x00000D43, 2])),
+            subrecord(b"CNTO", &u32s(&[0x00000D44, 1])),
+            condition(),
+            form_id(b"CNAM", 0x00000D45),
+            form_id(b"BNAM", 0x00000D46),
+            subrecord(b"NAM1", &1u16.to_le_bytes()),
+        ],
+    )
+}
+
+pub fn coll() -> Vec<u8> {
+    sample(
+        b"COLL",
+        &[
+            edid("CollisionLayerTest"),
+            string(b"DESC", "Solid things"),
+            form_id(b"BNAM", 5),
+            subrecord(b"FNAM", &[0x80, 0x40, 0x20, 0]),
+            form_id(b"GNAM", 1),
+            string(b"MNAM", "L_TEST"),
+            form_id(b"INTV", 2),
+            subrecord(b"CNAM", &u32s(&[0x00000D47, 0x00000D48])),
+        ],
+    )
+}
+
+pub fn cont() -> Vec<u8> {
+    let mut extra = u32s(&[0x00000D4A, 0x00000D4B]);
+    extra.extend(f32s(&[1.0]));
+
+    sample(
+        b"CONT",
+        &[
+            edid("ContainerTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Chest"),
+            model(),
+            form_id(b"COCT", 2),
+            subrecord(b"CNTO", &u32s(&[0x00000D49, 5])),
+            subrecord(b"COED", &extra),
+            subrecord(b"CNTO", &u32s(&[0x00000D4C, 1])),
+            subrecord(b"DATA", &[0x02, 0, 0, 0, 0]),
+            form_id(b"SNAM", 0x00000D4D),
+            form_id(b"QNAM", 0x00000D4E),
+        ],
+    )
+}
+
+pub fn cpth() -> Vec<u8> {
+    sample(
+        b"CPTH",
+        &[
+            edid("CameraPathTest"),
+            condition(),
+            subrecord(b"ANAM", &u32s(&[0x00000D4F, 0])),
+            subrecord(b"DATA", &[1]),
+            form_id(b"SNAM", 0x00000D50),
+            form_id(b"SNAM", 0x00000D51),
+        ],
+    )
+}
+
+pub fn csty() -> Vec<u8> {
+    sample(
+        b"CSTY",
+        &[
+            edid("CombatStyleTest"),
+            subrecord(
+                b"CSGD",
+                &f32s(&[0.5, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.2]),
+            ),
+            subrecord(b"CSME", &f32s(&[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8])),
+            subrecord(b"CSCR", &f32s(&[0.2, 0.1, 0.5, 0.25])),
+            float(b"CSLR", 0.3),
+            subrecord(b"CSFL", &f32s(&[0.5, 0.1, 0.1, 3.0, 2.0, 0.1, 1.0, 0.5])),
+            form_id(b"DATA", 1),
+            subrecord(b"CSMD", &f32s(&[1.0, 2.0])),
+        ],
+    )
+}
+
+pub fn debr() -> Vec<u8> {
+    let mut data = vec![60];
+    data.extend(zstring("meshes\\debris.nif"));
+    data.push(1);
+
+    sample(
+        b"DEBR",
+        &[
+            edid("DebrisTest"),
+            subrecord(b"DATA", &data),
+            subrecord(b"MODT", &u32s(&[1, 2, 3])),
+        ],
+    )
+}
+
+pub fn dial() -> Vec<u8> {
+    sample(
+        b"DIAL",
+        &[
+            edid("TopicTest"),
+            string(b"FULL", "Hello"),
+            float(b"PNAM", 50.0),
+            form_id(b"BNAM", 0x00000D52),
+            form_id(b"QNAM", 0x00000D53),
+            subrecord(b"DATA", &[0, 3, 14, 0]),
+            subrecord(b"SNAM", b"CUST"),
+            form_id(b"TIFC", 2),
+        ],
+    )
+}
+
+pub fn dlbr() -> Vec<u8> {
+    sample(
+        b"DLBR",
+        &[
+            edid("BranchTest"),
+            form_id(b"QNAM", 0x00000D54),
+            form_id(b"TNAM", 0),
+            form_id(b"DNAM", 1),
+            form_id(b"SNAM", 0x00000D55),
+        ],
+    )
+}
+
+pub fn dlvw() -> Vec<u8> {
+    sample(
+        b"DLVW",
+        &[
+            edid("ViewTest"),
+            form_id(b"QNAM", 0x00000D56),
+            form_id(b"BNAM", 0x00000D57),
+            form_id(b"TNAM", 0x00000D58),
+            form_id(b"TNAM", 0x00000D59),
+            form_id(b"ENAM", 0),
+            subrecord(b"DNAM", &[1]),
+        ],
+    )
+}
+
+pub fn dobj() -> Vec<u8> {
+    let mut objects = b"WWSP".to_vec();
+    objects.extend(0x00000D5Au32.to_le_bytes());
+    objects.extend(b"FTHD");
+    objects.extend(0x00000D5Bu32.to_le_bytes());
+
+    sample(b"DOBJ", &[subrecord(b"DNAM", &objects)])
+}
+
+pub fn door() -> Vec<u8> {
+    sample(
+        b"DOOR",
+        &[
+            edid("DoorTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Door"),
+            model(),
+            form_id(b"SNAM", 0x00000D5C),
+            form_id(b"ANAM", 0x00000D5D),
+            form_id(b"BNAM", 0x00000D5E),
+            subrecord(b"FNAM", &[0x02]),
+            form_id(b"TNAM", 0x00000D5F),
+        ],
+    )
+}
+
+pub fn dual() -> Vec<u8> {
+    sample(
+        b"DUAL",
+        &[
+            edid("DualCastTest"),
+            bounds(),
+            subrecord(
+                b"DATA",
+                &u32s(&[
+                    0x00000D60, 0x00000D61, 0x00000D62, 0x00000D63, 0x00000D64, 3,
+                ]),
+            ),
+        ],
+    )
+}
+
+pub fn eczn() -> Vec<u8> {
+    let mut data = u32s(&[0x00000D65, 0x00000D66]);
+    data.extend([0xFF, 5, 0x02, 30]);
+
+    sample(
+        b"ECZN",
+        &[edid("EncounterZoneTest"), subrecord(b"DATA", &data)],
+    )
+}
+
+/// Effect shader DATA, with recognisable fill and edge colours, edge width,
+/// addon models, ambient sound and flags
+pub fn efsh_data() -> Vec<u8> {
+    let mut words = [0u32; 100];
+    words[1] = 5;
+    words[4] = u32::from_le_bytes([0x10, 0x20, 0x30, 0x00]);
+    words[14] = u32::from_le_bytes([0xC0, 0xB0, 0xA0, 0x00]);
+    words[61] = 0x00000D67;
+    words[66] = 2.5f32.to_bits();
+    words[77] = 0x00000D68;
+    words[96] = 0x00000201;
+    words[99] = 8;
+    u32s(&words)
+}
+
+pub fn efsh() -> Vec<u8> {
+    sample(
+        b"EFSH",
+        &[
+            edid("EffectShaderTest"),
+            string(b"ICON", "fill.dds"),
+            string(b"ICO2", "particle.dds"),
+            string(b"NAM7", "holes.dds"),
+            string(b"NAM8", "membrane.dds"),
+            string(b"NAM9", "particle_gradient.dds"),
+            subrecord(b"DATA", &efsh_data()),
+        ],
+    )
+}
+
+pub fn ench() -> Vec<u8> {
+    sample(
+        b"ENCH",
+        &[
+            edid("EnchantmentTest"),
+            bounds(),
+            string(b"FULL", "Fire Damage"),
+            subrecord(
+                b"ENIT",
+                &[
+                    u32s(&[10, 1, 1, 100, 1, 6]),
+                    f32s(&[0.0]),
+                    u32s(&[0, 0x00000D69]),
+                ]
+                .concat(),
+            ),
+            effect(),
+        ],
+    )
+}
+
+pub fn equp() -> Vec<u8> {
+    sample(
+        b"EQUP",
+        &[
+            edid("EquipSlotTest"),
+            subrecord(b"PNAM", &u32s(&[0x00000D6A, 0x00000D6B])),
+            form_id(b"DATA", 1),
+        ],
+    )
+}
+
+pub fn expl() -> Vec<u8> {
+    sample(
+        b"EXPL",
+        &[
+            edid("ExplosionTest"),
+            bounds(),
+            string(b"FULL", "Boom"),
+            model(),
+            form_id(b"EITM", 0x00000D6C),
+            form_id(b"MNAM", 0x00000D6D),
+            subrecord(b"DATA", &u32s(&[0x00000D6E, 0, 0x00000D6F, 0, 0, 0])),
+        ],
+    )
+}
+
+pub fn eyes() -> Vec<u8> {
+    sample(
+        b"EYES",
+        &[
+            edid("EyesTest"),
+            string(b"FULL", "Blue"),
+            string(b"ICON", "eyes.dds"),
+            subrecord(b"DATA", &[0x01]),
+        ],
+    )
+}
+
+pub fn fact() -> Vec<u8> {
+    let mut crime_gold = vec![1, 1];
+    crime_gold.extend([1000u16, 40, 5, 25, 0].iter().flat_map(|v| v.to_le_bytes()));
+    crime_gold.extend(f32s(&[0.5]));
+    crime_gold.extend([100u16, 1000].iter().flat_map(|v| v.to_le_bytes()));
+    let mut vendor = [6u16, 22]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect::<Vec<_>>();
+    vendor.extend(u32s(&[300]));
+    vendor.extend([1, 0, 0, 0]);
+
+    sample(
+        b"FACT",
+        &[
+            edid("FactionTest"),
+            string(b"FULL", "Guild"),
+            subrecord(b"XNAM", &u32s(&[0x00000D70, 0, 1])),
+            form_id(b"DATA", 0x5041),
+            form_id(b"JAIL", 0x00000D71),
+            form_id(b"WAIT", 0x00000D72),
+            form_id(b"STOL", 0x00000D73),
+            form_id(b"PLCN", 0x00000D74),
+            form_id(b"CRGR", 0x00000D75),
+            form_id(b"JOUT", 0x00000D76),
+            subrecord(b"CRVA", &crime_gold),
+            form_id(b"RNAM", 0),
+            string(b"MNAM", "Initiate"),
+            string(b"FNAM", "Initiate"),
+            form_id(b"RNAM", 1),
+            string(b"MNAM", "Master"),
+            form_id(b"VEND", 0x00000D77),
+            form_id(b"VENC", 0x00000D78),
+            subrecord(b"VENV", &vendor),
+            subrecord(b"PLVD", &u32s(&[1, 0x00000D79, 0])),
+            condition(),
+        ],
+    )
+}
+
+pub fn flor() -> Vec<u8> {
+    sample(
+        b"FLOR",
+        &[
+            edid("FloraTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Mushroom"),
+            model(),
+            destruction(),
+            keywords(&[0x00000D7A]),
+            form_id(b"PNAM", 0),
+            string(b"RNAM", "Harvest"),
+            subrecord(b"FNAM", &1u16.to_le_bytes()),
+            form_id(b"PFIG", 0x00000D7B),
+            form_id(b"SNAM", 0x00000D7C),
+            subrecord(b"PFPC", &[100, 50, 25, 75]),
+        ],
+    )
+}
+
+pub fn flst() -> Vec<u8> {
+    super::form_list(FORM_ID, "FormListTest", &[0x00000D80, 0x00000D81])
+}
+
+pub fn fstp() -> Vec<u8> {
+    sample(
+        b"FSTP",
+        &[
+            edid("FootstepTest"),
+            form_id(b"DATA", 0x00000D82),
+            string(b"ANAM", "FootLeft"),
+        ],
+    )
+}
+
+pub fn fsts() -> Vec<u8> {
+    sample(
+        b"FSTS",
+        &[
+            edid("FootstepSetTest"),
+            subrecord(b"XCNT", &u32s(&[1, 1, 0, 0, 0])),
+            subrecord(b"DATA", &u32s(&[0x00000D83, 0x00000D84])),
+        ],
+    )
+}
+
+pub fn furn() -> Vec<u8> {
+    sample(
+        b"FURN",
+        &[
+            edid("FurnitureTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Chair"),
+            model(),
+            destruction(),
+            keywords(&[0x00000D85]),
+            form_id(b"PNAM", 0),
+            subrecord(b"FNAM", &2u16.to_le_bytes()),
+            form_id(b"KNAM", 0x00000D86),
+            form_id(b"MNAM", 0x3),
+            subrecord(b"WBDT", &[1, 0xFF]),
+            form_id(b"ENAM", 0),
+            subrecord(b"NAM0", &u32s(&[0x0001_0000])),
+            form_id(b"FNMK", 0x00000D87),
+            form_id(b"ENAM", 1),
+            subrecord(b"FNPR", &u32s(&[0x0002_0001])),
+            string(b"XMRK", "marker.nif"),
+        ],
+    )
+}
+
+pub fn glob() -> Vec<u8> {
+    super::global(FORM_ID, "GlobalTest", 2.5)
+}
+
+pub fn gmst() -> Vec<u8> {
+    sample(b"GMST", &[edid("fSettingTest"), float(b"DATA", 1.5)])
+}
+
+pub fn gmst_string() -> Vec<u8> {
+    sample(b"GMST", &[edid("sSettingTest"), string(b"DATA", "Setting")])
+}
+
+pub fn gras() -> Vec<u8> {
+    let mut data = vec![100, 0, 90, 0];
+    data.extend([10u16, 0].iter().flat_map(|v| v.to_le_bytes()));
+    data.extend(u32s(&[1]));
+    data.extend(f32s(&[32.0, 0.25, 0.5, 2.0]));
+    data.extend([0x3, 0, 0, 0]);
+
+    sample(
+        b"GRAS",
+        &[
+            edid("GrassTest"),
+            bounds(),
+            model(),
+            subrecord(b"DATA", &data),
+        ],
+    )
+}
+
+pub fn hazd() -> Vec<u8> {
+    let mut data = u32s(&[4]);
+    data.extend(f32s(&[128.0, 30.0, 0.0, 1.0]));
+    data.extend(u32s(&[0x2, 0x00000D88, 0x00000D89, 0x00000D8A, 0x00000D8B]));
+
+    sample(
+        b"HAZD",
+        &[
+            edid("HazardTest"),
+            bounds(),
+            string(b"FULL", "Fire"),
+            model(),
+            form_id(b"MNAM", 0x00000D8C),
+            subrecord(b"DATA", &data),
+        ],
+    )
+}
+
+pub fn hdpt() -> Vec<u8> {
+    sample(
+        b"HDPT",
+        &[
+            edid("HeadPartTest"),
+            string(b"FULL", "Hair"),
+            model(),
+            subrecord(b"DATA", &[0x03]),
+            form_id(b"PNAM", 3),
+            form_id(b"HNAM", 0x00000D8D),
+            form_id(b"NAM0", 0),
+            string(b"NAM1", "hair.tri"),
+            form_id(b"TNAM", 0x00000D8E),
+            form_id(b"CNAM", 0x00000D8F),
+            form_id(b"RNAM", 0x00000D90),
+        ],
+    )
+}
+
+pub fn idle() -> Vec<u8> {
+    let mut data = vec![1, 5, 0x01, 0];
+    data.extend(3u16.to_le_bytes());
+
+    sample(
+        b"IDLE",
+        &[
+            edid("IdleTest"),
+            condition(),
+            string(b"DNAM", "idle.hkx"),
+            string(b"ENAM", "IdleStart"),
+            subrecord(b"ANAM", &u32s(&[0x00000D91, 0])),
+            subrecord(b"DATA", &data),
+        ],
+    )
+}
+
+pub fn idlm() -> Vec<u8> {
+    sample(
+        b"IDLM",
+        &[
+            edid("IdleMarkerTest"),
+            bounds(),
+            subrecord(b"IDLF", &[0x01]),
+            subrecord(b"IDLC", &[2]),
+            float(b"IDLT", 5.0),
+            subrecord(b"IDLA", &u32s(&[0x00000D92, 0x00000D93])),
+        ],
+    )
+}
+
+pub fn imad() -> Vec<u8> {
+    let timestamps = f32s(&[0.0, 1.0, 1.0, 0.5]);
+    let mut data = edid("ImageSpaceAdapterTest");
+    data.extend(subrecord(
+        b"DNAM",
+        &[u32s(&[1]), f32s(&[2.0]), u32s(&[2, 0, 1])].concat(),
+    ));
+    for magic in [b"BNAM", b"VNAM"] {
+        data.extend(subrecord(magic, &timestamps));
+    }
+    data.extend(subrecord(b"TNAM", &f32s(&[0.0, 1.0, 0.5, 0.5, 1.0])));
+    data.extend(subrecord(b"NAM3", &f32s(&[0.0, 0.0, 0.0, 0.0, 1.0])));
+    for magic in [
+        b"RNAM", b"SNAM", b"UNAM", b"NAM1", b"NAM2", b"WNAM", b"XNAM", b"YNAM", b"NAM4",
+    ] {
+        data.extend(subrecord(magic, &timestamps));
+    }
+    for index in 0..0x15 {
+        for half in [0x00, 0x40] {
+            data.extend(subrecord(&[index | half, b'I', b'A', b'D'], &timestamps));
+        }
+    }
+
+    record(b"IMAD", 0, FORM_ID, &data)
+}
+
+pub fn imgs() -> Vec<u8> {
+    sample(
+        b"IMGS",
+        &[
+            edid("ImageSpaceTest"),
+            subrecord(b"ENAM", &[0; 16]),
+            subrecord(
+                b"HNAM",
+                &f32s(&[0.7, 7.0, 0.6, 0.5, 0.35, 2.0, 1.5, 1.25, 1.0]),
+            ),
+            subrecord(b"CNAM", &f32s(&[1.1, 0.9, 1.2])),
+            subrecord(b"TNAM", &f32s(&[0.25, 1.0, 0.5, 0.0])),
+            subrecord(
+                b"DNAM",
+                &[
+                    f32s(&[0.5, 1000.0, 200.0]),
+                    vec![0, 0],
+                    3u16.to_le_bytes().to_vec(),
+                ]
+                .concat(),
+            ),
+        ],
+    )
+}
+
+/// Scripts followed by a fragment list with a single fragment in `file`,
+/// the layout shared by topic info and package fragments
+fn fragment_scripts(file: &str) -> Vec<u8> {
+    let mut data = scripts_data();
+    data.extend([2, 0x01]);
+    data.extend(wstring(file));
+    data.push(0);
+    data.extend(wstring(file));
+    data.extend(wstring("Fragment_0"));
+    subrecord(b"VMAD", &data)
+}
+
+pub fn info() -> Vec<u8> {
+    let mut response = u32s(&[3, 50]);
+    response.extend((-1i32).to_le_bytes());
+    response.extend([1, 0, 0, 0]);
+    response.extend(u32s(&[0x00000DA0]));
+    response.extend([1, 0, 0, 0]);
+
+    sample(
+        b"INFO",
+        &[
+            edid("InfoTest"),
+            fragment_scripts("TIF__0000800"),
+            subrecord(
+                b"ENAM",
+                &[0x0040u16.to_le_bytes(), 24u16.to_le_bytes()].concat(),
+            ),
+            form_id(b"TPIC", 0x00000DA1),
+            form_id(b"PNAM", 0x00000DA2),
+            subrecord(b"CNAM", &[2]),
+            form_id(b"TCLT", 0x00000DA3),
+            form_id(b"TCLT", 0x00000DA4),
+            subrecord(b"TRDT", &response),
+            string(b"NAM1", "Well met."),
+            string(b"NAM2", "Friendly"),
+            string(b"NAM3", ""),
+            form_id(b"SNAM", 0x00000DA5),
+            form_id(b"LNAM", 0x00000DA6),
+            condition(),
+            subrecord(b"CTDA", &condition_data(14, 0x00000DA7)),
+            string(b"RNAM", "Greetings"),
+            form_id(b"ANAM", 0x00000DA8),
+            form_id(b"TWAT", 0x00000DA9),
+            form_id(b"ONAM", 0x00000DAA),
+        ],
+    )
+}
+
+pub fn ingr() -> Vec<u8> {
+    sample(
+        b"INGR",
+        &[
+            edid("IngredientTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Test Ingredient"),
+            keywords(&[0x00000DB0]),
+            model(),
+            string(b"ICON", "textures\\ingredient.dds"),
+            form_id(b"YNAM", 0x00000DB1),
+            form_id(b"ZNAM", 0x00000DB2),
+            subrecord(b"DATA", &[u32s(&[5]), f32s(&[0.1])].concat()),
+            subrecord(b"ENIT", &u32s(&[12, 0x002])),
+            effect(),
+            effect(),
+        ],
+    )
+}
+
+pub fn ipct() -> Vec<u8> {
+    let mut decal = f32s(&[8.0, 16.0, 8.0, 16.0, 32.0, 4.0, 0.04]);
+    decal.extend([4, 0x01, 0, 0, 0x80, 0x80, 0x80, 0]);
+
+    sample(
+        b"IPCT",
+        &[
+            edid("ImpactTest"),
+            model(),
+            subrecord(
+                b"DATA",
+                &[
+                    f32s(&[0.25]),
+                    u32s(&[1]),
+                    f32s(&[15.0, 16.0]),
+                    u32s(&[1, 0]),
+                ]
+                .concat(),
+            ),
+            subrecord(b"DODT", &decal),
+            form_id(b"DNAM", 0x00000DB3),
+            form_id(b"ENAM", 0x00000DB4),
+            form_id(b"SNAM", 0x00000DB5),
+            form_id(b"NAM1", 0x00000DB6),
+            form_id(b"NAM2", 0x00000DB7),
+        ],
+    )
+}
+
+pub fn ipds() -> Vec<u8> {
+    sample(
+        b"IPDS",
+        &[
+            edid("ImpactSetTest"),
+            subrecord(b"PNAM", &u32s(&[0x00000DB8, 0x00000DB9])),
+            subrecord(b"PNAM", &u32s(&[0x00000DBA, 0x00000DBB])),
+        ],
+    )
+}
+
+pub fn keym() -> Vec<u8> {
+    sample(
+        b"KEYM",
+        &[
+            edid("KeyTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Test Key"),
+            model(),
+            form_id(b"YNAM", 0x00000DBC),
+            form_id(b"ZNAM", 0x00000DBD),
+            keywords(&[0x00000DBE]),
+            subrecord(b"DATA", &[u32s(&[0]), f32s(&[0.0])].concat()),
+        ],
+    )
+}
+
+pub fn kywd() -> Vec<u8> {
+    super::keyword(FORM_ID, "KeywordTest", [0x10, 0x20, 0x30, 0])
+}
+
+pub fn land() -> Vec<u8> {
+    const VERTICES: usize = 33 * 33;
+
+    let normals: Vec<u8> = (0..VERTICES * 3).map(|i| (i % 7) as u8).collect();
+    let mut heights = 12.0f32.to_le_bytes().to_vec();
+    heights.extend((0..VERTICES).map(|i| (i % 5) as u8));
+    heights.extend([0, 0, 0]);
+    let colors: Vec<u8> = (0..VERTICES * 3).map(|i| (i % 251) as u8).collect();
+    let mut alpha = Vec::new();
+    for (position, opacity) in [(0u16, 0.25f32), (16, 1.0)] {
+        alpha.extend(position.to_le_bytes());
+        alpha.extend([0, 0]);
+        alpha.extend(opacity.to_le_bytes());
+    }
+
+    sample(
+        b"LAND",
+        &[
+            subrecord(b"DATA", &0x1Fu32.to_le_bytes()),
+            subrecord(b"VNML", &normals),
+            subrecord(b"VHGT", &heights),
+            subrecord(b"VCLR", &colors),
+            subrecord(b"BTXT", &[u32s(&[0x00000F10]), vec![0, 0, 0, 0]].concat()),
+            subrecord(b"ATXT", &[u32s(&[0x00000F11]), vec![1, 0, 1, 0]].concat()),
+            subrecord(b"VTXT", &alpha),
+            subrecord(b"VTEX", &u32s(&[0x00000F12, 0x00000F13])),
+        ],
+    )
+}
+
+pub fn lcrt() -> Vec<u8> {
+    sample(
+        b"LCRT",
+        &[
+            edid("LocationRefTest"),
+            subrecord(b"CNAM", &[0x40, 0x50, 0x60, 0]),
+        ],
+    )
+}
+
+pub fn lctn() -> Vec<u8> {
+    let grid = [(-3i16).to_le_bytes(), 7i16.to_le_bytes()].concat();
+
+    sample(
+        b"LCTN",
+        &[
+            edid("LocationTest"),
+            subrecord(
+                b"ACPR",
+                &[u32s(&[0x00000DC0, 0x00000DC1]), grid.clone()].concat(),
+            ),
+            subrecord(b"RCPR", &u32s(&[0x00000DC2])),
+            subrecord(b"ACUN", &u32s(&[0x00000DC3, 0x00000DC4, 0x00000DC5])),
+            subrecord(
+                b"ACEC",
+                &[u32s(&[0x00000DC6]), grid.clone(), grid.clone()].concat(),
+            ),
+            subrecord(b"ACID", &u32s(&[0x00000DC7])),
+            subrecord(b"ACEP", &[u32s(&[0x00000DC8, 0x00000DC9]), grid].concat()),
+            string(b"FULL", "Test Location"),
+            keywords(&[0x00000DCA]),
+            form_id(b"PNAM", 0x00000DCB),
+            form_id(b"NAM1", 0x00000DCC),
+            form_id(b"FNAM", 0x00000DCD),
+            form_id(b"MNAM", 0x00000DCE),
+            float(b"RNAM", 512.0),
+            form_id(b"NAM0", 0x00000DCF),
+            subrecord(b"CNAM", &[0x70, 0x80, 0x90, 0]),
+        ],
+    )
+}
+
+pub fn lgtm() -> Vec<u8> {
+    let mut lighting = vec![10, 20, 30, 0, 40, 50, 60, 0, 70, 80, 90, 0];
+    lighting.extend(f32s(&[64.0, 4096.0]));
+    lighting.extend([90i32.to_le_bytes(), (-45i32).to_le_bytes()].concat());
+    lighting.extend(f32s(&[1.0, 8192.0, 1.0]));
+    lighting.extend([0; 32]);
+    lighting.extend([100, 110, 120, 0]);
+    lighting.extend(f32s(&[0.9, 10000.0, 20000.0]));
+    lighting.extend([0; 4]);
+
+    let mut ambient = Vec::new();
+    for index in 0..7 {
+        ambient.extend([index * 10, index * 10 + 1, index * 10 + 2, 0]);
+    }
+    ambient.extend(1.0f32.to_le_bytes());
+
+    sample(
+        b"LGTM",
+        &[
+            edid("LightingTemplateTest"),
+            subrecord(b"DATA", &lighting),
+            subrecord(b"DALC", &ambient),
+        ],
+    )
+}
+
+pub fn ligh() -> Vec<u8> {
+    let mut light = (-1i32).to_le_bytes().to_vec();
+    light.extend(u32s(&[256]));
+    light.extend([0xFF, 0xC0, 0x80, 0]);
+    light.extend(u32s(&[0x002]));
+    light.extend(f32s(&[1.0, 90.0, 0.1, 0.2, 0.3, 0.4]));
+    light.extend(u32s(&[10]));
+    light.extend(f32s(&[1.0]));
+
+    sample(
+        b"LIGH",
+        &[
+            edid("LightTest"),
+            scripts(),
+            bounds(),
+            model(),
+            destruction(),
+            string(b"FULL", "Test Light"),
+            string(b"ICON", "textures\\light.dds"),
+            string(b"MICO", "textures\\light_small.dds"),
+            subrecord(b"DATA", &light),
+            float(b"FNAM", 0.5),
+            form_id(b"SNAM", 0x00000DD0),
+        ],
+    )
+}
+
+pub fn lscr() -> Vec<u8> {
+    sample(
+        b"LSCR",
+        &[
+            edid("LoadScreenTest"),
+            string(b"DESC", "Loading..."),
+            form_id(b"NNAM", 0x00000DD1),
+        ],
+    )
+}
+
+pub fn ltex() -> Vec<u8> {
+    sample(
+        b"LTEX",
+        &[
+            edid("LandTextureTest"),
+            form_id(b"TNAM", 0x00000DD2),
+            subrecord(b"HNAM", &[1, 30]),
+            subrecord(b"SNAM", &[30]),
+        ],
+    )
+}
+
+pub fn lvli() -> Vec<u8> {
+    super::leveled_item(
+        FORM_ID,
+        25,
+        0x01,
+        Some(0x00000DD3),
+        &[(1, 0x00000DD4, 1), (10, 0x00000DD5, 2)],
+    )
+}
+
+/// A leveled list entry, without its trailing extra data
+fn leveled_entry(level: u16, reference: u32, count: u16) -> Vec<u8> {
+    let mut entry = level.to_le_bytes().to_vec();
+    entry.extend([0; 2]);
+    entry.extend(reference.to_le_bytes());
+    entry.extend(count.to_le_bytes());
+    entry.extend([0; 2]);
+    subrecord(b"LVLO", &entry)
+}
+
+pub fn lvln() -> Vec<u8> {
+    sample(
+        b"LVLN",
+        &[
+            edid("LeveledActorTest"),
+            bounds(),
+            subrecord(b"LVLD", &[0]),
+            subrecord(b"LVLF", &[0x03]),
+            subrecord(b"LLCT", &[2]),
+            leveled_entry(1, 0x00000DD6, 1),
+            subrecord(
+                b"COED",
+                &[u32s(&[0x00000DD7, 0xFFFFFFFF]), f32s(&[1.0])].concat(),
+            ),
+            leveled_entry(5, 0x00000DD8, 1),
+            model(),
+        ],
+    )
+}
+
+pub fn lvsp() -> Vec<u8> {
+    sample(
+        b"LVSP",
+        &[
+            edid("LeveledSpellTest"),
+            bounds(),
+            subrecord(b"LVLD", &[10]),
+            subrecord(b"LVLF", &[0x04]),
+            subrecord(b"LLCT", &[1]),
+            leveled_entry(1, 0x00000DD9, 1),
+        ],
+    )
+}
+
+pub fn mato() -> Vec<u8> {
+    sample(
+        b"MATO",
+        &[
+            edid("MaterialObjectTest"),
+            model(),
+            subrecord(b"DATA", &f32s(&[1.0, 0.5, 0.0, 1.0])),
+        ],
+    )
+}
+
+pub fn matt() -> Vec<u8> {
+    sample(
+        b"MATT",
+        &[
+            edid("MaterialTypeTest"),
+            form_id(b"PNAM", 0x00000DDA),
+            string(b"MNAM", "Stone"),
+            subrecord(b"CNAM", &f32s(&[0.5, 0.5, 0.5])),
+            float(b"BNAM", 0.3),
+        ],
+    )
+}
+
+pub fn mesg() -> Vec<u8> {
+    sample(
+        b"MESG",
+        &[
+            edid("MessageTest"),
+            string(b"DESC", "A test message"),
+            string(b"FULL", "Test"),
+            form_id(b"INAM", 0),
+            form_id(b"DNAM", 0x01),
+            form_id(b"TNAM", 2),
+            string(b"ITXT", "Ok"),
+        ],
+    )
+}
+
+/// Magic effect data (DATA) for a value modifier; the skill is Destruction
+/// (20), the actor value Health (24) and the cast type fire and forget
+pub fn mgef_data() -> Vec<u8> {
+    let mut data = u32s(&[0x00000001]);
+    data.extend(f32s(&[10.0]));
+    data.extend(u32s(&[0]));
+    data.extend([20i32.to_le_bytes(), (-1i32).to_le_bytes()].concat());
+    data.extend([1u16.to_le_bytes(), [0; 2]].concat());
+    data.extend(u32s(&[0x00000DDB]));
+    data.extend(f32s(&[0.0]));
+    data.extend(u32s(&[0x00000DDC, 0x00000DDD, 25, 0]));
+    data.extend(f32s(&[0.5, 0.0, 0.0, 0.0]));
+    data.extend(u32s(&[0]));
+    data.extend(24i32.to_le_bytes());
+    data.extend(u32s(&[0x00000DDE, 0x00000DDF, 1, 1]));
+    data.extend((-1i32).to_le_bytes());
+    data.extend(u32s(&[0x00000DE0, 0x00000DE1, 0x00000DE2]));
+    data.extend(f32s(&[1.0]));
+    data.extend(u32s(&[0x00000DE3]));
+    data.extend(f32s(&[1.5]));
+    data.extend(u32s(&[0x00000DE4, 0, 0, 0, 0x00000DE5, 0, 1]));
+    data.extend(f32s(&[0.0, 0.0]));
+    data
+}
+
+pub fn mgef() -> Vec<u8> {
+    sample(
+        b"MGEF",
+        &[
+            edid("MagicEffectTest"),
+            scripts(),
+            string(b"FULL", "Test Effect"),
+            form_id(b"MDOB", 0x00000DE6),
+            keywords(&[0x00000DE7]),
+            subrecord(b"DATA", &mgef_data()),
+            form_id(b"ESCE", 0x00000DE8),
+            subrecord(b"SNDD", &u32s(&[1, 0x00000DE9, 3, 0x00000DEA])),
+            string(b"DNAM", "Does <mag> points of damage."),
+            condition(),
+        ],
+    )
+}
+
+pub fn misc() -> Vec<u8> {
+    sample(
+        b"MISC",
+        &[
+            edid("MiscItemTest"),
+            bounds(),
+            string(b"FULL", "Test Item"),
+            model(),
+            subrecord(b"DATA", &[u32s(&[5]), f32s(&[0.5])].concat()),
+        ],
+    )
+}
+
+pub fn movt() -> Vec<u8> {
+    sample(
+        b"MOVT",
+        &[
+            edid("MovementTypeTest"),
+            string(b"MNAM", "Default"),
+            subrecord(b"SPED", &f32s(&[1.0; 11])),
+            subrecord(b"INAM", &f32s(&[0.1, 0.2, 0.3])),
+        ],
+    )
+}
+
+pub fn mstt() -> Vec<u8> {
+    sample(
+        b"MSTT",
+        &[
+            edid("MovableStaticTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Test Movable Static"),
+            model(),
+            destruction(),
+            subrecord(b"DATA", &[0x01]),
+            form_id(b"SNAM", 0x00000DEB),
+        ],
+    )
+}
+
+pub fn musc() -> Vec<u8> {
+    sample(
+        b"MUSC",
+        &[
+            edid("MusicTypeTest"),
+            form_id(b"FNAM", 0x00000005),
+            subrecord(
+                b"PNAM",
+                &[50u16.to_le_bytes(), 400u16.to_le_bytes()].concat(),
+            ),
+            float(b"WNAM", 2.5),
+            subrecord(b"TNAM", &u32s(&[0x00000DEC, 0x00000DED])),
+        ],
+    )
+}
+
+pub fn must() -> Vec<u8> {
+    sample(
+        b"MUST",
+        &[
+            edid("MusicTrackTest"),
+            form_id(b"CNAM", 0x6ED7E048),
+            float(b"FLTV", 120.0),
+            float(b"DNAM", 4.0),
+            string(b"ANAM", "music\\test.xwm"),
+            string(b"BNAM", "music\\test_finale.xwm"),
+            subrecord(b"FNAM", &f32s(&[0.0, 30.0, 60.0])),
+            subrecord(b"LNAM", &[f32s(&[10.0, 110.0]), u32s(&[2])].concat()),
+            form_id(b"CITC", 1),
+            condition(),
+        ],
+    )
+}
+
+pub fn navi() -> Vec<u8> {
+    sample(b"NAVI", &[edid("NavigationTest"), form_id(b"NVER", 12)])
+}
+
+pub fn note() -> Vec<u8> {
+    sample(
+        b"NOTE",
+        &[
+            edid("NoteTest"),
+            bounds(),
+            string(b"FULL", "Test Note"),
+            subrecord(b"DNAM", &[0]),
+            form_id(b"SNAM", 0x00000DEE),
+        ],
+    )
+}
+
+pub fn otft() -> Vec<u8> {
+    sample(
+        b"OTFT",
+        &[
+            edid("OutfitTest"),
+            subrecord(b"INAM", &u32s(&[0x00000DEF, 0x00000DF0])),
+        ],
+    )
+}
+
+pub fn navm() -> Vec<u8> {
+    let mut geometry = u32s(&[12, 0, 0, 0x00000F00, 3]);
+    geometry.extend(f32s(&[0.0, 0.0, 0.0, 128.0, 0.0, 0.0, 0.0, 128.0, 0.0]));
+    geometry.extend(u32s(&[1]));
+    geometry.extend([0u16, 1, 2].iter().flat_map(|v| v.to_le_bytes()));
+    geometry.extend([-1i16, -1, -1].iter().flat_map(|v| v.to_le_bytes()));
+    geometry.extend([0; 4]);
+    geometry.extend(u32s(&[0, 0, 0, 1]));
+    geometry.extend(f32s(&[128.0, 128.0, 0.0, 0.0, 0.0, 128.0, 128.0, 0.0]));
+    geometry.extend(u32s(&[1]));
+    geometry.extend(0u16.to_le_bytes());
+
+    sample(
+        b"NAVM",
+        &[
+            edid("NavMeshTest"),
+            subrecord(b"NVNM", &geometry),
+            subrecord(b"ONAM", &u32s(&[0])),
+            subrecord(b"PNAM", &u32s(&[0])),
+            subrecord(b"NNAM", &u32s(&[0])),
+        ],
+    )
+}
+
+/// Actor base stats (ACBS) for an essential, unique level 10 actor
+pub fn npc_stats() -> Vec<u8> {
+    let mut stats = u32s(&[0x22]);
+    stats.extend([50i16.to_le_bytes(), 25i16.to_le_bytes()].concat());
+    stats.extend([10u16, 1, 50, 100].iter().flat_map(|v| v.to_le_bytes()));
+    stats.extend(35i16.to_le_bytes());
+    stats.extend(0x0004u16.to_le_bytes());
+    stats.extend(100i16.to_le_bytes());
+    stats.extend(0u16.to_le_bytes());
+    stats
+}
+
+pub fn npc_() -> Vec<u8> {
+    let mut attack = f32s(&[1.0, 0.5]);
+    attack.extend(u32s(&[0, 0]));
+    attack.extend(f32s(&[35.0, 0.0, 0.0]));
+    attack.extend(u32s(&[0x00000E00]));
+    attack.extend(f32s(&[0.0, 0.0, 1.0]));
+
+    let mut ai = vec![1, 2, 50, 0, 0, 1, 0, 0];
+    ai.extend(u32s(&[0, 0, 0]));
+
+    let mut skills: Vec<u8> = (0..18).map(|i| 15 + i).collect();
+    skills.extend([0; 18]);
+    skills.extend([50u16, 25, 10, 0].iter().flat_map(|v| v.to_le_bytes()));
+    skills.extend(f32s(&[0.0]));
+    skills.extend([0; 4]);
+
+    let face_morph: Vec<f32> = (0..19).map(|i| i as f32 / 20.0).collect();
+
+    sample(
+        b"NPC_",
+        &[
+            edid("ActorTest"),
+            scripts(),
+            bounds(),
+            subrecord(b"ACBS", &npc_stats()),
+            subrecord(b"SNAM", &[u32s(&[0x00000E01]), vec![2, 0, 0, 0]].concat()),
+            form_id(b"INAM", 0x00000E02),
+            form_id(b"VTCK", 0x00000E03),
+            form_id(b"TPLT", 0x00000E04),
+            form_id(b"RNAM", 0x00000E05),
+            form_id(b"SPCT", 1),
+            form_id(b"SPLO", 0x00000E06),
+            destruction(),
+            form_id(b"WNAM", 0x00000E07),
+            form_id(b"ANAM", 0x00000E08),
+            form_id(b"ATKR", 0x00000E09),
+            subrecord(b"ATKD", &attack),
+            string(b"ATKE", "attackStart"),
+            form_id(b"SPOR", 0x00000E0A),
+            form_id(b"OCOR", 0x00000E0B),
+            form_id(b"GWOR", 0x00000E0C),
+            form_id(b"ECOR", 0x00000E0D),
+            form_id(b"PRKZ", 1),
+            subrecord(b"PRKR", &[u32s(&[0x00000E0E]), vec![1, 0, 0, 0]].concat()),
+            form_id(b"COCT", 1),
+            subrecord(b"CNTO", &u32s(&[0x00000E0F, 3])),
+            subrecord(b"AIDT", &ai),
+            form_id(b"PKID", 0x00000E10),
+            keywords(&[0x00000E11]),
+            form_id(b"CNAM", 0x00000E12),
+            string(b"FULL", "Test Actor"),
+            string(b"SHRT", "Test"),
+            subrecord(b"DATA", &[]),
+            subrecord(b"DNAM", &skills),
+            form_id(b"PNAM", 0x00000E13),
+            form_id(b"PNAM", 0x00000E14),
+            form_id(b"HCLF", 0x00000E15),
+            form_id(b"ZNAM", 0x00000E16),
+            form_id(b"GNAM", 0x00000E17),
+            subrecord(b"NAM5", &255u16.to_le_bytes()),
+            float(b"NAM6", 1.05),
+            float(b"NAM7", 50.0),
+            form_id(b"NAM8", 2),
+            form_id(b"CSDT", 0),
+            form_id(b"CSDI", 0x00000E18),
+            subrecord(b"CSDC", &[25]),
+            form_id(b"CSCR", 0x00000E19),
+            form_id(b"DOFT", 0x00000E1A),
+            form_id(b"SOFT", 0x00000E1B),
+            form_id(b"DPLT", 0x00000E1C),
+            form_id(b"CRIF", 0x00000E1D),
+            form_id(b"FTST", 0x00000E1E),
+            subrecord(b"QNAM", &f32s(&[0.9, 0.8, 0.7])),
+            subrecord(b"NAM9", &f32s(&face_morph)),
+            subrecord(b"NAMA", &u32s(&[1, 0xFFFFFFFF, 2, 3])),
+            subrecord(b"TINI", &7u16.to_le_bytes()),
+            subrecord(b"TINC", &[0x80, 0x40, 0x20, 0xFF]),
+            subrecord(b"TINV", &60i32.to_le_bytes()),
+            subrecord(b"TIAS", &(-1i16).to_le_bytes()),
+        ],
+    )
+}
+
+/// Package data (PKDT) for a sandbox package
+pub fn pack_data() -> Vec<u8> {
+    let mut data = u32s(&[0x00000004]);
+    data.extend([18, 1, 1, 0]);
+    data.extend([0x01FFu16, 0].iter().flat_map(|v| v.to_le_bytes()));
+    data
+}
+
+pub fn pack() -> Vec<u8> {
+    let mut schedule = vec![0xFF, 0xFF, 0, 8, 0, 0, 0, 0];
+    schedule.extend(4i32.to_le_bytes());
+
+    let location = [
+        1i32.to_le_bytes(),
+        0x00000E20u32.to_le_bytes(),
+        512i32.to_le_bytes(),
+    ]
+    .concat();
+
+    sample(
+        b"PACK",
+        &[
+            edid("PackageTest"),
+            fragment_scripts("PF_PackageTest_00000800"),
+            subrecord(b"PKDT", &pack_data()),
+            subrecord(b"PSDT", &schedule),
+            form_id(b"CITC", 1),
+            condition(),
+            subrecord(b"IDLF", &[0x08]),
+            subrecord(b"IDLC", &[2, 0, 0, 0]),
+            float(b"IDLT", 10.0),
+            subrecord(b"IDLA", &u32s(&[0x00000E21, 0x00000E22])),
+            form_id(b"CNAM", 0x00000E23),
+            form_id(b"QNAM", 0x00000E24),
+            subrecord(b"PKCU", &u32s(&[2, 0x00000E25, 7])),
+            string(b"ANAM", "Location"),
+            subrecord(b"PLDT", &location),
+            string(b"ANAM", "Bool"),
+            subrecord(b"CNAM", &[1]),
+            subrecord(b"UNAM", &[0]),
+            string(b"BNAM", "Location"),
+            subrecord(b"UNAM", &[1]),
+            string(b"BNAM", "AllowEating"),
+            subrecord(b"XNAM", &[2]),
+            string(b"ANAM", "Sandbox"),
+            string(b"PNAM", "Sandbox"),
+            form_id(b"FNAM", 0),
+            subrecord(b"PKC2", &[0]),
+            subrecord(b"PKC2", &[1]),
+            subrecord(b"POBA", &[]),
+            form_id(b"INAM", 0x00000E26),
+            subrecord(b"PDTO", &u32s(&[0, 0x00000E27])),
+            subrecord(b"POEA", &[]),
+            form_id(b"INAM", 0),
+            subrecord(b"POCA", &[]),
+            form_id(b"INAM", 0),
+        ],
+    )
+}
+
+/// Perk data (DATA) for a playable, non-trainable, single rank perk at
+/// level 25
+pub fn perk_data() -> Vec<u8> {
+    vec![0, 25, 1, 1, 0]
+}
+
+pub fn perk() -> Vec<u8> {
+    let mut scripts = scripts_data();
+    scripts.push(2);
+    scripts.extend(wstring("PRKF_PerkTest_00000800"));
+    scripts.extend(1u16.to_le_bytes());
+    scripts.extend([0u16.to_le_bytes(), 0i16.to_le_bytes()].concat());
+    scripts.push(0);
+    scripts.extend(wstring("PRKF_PerkTest_00000800"));
+    scripts.extend(wstring("Fragment_0"));
+
+    let prkf = subrecord(b"PRKF", &[]);
+
+    sample(
+        b"PERK",
+        &[
+            edid("PerkTest"),
+            subrecord(b"VMAD", &scripts),
+            string(b"FULL", "Test Perk"),
+            string(b"DESC", "Does something."),
+            string(b"ICON", "textures\\perk.dds"),
+            condition(),
+            subrecord(b"DATA", &perk_data()),
+            form_id(b"NNAM", 0x00000E30),
+            subrecord(b"PRKE", &[0, 0, 0]),
+            subrecord(b"DATA", &[u32s(&[0x00000E31]), vec![10, 0, 0, 0]].concat()),
+            prkf.clone(),
+            subrecord(b"PRKE", &[1, 0, 1]),
+            form_id(b"DATA", 0x00000E32),
+            prkf.clone(),
+            subrecord(b"PRKE", &[2, 0, 2]),
+            subrecord(b"DATA", &[0, 2, 1]),
+            subrecord(b"PRKC", &[0]),
+            condition(),
+            subrecord(b"EPFT", &[1]),
+            float(b"EPFD", 0.5),
+            prkf.clone(),
+            subrecord(b"PRKE", &[2, 0, 3]),
+            subrecord(b"DATA", &[36, 9, 1]),
+            subrecord(b"EPFT", &[4]),
+            string(b"EPF2", "Absorb"),
+            subrecord(b"EPF3", &[1u16.to_le_bytes(), 0u16.to_le_bytes()].concat()),
+            form_id(b"EPFD", 0x00000E33),
+            prkf,
+        ],
+    )
+}
+
+pub fn pgre() -> Vec<u8> {
+    sample(
+        b"PGRE",
+        &[
+            edid("PlacedGrenadeTest"),
+            form_id(b"NAME", 0x00000E34),
+            subrecord(b"DATA", &f32s(&[0.0; 6])),
+        ],
+    )
+}
+
+pub fn phzd() -> Vec<u8> {
+    sample(
+        b"PHZD",
+        &[
+            edid("PlacedHazardTest"),
+            form_id(b"NAME", 0x00000E35),
+            subrecord(b"DATA", &f32s(&[0.0; 6])),
+        ],
+    )
+}
+
+pub fn proj() -> Vec<u8> {
+    sample(
+        b"PROJ",
+        &[
+            edid("ProjectileTest"),
+            bounds(),
+            string(b"FULL", "Test Projectile"),
+            model(),
+            subrecord(b"DATA", &[0; 92]),
+            string(b"NAM1", "meshes\\muzzle.nif"),
+            subrecord(b"NAM2", &[]),
+            form_id(b"VNAM", 0),
+        ],
+    )
+}
+
+pub fn qust() -> Vec<u8> {
+    let mut scripts = scripts_data();
+    scripts.push(2);
+    scripts.extend(1u16.to_le_bytes());
+    scripts.extend(wstring("QF_QuestTest_00000800"));
+    scripts.extend([10u16.to_le_bytes(), 0u16.to_le_bytes()].concat());
+    scripts.extend(0i32.to_le_bytes());
+    scripts.push(0);
+    scripts.extend(wstring("QF_QuestTest_00000800"));
+    scripts.extend(wstring("Fragment_0"));
+    scripts.extend(1u16.to_le_bytes());
+    scripts.extend(u32s(&[0, FORM_ID]));
+    scripts.extend([5u16, 2, 1].iter().flat_map(|v| v.to_le_bytes()));
+    scripts.extend(script());
+
+    let mut data = [0x0011u16.to_le_bytes()].concat();
+    data.extend([50, 3]);
+    data.extend(u32s(&[0, 0]));
+
+    sample(
+        b"QUST",
+        &[
+            edid("QuestTest"),
+            subrecord(b"VMAD", &scripts),
+            string(b"FULL", "Test Quest"),
+            subrecord(b"DNAM", &data),
+            subrecord(b"ENAM", b"KILL"),
+            form_id(b"QTGL", 0x00000E36),
+            string(b"FLTR", "Tests\\"),
+            condition(),
+            subrecord(b"NEXT", &[]),
+            subrecord(b"CTDA", &condition_data(14, 0)),
+            subrecord(b"INDX", &[10u16.to_le_bytes(), [0, 0]].concat()),
+            subrecord(b"QSDT", &[0]),
+            condition(),
+            string(b"CNAM", "The test began."),
+            form_id(b"NAM0", 0x00000E37),
+            subrecord(b"INDX", &[200u16.to_le_bytes(), [0x02, 0]].concat()),
+            subrecord(b"QSDT", &[0x01]),
+            subrecord(b"QOBJ", &10u16.to_le_bytes()),
+            form_id(b"FNAM", 0),
+            string(b"NNAM", "Finish the test"),
+            subrecord(b"QSTA", &[0i32.to_le_bytes(), 0u32.to_le_bytes()].concat()),
+            condition(),
+            form_id(b"ANAM", 2),
+            form_id(b"ALST", 0),
+            string(b"ALID", "Target"),
+            form_id(b"FNAM", 0x00000040),
+            form_id(b"ALFR", 0x00000E38),
+            condition(),
+            keywords(&[0x00000E39]),
+            form_id(b"COCT", 1),
+            subrecord(b"CNTO", &u32s(&[0x00000E3A, 1])),
+            form_id(b"ALSP", 0x00000E3B),
+            form_id(b"ALFC", 0x00000E3C),
+            form_id(b"ALPC", 0x00000E3D),
+            subrecord(b"ALED", &[]),
+            form_id(b"ALLS", 1),
+            string(b"ALID", "Place"),
+            form_id(b"ALFL", 0x00000E3E),
+            subrecord(b"ALED", &[]),
+        ],
+    )
+}
+
+/// Race data (DATA) for a medium sized race with mount data
+pub fn race_data() -> Vec<u8> {
+    let mut data: Vec<u8> = [
+        (10i8, 10i8),
+        (11, 5),
+        (12, 5),
+        (-1, 0),
+        (-1, 0),
+        (-1, 0),
+        (-1, 0),
+    ]
+    .iter()
+    .flat_map(|(skill, boost)| [*skill as u8, *boost as u8])
+    .collect();
+    data.extend([0; 2]);
+    data.extend(f32s(&[1.0, 0.95, 1.0, 1.0]));
+    data.extend(u32s(&[0x00000001]));
+    data.extend(f32s(&[50.0, 50.0, 50.0, 300.0, 1.0, 1.0, 1.0]));
+    data.extend(u32s(&[1]));
+    data.extend([0i32, 1].iter().flat_map(|v| v.to_le_bytes()));
+    data.extend(f32s(&[0.5]));
+    data.extend(9i32.to_le_bytes());
+    data.extend(f32s(&[0.7, 3.0, 5.0, 4.0, 96.0]));
+    data.extend(2i32.to_le_bytes());
+    data.extend(f32s(&[90.0, 0.0, 0.0, 0.0]));
+    data.extend(u32s(&[0]));
+    data.extend(f32s(&[0.0; 9]));
+    data
+}
+
+pub fn race() -> Vec<u8> {
+    let mut attack = f32s(&[1.0, 0.5]);
+    attack.extend(u32s(&[0, 0]));
+    attack.extend(f32s(&[35.0, 0.0, 0.0]));
+    attack.extend(u32s(&[0x00000E40]));
+    attack.extend(f32s(&[0.0, 0.0, 1.0]));
+
+    let empty = |magic: &[u8; 4]| subrecord(magic, &[]);
+    let skeleton = |path: &str| [string(b"ANAM", path), subrecord(b"MODT", &model_textures())];
+    let head_data = |magics: [&[u8; 4]; 4]| {
+        let mut data = form_id(b"INDX", 0);
+        data.extend(form_id(b"HEAD", 0x00000E41));
+        data.extend(form_id(b"MPAI", 0));
+        data.extend(subrecord(b"MPAV", &u32s(&[1, 0, 0, 0, 0, 0, 0, 0])));
+        for (magic, value) in magics
+            .iter()
+            .zip([0x00000E42, 0x00000E43, 0x00000E44, 0x00000E45])
+        {
+            data.extend(form_id(magic, value));
+        }
+        data.extend(subrecord(b"TINI", &1u16.to_le_bytes()));
+        data.extend(string(b"TINT", "textures\\tint.dds"));
+        data.extend(subrecord(b"TINP", &2u16.to_le_bytes()));
+        data.extend(form_id(b"TIND", 0x00000E46));
+        data.extend(form_id(b"TINC", 0x00000E46));
+        data.extend(float(b"TINV", 0.5));
+        data.extend(subrecord(b"TIRS", &3u16.to_le_bytes()));
+        data.extend(model());
+        data
+    };
+
+    sample(
+        b"RACE",
+        &[
+            edid("RaceTest"),
+            string(b"FULL", "Test Race"),
+            string(b"DESC", "A race for testing."),
+            form_id(b"SPCT", 1),
+            form_id(b"SPLO", 0x00000E47),
+            form_id(b"WNAM", 0x00000E48),
+            subrecord(b"BOD2", &u32s(&[0x00000001, 0])),
+            keywords(&[0x00000E49]),
+            subrecord(b"DATA", &race_data()),
+            empty(b"MNAM"),
+            skeleton("actors\\character\\male.nif").concat(),
+            empty(b"FNAM"),
+            skeleton("actors\\character\\female.nif").concat(),
+            empty(b"NAM2"),
+            subrecord(b"MTNM", b"WALK"),
+            subrecord(b"MTNM", b"RUN1"),
+            subrecord(b"VTCK", &u32s(&[0x00000E4A, 0x00000E4B])),
+            subrecord(b"DNAM", &u32s(&[0x00000E4C, 0x00000E4D])),
+            subrecord(b"HCLF", &u32s(&[0x00000E4E, 0x00000E4F])),
+            subrecord(b"TINL", &42u16.to_le_bytes()),
+            float(b"PNAM", 5.0),
+            float(b"UNAM", 3.0),
+            form_id(b"ATKR", 0x00000E50),
+            subrecord(b"ATKD", &attack),
+            string(b"ATKE", "attackStart"),
+            empty(b"NAM1"),
+            empty(b"MNAM"),
+            form_id(b"INDX", 0),
+            model_with(
+                [b"MODL", b"MODT", b"MODS"],
+                "actors\\character\\male_body.nif",
+            ),
+            empty(b"FNAM"),
+            form_id(b"INDX", 0),
+            model_with(
+                [b"MODL", b"MODT", b"MODS"],
+                "actors\\character\\female_body.nif",
+            ),
+            subrecord(b"HNAM", &u32s(&[0x00000E51, 0x00000E52])),
+            subrecord(b"ENAM", &u32s(&[0x00000E53])),
+            form_id(b"GNAM", 0x00000E54),
+            empty(b"NAM2"),
+            empty(b"NAM3"),
+            empty(b"MNAM"),
+            model_with([b"MODL", b"MODT", b"MODS"], "actors\\character\\male.hkx"),
+            empty(b"FNAM"),
+            model_with([b"MODL", b"MODT", b"MODS"], "actors\\character\\female.hkx"),
+            form_id(b"NAM4", 0x00000E55),
+            form_id(b"NAM5", 0x00000E56),
+            form_id(b"NAM7", 0x00000E57),
+            form_id(b"ONAM", 0x00000E58),
+            form_id(b"LNAM", 0x00000E59),
+            string(b"NAME", "Head"),
+            string(b"NAME", "Body"),
+            form_id(b"MTYP", 0x00000E5A),
+            subrecord(b"SPED", &f32s(&[1.0; 11])),
+            form_id(b"VNAM", 0x00000003),
+            form_id(b"QNAM", 0x00000E5B),
+            form_id(b"QNAM", 0x00000E5C),
+            form_id(b"UNES", 0x00000E5D),
+            string(b"PHTN", "Aah"),
+            subrecord(b"PHWT", &f32s(&[0.25; 16])),
+            form_id(b"WKMV", 0x00000E5E),
+            form_id(b"RNMV", 0x00000E5F),
+            form_id(b"SWMV", 0x00000E60),
+            form_id(b"FLMV", 0x00000E61),
+            form_id(b"SNMV", 0x00000E62),
+            form_id(b"SPMV", 0x00000E63),
+            empty(b"NAM0"),
+            empty(b"MNAM"),
+            head_data([b"RPRM", b"AHCM", b"FTSM", b"DFTM"]),
+            empty(b"FNAM"),
+            head_data([b"RPRF", b"AHCF", b"FTSF", b"DFTF"]),
+            form_id(b"NAM8", 0x00000E64),
+            form_id(b"RNAM", 0x00000E65),
+        ],
+    )
+}
+
+pub fn refr() -> Vec<u8> {
+    let mut primitive = f32s(&[64.0, 64.0, 32.0, 0.5, 0.5, 0.5, 0.15]);
+    primitive.extend(u32s(&[1]));
+
+    let mut teleport = u32s(&[0x00000E70]);
+    teleport.extend(f32s(&[100.0, 200.0, 0.0, 0.0, 0.0, 1.5]));
+    teleport.extend(u32s(&[0]));
+
+    let mut lock = vec![50, 0, 0, 0];
+    lock.extend(u32s(&[0x00000E71]));
+    lock.extend([0x04, 0, 0, 0]);
+    lock.extend([0; 8]);
+
+    sample(
+        b"REFR",
+        &[
+            edid("ObjectRefTest"),
+            scripts(),
+            form_id(b"NAME", 0x00000E72),
+            subrecord(b"XPRM", &primitive),
+            float(b"XRDS", 128.0),
+            subrecord(b"XTEL", &teleport),
+            float(b"XSCL", 0.75),
+            form_id(b"XLCN", 0x00000E73),
+            subrecord(b"XLOC", &lock),
+            form_id(b"XEZN", 0x00000E74),
+            subrecord(b"XLKR", &u32s(&[0x00000E75, 0x00000E76])),
+            subrecord(b"XLKR", &u32s(&[0x00000E77, 0x00000E78])),
+            subrecord(b"XCNT", &3i32.to_le_bytes()),
+            form_id(b"XOWN", 0x00000E79),
+            subrecord(b"XMRK", &[]),
+            subrecord(b"FNAM", &[0x03]),
+            string(b"FULL", "Test Marker"),
+            subrecord(b"TNAM", &[5, 0]),
+            subrecord(
+                b"XESP",
+                &[u32s(&[0x00000E7A]), vec![0x01, 0, 0, 0]].concat(),
+            ),
+            subrecord(b"DATA", &f32s(&[10.0, 20.0, 30.0, 0.0, 0.0, 1.57])),
+        ],
+    )
+}
+
+pub fn regn() -> Vec<u8> {
+    let header = |kind: u32| [u32s(&[kind]), vec![0x01, 50, 0, 0]].concat();
+
+    let mut object = u32s(&[0x00000E80]);
+    object.extend([0; 4]);
+    object.extend(f32s(&[0.5]));
+    object.extend([10, 0, 45, 0x04]);
+    object.extend([0u16, 256].iter().flat_map(|v| v.to_le_bytes()));
+    object.extend(f32s(&[0.0, 1024.0, 0.0, 0.0, 0.1]));
+    object.extend([0; 12]);
+
+    sample(
+        b"REGN",
+        &[
+            edid("RegionTest"),
+            subrecord(b"RCLR", &[0x20, 0x40, 0x60, 0]),
+            form_id(b"WNAM", 0x00000E81),
+            form_id(b"RPLI", 64),
+            subrecord(b"RPLD", &f32s(&[0.0, 0.0, 4096.0, 0.0, 4096.0, 4096.0])),
+            subrecord(b"RDAT", &header(2)),
+            subrecord(b"RDOT", &object),
+            subrecord(b"RDAT", &header(3)),
+            subrecord(b"RDWT", &u32s(&[0x00000E82, 60, 0, 0x00000E83, 40, 0])),
+            subrecord(b"RDAT", &header(4)),
+            string(b"RDMP", "Test Region"),
+            subrecord(b"RDAT", &header(6)),
+            subrecord(b"RDGS", &[u32s(&[0x00000E84]), vec![0; 4]].concat()),
+            subrecord(b"RDAT", &header(7)),
+            form_id(b"RDMO", 0x00000E85),
+            subrecord(
+                b"RDSA",
+                &[u32s(&[0x00000E86, 0x01]), f32s(&[0.25])].concat(),
+            ),
+        ],
+    )
+}
+
+pub fn rela() -> Vec<u8> {
+    sample(
+        b"RELA",
+        &[
+            edid("RelationshipTest"),
+            subrecord(
+                b"DATA",
+                &[
+                    u32s(&[0x00000E87, 0x00000E88]),
+                    vec![3, 0, 0, 0x80],
+                    u32s(&[0]),
+                ]
+                .concat(),
+            ),
+        ],
+    )
+}
+
+pub fn revb() -> Vec<u8> {
+    sample(b"REVB", &[edid("ReverbTest"), subrecord(b"DATA", &[0; 14])])
+}
+
+pub fn rfct() -> Vec<u8> {
+    sample(
+        b"RFCT",
+        &[
+            edid("VisualEffectTest"),
+            subrecord(b"DATA", &u32s(&[0x00000E89, 0, 0])),
+        ],
+    )
+}
+
+pub fn scen() -> Vec<u8> {
+    let mut scripts = scripts_data();
+    scripts.extend([2, 0x01]);
+    scripts.extend(wstring("SF_SceneTest_00000800"));
+    scripts.push(0);
+    scripts.extend(wstring("SF_SceneTest_00000800"));
+    scripts.extend(wstring("Fragment_0"));
+    scripts.extend(1u16.to_le_bytes());
+    scripts.extend([0x01, 0]);
+    scripts.extend(0i16.to_le_bytes());
+    scripts.push(0);
+    scripts.extend(wstring("SF_SceneTest_00000800"));
+    scripts.extend(wstring("Fragment_1"));
+
+    let empty = |magic: &[u8; 4]| subrecord(magic, &[]);
+
+    sample(
+        b"SCEN",
+        &[
+            edid("SceneTest"),
+            subrecord(b"VMAD", &scripts),
+            form_id(b"FNAM", 0x00000011),
+            empty(b"HNAM"),
+            string(b"NAM0", "Greeting"),
+            condition(),
+            empty(b"NEXT"),
+            subrecord(b"CTDA", &condition_data(14, 0)),
+            empty(b"NEXT"),
+            form_id(b"WNAM", 350),
+            empty(b"HNAM"),
+            subrecord(b"ALID", &0i32.to_le_bytes()),
+            form_id(b"LNAM", 0x00000002),
+            form_id(b"DNAM", 0x00000003),
+            subrecord(b"ANAM", &0u16.to_le_bytes()),
+            subrecord(b"ALID", &0i32.to_le_bytes()),
+            form_id(b"INAM", 1),
+            form_id(b"FNAM", 0x00008000),
+            form_id(b"SNAM", 0),
+            form_id(b"ENAM", 0),
+            form_id(b"DATA", 0x00000E8A),
+            subrecord(b"HTID", &(-1i32).to_le_bytes()),
+            float(b"DMAX", 2.0),
+            float(b"DMIN", 1.0),
+            form_id(b"DEMO", 1),
+            form_id(b"DEVA", 50),
+            empty(b"ANAM"),
+            subrecord(b"ANAM", &1u16.to_le_bytes()),
+            subrecord(b"ALID", &0i32.to_le_bytes()),
+            form_id(b"INAM", 2),
+            form_id(b"SNAM", 0),
+            form_id(b"ENAM", 0),
+            form_id(b"PNAM", 0x00000E8B),
+            empty(b"ANAM"),
+            empty(b"NEXT"),
+            form_id(b"PNAM", 0x00000E8C),
+            form_id(b"INAM", 3),
+            subrecord(b"VNAM", &[0; 4]),
+            condition(),
+        ],
+    )
+}
+
+/// Scroll data (DATA) for a scroll worth 80 gold
+pub fn scrl_data() -> Vec<u8> {
+    [u32s(&[80]), f32s(&[0.5])].concat()
+}
+
+pub fn scrl() -> Vec<u8> {
+    let mut spell = u32s(&[100, 0x00000001, 0]);
+    spell.extend(f32s(&[0.0]));
+    spell.extend(u32s(&[1, 2]));
+    spell.extend(f32s(&[0.0, 0.0]));
+    spell.extend(u32s(&[0]));
+
+    sample(
+        b"SCRL",
+        &[
+            edid("ScrollTest"),
+            bounds(),
+            string(b"FULL", "Test Scroll"),
+            keywords(&[0x00000E8D]),
+            form_id(b"MDOB", 0x00000E8E),
+            form_id(b"ETYP", 0x00000E8F),
+            string(b"DESC", "A test scroll."),
+            model(),
+            destruction(),
+            form_id(b"YNAM", 0x00000E90),
+            form_id(b"ZNAM", 0x00000E91),
+            subrecord(b"DATA", &scrl_data()),
+            subrecord(b"SPIT", &spell),
+            effect(),
+        ],
+    )
+}
+
+/// A shout word (SNAM) learnt from `word`, casting `spell` with a recharge
+/// time of `recharge` seconds
+pub fn shout_word(word: u32, spell: u32, recharge: f32) -> Vec<u8> {
+    subrecord(b"SNAM", &[u32s(&[word, spell]), f32s(&[recharge])].concat())
+}
+
+pub fn shou() -> Vec<u8> {
+    sample(
+        b"SHOU",
+        &[
+            edid("ShoutTest"),
+            string(b"FULL", "Test Shout"),
+            form_id(b"MDOB", 0x00000E92),
+            string(b"DESC", "A test shout."),
+            shout_word(0x00000E93, 0x00000E94, 20.0),
+            shout_word(0x00000E95, 0x00000E96, 30.0),
+            shout_word(0x00000E97, 0x00000E98, 45.0),
+        ],
+    )
+}
+
+pub fn slgm() -> Vec<u8> {
+    sample(
+        b"SLGM",
+        &[
+            edid("SoulGemTest"),
+            bounds(),
+            string(b"FULL", "Test Soul Gem"),
+            model(),
+            subrecord(b"DATA", &[u32s(&[100]), f32s(&[0.1])].concat()),
+            subrecord(b"SOUL", &[0]),
+            subrecord(b"SLCP", &[3]),
+        ],
+    )
+}
+
+pub fn smbn() -> Vec<u8> {
+    sample(
+        b"SMBN",
+        &[
+            edid("BranchNodeTest"),
+            form_id(b"PNAM", 0x00000EA0),
+            form_id(b"SNAM", 0x00000EA1),
+            form_id(b"CITC", 1),
+            condition(),
+            form_id(b"DNAM", 0x00000001),
+            subrecord(b"XNAM", &[0; 4]),
+        ],
+    )
+}
+
+pub fn smen() -> Vec<u8> {
+    sample(
+        b"SMEN",
+        &[
+            edid("EventNodeTest"),
+            form_id(b"PNAM", 0x00000EA2),
+            form_id(b"CITC", 1),
+            condition(),
+            form_id(b"DNAM", 0),
+            subrecord(b"XNAM", &[0; 4]),
+            subrecord(b"ENAM", b"ADIA"),
+        ],
+    )
+}
+
+pub fn smqn() -> Vec<u8> {
+    sample(
+        b"SMQN",
+        &[
+            edid("QuestNodeTest"),
+            form_id(b"PNAM", 0x00000EA3),
+            form_id(b"SNAM", 0x00000EA4),
+            form_id(b"CITC", 1),
+            condition(),
+            subrecord(
+                b"DNAM",
+                &[0x0001u16.to_le_bytes(), 0x0002u16.to_le_bytes()].concat(),
+            ),
+            form_id(b"XNAM", 2),
+            form_id(b"MNAM", 1),
+            form_id(b"QNAM", 2),
+            form_id(b"NNAM", 0x00000EA5),
+            form_id(b"FNAM", 0),
+            float(b"RNAM", 24.0),
+            form_id(b"NNAM", 0x00000EA6),
+            form_id(b"FNAM", 0),
+        ],
+    )
+}
+
+pub fn snct() -> Vec<u8> {
+    sample(
+        b"SNCT",
+        &[
+            edid("SoundCategoryTest"),
+            string(b"FULL", "Test Category"),
+            form_id(b"FNAM", 0x00000002),
+            form_id(b"PNAM", 0x00000EA7),
+            subrecord(b"VNAM", &32768u16.to_le_bytes()),
+            subrecord(b"UNAM", &65535u16.to_le_bytes()),
+        ],
+    )
+}
+
+/// Sound descriptor values (BNAM) with a static attenuation of 3dB
+pub fn sndr_values() -> Vec<u8> {
+    let mut values = vec![(-5i8) as u8, 10, 128, 2];
+    values.extend(300u16.to_le_bytes());
+    values
+}
+
+pub fn sndr() -> Vec<u8> {
+    sample(
+        b"SNDR",
+        &[
+            edid("SoundDescriptorTest"),
+            form_id(b"CNAM", 0x1EEF540A),
+            form_id(b"GNAM", 0x00000EA8),
+            form_id(b"SNAM", 0x00000EA9),
+            string(b"ANAM", "fx\\test01.wav"),
+            string(b"ANAM", "fx\\test02.wav"),
+            form_id(b"ONAM", 0x00000EAA),
+            string(b"FNAM", "unknown"),
+            condition(),
+            subrecord(b"LNAM", &[0, 0x08, 0, 20]),
+            subrecord(b"BNAM", &sndr_values()),
+        ],
+    )
+}
+
+pub fn sopm() -> Vec<u8> {
+    let mut attenuation = vec![0; 4];
+    attenuation.extend(f32s(&[100.0, 2000.0]));
+    attenuation.extend([100, 50, 20, 5, 0, 0, 0, 0]);
+
+    sample(
+        b"SOPM",
+        &[
+            edid("OutputModelTest"),
+            subrecord(b"NAM1", &[0x01, 0, 0, 25]),
+            subrecord(b"FNAM", &[0; 4]),
+            form_id(b"MNAM", 1),
+            subrecord(b"CNAM", &[0; 4]),
+            subrecord(b"SNAM", &[0; 4]),
+            subrecord(b"ONAM", &(0..24).collect::<Vec<u8>>()),
+            subrecord(b"ANAM", &attenuation),
+        ],
+    )
+}
+
+pub fn soun() -> Vec<u8> {
+    sample(
+        b"SOUN",
+        &[
+            edid("SoundTest"),
+            bounds(),
+            string(b"FNAM", "fx\\test.wav"),
+            subrecord(b"SNDD", &[0; 36]),
+            form_id(b"SDSC", 0x00000EAB),
+        ],
+    )
+}
+
+pub fn spel() -> Vec<u8> {
+    let mut spell = u32s(&[45, 0x00000000, 0]);
+    spell.extend(f32s(&[0.5]));
+    spell.extend(u32s(&[1, 2]));
+    spell.extend(f32s(&[0.0, 0.0]));
+    spell.extend(u32s(&[0x00000EAC]));
+
+    sample(
+        b"SPEL",
+        &[
+            edid("SpellTest"),
+            bounds(),
+            string(b"FULL", "Test Spell"),
+            keywords(&[0x00000EAD]),
+            form_id(b"MDOB", 0x00000EAE),
+            form_id(b"ETYP", 0x00000EAF),
+            string(b"DESC", "A test spell."),
+            subrecord(b"SPIT", &spell),
+            effect(),
+            effect(),
+        ],
+    )
+}
+
+pub fn spgd() -> Vec<u8> {
+    sample(
+        b"SPGD",
+        &[
+            edid("ParticleTest"),
+            subrecord(b"DATA", &[0; 48]),
+            string(b"ICON", "textures\\particle.dds"),
+        ],
+    )
+}
+
+pub fn stat() -> Vec<u8> {
+    let lod: Vec<u8> = (0..4)
+        .flat_map(|level| {
+            let mut mesh = format!("lod\\test_lod_{}.nif", level).into_bytes();
+            mesh.resize(260, 0);
+            mesh
+        })
+        .collect();
+
+    sample(
+        b"STAT",
+        &[
+            edid("StaticTest"),
+            bounds(),
+            model(),
+            subrecord(
+                b"DNAM",
+                &[f32s(&[90.0]), u32s(&[0x00000EB0]), vec![0x01, 0, 0, 0]].concat(),
+            ),
+            subrecord(b"MNAM", &lod),
+        ],
+    )
+}
+
+pub fn tact() -> Vec<u8> {
+    sample(
+        b"TACT",
+        &[
+            edid("TalkingActivatorTest"),
+            bounds(),
+            model(),
+            string(b"FULL", "Talking Skull"),
+        ],
+    )
+}
+
+pub fn tree() -> Vec<u8> {
+    sample(
+        b"TREE",
+        &[
+            edid("TreeTest"),
+            bounds(),
+            model(),
+            form_id(b"PFIG", 0x00000F20),
+            form_id(b"SNAM", 0x00000F21),
+            subrecord(b"PFPC", &[50, 60, 70, 80]),
+            string(b"FULL", "Pine"),
+            subrecord(
+                b"CNAM",
+                &[f32s(&[0.5, 0.25]), vec![0; 32], f32s(&[2.0, 0.75])].concat(),
+            ),
+        ],
+    )
+}
+
+pub fn txst() -> Vec<u8> {
+    let mut decal = f32s(&[32.0, 64.0, 32.0, 64.0, 4.0, 1.0, 0.5]);
+    decal.extend([4, 0x01, 0, 0]);
+    decal.extend([255, 128, 64, 0]);
+
+    sample(
+        b"TXST",
+        &[
+            edid("TextureSetTest"),
+            bounds(),
+            string(b"TX00", "textures\\test.dds"),
+            string(b"TX01", "textures\\test_n.dds"),
+            string(b"TX02", "textures\\test_m.dds"),
+            string(b"TX03", "textures\\test_g.dds"),
+            string(b"TX04", "textures\\test_d.dds"),
+            string(b"TX05", "textures\\test_e.dds"),
+            string(b"TX06", "textures\\test_ml.dds"),
+            string(b"TX07", "textures\\test_s.dds"),
+            subrecord(b"DODT", &decal),
+            subrecord(b"DNAM", &0x0001u16.to_le_bytes()),
+        ],
+    )
+}
+
+pub fn vtyp() -> Vec<u8> {
+    sample(
+        b"VTYP",
+        &[edid("VoiceTypeTest"), subrecord(b"DNAM", &[0x01])],
+    )
+}
+
+pub fn watr() -> Vec<u8> {
+    let mut visual = f32s(&[0.1, 0.2, 0.3, 0.4, 50.0, 0.5, 0.25]);
+    visual.extend([0; 4]);
+    visual.extend(f32s(&[100.0, 4000.0]));
+    visual.extend([10, 20, 30, 255, 5, 10, 15, 255, 200, 200, 255, 255]);
+    visual.extend([0; 4]);
+    visual.extend(f32s(&(0..44).map(|i| i as f32).collect::<Vec<_>>()));
+    let velocity = f32s(&[1.0, 2.0, 3.0]);
+
+    sample(
+        b"WATR",
+        &[
+            edid("WaterTest"),
+            string(b"FULL", "Test Water"),
+            string(b"NNAM", "textures\\water\\noise.dds"),
+            subrecord(b"ANAM", &[75]),
+            subrecord(b"FNAM", &[0x01]),
+            subrecord(b"MNAM", &[0]),
+            form_id(b"TNAM", 0x00000F30),
+            form_id(b"SNAM", 0x00000F31),
+            form_id(b"XNAM", 0x00000F32),
+            form_id(b"INAM", 0x00000F33),
+            subrecord(b"DATA", &5u16.to_le_bytes()),
+            subrecord(b"DNAM", &visual),
+            subrecord(b"GNAM", &[0; 12]),
+            subrecord(b"NAM0", &velocity),
+            subrecord(b"NAM1", &velocity),
+            string(b"NAM2", "textures\\water\\layer1.dds"),
+            string(b"NAM3", "textures\\water\\layer2.dds"),
+            string(b"NAM4", "textures\\water\\layer3.dds"),
+            string(b"NAM5", "textures\\water\\flow.dds"),
+        ],
+    )
+}
+
+pub fn weap() -> Vec<u8> {
+    let mut data = 25u32.to_le_bytes().to_vec();
+    data.extend(9.0f32.to_le_bytes());
+    data.extend(7u16.to_le_bytes());
+
+    let mut stats = vec![1, 0, 0, 0];
+    stats.extend(f32s(&[1.0, 0.75]));
+    stats.extend(0x0002u16.to_le_bytes());
+    stats.extend([0, 0]);
+    stats.extend(f32s(&[65.0]));
+    stats.extend(u32s(&[0]));
+    stats.extend([30, 0, 1, 0]);
+    stats.extend(f32s(&[0.0, 0.0]));
+    stats.extend(u32s(&[0, 0]));
+    stats.extend(f32s(&[1.0, 0.0, 1.0, 1.0, 0.33]));
+    stats.extend([0; 12]);
+    stats.extend(6i32.to_le_bytes());
+    stats.extend([0; 8]);
+    stats.extend((-1i32).to_le_bytes());
+    stats.extend(u32s(&[0]));
+    stats.extend(f32s(&[0.5]));
+
+    let mut critical = 3u16.to_le_bytes().to_vec();
+    critical.extend([0, 0]);
+    critical.extend(1.0f32.to_le_bytes());
+    critical.extend([0x01, 0, 0, 0, 0, 0, 0, 0]);
+    critical.extend(u32s(&[0x00000F40, 0]));
+
+    sample(
+        b"WEAP",
+        &[
+            edid("WeaponTest"),
+            scripts(),
+            bounds(),
+            string(b"FULL", "Test Sword"),
+            model(),
+            string(b"ICON", "textures\\sword.dds"),
+            string(b"MICO", "textures\\sword_small.dds"),
+            form_id(b"EITM", 0x00000F41),
+            subrecord(b"EAMT", &500u16.to_le_bytes()),
+            destruction(),
+            form_id(b"ETYP", 0x00000F42),
+            form_id(b"BIDS", 0x00000F43),
+            form_id(b"BAMT", 0x00000F44),
+            form_id(b"YNAM", 0x00000F45),
+            form_id(b"ZNAM", 0x00000F46),
+            keywords(&[0x00000F47, 0x00000F48]),
+            string(b"DESC", "A sword for testing"),
+            string(b"NNAM", "WeaponNode"),
+            form_id(b"INAM", 0x00000F49),
+            form_id(b"WNAM", 0x00000F4A),
+            form_id(b"SNAM", 0x00000F4B),
+            form_id(b"XNAM", 0x00000F4C),
+            form_id(b"NAM7", 0x00000F4D),
+            form_id(b"TNAM", 0x00000F4E),
+            form_id(b"UNAM", 0x00000F4F),
+            form_id(b"NAM9", 0x00000F50),
+            form_id(b"NAM8", 0x00000F51),
+            subrecord(b"DATA", &data),
+            subrecord(b"DNAM", &stats),
+            subrecord(b"CRDT", &critical),
+            subrecord(b"VNAM", &1u32.to_le_bytes()),
+            form_id(b"CNAM", 0x00000F52),
+        ],
+    )
+}
+
+pub fn woop() -> Vec<u8> {
+    sample(
+        b"WOOP",
+        &[
+            edid("WordTest"),
+            string(b"FULL", "Fus"),
+            string(b"TNAM", "Force"),
+        ],
+    )
+}
+
+pub fn wrld() -> Vec<u8> {
+    let mut large = [2i16, -3]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect::<Vec<_>>();
+    large.extend(u32s(&[1, 0x00000F60]));
+    large.extend([2i16, -3].iter().flat_map(|v| v.to_le_bytes()));
+
+    let mut map = [1024i32, 768]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect::<Vec<_>>();
+    map.extend([-10i16, 10, 10, -10].iter().flat_map(|v| v.to_le_bytes()));
+    map.extend(f32s(&[50000.0, 80000.0, 50.0]));
+
+    sample(
+        b"WRLD",
+        &[
+            edid("WorldTest"),
+            subrecord(b"RNAM", &large),
+            subrecord(b"MHDT", &[0x10, 0x20, 0x30, 0x40]),
+            string(b"FULL", "Test World"),
+            subrecord(
+                b"WCTR",
+                &[4i16, -4]
+                    .iter()
+                    .flat_map(|v| v.to_le_bytes())
+                    .collect::<Vec<_>>(),
+            ),
+            form_id(b"LTMP", 0x00000F61),
+            form_id(b"XEZN", 0x00000F62),
+            form_id(b"XLCN", 0x00000F63),
+            form_id(b"WNAM", 0x00000F64),
+            subrecord(b"PNAM", &[0x01, 0x00]),
+            form_id(b"CNAM", 0x00000F65),
+            form_id(b"NAM2", 0x00000F66),
+            form_id(b"NAM3", 0x00000F67),
+            float(b"NAM4", -100.0),
+            subrecord(b"DNAM", &f32s(&[-2048.0, 0.0])),
+            string(b"ICON", "textures\\map.dds"),
+            model(),
+            subrecord(b"MNAM", &map),
+            subrecord(b"ONAM", &f32s(&[1.5, 100.0, 200.0, 0.0])),
+            float(b"NAMA", 1.0),
+            subrecord(b"DATA", &[0x01]),
+            subrecord(b"NAM0", &f32s(&[-40960.0, -40960.0])),
+            subrecord(b"NAM9", &f32s(&[40960.0, 40960.0])),
+            form_id(b"ZNAM", 0x00000F68),
+            string(b"NNAM", "textures\\shadow.dds"),
+            string(b"XNAM", "textures\\water\\noise.dds"),
+            string(b"TNAM", "textures\\lod_d.dds"),
+            string(b"UNAM", "textures\\lod_n.dds"),
+            string(b"XWEM", "textures\\water\\env.dds"),
+            subrecord(b"OFST", &u32s(&[0, 0x100, 0x200])),
+        ],
+    )
+}
+
+pub fn wthr() -> Vec<u8> {
+    let time_of_day = |base: u8| -> Vec<u8> { (0..16).map(|i| base.wrapping_add(i)).collect() };
+    let mut data = vec![128, 0, 0, 8, 64, 0, 10, 20, 30, 40, 50, 0x01];
+    data.extend([255, 255, 200, 0, 0, 180, 20]);
+    let mut sound = 0x00000F70u32.to_le_bytes().to_vec();
+    sound.extend(1u32.to_le_bytes());
+    let mut ambient: Vec<u8> = (0..28).collect();
+    ambient.extend(1.0f32.to_le_bytes());
+
+    sample(
+        b"WTHR",
+        &[
+            edid("WeatherTest"),
+            string(b"00TX", "sky\\cloud0.dds"),
+            string(b"10TX", "sky\\cloud1.dds"),
+            subrecord(b"LNAM", &2u32.to_le_bytes()),
+            form_id(b"MNAM", 0x00000F71),
+            form_id(b"NNAM", 0x00000F72),
+            subrecord(b"ONAM", &[0; 4]),
+            subrecord(b"RNAM", &[1; 32]),
+            subrecord(b"QNAM", &[2; 32]),
+            subrecord(b"PNAM", &[time_of_day(10), time_of_day(40)].concat()),
+            subrecord(b"JNAM", &f32s(&[0.5, 1.0, 0.5, 0.25, 1.0, 1.0, 1.0, 1.0])),
+            subrecord(b"NAM0", &[time_of_day(70), time_of_day(100)].concat()),
+            subrecord(
+                b"FNAM",
+                &f32s(&[100.0, 5000.0, 50.0, 3000.0, 1.0, 1.0, 1.0, 1.0]),
+            ),
+            subrecord(b"DATA", &data),
+            subrecord(b"NAM1", &0u32.to_le_bytes()),
+            subrecord(b"SNAM", &sound),
+            form_id(b"TNAM", 0x00000F73),
+            subrecord(
+                b"IMSP",
+                &u32s(&[0x00000F74, 0x00000F75, 0x00000F76, 0x00000F77]),
+            ),
+            subrecord(
+                b"HNAM",
+                &u32s(&[0x00000F78, 0x00000F79, 0x00000F7A, 0x00000F7B]),
+            ),
+            subrecord(b"DALC", &ambient),
+            subrecord(b"NAM2", &[0; 4]),
+            subrecord(b"NAM3", &[0; 4]),
+            model(),
+            form_id(b"GNAM", 0x00000F7C),
+        ],
+    )
+}
+
+/// Parses a single record, checking that it decodes and that writing the
+/// plugin back gives the same bytes
+pub fn parse(sample: Vec<u8>) -> Record {
+    let magic = String::from_utf8_lossy(&sample[..4]).to_string();
+    let bytes = plugin_bytes(0, &[], &[sample]);
+    let mut written = Cursor::new(Vec::new());
+    Plugin::parse(&mut Cursor::new(&bytes))
+        .unwrap()
+        .write(&mut written)
+        .unwrap();
+    assert!(written.into_inner() == bytes, "{}: round trip", magic);
+
+    let mut plugin = Plugin::parse(&mut Cursor::new(&bytes)).unwrap();
+
+    let Ok(Record::Group(mut group)) = plugin.records.remove(0) else {
+        panic!("{}: expected a group", magic);
+    };
+    match group.records.remove(0) {
+        Ok(record) => record,
+        Err(e) => panic!("{}: {}", magic, e),
+    }
+}
+
+/// One sample of every decoded record type
+pub fn all() -> Vec<Vec<u8>> {
+    vec![
+        aact(),
+        achr(),
+        acti(),
+        addn(),
+        alch(),
+        ammo(),
+        anio(),
+        appa(),
+        arma(),
+        armo(),
+        arto(),
+        aspc(),
+        astp(),
+        avif(),
+        book(),
+        bptd(),
+        cams(),
+        cell(),
+        clas(),
+        clfm(),
+        clmt(),
+        cobj(),
+        coll(),
+        cont(),
+        cpth(),
+        csty(),
+        debr(),
+        dial(),
+        dlbr(),
+        dlvw(),
+        dobj(),
+        door(),
+        dual(),
+        eczn(),
+        efsh(),
+        ench(),
+        equp(),
+        expl(),
+        eyes(),
+        fact(),
+        flor(),
+        flst(),
+        fstp(),
+        fsts(),
+        furn(),
+        glob(),
+        gmst(),
+        gmst_string(),
+        gras(),
+        hazd(),
+        hdpt(),
+        idle(),
+        idlm(),
+        imad(),
+        imgs(),
+        info(),
+        ingr(),
+        ipct(),
+        ipds(),
+        keym(),
+        kywd(),
+        land(),
+        lcrt(),
+        lctn(),
+        lgtm(),
+        ligh(),
+        lscr(),
+        ltex(),
+        lvli(),
+        lvln(),
+        lvsp(),
+        mato(),
+        matt(),
+        mesg(),
+        mgef(),
+        misc(),
+        movt(),
+        mstt(),
+        musc(),
+        must(),
+        navi(),
+        note(),
+        otft(),
+        navm(),
+        npc_(),
+        pack(),
+        perk(),
+        pgre(),
+        phzd(),
+        proj(),
+        qust(),
+        race(),
+        refr(),
+        regn(),
+        rela(),
+        revb(),
+        rfct(),
+        scen(),
+        scrl(),
+        shou(),
+        slgm(),
+        smbn(),
+        smen(),
+        smqn(),
+        snct(),
+        sndr(),
+        sopm(),
+        soun(),
+        spel(),
+        spgd(),
+        stat(),
+        tact(),
+        tree(),
+        txst(),
+        vtyp(),
+        watr(),
+        weap(),
+        woop(),
+        wrld(),
+        wthr(),
+    ]
+}
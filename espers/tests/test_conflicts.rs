@@ -0,0 +1,156 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{form_list, keyword, plugin, MASTER};
+    use espers::common::FormID;
+    use espers::conflict::FieldDiff;
+    use espers::game::Game;
+    use espers::plugin::Plugin;
+    use espers::string_table::StringTables;
+
+    fn game(plugins: Vec<(&str, Plugin)>) -> Game {
+        let plugins = plugins
+            .into_iter()
+            .map(|(n, p)| (n.to_string(), p))
+            .collect();
+        Game::from_load_order(plugins, StringTables::new()).unwrap()
+    }
+
+    fn itm(game: &Game, fid: u32, plugin: &str) -> bool {
+        let conflict = game.conflicts().get(&FormID(fid)).unwrap();
+        conflict
+            .versions
+            .iter()
+            .find(|v| v.plugin == plugin)
+            .unwrap()
+            .itm
+    }
+
+    #[test]
+    fn test_itm() {
+        let game = game(vec![
+            (
+                "Skyrim.esm",
+                plugin(MASTER, &[], &[keyword(0x00000F00, "Base", [1, 2, 3, 0])]),
+            ),
+            (
+                "Mod.esp",
+                plugin(
+                    0,
+                    &["Skyrim.esm"],
+                    &[keyword(0x00000F00, "Base", [1, 2, 3, 0])],
+                ),
+            ),
+        ]);
+
+        assert!(!itm(&game, 0x00000F00, "Skyrim.esm"));
+        assert!(itm(&game, 0x00000F00, "Mod.esp"));
+        assert_eq!(game.conflicts().itms().count(), 1);
+        assert_eq!(game.conflicts().overridden().count(), 1);
+        assert_eq!(game.diff(&FormID(0x00000F00), "Skyrim.esm"), Some(vec![]));
+    }
+
+    #[test]
+    fn test_override() {
+        let game = game(vec![
+            (
+                "Skyrim.esm",
+                plugin(
+                    MASTER,
+                    &[],
+                    &[
+                        keyword(0x00000F00, "First", [0, 0, 0, 0]),
+                        keyword(0x00000F01, "Second", [0, 0, 0, 0]),
+                        form_list(0x00000F10, "List", &[0x00000F00, 0x00000F01]),
+                    ],
+                ),
+            ),
+            (
+                "Mod.esp",
+                plugin(
+                    0,
+                    &["Skyrim.esm"],
+                    &[
+                        keyword(0x01000800, "Added", [0, 0, 0, 0]),
+                        form_list(0x00000F10, "List", &[0x00000F00, 0x01000800, 0x00000F01]),
+                    ],
+                ),
+            ),
+        ]);
+
+        assert!(!itm(&game, 0x00000F10, "Mod.esp"));
+        assert_eq!(game.conflicts().itms().count(), 0);
+        assert_eq!(
+            game.diff(&FormID(0x00000F10), "Skyrim.esm").unwrap(),
+            vec![
+                FieldDiff {
+                    path: "objects[1]".into(),
+                    winner: Some("FormID(0x01000800)".into()),
+                    other: Some("FormID(0x00000F01)".into()),
+                },
+                FieldDiff {
+                    path: "objects[2]".into(),
+                    winner: Some("FormID(0x00000F01)".into()),
+                    other: None,
+                },
+            ]
+        );
+        assert_eq!(game.diff(&FormID(0x00000F10), "Mod.esp"), Some(vec![]));
+    }
+
+    /// The same record referenced through a different master index in each
+    /// plugin is still the same record
+    #[test]
+    fn test_master_index_remap() {
+        let game = game(vec![
+            ("Skyrim.esm", plugin(MASTER, &[], &[])),
+            ("Dawnguard.esm", plugin(MASTER, &["Skyrim.esm"], &[])),
+            (
+                "Update.esm",
+                plugin(
+                    MASTER,
+                    &["Skyrim.esm"],
+                    &[
+                        keyword(0x01000800, "Keyword", [0, 0, 0, 0]),
+                        form_list(0x01000801, "List", &[0x01000800]),
+                    ],
+                ),
+            ),
+            (
+                "Same.esp",
+                plugin(
+                    0,
+                    &["Skyrim.esm", "Dawnguard.esm", "Update.esm"],
+                    &[form_list(0x02000801, "List", &[0x02000800])],
+                ),
+            ),
+            (
+                "Changed.esp",
+                plugin(
+                    0,
+                    &["Skyrim.esm", "Update.esm", "Dawnguard.esm"],
+                    &[form_list(0x01000801, "List", &[0x02000800])],
+                ),
+            ),
+        ]);
+
+        // Update.esm is the third plugin in the load order
+        let list = 0x02000801;
+        let conflict = game.conflicts().get(&FormID(list)).unwrap();
+        let plugins: Vec<_> = conflict.versions.iter().map(|v| &v.plugin).collect();
+        assert_eq!(plugins, ["Update.esm", "Same.esp", "Changed.esp"]);
+
+        assert!(itm(&game, list, "Same.esp"));
+        assert!(!itm(&game, list, "Changed.esp"));
+        assert_eq!(game.diff(&FormID(list), "Same.esp").unwrap().len(), 1);
+        assert_eq!(
+            game.diff(&FormID(list), "Update.esm").unwrap(),
+            vec![FieldDiff {
+                path: "objects[0]".into(),
+                winner: Some("FormID(0x01000800)".into()),
+                other: Some("FormID(0x02000800)".into()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,291 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{keyword, plugin, plugin_bytes, LIGHT_MASTER, MASTER};
+    use espers::common::FormID;
+    use espers::error::{Error, MasterError};
+    use espers::game::{parse_load_order, Game, LoadIndex};
+    use espers::plugin::Plugin;
+    use espers::records::Record;
+    use espers::string_table::StringTables;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+    use std::path::{Path, PathBuf};
+
+    fn game(plugins: Vec<(&str, Plugin)>) -> Result<Game, Error> {
+        let plugins = plugins
+            .into_iter()
+            .map(|(n, p)| (n.to_string(), p))
+            .collect();
+        Game::from_load_order(plugins, StringTables::new())
+    }
+
+    fn sample() -> Game {
+        game(vec![
+            (
+                "Skyrim.esm",
+                plugin(MASTER, &[], &[keyword(0x00000F00, "Base", [1, 1, 1, 0])]),
+            ),
+            (
+                "Light.esl",
+                plugin(
+                    LIGHT_MASTER,
+                    &["Skyrim.esm"],
+                    &[keyword(0x01000801, "LightKeyword", [2, 2, 2, 0])],
+                ),
+            ),
+            (
+                "Mod.esp",
+                plugin(
+                    0,
+                    &["Skyrim.esm", "Light.esl"],
+                    &[
+                        keyword(0x00000F00, "Base", [3, 3, 3, 0]),
+                        keyword(0x01000801, "LightKeyword", [4, 4, 4, 0]),
+                        keyword(0x02000802, "ModKeyword", [5, 5, 5, 0]),
+                    ],
+                ),
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_load_indices() {
+        let game = sample();
+
+        assert_eq!(game.load_index("Skyrim.esm"), Some(LoadIndex::Full(0)));
+        assert_eq!(game.load_index("light.ESL"), Some(LoadIndex::Light(0)));
+        assert_eq!(game.load_index("Mod.esp"), Some(LoadIndex::Full(1)));
+        assert_eq!(game.load_index("Missing.esp"), None);
+    }
+
+    #[test]
+    fn test_global_form_id() {
+        let game = sample();
+        let global = |p: &str, fid: u32| game.global_form_id(p, &FormID(fid)).map(|f| f.0);
+
+        assert_eq!(global("Skyrim.esm", 0x00000F00), Some(0x00000F00));
+        assert_eq!(global("Light.esl", 0x00000F00), Some(0x00000F00));
+        assert_eq!(global("Light.esl", 0x01000801), Some(0xFE000801));
+        assert_eq!(global("Mod.esp", 0x01000801), Some(0xFE000801));
+        assert_eq!(global("Mod.esp", 0x02000802), Some(0x01000802));
+
+        // Index past the plugin itself
+        assert_eq!(global("Mod.esp", 0x03000802), None);
+    }
+
+    #[test]
+    fn test_local_form_id() {
+        let game = sample();
+        let local = |p: &str, fid: u32| game.local_form_id(p, &FormID(fid)).map(|f| f.0);
+
+        assert_eq!(local("Mod.esp", 0x00000F00), Some(0x00000F00));
+        assert_eq!(local("Mod.esp", 0xFE000801), Some(0x01000801));
+        assert_eq!(local("Light.esl", 0xFE000801), Some(0x01000801));
+        assert_eq!(local("Mod.esp", 0x01000802), Some(0x02000802));
+
+        // Not visible from a plugin that does not have it as a master
+        assert_eq!(local("Skyrim.esm", 0x01000802), None);
+        assert_eq!(local("Light.esl", 0x01000802), None);
+    }
+
+    #[test]
+    fn test_light_addressing() {
+        assert_eq!(LoadIndex::Light(0x123).form_id(0x801).0, 0xFE123801);
+        assert_eq!(LoadIndex::Light(0x123).form_id(0x1801).0, 0xFE123801);
+        assert_eq!(
+            LoadIndex::Light(0x123).object(&FormID(0xFE123801)),
+            Some(0x801)
+        );
+        assert_eq!(LoadIndex::Light(0x123).object(&FormID(0xFE124801)), None);
+        assert_eq!(LoadIndex::Full(0xFD).object(&FormID(0xFE123801)), None);
+    }
+
+    #[test]
+    fn test_winner() {
+        let game = sample();
+        let color = |fid: u32| match game.get_record_by_form_id(&FormID(fid)) {
+            Some(Ok(Record::Keyword(k))) => k.color.unwrap().r,
+            other => panic!("{:?}", other),
+        };
+
+        assert_eq!(color(0x00000F00), 3);
+        assert_eq!(color(0xFE000801), 4);
+        assert_eq!(color(0x01000802), 5);
+
+        let conflict = game.conflicts().get(&FormID(0xFE000801)).unwrap();
+        assert_eq!(conflict.origin().plugin, "Light.esl");
+        assert_eq!(conflict.winner().plugin, "Mod.esp");
+    }
+
+    #[test]
+    fn test_full_overflow() {
+        let plugins = |n: usize| {
+            (0..n)
+                .map(|i| (format!("Plugin{}.esp", i), plugin(0, &[], &[])))
+                .collect::<Vec<_>>()
+        };
+
+        let game = Game::from_load_order(plugins(0xFE), StringTables::new()).unwrap();
+        assert_eq!(
+            game.load_index("Plugin253.esp"),
+            Some(LoadIndex::Full(0xFD))
+        );
+
+        match Game::from_load_order(plugins(0xFF), StringTables::new()) {
+            Err(Error::LoadIndexOverflow(name)) => assert_eq!(name, "Plugin254.esp"),
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_light_overflow() {
+        let plugins = |n: usize| {
+            (0..n)
+                .map(|i| (format!("Plugin{}.esl", i), plugin(0, &[], &[])))
+                .collect::<Vec<_>>()
+        };
+
+        let game = Game::from_load_order(plugins(0x1000), StringTables::new()).unwrap();
+        assert_eq!(
+            game.load_index("Plugin4095.esl"),
+            Some(LoadIndex::Light(0xFFF))
+        );
+
+        match Game::from_load_order(plugins(0x1001), StringTables::new()) {
+            Err(Error::LoadIndexOverflow(name)) => assert_eq!(name, "Plugin4096.esl"),
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+    }
+
+    /// Writes a data directory of empty plugins with the given masters, along
+    /// with a `plugins.txt`
+    fn data_dir(name: &str, plugins: &[(&str, &[&str])], load_order: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("espers-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+        create_dir_all(&dir).unwrap();
+
+        for (plugin, masters) in plugins {
+            write(dir.join(plugin), plugin_bytes(0, masters, &[])).unwrap();
+        }
+        write(dir.join("plugins.txt"), load_order).unwrap();
+
+        dir
+    }
+
+    fn load(dir: &Path) -> Result<Game, Error> {
+        Game::load_from_load_order(
+            &dir.to_string_lossy(),
+            &dir.join("plugins.txt").to_string_lossy(),
+            "English",
+        )
+    }
+
+    #[test]
+    fn test_parse_plugins_txt() {
+        let contents =
+            "# This file is used by Skyrim to keep track of your downloaded content.\r\n\
+                        *Mod.esp\r\n\
+                        Disabled.esp\r\n\
+                        \r\n\
+                        *Other.esp\r\n";
+
+        assert_eq!(parse_load_order(contents), vec!["Mod.esp", "Other.esp"]);
+    }
+
+    #[test]
+    fn test_parse_loadorder_txt() {
+        let contents = "# comment\nSkyrim.esm\n  Update.esm  \n\n# another\nMod.esp";
+
+        assert_eq!(
+            parse_load_order(contents),
+            vec!["Skyrim.esm", "Update.esm", "Mod.esp"]
+        );
+    }
+
+    #[test]
+    fn test_implicit_masters() {
+        let dir = data_dir(
+            "implicit",
+            &[
+                ("Skyrim.esm", &[]),
+                ("Update.esm", &["Skyrim.esm"]),
+                ("Dragonborn.esm", &["Skyrim.esm", "Update.esm"]),
+                ("Mod.esp", &["Skyrim.esm", "Dragonborn.esm"]),
+            ],
+            "*Mod.esp\r\n",
+        );
+        let game = load(&dir).unwrap();
+        remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            game.load_order(),
+            ["Skyrim.esm", "Update.esm", "Dragonborn.esm", "Mod.esp"]
+        );
+    }
+
+    #[test]
+    fn test_listed_implicit_master() {
+        let dir = data_dir(
+            "listed",
+            &[("Skyrim.esm", &[]), ("Update.esm", &["Skyrim.esm"])],
+            "Skyrim.esm\nUpdate.esm\n",
+        );
+        let game = load(&dir).unwrap();
+        remove_dir_all(&dir).unwrap();
+
+        assert_eq!(game.load_order(), ["Skyrim.esm", "Update.esm"]);
+    }
+
+    #[test]
+    fn test_missing_master() {
+        let dir = data_dir(
+            "missing",
+            &[
+                ("Skyrim.esm", &[]),
+                ("Mod.esp", &["Skyrim.esm", "Gone.esm"]),
+            ],
+            "*Mod.esp\n",
+        );
+        let result = load(&dir);
+        remove_dir_all(&dir).unwrap();
+
+        match result {
+            Err(Error::InvalidLoadOrder(errors)) => assert_eq!(
+                errors,
+                vec![MasterError::Missing {
+                    plugin: "Mod.esp".into(),
+                    master: "Gone.esm".into(),
+                }]
+            ),
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_master_out_of_order() {
+        let dir = data_dir(
+            "order",
+            &[
+                ("Skyrim.esm", &[]),
+                ("Master.esm", &["Skyrim.esm"]),
+                ("Mod.esp", &["skyrim.esm", "Master.esm"]),
+            ],
+            "Skyrim.esm\nMod.esp\nMaster.esm\n",
+        );
+        let result = load(&dir);
+        remove_dir_all(&dir).unwrap();
+
+        match result {
+            Err(Error::InvalidLoadOrder(errors)) => assert_eq!(
+                errors,
+                vec![MasterError::OutOfOrder {
+                    plugin: "Mod.esp".into(),
+                    master: "Master.esm".into(),
+                }]
+            ),
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{info, parse};
+    use espers::fields::FragmentList;
+    use espers::records::Record;
+
+    #[test]
+    fn test_info_fields() {
+        let Record::DialogueTopicInfo(info) = parse(info()) else {
+            panic!("expected a DialogueTopicInfo");
+        };
+        assert_eq!(info.edid.as_deref(), Some("InfoTest"));
+        assert_eq!(info.flags.as_ref().unwrap().reset_hours, 24);
+        assert_eq!(info.topic.as_ref().unwrap().0, 0x00000DA1);
+        assert_eq!(info.favor_level, Some(2));
+        assert_eq!(info.linked_topics.len(), 2);
+        assert_eq!(info.responses.len(), 1);
+        assert_eq!(info.responses[0].data.emotion_value, 50);
+        assert_eq!(info.responses[0].data.sound.0, 0x00000DA0);
+        assert_eq!(info.responses[0].text.to_string(), "Well met.");
+        assert_eq!(info.responses[0].notes.as_deref(), Some("Friendly"));
+        assert_eq!(
+            info.responses[0].listener_idle.as_ref().unwrap().0,
+            0x00000DA6
+        );
+        assert_eq!(info.conditions.len(), 2);
+        assert_eq!(info.speaker.as_ref().unwrap().0, 0x00000DA8);
+
+        let fragments = info.scripts.unwrap().fragments;
+        let Some(FragmentList::TopicInfo(fragments)) = fragments else {
+            panic!("expected topic info fragments");
+        };
+        assert_eq!(fragments.filename.to_string(), "TIF__0000800");
+        assert_eq!(
+            fragments.fragments[0].fragment_name.to_string(),
+            "Fragment_0"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{parse, FORM_ID};
+    use super::common::{record, subrecord};
+    use espers::records::land::{HeightMap, TextureLayer, GRID_SIZE, HEIGHT_SCALE};
+    use espers::records::Record;
+
+    #[test]
+    fn test_heights() {
+        // Each row starts one step above the previous one, and then climbs
+        // by -1, 0 or 1 per column depending on the row
+        let gradients = (0..GRID_SIZE)
+            .flat_map(|row| {
+                (0..GRID_SIZE).map(move |col| if col == 0 { 1 } else { (row % 3) as i8 - 1 })
+            })
+            .collect();
+        let height_map = HeightMap {
+            offset: 2.0,
+            gradients,
+            unused: [0; 3],
+        };
+
+        let heights = height_map.heights();
+        for (row, heights) in heights.iter().enumerate() {
+            let row_start = 2.0 + (row + 1) as f32;
+            let step = (row % 3) as f32 - 1.0;
+            for (col, height) in heights.iter().enumerate() {
+                assert_eq!(*height, (row_start + col as f32 * step) * HEIGHT_SCALE);
+            }
+        }
+        assert_eq!(heights[0][0], 3.0 * HEIGHT_SCALE);
+        assert_eq!(heights[32][32], (35.0 + 32.0 * 1.0) * HEIGHT_SCALE);
+    }
+
+    #[test]
+    fn test_empty_alpha_layer() {
+        let mut header = 0x00000F11u32.to_le_bytes().to_vec();
+        header.extend([1, 0, 1, 0]);
+        let data = [subrecord(b"ATXT", &header), subrecord(b"VTXT", &[])].concat();
+
+        let Record::Landscape(land) = parse(record(b"LAND", 0, FORM_ID, &data)) else {
+            panic!("expected a Landscape");
+        };
+        let TextureLayer::Alpha { points, .. } = &land.layers[0] else {
+            panic!("expected an alpha layer");
+        };
+        assert!(points.as_ref().is_some_and(Vec::is_empty));
+    }
+}
@@ -0,0 +1,148 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{global, leveled_item, plugin, LIGHT_MASTER, MASTER};
+    use espers::common::FormID;
+    use espers::game::Game;
+    use espers::leveled::Roller;
+    use espers::plugin::Plugin;
+    use espers::string_table::StringTables;
+
+    const FROM_ALL_LEVELS: u8 = 0x01;
+    const FOR_EACH_ITEM: u8 = 0x02;
+    const USE_ALL: u8 = 0x04;
+
+    fn roll(plugin: &Plugin, seed: u64, list: u32, level: u16) -> Vec<(u32, u32)> {
+        Roller::new(seed)
+            .roll(plugin, &FormID(list), level)
+            .into_iter()
+            .map(|(f, c)| (f.0, c))
+            .collect()
+    }
+
+    #[test]
+    fn test_same_seed() {
+        let entries: Vec<_> = (0..10).map(|i| (1, 0x100 + i, 1)).collect();
+        let plugin = plugin(MASTER, &[], &[leveled_item(0x800, 0, 0, None, &entries)]);
+
+        let rolls: Vec<_> = (0..20).map(|seed| roll(&plugin, seed, 0x800, 1)).collect();
+        for (seed, result) in rolls.iter().enumerate() {
+            assert_eq!(result.len(), 1);
+            assert_eq!(*result, roll(&plugin, seed as u64, 0x800, 1));
+        }
+        assert!(rolls.iter().any(|r| *r != rolls[0]));
+    }
+
+    #[test]
+    fn test_level_filtering() {
+        let entries = [(1, 0x100, 1), (10, 0x200, 1), (20, 0x300, 1)];
+        let plugin = plugin(
+            MASTER,
+            &[],
+            &[
+                leveled_item(0x800, 0, 0, None, &entries),
+                leveled_item(0x801, 0, FROM_ALL_LEVELS, None, &entries),
+            ],
+        );
+
+        for seed in 0..20 {
+            assert_eq!(roll(&plugin, seed, 0x800, 15), vec![(0x200, 1)]);
+            assert_eq!(roll(&plugin, seed, 0x800, 0), vec![]);
+        }
+
+        let picked: Vec<_> = (0..50)
+            .map(|seed| roll(&plugin, seed, 0x801, 15)[0].0)
+            .collect();
+        assert!(picked.contains(&0x100));
+        assert!(picked.contains(&0x200));
+        assert!(!picked.contains(&0x300));
+    }
+
+    #[test]
+    fn test_use_all() {
+        let plugin = plugin(
+            MASTER,
+            &[],
+            &[leveled_item(
+                0x800,
+                0,
+                USE_ALL,
+                None,
+                &[(1, 0x100, 2), (1, 0x200, 3), (5, 0x300, 1)],
+            )],
+        );
+
+        assert_eq!(roll(&plugin, 0, 0x800, 1), vec![(0x100, 2), (0x200, 3)]);
+    }
+
+    #[test]
+    fn test_calculate_for_each_item() {
+        let nested = [(1, 0x100, 1), (1, 0x200, 1)];
+        let plugin = plugin(
+            MASTER,
+            &[],
+            &[
+                leveled_item(0x800, 0, 0, None, &nested),
+                leveled_item(0x801, 0, 0, None, &[(1, 0x800, 3)]),
+                leveled_item(0x802, 0, FOR_EACH_ITEM, None, &[(1, 0x800, 3)]),
+            ],
+        );
+
+        for seed in 0..20 {
+            let once = roll(&plugin, seed, 0x801, 1);
+            assert_eq!(once.len(), 1);
+            assert_eq!(once[0].1, 3);
+
+            let each = roll(&plugin, seed, 0x802, 1);
+            assert_eq!(each.len(), 3);
+            assert!(each.iter().all(|(_, count)| *count == 1));
+        }
+    }
+
+    #[test]
+    fn test_recursion_limit() {
+        let plugin = plugin(
+            MASTER,
+            &[],
+            &[leveled_item(
+                0x800,
+                0,
+                USE_ALL,
+                None,
+                &[(1, 0x800, 1), (1, 0x100, 1)],
+            )],
+        );
+
+        let result = roll(&plugin, 0, 0x800, 1);
+        assert_eq!(result.len(), 32);
+        assert!(result.iter().all(|r| *r == (0x100, 1)));
+    }
+
+    /// The chance none global is stored as a FormID of the list's plugin, so
+    /// has to be resolved into the load order like the entries are
+    #[test]
+    fn test_chance_none_global() {
+        let plugins = vec![
+            ("Skyrim.esm".to_string(), plugin(MASTER, &[], &[])),
+            (
+                "Lists.esl".to_string(),
+                plugin(
+                    LIGHT_MASTER,
+                    &["Skyrim.esm"],
+                    &[
+                        global(0x01000801, "AlwaysNone", 100.0),
+                        leveled_item(0x01000802, 0, 0, Some(0x01000801), &[(1, 0x100, 1)]),
+                    ],
+                ),
+            ),
+        ];
+        let game = Game::from_load_order(plugins, StringTables::new()).unwrap();
+
+        for seed in 0..20 {
+            assert!(Roller::new(seed)
+                .roll(&game, &FormID(0xFE000802), 1)
+                .is_empty());
+        }
+    }
+}
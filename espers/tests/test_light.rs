@@ -0,0 +1,19 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{ligh, parse};
+    use espers::records::Record;
+    use rgb::RGBA8;
+
+    #[test]
+    fn test_light_data() {
+        let Record::Light(light) = parse(ligh()) else {
+            panic!("expected a Light");
+        };
+        assert_eq!(light.data.time, -1);
+        assert_eq!(light.data.radius, 256);
+        assert_eq!(light.data.color, RGBA8::new(0xFF, 0xC0, 0x80, 0));
+        assert_eq!(light.data.value, 10);
+    }
+}
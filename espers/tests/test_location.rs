@@ -0,0 +1,92 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{lcrt, lctn, parse, regn};
+    use espers::common::LocalizedString;
+    use espers::records::Record;
+    use rgb::RGBA8;
+
+    #[test]
+    fn test_location_fields() {
+        let Record::Location(location) = parse(lctn()) else {
+            panic!("expected a Location");
+        };
+        assert_eq!(location.edid, "LocationTest");
+
+        let refs = &location.refs;
+        assert_eq!(refs.added_persistent.len(), 1);
+        assert_eq!(refs.added_persistent[0].reference.0, 0x00000DC0);
+        assert_eq!(refs.added_persistent[0].location.0, 0x00000DC1);
+        assert_eq!(refs.added_persistent[0].grid_y, -3);
+        assert_eq!(refs.added_persistent[0].grid_x, 7);
+        assert_eq!(refs.removed_persistent[0].0, 0x00000DC2);
+        assert_eq!(refs.added_unique[0].location.0, 0x00000DC5);
+        assert_eq!(refs.added_encounter_cells[0].location.0, 0x00000DC6);
+        assert_eq!(refs.added_encounter_cells[0].cells, [(-3, 7), (-3, 7)]);
+        assert_eq!(refs.added_markers[0].0, 0x00000DC7);
+        assert_eq!(refs.added_enable_points[0].reference.0, 0x00000DC9);
+
+        assert!(matches!(
+            location.full_name,
+            Some(LocalizedString::ZString(ref name)) if name == "Test Location"
+        ));
+        assert_eq!(location.keywords[0].0, 0x00000DCA);
+        assert_eq!(location.parent.unwrap().0, 0x00000DCB);
+        assert_eq!(location.music.unwrap().0, 0x00000DCC);
+        assert_eq!(location.unreported_crime_faction.unwrap().0, 0x00000DCD);
+        assert_eq!(location.world_location_marker.unwrap().0, 0x00000DCE);
+        assert_eq!(location.world_location_radius, Some(512.0));
+        assert_eq!(location.horse_marker.unwrap().0, 0x00000DCF);
+        assert_eq!(location.color, Some(RGBA8::new(0x70, 0x80, 0x90, 0)));
+    }
+
+    #[test]
+    fn test_location_ref_fields() {
+        let Record::LocationRef(location) = parse(lcrt()) else {
+            panic!("expected a LocationRef");
+        };
+        assert_eq!(location.edid, "LocationRefTest");
+        assert_eq!(location.color, Some(RGBA8::new(0x40, 0x50, 0x60, 0)));
+    }
+
+    #[test]
+    fn test_region_fields() {
+        let Record::Region(region) = parse(regn()) else {
+            panic!("expected a Region");
+        };
+        assert_eq!(region.edid.as_deref(), Some("RegionTest"));
+        assert_eq!(region.map_color, Some(RGBA8::new(0x20, 0x40, 0x60, 0)));
+        assert_eq!(region.worldspace.unwrap().0, 0x00000E81);
+        assert_eq!(region.areas.len(), 1);
+        assert_eq!(region.areas[0].edge_falloff, Some(64));
+        assert_eq!(region.areas[0].points.len(), 3);
+        assert_eq!(region.areas[0].points[2].y, 4096.0);
+        assert_eq!(region.data.len(), 5);
+
+        let object = &region.data[0].objects[0];
+        assert_eq!(region.data[0].header.priority, 50);
+        assert_eq!(object.object.0, 0x00000E80);
+        assert_eq!(object.density, 0.5);
+        assert_eq!(object.clustering, 10);
+        assert_eq!(object.max_slope, 45);
+        assert_eq!(object.radius, 256);
+        assert_eq!(object.max_height, 1024.0);
+        assert_eq!(object.size_variance, 0.1);
+
+        let weathers = &region.data[1].weathers;
+        assert_eq!(weathers.len(), 2);
+        assert_eq!(weathers[0].weather.0, 0x00000E82);
+        assert_eq!(weathers[0].chance, 60);
+        assert_eq!(weathers[1].chance, 40);
+
+        assert!(matches!(
+            region.data[2].map_name,
+            Some(LocalizedString::ZString(ref name)) if name == "Test Region"
+        ));
+        assert_eq!(region.data[3].grasses[0].grass.0, 0x00000E84);
+        assert_eq!(region.data[4].music.as_ref().unwrap().0, 0x00000E85);
+        assert_eq!(region.data[4].sounds[0].sound.0, 0x00000E86);
+        assert_eq!(region.data[4].sounds[0].chance, 0.25);
+    }
+}
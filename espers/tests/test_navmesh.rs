@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use binrw::{BinRead, BinWrite};
+    use espers::common::FormID;
+    use espers::fields::NVNM;
+    use espers::records::navm::{NavMeshGeometry, NavMeshGridCell, Triangle, Vertex};
+    use std::io::Cursor;
+
+    fn geometry(vertex_count: usize, triangles: &[[u16; 3]]) -> NavMeshGeometry {
+        let vertices: Vec<_> = (0..vertex_count)
+            .map(|i| Vertex {
+                x: i as f32,
+                y: 0.0,
+                z: 0.0,
+            })
+            .collect();
+        let triangles: Vec<_> = triangles
+            .iter()
+            .map(|t| Triangle {
+                vertices: *t,
+                edges: [-1; 3],
+                flags: 0,
+                cover_flags: 0,
+            })
+            .collect();
+
+        NavMeshGeometry {
+            version: 12,
+            unknown: 0,
+            worldspace: FormID(0),
+            cell: Some(FormID(0x00000F00)),
+            grid: None,
+            vertices,
+            triangles,
+            edge_links: vec![],
+            door_triangles: vec![],
+            cover_triangles: vec![],
+            grid_size: 0,
+            max_x_distance: 0.0,
+            max_y_distance: 0.0,
+            min: Vertex {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            max: Vertex {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            grid_cells: vec![],
+        }
+    }
+
+    fn write(field: NVNM) -> Vec<u8> {
+        let mut cursor = Cursor::new(Vec::new());
+        field.write_le(&mut cursor).unwrap();
+        cursor.into_inner()
+    }
+
+    #[test]
+    fn test_invalid_triangles() {
+        let valid = geometry(3, &[[0, 1, 2], [2, 1, 0]]);
+        assert!(valid.invalid_triangles().is_empty());
+        assert!(valid.is_valid());
+
+        let invalid = geometry(3, &[[0, 1, 2], [0, 1, 3], [1, 2, 0], [7, 7, 7]]);
+        assert_eq!(invalid.invalid_triangles(), vec![1, 3]);
+        assert!(!invalid.is_valid());
+        assert!(invalid.triangle_vertices(&invalid.triangles()[1]).is_none());
+        assert_eq!(
+            invalid.triangle_vertices(&invalid.triangles()[2]).unwrap()[0].x,
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_small_geometry() {
+        let bytes = write(NVNM::try_from(geometry(3, &[[0, 1, 2]])).unwrap());
+
+        assert_eq!(&bytes[..4], b"NVNM");
+        assert_eq!(
+            u16::from_le_bytes([bytes[4], bytes[5]]) as usize,
+            bytes.len() - 6
+        );
+    }
+
+    /// Geometry over 64 KiB is written after an XXXX holding its real size,
+    /// and read back the same way
+    #[test]
+    fn test_oversized_geometry() {
+        let bytes = write(NVNM::try_from(geometry(6000, &[[0, 1, 5999]])).unwrap());
+        let len = bytes.len() - 16;
+        assert!(len > u16::MAX as usize);
+
+        assert_eq!(&bytes[..4], b"XXXX");
+        assert_eq!(&bytes[4..6], &4u16.to_le_bytes());
+        assert_eq!(&bytes[6..10], &(len as u32).to_le_bytes());
+        assert_eq!(&bytes[10..14], b"NVNM");
+        assert_eq!(&bytes[14..16], &0u16.to_le_bytes());
+
+        let field = NVNM::read_le(&mut Cursor::new(&bytes)).unwrap();
+        assert_eq!(field.data.len(), len);
+
+        let read = NavMeshGeometry::try_from(field).unwrap();
+        assert_eq!(read.vertices().len(), 6000);
+        assert_eq!(read.vertices()[5999].x, 5999.0);
+        assert!(read.is_valid());
+    }
+
+    #[test]
+    fn test_grid_cells() {
+        let mut geometry = geometry(3, &[[0, 1, 2]]);
+        geometry.grid_size = 2;
+        geometry.grid_cells = (0..4)
+            .map(|i| NavMeshGridCell {
+                triangles: vec![0; i],
+            })
+            .collect();
+
+        let read = NavMeshGeometry::try_from(
+            NVNM::read_le(&mut Cursor::new(write(geometry.try_into().unwrap()))).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(read.grid_cells.len(), 4);
+        assert_eq!(read.grid_cells[3].triangles, vec![0; 3]);
+    }
+
+    /// A grid size whose square does not fit in 32 bits is an error rather
+    /// than an overflow
+    #[test]
+    fn test_grid_size_overflow() {
+        let mut geometry = geometry(3, &[[0, 1, 2]]);
+        geometry.grid_size = 0x10000;
+
+        let field = NVNM::read_le(&mut Cursor::new(write(geometry.try_into().unwrap()))).unwrap();
+        assert!(NavMeshGeometry::try_from(field).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{npc_, parse};
+    use espers::records::Record;
+
+    #[test]
+    fn test_npc_fields() {
+        let Record::NPC(npc) = parse(npc_()) else {
+            panic!("expected an NPC");
+        };
+        assert_eq!(npc.edid, "ActorTest");
+        assert_eq!(npc.stats.level, 10);
+        assert_eq!(npc.stats.health_offset, 100);
+        assert_eq!(npc.factions[0].faction.0, 0x00000E01);
+        assert_eq!(npc.factions[0].rank, 2);
+        assert_eq!(npc.race.0, 0x00000E05);
+        assert_eq!(npc.spells.len(), 1);
+        assert_eq!(npc.perks[0].rank, 1);
+        assert_eq!(npc.items[0].item.0, 0x00000E0F);
+        assert_eq!(npc.items[0].count, 3);
+        assert_eq!(npc.ai_data.confidence, 2);
+        assert_eq!(npc.class.0, 0x00000E12);
+        assert_eq!(npc.player_skills.skill_values[0], 15);
+        assert_eq!(npc.player_skills.health, 50);
+        assert_eq!(npc.head_parts.len(), 2);
+        assert_eq!(npc.height, 1.05);
+        assert_eq!(npc.weight, 50.0);
+        assert_eq!(npc.sound_level, 2);
+        assert_eq!(npc.tint_layers[0].index, 7);
+        assert_eq!(npc.tint_layers[0].value, Some(60));
+    }
+}
@@ -0,0 +1,47 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{pack, parse};
+    use espers::records::pack::{PackageTopic, PackageValueData};
+    use espers::records::Record;
+
+    #[test]
+    fn test_package_fields() {
+        let Record::AIPackage(pack) = parse(pack()) else {
+            panic!("expected an AIPackage");
+        };
+        assert_eq!(pack.edid, "PackageTest");
+        assert_eq!(pack.data.flags, 0x4);
+        assert_eq!(pack.data.kind, 18);
+        assert_eq!(pack.data.interrupt_flags, 0x01FF);
+        assert_eq!(pack.schedule.hour, 8);
+        assert_eq!(pack.schedule.duration, 4);
+        assert_eq!(pack.conditions.len(), 1);
+
+        let idles = pack.idle_animations.unwrap();
+        assert_eq!(idles.timer, Some(10.0));
+        assert_eq!(idles.animations.len(), 2);
+        assert_eq!(pack.template.unwrap().version, 7);
+
+        assert_eq!(pack.values.len(), 2);
+        assert_eq!(pack.values[0].kind, "Location");
+        assert_eq!(pack.values[0].location.as_ref().unwrap().radius, 512);
+        assert!(matches!(
+            pack.values[1].value,
+            Some(PackageValueData::Bool(true))
+        ));
+        assert_eq!(pack.inputs.len(), 2);
+        assert_eq!(pack.inputs[1].name.as_deref(), Some("AllowEating"));
+        assert_eq!(pack.procedure_tree[0].procedure.as_deref(), Some("Sandbox"));
+
+        let begin = pack.on_begin.unwrap();
+        assert_eq!(begin.idle.unwrap().0, 0x00000E26);
+        assert!(matches!(
+            begin.topics[..],
+            [PackageTopic::Reference(ref t)] if t.0 == 0x00000E27
+        ));
+        assert!(pack.on_end.is_some());
+        assert!(pack.on_change.is_some());
+    }
+}
@@ -0,0 +1,35 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{parse, FORM_ID};
+    use super::common::{record, subrecord};
+    use espers::records::perk::PerkEffectData;
+    use espers::records::Record;
+
+    #[test]
+    fn test_entry_point_without_data() {
+        let data = [
+            subrecord(b"EDID", b"PerkTest\0"),
+            subrecord(b"DESC", b"Does something.\0"),
+            subrecord(b"DATA", &[0, 25, 1, 2, 0]),
+            subrecord(b"PRKE", &[2, 0, 0]),
+            subrecord(b"DATA", &[36, 9, 0]),
+            subrecord(b"EPF2", b"Absorb\0"),
+            subrecord(b"EPF3", &[1, 0, 0, 0]),
+            subrecord(b"PRKF", &[]),
+        ]
+        .concat();
+
+        let Record::Perk(perk) = parse(record(b"PERK", 0, FORM_ID, &data)) else {
+            panic!("expected a Perk");
+        };
+        assert_eq!(perk.data.playable, 2);
+        let PerkEffectData::EntryPoint(ep) = &perk.effects[0].data else {
+            panic!("expected an entry point");
+        };
+        assert!(ep.data.is_none());
+        assert!(ep.button_label.is_some());
+        assert!(ep.script.is_some());
+    }
+}
@@ -0,0 +1,43 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{parse, race};
+    use espers::records::Record;
+
+    #[test]
+    fn test_race_fields() {
+        let Record::Race(race) = parse(race()) else {
+            panic!("expected a Race");
+        };
+        assert_eq!(race.edid, "RaceTest");
+        assert_eq!(race.spells.len(), 1);
+        assert_eq!(race.keywords[0].0, 0x00000E49);
+        assert_eq!(race.data.skill_boosts[0].skill, 10);
+        assert_eq!(race.data.male_height, 1.0);
+        assert_eq!(race.data.female_height, 0.95);
+        assert_eq!(race.data.starting_health, 50.0);
+        assert!(race.data.mount_data.is_some());
+        assert!(race.male_skeleton.is_some());
+        assert!(race.movement_marker);
+        assert_eq!(race.movement_type_names, ["WALK", "RUN1"]);
+        assert_eq!(race.voices.as_ref().unwrap().1 .0, 0x00000E4B);
+        assert_eq!(race.tint_count, Some(42));
+        assert_eq!(race.male_body_parts.len(), 1);
+        assert_eq!(race.female_body_parts.len(), 1);
+        assert!(race.behavior_marker);
+        assert_eq!(race.biped_object_names, ["Head", "Body"]);
+        assert_eq!(race.movement_types.len(), 1);
+        assert_eq!(race.equip_slots.len(), 2);
+        assert_eq!(race.phoneme_target_names, ["Aah"]);
+        assert_eq!(race.sprint_movement.as_ref().unwrap().0, 0x00000E63);
+
+        let male = race.male_head_data.unwrap();
+        assert_eq!(male.head_parts.len(), 1);
+        assert_eq!(male.race_presets[0].0, 0x00000E42);
+        assert_eq!(male.tint_masks.len(), 1);
+        let female = race.female_head_data.unwrap();
+        assert_eq!(female.race_presets[0].0, 0x00000E42);
+        assert_eq!(race.armor_race.unwrap().0, 0x00000E65);
+    }
+}
@@ -1,7 +1,10 @@
-use binrw::BinWrite;
 use binrw::__private::Required;
+use binrw::BinWrite;
+use flate2::read::ZlibDecoder;
 use glob::glob;
-use std::io::Cursor;
+use std::io::{Cursor, Read};
+
+mod common;
 
 fn serialize<T: BinWrite>(rec: &T) -> Vec<u8>
 where
@@ -13,18 +16,60 @@ where
 }
 
 fn list_dir() -> Vec<String> {
-    glob("assets/skyrim/*.es[mp]")
-        .unwrap()
-        .into_iter()
+    ["tests/fixtures/*.es[mlp]", "../assets/**/*.es[mlp]"]
+        .iter()
+        .flat_map(|g| glob(g).unwrap())
         .map(|f| format!("{}", f.unwrap().display()))
         .collect()
 }
 
+/// Replaces the data of every compressed record with its decompressed form,
+/// recomputing record and group sizes. Plugins written by other tools can use
+/// a different zlib encoder, so only the compressed contents can be compared.
+fn decompress_records(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let mut header = bytes[offset..offset + 24].to_vec();
+
+        let (data, next) = if bytes[offset..offset + 4] == *b"GRUP" {
+            let data = decompress_records(&bytes[offset + 24..offset + size]);
+            header[4..8].copy_from_slice(&(data.len() as u32 + 24).to_le_bytes());
+            (data, offset + size)
+        } else {
+            let flags = u32::from_le_bytes(bytes[offset + 8..offset + 12].try_into().unwrap());
+            let data = &bytes[offset + 24..offset + 24 + size];
+            let data = if flags & 0x00040000 != 0 {
+                let mut decompressed = data[..4].to_vec();
+                ZlibDecoder::new(&data[4..])
+                    .read_to_end(&mut decompressed)
+                    .unwrap();
+                decompressed
+            } else {
+                data.to_vec()
+            };
+            header[4..8].copy_from_slice(&(data.len() as u32).to_le_bytes());
+            (data, offset + 24 + size)
+        };
+
+        out.extend(header);
+        out.extend(data);
+        offset = next;
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{list_dir, serialize};
+    use super::common::{plugin_bytes, record, subrecord};
+    use super::{decompress_records, list_dir, serialize};
     use binrw::BinRead;
-    use espers::records::{Header, TES4};
+    use espers::error::Error;
+    use espers::plugin::Plugin;
+    use espers::records::{Header, Record, TES4};
     use std::fs::File;
     use std::io::{Cursor, Read};
 
@@ -57,4 +102,61 @@ mod tests {
             assert_eq!(raw_record, record);
         }
     }
+
+    #[test]
+    /// Tests that an entire plugin survives a parse and write unchanged
+    ///
+    /// Parse every record in the plugin, write the whole plugin back out and
+    /// assert that the result matches the original file, once compressed
+    /// records are decompressed. The checked-in fixtures were compressed with
+    /// the same encoder as the writer, so they must match byte for byte.
+    pub fn test_plugin_read_write_match() {
+        let files = list_dir();
+        assert!(!files.is_empty());
+
+        for file in files {
+            let mut bytes = Vec::new();
+            File::open(&file).unwrap().read_to_end(&mut bytes).unwrap();
+
+            let plugin = Plugin::parse(&mut Cursor::new(&bytes)).unwrap();
+            let mut written = Cursor::new(Vec::new());
+            plugin.write(&mut written).unwrap();
+            let written = written.into_inner();
+
+            assert!(
+                decompress_records(&bytes) == decompress_records(&written),
+                "{} did not round-trip",
+                file
+            );
+            if file.starts_with("tests/fixtures") {
+                assert!(bytes == written, "{} did not round-trip", file);
+            }
+        }
+    }
+
+    #[test]
+    /// Tests that records which fail to parse are written back unchanged,
+    /// whatever the reason they failed
+    pub fn test_unparsed_records_pass_through() {
+        let owner = subrecord(b"XOWN", &0x00000801u32.to_le_bytes());
+        let mut data = subrecord(b"NAME", &0x00000800u32.to_le_bytes());
+        data.extend(&owner);
+        data.extend(&owner);
+        let bytes = plugin_bytes(0, &[], &[record(b"ACHR", 0, 0x00000802, &data)]);
+
+        let plugin = Plugin::parse(&mut Cursor::new(&bytes)).unwrap();
+        let Ok(Record::Group(group)) = &plugin.records[0] else {
+            panic!("expected a group");
+        };
+        match &group.records[0] {
+            Err(Error::ParseErrorRaw(err, _)) => {
+                assert!(matches!(**err, Error::DuplicateField(_)))
+            }
+            other => panic!("expected a duplicate field error, got {:?}", other),
+        }
+
+        let mut written = Cursor::new(Vec::new());
+        plugin.write(&mut written).unwrap();
+        assert!(bytes == written.into_inner());
+    }
 }
@@ -0,0 +1,53 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{self, parse, FORM_ID};
+    use super::common::{record, subrecord};
+    use espers::records::Record;
+
+    #[test]
+    fn test_round_trip_every_record_type() {
+        for sample in records::all() {
+            parse(sample);
+        }
+    }
+
+    #[test]
+    fn test_refr_subrecord_order() {
+        let linked_ref = |keyword: u32, reference: u32| {
+            let mut data = keyword.to_le_bytes().to_vec();
+            data.extend(reference.to_le_bytes());
+            subrecord(b"XLKR", &data)
+        };
+        let topic = |topic: u32| {
+            let mut data = 0u32.to_le_bytes().to_vec();
+            data.extend(topic.to_le_bytes());
+            subrecord(b"PDTO", &data)
+        };
+        let data = [
+            subrecord(b"NAME", &0x00000D00u32.to_le_bytes()),
+            subrecord(b"XESP", &[0x00, 0x0E, 0, 0, 0x01, 0, 0, 0]),
+            linked_ref(0x00000E01, 0x00000E02),
+            subrecord(b"XSCL", &1.5f32.to_le_bytes()),
+            topic(0x00000E03),
+            subrecord(b"XOWN", &0x00000E04u32.to_le_bytes()),
+            linked_ref(0x00000E05, 0x00000E06),
+            topic(0x00000E07),
+            subrecord(b"DATA", &[0; 24]),
+        ]
+        .concat();
+
+        let Record::ObjectRef(refr) = parse(record(b"REFR", 0, FORM_ID, &data)) else {
+            panic!("expected an ObjectRef");
+        };
+        assert_eq!(refr.linked_refs.len(), 2);
+        assert_eq!(refr.linked_refs[1].reference.0, 0x00000E06);
+        assert_eq!(refr.topic_data.len(), 2);
+        assert_eq!(refr.scale, Some(1.5));
+        assert_eq!(
+            refr.subrecord_order,
+            [b"XESP", b"XLKR", b"XSCL", b"PDTO", b"XOWN", b"XLKR", b"PDTO"].map(|m| *m)
+        );
+    }
+}
@@ -0,0 +1,52 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{parse, FORM_ID};
+    use super::common::{record, subrecord};
+    use espers::records::Record;
+
+    #[test]
+    fn test_edid_only() {
+        let data = subrecord(b"EDID", b"SceneTest\0");
+
+        let Record::Scene(scene) = parse(record(b"SCEN", 0, FORM_ID, &data)) else {
+            panic!("expected a Scene");
+        };
+        assert!(!scene.actions_next);
+    }
+
+    #[test]
+    fn test_timer_and_phase_markers() {
+        let data = [
+            subrecord(b"EDID", b"SceneTest\0"),
+            subrecord(b"HNAM", &[]),
+            subrecord(b"NEXT", &[]),
+            subrecord(b"HNAM", &[]),
+            subrecord(b"ANAM", &2u16.to_le_bytes()),
+            subrecord(b"SNAM", &2.5f32.to_le_bytes()),
+            subrecord(b"ANAM", &[]),
+            subrecord(b"ANAM", &1u16.to_le_bytes()),
+            subrecord(b"SNAM", &1u32.to_le_bytes()),
+            subrecord(b"ENAM", &2u32.to_le_bytes()),
+            subrecord(b"ANAM", &[]),
+            subrecord(b"NEXT", &[]),
+        ]
+        .concat();
+
+        let Record::Scene(scene) = parse(record(b"SCEN", 0, FORM_ID, &data)) else {
+            panic!("expected a Scene");
+        };
+        assert!(!scene.phases[0].completion_next);
+        assert!(scene.actions_next);
+
+        let timer = &scene.actions[0];
+        assert_eq!(timer.timer, Some(2.5));
+        assert_eq!(timer.start_phase, None);
+
+        let package = &scene.actions[1];
+        assert_eq!(package.start_phase, Some(1));
+        assert_eq!(package.end_phase, Some(2));
+        assert_eq!(package.timer, None);
+    }
+}
@@ -0,0 +1,36 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::FORM_ID;
+    use super::common::{plugin, record, subrecord};
+    use espers::records::Record;
+
+    #[test]
+    fn test_invalid_alias_object_format() {
+        // No scripts, then a quest fragment list with one alias using
+        // object format 3, which doesn't exist
+        let mut scripts: Vec<u8> = [5u16, 2, 0].iter().flat_map(|v| v.to_le_bytes()).collect();
+        scripts.push(2);
+        scripts.extend(0u16.to_le_bytes());
+        scripts.extend(0u16.to_le_bytes());
+        scripts.extend(1u16.to_le_bytes());
+        scripts.extend([0u32, FORM_ID].iter().flat_map(|v| v.to_le_bytes()));
+        scripts.extend([5u16, 3, 0].iter().flat_map(|v| v.to_le_bytes()));
+
+        let data = [
+            subrecord(b"EDID", b"QuestTest\0"),
+            subrecord(b"VMAD", &scripts),
+        ]
+        .concat();
+        let plugin = plugin(0, &[], &[record(b"QUST", 0, FORM_ID, &data)]);
+
+        let Ok(Record::Group(group)) = &plugin.records[0] else {
+            panic!("expected a group");
+        };
+        let error = group.records[0].as_ref().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Invalid Object Format Version: 3"));
+    }
+}
@@ -0,0 +1,123 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{musc, must, parse, snct, sndr, sopm, soun};
+    use espers::common::LocalizedString;
+    use espers::records::{MusicTrackType, MusicTypeFlags, Record, SoundCategoryFlags};
+
+    #[test]
+    fn test_sound_fields() {
+        let Record::Sound(sound) = parse(soun()) else {
+            panic!("expected a Sound");
+        };
+        assert_eq!(sound.edid.as_deref(), Some("SoundTest"));
+        assert_eq!(sound.filename.as_deref(), Some("fx\\test.wav"));
+        assert_eq!(sound.unknown.map(|x| x.len()), Some(36));
+        assert_eq!(sound.descriptor.unwrap().0, 0x00000EAB);
+    }
+
+    #[test]
+    fn test_sound_reference_fields() {
+        let Record::SoundReference(sound) = parse(sndr()) else {
+            panic!("expected a SoundReference");
+        };
+        assert_eq!(sound.edid.as_deref(), Some("SoundDescriptorTest"));
+        assert_eq!(sound.kind, Some(0x1EEF540A));
+        assert_eq!(sound.category.unwrap().0, 0x00000EA8);
+        assert_eq!(sound.alternate_sound_for.unwrap().0, 0x00000EA9);
+        assert_eq!(sound.files, ["fx\\test01.wav", "fx\\test02.wav"]);
+        assert_eq!(sound.output_model.unwrap().0, 0x00000EAA);
+        assert_eq!(sound.conditions.len(), 1);
+
+        let looping = sound.loop_and_rumble.unwrap();
+        assert_eq!(looping.looping as u8, 0x08);
+        assert_eq!(looping.rumble_send_value, 20);
+
+        let values = sound.values.unwrap();
+        assert_eq!(values.frequency_shift_pct, -5);
+        assert_eq!(values.frequency_variance_pct, 10);
+        assert_eq!(values.priority, 128);
+        assert_eq!(values.db_variance, 2);
+        assert_eq!(values.static_attenuation, 300);
+    }
+
+    #[test]
+    fn test_sound_category_fields() {
+        let Record::SoundCategory(category) = parse(snct()) else {
+            panic!("expected a SoundCategory");
+        };
+        assert_eq!(category.edid.as_deref(), Some("SoundCategoryTest"));
+        assert!(matches!(
+            category.full_name,
+            Some(LocalizedString::ZString(ref name)) if name == "Test Category"
+        ));
+        assert_eq!(
+            category.flags,
+            Some(SoundCategoryFlags::SHOULD_APPEAR_ON_MENU)
+        );
+        assert_eq!(category.parent.unwrap().0, 0x00000EA7);
+        assert_eq!(category.static_volume_multiplier, Some(32768));
+        assert_eq!(category.default_menu_volume, Some(65535));
+    }
+
+    #[test]
+    fn test_sound_output_model_fields() {
+        let Record::SoundOutputModel(model) = parse(sopm()) else {
+            panic!("expected a SoundOutputModel");
+        };
+        assert_eq!(model.edid.as_deref(), Some("OutputModelTest"));
+        assert_eq!(model.data.unwrap().reverb_send_pct, 25);
+        assert_eq!(model.kind.map(|x| x as u32), Some(1));
+
+        let output = model.output.unwrap();
+        assert_eq!(output.channels[0].front_left, 0);
+        assert_eq!(output.channels[1].front_right, 9);
+        assert_eq!(output.channels[2].back_right, 23);
+
+        let attenuation = model.attenuation.unwrap();
+        assert_eq!(attenuation.min_distance, 100.0);
+        assert_eq!(attenuation.max_distance, 2000.0);
+        assert_eq!(attenuation.curve, [100, 50, 20, 5, 0]);
+    }
+
+    #[test]
+    fn test_music_type_fields() {
+        let Record::MusicType(music) = parse(musc()) else {
+            panic!("expected a MusicType");
+        };
+        assert_eq!(music.edid.as_deref(), Some("MusicTypeTest"));
+        assert_eq!(
+            music.flags,
+            Some(MusicTypeFlags::PLAYS_ONE_SELECTION | MusicTypeFlags::CYCLE_TRACKS)
+        );
+        let data = music.data.unwrap();
+        assert_eq!(data.priority, 50);
+        assert_eq!(data.ducking, 400);
+        assert_eq!(music.fade_duration, Some(2.5));
+        let tracks: Vec<u32> = music.tracks.iter().map(|x| x.0).collect();
+        assert_eq!(tracks, [0x00000DEC, 0x00000DED]);
+    }
+
+    #[test]
+    fn test_music_track_fields() {
+        let Record::MusicTrack(music) = parse(must()) else {
+            panic!("expected a MusicTrack");
+        };
+        assert_eq!(music.edid.as_deref(), Some("MusicTrackTest"));
+        assert_eq!(music.kind, MusicTrackType::SingleTrack);
+        assert_eq!(music.duration, Some(120.0));
+        assert_eq!(music.fade_out, Some(4.0));
+        assert_eq!(music.track.as_deref(), Some("music\\test.xwm"));
+        assert_eq!(music.finale.as_deref(), Some("music\\test_finale.xwm"));
+        assert_eq!(music.cue_points, [0.0, 30.0, 60.0]);
+
+        let loop_data = music.loop_data.unwrap();
+        assert_eq!(loop_data.begins, 10.0);
+        assert_eq!(loop_data.ends, 110.0);
+        assert_eq!(loop_data.count, 2);
+
+        assert_eq!(music.conditions.len(), 1);
+        assert!(music.tracks.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{keyword, plugin, story_node, subrecord, MASTER};
+    use espers::game::Game;
+    use espers::story::{StoryManagerTree, StoryNode};
+    use espers::string_table::StringTables;
+
+    fn event(form_id: u32, event: &[u8; 4]) -> Vec<u8> {
+        story_node(b"SMEN", form_id, None, None, &subrecord(b"ENAM", event))
+    }
+
+    fn quest_node(form_id: u32, parent: u32, previous: Option<u32>, quests: &[u32]) -> Vec<u8> {
+        let quests: Vec<u8> = quests
+            .iter()
+            .flat_map(|q| subrecord(b"NNAM", &q.to_le_bytes()))
+            .collect();
+        story_node(b"SMQN", form_id, Some(parent), previous, &quests)
+    }
+
+    fn ids(nodes: &[StoryNode]) -> Vec<u32> {
+        nodes.iter().map(|n| n.form_id.0).collect()
+    }
+
+    #[test]
+    fn test_sibling_order() {
+        let plugin = plugin(
+            MASTER,
+            &[],
+            &[
+                event(0x800, b"CHRR"),
+                quest_node(0x801, 0x800, Some(0x803), &[]),
+                quest_node(0x802, 0x800, Some(0x801), &[]),
+                quest_node(0x803, 0x800, None, &[]),
+                // Previous sibling is not in the tree
+                quest_node(0x804, 0x800, Some(0x999), &[]),
+            ],
+        );
+        let tree = StoryManagerTree::from_plugin(&plugin);
+
+        assert_eq!(ids(&tree.roots), vec![0x800]);
+        assert_eq!(
+            ids(&tree.roots[0].children),
+            vec![0x803, 0x801, 0x802, 0x804]
+        );
+    }
+
+    #[test]
+    fn test_quests_for_event() {
+        let plugin = plugin(
+            MASTER,
+            &[],
+            &[
+                event(0x800, b"CHRR"),
+                event(0x810, b"ADIA"),
+                quest_node(0x801, 0x800, None, &[0xA01]),
+                story_node(b"SMBN", 0x802, Some(0x800), Some(0x801), &[]),
+                quest_node(0x803, 0x802, None, &[0xA02, 0xA03]),
+                quest_node(0x804, 0x800, Some(0x802), &[0xA04]),
+                quest_node(0x811, 0x810, None, &[0xB01]),
+            ],
+        );
+        let tree = StoryManagerTree::from_plugin(&plugin);
+        let quests = |event: &str| -> Vec<u32> {
+            tree.quests_for_event(event)
+                .into_iter()
+                .map(|q| q.0)
+                .collect()
+        };
+
+        assert_eq!(quests("CHRR"), vec![0xA01, 0xA02, 0xA03, 0xA04]);
+        assert_eq!(quests("ADIA"), vec![0xB01]);
+        assert_eq!(quests("KILL"), Vec::<u32>::new());
+    }
+
+    /// Nodes whose parent links loop are kept, with the loop broken at its
+    /// lowest FormID
+    #[test]
+    fn test_parent_cycle() {
+        let plugin = plugin(
+            MASTER,
+            &[],
+            &[
+                event(0x800, b"CHRR"),
+                story_node(b"SMBN", 0x811, Some(0x810), None, &[]),
+                story_node(b"SMBN", 0x810, Some(0x811), None, &[]),
+                quest_node(0x812, 0x811, None, &[0xA01]),
+                story_node(b"SMBN", 0x820, Some(0x820), None, &[]),
+            ],
+        );
+        let tree = StoryManagerTree::from_plugin(&plugin);
+
+        assert_eq!(ids(&tree.roots), vec![0x800, 0x810, 0x820]);
+        assert_eq!(ids(&tree.roots[1].children), vec![0x811]);
+        assert_eq!(ids(&tree.roots[1].children[0].children), vec![0x812]);
+        assert!(tree.roots[2].children.is_empty());
+    }
+    /// Only story manager nodes are picked up, using the winning version
+    #[test]
+    fn test_from_game() {
+        let master = plugin(
+            MASTER,
+            &[],
+            &[
+                event(0x800, b"CHRR"),
+                quest_node(0x801, 0x800, None, &[0xA01]),
+                keyword(0x900, "NotANode", [0, 0, 0, 0]),
+            ],
+        );
+        let patch = plugin(
+            0,
+            &["Skyrim.esm"],
+            &[quest_node(0x801, 0x800, None, &[0xA02])],
+        );
+        let plugins = vec![
+            ("Skyrim.esm".to_string(), master),
+            ("Patch.esp".to_string(), patch),
+        ];
+        let game = Game::from_load_order(plugins, StringTables::new()).unwrap();
+        let tree = StoryManagerTree::from_game(&game);
+
+        assert_eq!(ids(&tree.roots), vec![0x800]);
+        assert_eq!(ids(&tree.roots[0].children), vec![0x801]);
+        let quests: Vec<u32> = tree
+            .quests_for_event("CHRR")
+            .into_iter()
+            .map(|q| q.0)
+            .collect();
+        assert_eq!(quests, vec![0xA02]);
+    }
+}
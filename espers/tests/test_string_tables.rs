@@ -9,7 +9,6 @@ mod tests {
     pub fn test_read_write_match() {
         let paths = glob("../assets/**/*.es[mp]")
             .unwrap()
-            .into_iter()
             .map(|p| p.unwrap())
             .collect::<Vec<_>>();
 
@@ -17,8 +16,7 @@ mod tests {
             let language = "English";
 
             let mut string_tables = StringTables::new();
-            let loaded =
-                string_tables.load_plugin_path(&path.to_string_lossy().to_string(), language);
+            let loaded = string_tables.load_plugin_path(path.to_string_lossy().as_ref(), language);
 
             if loaded.is_err() {
                 continue;
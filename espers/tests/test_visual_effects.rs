@@ -0,0 +1,136 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{efsh, imgs, lgtm, parse, watr};
+    use espers::common::LocalizedString;
+    use espers::records::Record;
+    use rgb::RGBA8;
+
+    #[test]
+    fn test_image_space_fields() {
+        let Record::ImageSpace(space) = parse(imgs()) else {
+            panic!("expected an ImageSpace");
+        };
+        assert_eq!(space.edid, "ImageSpaceTest");
+        assert_eq!(space.unknown.map(|x| x.len()), Some(16));
+
+        let hdr = space.hdr.unwrap();
+        assert_eq!(hdr.eye_adapt_speed, 0.7);
+        assert_eq!(hdr.bloom_blur_radius, 7.0);
+        assert_eq!(hdr.white, 2.0);
+        assert_eq!(hdr.eye_adapt_strength, 1.0);
+
+        let cinematic = space.cinematic.unwrap();
+        assert_eq!(cinematic.saturation, 1.1);
+        assert_eq!(cinematic.brightness, 0.9);
+        assert_eq!(cinematic.contrast, 1.2);
+
+        let tint = space.tint.unwrap();
+        assert_eq!(tint.amount, 0.25);
+        assert_eq!(tint.green, 0.5);
+
+        let depth_of_field = space.depth_of_field.unwrap();
+        assert_eq!(depth_of_field.strength, 0.5);
+        assert_eq!(depth_of_field.distance, 1000.0);
+        assert_eq!(depth_of_field.range, 200.0);
+        assert_eq!(depth_of_field.sky_blur_radius, Some(3));
+    }
+
+    #[test]
+    fn test_lighting_template_fields() {
+        let Record::LightingTemplate(template) = parse(lgtm()) else {
+            panic!("expected a LightingTemplate");
+        };
+        assert_eq!(template.edid.as_deref(), Some("LightingTemplateTest"));
+
+        let data = template.data.unwrap();
+        assert_eq!(data.ambient, RGBA8::new(10, 20, 30, 0));
+        assert_eq!(data.directional, RGBA8::new(40, 50, 60, 0));
+        assert_eq!(data.fog_near_color, RGBA8::new(70, 80, 90, 0));
+        assert_eq!(data.fog_near, 64.0);
+        assert_eq!(data.fog_far, 4096.0);
+        assert_eq!(data.rotation_xy, 90);
+        assert_eq!(data.rotation_z, -45);
+        assert_eq!(data.fog_clip_distance, 8192.0);
+        assert_eq!(data.fog_far_color, RGBA8::new(100, 110, 120, 0));
+        assert_eq!(data.fog_max, 0.9);
+        assert_eq!(data.light_fade_dist_end, 20000.0);
+        assert!(data.unknown2.is_some());
+
+        let ambient = template.directional_ambient.unwrap();
+        assert_eq!(ambient.x_plus, RGBA8::new(0, 1, 2, 0));
+        assert_eq!(ambient.z_minus, RGBA8::new(50, 51, 52, 0));
+        assert_eq!(ambient.specular, RGBA8::new(60, 61, 62, 0));
+        assert_eq!(ambient.fresnel_power, 1.0);
+    }
+
+    #[test]
+    fn test_water_type_fields() {
+        let Record::WaterType(water) = parse(watr()) else {
+            panic!("expected a WaterType");
+        };
+        assert_eq!(water.edid.as_deref(), Some("WaterTest"));
+        assert!(matches!(
+            water.full_name,
+            Some(LocalizedString::ZString(ref name)) if name == "Test Water"
+        ));
+        assert_eq!(water.noise_maps, ["textures\\water\\noise.dds"]);
+        assert_eq!(water.opacity, Some(75));
+        assert_eq!(water.flags.map(|x| x.bits()), Some(0x01));
+        assert_eq!(water.material.unwrap().0, 0x00000F30);
+        assert_eq!(water.open_sound.unwrap().0, 0x00000F31);
+        assert_eq!(water.spell.unwrap().0, 0x00000F32);
+        assert_eq!(water.image_space.unwrap().0, 0x00000F33);
+        assert_eq!(water.damage_per_second, Some(5));
+
+        let visual = water.visual_data.unwrap();
+        assert_eq!(visual.sun_specular_power, 50.0);
+        assert_eq!(visual.fresnel_amount, 0.25);
+        assert_eq!(visual.fog_far, 4000.0);
+        assert_eq!(visual.shallow_color, RGBA8::new(10, 20, 30, 255));
+        assert_eq!(visual.deep_color, RGBA8::new(5, 10, 15, 255));
+        assert_eq!(visual.reflection_color, RGBA8::new(200, 200, 255, 255));
+        assert_eq!(visual.displacement_starting_size, 4.0);
+        assert_eq!(visual.sun_sparkle_power, 42.0);
+        assert_eq!(visual.flowmap_scale, Some(43.0));
+
+        let linear = water.linear_velocity.unwrap();
+        assert_eq!((linear.x, linear.y, linear.z), (1.0, 2.0, 3.0));
+        assert!(water.angular_velocity.is_some());
+        assert_eq!(
+            water.noise_layer_three.as_deref(),
+            Some("textures\\water\\layer3.dds")
+        );
+        assert_eq!(
+            water.flow_normals.as_deref(),
+            Some("textures\\water\\flow.dds")
+        );
+    }
+
+    #[test]
+    fn test_effect_shader_fields() {
+        let Record::EffectShader(shader) = parse(efsh()) else {
+            panic!("expected an EffectShader");
+        };
+        assert_eq!(shader.edid, "EffectShaderTest");
+        assert_eq!(shader.start_effect, "fill.dds");
+        assert_eq!(shader.looped_effect, "particle.dds");
+        assert_eq!(shader.post_effect, "holes.dds");
+        assert_eq!(shader.looped_gradient.as_deref(), Some("membrane.dds"));
+        assert_eq!(
+            shader.end_gradient.as_deref(),
+            Some("particle_gradient.dds")
+        );
+
+        let data = shader.shader_data;
+        assert_eq!(data.membrane_source_blend_mode, 5);
+        assert_eq!(data.fill_color_key1, RGBA8::new(0x10, 0x20, 0x30, 0));
+        assert_eq!(data.edge_color, RGBA8::new(0xC0, 0xB0, 0xA0, 0));
+        assert_eq!(data.addon_models.0, 0x00000D67);
+        assert_eq!(data.edge_width, 2.5);
+        assert_eq!(data.ambient_sound.0, 0x00000D68);
+        assert_eq!(data.flags.bits(), 0x00000201);
+        assert_eq!(data.scene_graph_emit_depth_limit, 8);
+    }
+}
@@ -0,0 +1,36 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::records::{parse, wthr, FORM_ID};
+    use super::common::{plugin, record, subrecord};
+    use espers::records::Record;
+    use rgb::{RGB8, RGBA8};
+
+    #[test]
+    fn test_colors() {
+        let Record::Weather(weather) = parse(wthr()) else {
+            panic!("expected a Weather");
+        };
+        assert_eq!(weather.cloud_colors[0].day, RGBA8::new(14, 15, 16, 17));
+        assert_eq!(weather.colors[1].night, RGBA8::new(112, 113, 114, 115));
+        let data = weather.data.unwrap();
+        assert_eq!(data.lightning_color, RGB8::new(255, 255, 200));
+        assert_eq!(weather.cloud_textures[1].layer, 1);
+    }
+
+    #[test]
+    fn test_invalid_cloud_layer() {
+        let data = [
+            subrecord(b"EDID", b"WeatherTest\0"),
+            subrecord(b"/0TX", b"sky\\cloud.dds\0"),
+        ]
+        .concat();
+        let plugin = plugin(0, &[], &[record(b"WTHR", 0, FORM_ID, &data)]);
+
+        let Ok(Record::Group(group)) = &plugin.records[0] else {
+            panic!("expected a group");
+        };
+        assert!(group.records[0].is_err());
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use binrw::{BinRead, BinWrite};
+    use espers::fields::{MHDT, OFST};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_oversized_offsets() {
+        let offsets: Vec<u32> = (0..20000).collect();
+        let mut cursor = Cursor::new(Vec::new());
+        OFST::try_from(offsets.clone())
+            .unwrap()
+            .write_le(&mut cursor)
+            .unwrap();
+        let bytes = cursor.into_inner();
+
+        assert_eq!(&bytes[..4], b"XXXX");
+        assert_eq!(&bytes[6..10], &80000u32.to_le_bytes());
+        assert_eq!(&bytes[10..16], b"OFST\0\0");
+
+        let read: Vec<u32> = OFST::read_le(&mut Cursor::new(&bytes))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(read, offsets);
+    }
+
+    #[test]
+    fn test_height_data_sizes() {
+        for len in [16usize, u16::MAX as usize, u16::MAX as usize + 1] {
+            let mut cursor = Cursor::new(Vec::new());
+            MHDT::try_from(vec![7u8; len])
+                .unwrap()
+                .write_le(&mut cursor)
+                .unwrap();
+            let bytes = cursor.into_inner();
+
+            let header = if len > u16::MAX as usize { 16 } else { 6 };
+            assert_eq!(bytes.len(), header + len);
+            assert_eq!(
+                MHDT::read_le(&mut Cursor::new(&bytes)).unwrap().data.len(),
+                len
+            );
+        }
+    }
+}
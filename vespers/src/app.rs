@@ -1,4 +1,4 @@
-use crate::widgets::ToIced;
+use crate::widgets::{ToIced, View};
 use crate::Args;
 use espers::game::Game;
 use espers::plugin::Plugin;
@@ -185,7 +185,7 @@ impl Application for VespersApp {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let plugin = self.get_active_plugin();
 
         let items: Vec<Element<Message>> = match self.display_strings {
@@ -239,7 +239,13 @@ impl Application for VespersApp {
         };
 
         let displayed: Element<Message> = match self.selected() {
-            Some(ref rec) => rec.to_iced(&self.game).into(),
+            Some(rec) => {
+                let view = View {
+                    game: &self.game,
+                    plugin: &self.plugin_names[self.selected_plugin],
+                };
+                rec.to_iced(&view)
+            }
             None => text("Select an item")
                 .width(Length::Fill)
                 .horizontal_alignment(Horizontal::Center)
@@ -261,13 +267,13 @@ impl Application for VespersApp {
 
         let title_row = row![
             button(text("Back")).on_press(Message::Back),
-            text(&self.args.paths.join(", ")).width(Length::Fill),
-            text(&format!(
+            text(self.args.paths.join(", ")).width(Length::Fill),
+            text(format!(
                 "Author: {}",
                 plugin.header.author.as_deref().unwrap_or("<not set>")
             ))
             .width(Length::Fill),
-            text(&format!(
+            text(format!(
                 "Description: {}",
                 plugin.header.description.as_deref().unwrap_or("<not set>")
             ))
@@ -1,6 +1,5 @@
 use crate::app::Message;
-use crate::widgets::ToIced;
-use espers::game::Game;
+use crate::widgets::{ToIced, View};
 use espers::records::{AddonNode, AddonNodeFlags};
 use iced::{
     widget::{column, text, Container},
@@ -8,7 +7,7 @@ use iced::{
 };
 
 impl ToIced for AddonNodeFlags {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(format!("{:?}", self)))
             .width(Length::Fill)
             .padding(10)
@@ -17,22 +16,22 @@ impl ToIced for AddonNodeFlags {
 }
 
 impl ToIced for AddonNode {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         column![
             Container::new(text("EDID").size(30)).padding(10),
-            self.edid.to_iced(game),
+            self.edid.to_iced(view),
             Container::new(text("Object Bounds").size(30)).padding(10),
-            self.bounds.to_iced(game),
+            self.bounds.to_iced(view),
             Container::new(text("Model").size(30)).padding(10),
-            self.model.to_iced(game),
+            self.model.to_iced(view),
             Container::new(text("Addon Node Index").size(30)).padding(10),
-            self.addon_node_index.to_iced(game),
+            self.addon_node_index.to_iced(view),
             Container::new(text("Ambient Sound").size(30)).padding(10),
-            self.ambient_sound.to_iced(game),
+            self.ambient_sound.to_iced(view),
             Container::new(text("Particle System Cap").size(30)).padding(10),
-            self.particle_system_cap.to_iced(game),
+            self.particle_system_cap.to_iced(view),
             Container::new(text("Flags").size(30)).padding(10),
-            self.flags.to_iced(game),
+            self.flags.to_iced(view),
         ]
         .width(Length::Fill)
         .padding(10)
@@ -1,6 +1,5 @@
 use crate::app::Message;
-use crate::widgets::ToIced;
-use espers::game::Game;
+use crate::widgets::{ToIced, View};
 use espers::records::AcousticSpace;
 use iced::{
     widget::{column, text, Container},
@@ -8,18 +7,18 @@ use iced::{
 };
 
 impl ToIced for AcousticSpace {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         column![
             Container::new(text("EDID").size(30)).padding(10),
-            self.edid.to_iced(game),
+            self.edid.to_iced(view),
             Container::new(text("Object Bounds").size(30)).padding(10),
-            self.bounds.to_iced(game),
+            self.bounds.to_iced(view),
             Container::new(text("Ambient").size(30)).padding(10),
-            self.ambient.to_iced(game),
+            self.ambient.to_iced(view),
             Container::new(text("Region Data").size(30)).padding(10),
-            self.region_data.to_iced(game),
+            self.region_data.to_iced(view),
             Container::new(text("Reverb").size(30)).padding(10),
-            self.reverb.to_iced(game),
+            self.reverb.to_iced(view),
         ]
         .width(Length::Fill)
         .padding(10)
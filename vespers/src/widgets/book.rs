@@ -1,6 +1,5 @@
 use crate::app::Message;
-use crate::widgets::ToIced;
-use espers::game::Game;
+use crate::widgets::{ToIced, View};
 use espers::records::{Book, BookData, BookFlags};
 use iced::{
     widget::{column, text, Container},
@@ -8,7 +7,7 @@ use iced::{
 };
 
 impl ToIced for BookFlags {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(format!("{:?}", self)))
             .width(Length::Fill)
             .padding(10)
@@ -17,20 +16,20 @@ impl ToIced for BookFlags {
 }
 
 impl ToIced for BookData {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         column![
             Container::new(text("Flags")).padding(10),
-            self.flags.to_iced(game),
+            self.flags.to_iced(view),
             Container::new(text("Type")).padding(10),
-            self.kind.to_iced(game),
+            self.kind.to_iced(view),
             Container::new(text("Unknown")).padding(10),
-            self.unknown.to_iced(game),
+            self.unknown.to_iced(view),
             Container::new(text("Teaches")).padding(10),
-            self.teaches.to_iced(game),
+            self.teaches.to_iced(view),
             Container::new(text("Value")).padding(10),
-            self.value.to_iced(game),
+            self.value.to_iced(view),
             Container::new(text("Weight")).padding(10),
-            self.weight.to_iced(game)
+            self.weight.to_iced(view)
         ]
         .width(Length::Fill)
         .padding(10)
@@ -39,38 +38,38 @@ impl ToIced for BookData {
 }
 
 impl ToIced for Book {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         column![
             Container::new(text("EDID").size(30)).padding(10),
-            self.edid.to_iced(game),
+            self.edid.to_iced(view),
             Container::new(text("Full Name").size(30)).padding(10),
-            self.full_name.to_iced(game),
+            self.full_name.to_iced(view),
             Container::new(text("Description").size(30)).padding(10),
-            self.description.to_iced(game),
+            self.description.to_iced(view),
             Container::new(text("Text").size(30)).padding(10),
-            self.text.to_iced(game),
+            self.text.to_iced(view),
             Container::new(text("Scripts").size(30)).padding(10),
-            self.scripts.to_iced(game),
+            self.scripts.to_iced(view),
             Container::new(text("Object Bounds").size(30)).padding(10),
-            self.bounds.to_iced(game),
+            self.bounds.to_iced(view),
             Container::new(text("Model").size(30)).padding(10),
-            self.model.to_iced(game),
+            self.model.to_iced(view),
             Container::new(text("Inventory Image").size(30)).padding(10),
-            self.inventory_image.to_iced(game),
+            self.inventory_image.to_iced(view),
             Container::new(text("Message Image").size(30)).padding(10),
-            self.message_image.to_iced(game),
+            self.message_image.to_iced(view),
             Container::new(text("Destruction Data").size(30)).padding(10),
-            self.destruction_data.to_iced(game),
+            self.destruction_data.to_iced(view),
             Container::new(text("Pickup Sound").size(30)).padding(10),
-            self.pickup_sound.to_iced(game),
+            self.pickup_sound.to_iced(view),
             Container::new(text("Drop Sound").size(30)).padding(10),
-            self.drop_sound.to_iced(game),
+            self.drop_sound.to_iced(view),
             Container::new(text("Keywords").size(30)).padding(10),
-            self.keywords.to_iced(game),
+            self.keywords.to_iced(view),
             Container::new(text("Data").size(30)).padding(10),
-            self.data.to_iced(game),
+            self.data.to_iced(view),
             Container::new(text("Inventory Art").size(30)).padding(10),
-            self.inventory_art.to_iced(game),
+            self.inventory_art.to_iced(view),
         ]
         .width(Length::Fill)
         .padding(10)
@@ -1,6 +1,5 @@
 use crate::app::Message;
-use crate::widgets::ToIced;
-use espers::game::Game;
+use crate::widgets::{ToIced, View};
 use espers::records::FormList;
 use iced::{
     widget::{column, text, Container},
@@ -8,12 +7,12 @@ use iced::{
 };
 
 impl ToIced for FormList {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         column![
             Container::new(text("EDID").size(30)).padding(10),
-            self.edid.to_iced(game),
+            self.edid.to_iced(view),
             Container::new(text("Full Name").size(30)).padding(10),
-            self.objects.to_iced(game),
+            self.objects.to_iced(view),
         ]
         .width(Length::Fill)
         .padding(10)
@@ -1,6 +1,5 @@
 use crate::app::Message;
-use crate::widgets::ToIced;
-use espers::game::Game;
+use crate::widgets::{ToIced, View};
 use espers::records::gmst::Value;
 use espers::records::GameSetting;
 use iced::{
@@ -8,19 +7,19 @@ use iced::{
     Element, Length,
 };
 impl ToIced for Value {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         match self {
-            Value::Bool(b) => b.to_iced(game),
-            Value::Int(i) => i.to_iced(game),
-            Value::Float(f) => f.to_iced(game),
-            Value::Str(s) => s.to_iced(game),
-            Value::Unknown(u) => u.to_iced(game),
+            Value::Bool(b) => b.to_iced(view),
+            Value::Int(i) => i.to_iced(view),
+            Value::Float(f) => f.to_iced(view),
+            Value::Str(s) => s.to_iced(view),
+            Value::Unknown(u) => u.to_iced(view),
         }
     }
 }
 
 impl ToIced for GameSetting {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let type_text = match self.value {
             Value::Bool(_) => "Boolean",
             Value::Int(_) => "Integer",
@@ -31,7 +30,7 @@ impl ToIced for GameSetting {
         column![
             row![
                 Container::new(text("EDID")).width(Length::Fill).padding(10),
-                self.edid.to_iced(game)
+                self.edid.to_iced(view)
             ],
             row![
                 Container::new(text("Type")).width(Length::Fill).padding(10),
@@ -43,7 +42,7 @@ impl ToIced for GameSetting {
                 Container::new(text("Value"))
                     .width(Length::Fill)
                     .padding(10),
-                self.value.to_iced(game)
+                self.value.to_iced(view)
             ],
         ]
         .width(Length::Fill)
@@ -1,6 +1,5 @@
 use crate::app::Message;
-use crate::widgets::ToIced;
-use espers::game::Game;
+use crate::widgets::{ToIced, View};
 use espers::records::Keyword;
 use iced::{
     widget::{column, row, text, Container},
@@ -8,17 +7,17 @@ use iced::{
 };
 
 impl ToIced for Keyword {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         column![
             row![
                 Container::new(text("EDID")).width(Length::Fill).padding(10),
-                self.edid.to_iced(game)
+                self.edid.to_iced(view)
             ],
             row![
                 Container::new(text("Color"))
                     .width(Length::Fill)
                     .padding(10),
-                self.color.to_iced(game)
+                self.color.to_iced(view)
             ],
         ]
         .width(Length::Fill)
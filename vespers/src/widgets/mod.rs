@@ -29,12 +29,19 @@ use rgb::RGBA8;
 use ron::ser::to_string_pretty;
 use std::fmt::Debug;
 
+/// What a widget is rendered for. FormIDs stored in a record are indexed by
+/// the masters of the plugin the record comes from.
+pub struct View<'a> {
+    pub game: &'a Game,
+    pub plugin: &'a str,
+}
+
 pub trait ToIced {
-    fn to_iced(&self, _: &Game) -> Element<Message>;
+    fn to_iced(&self, _: &View) -> Element<'_, Message>;
 }
 
 impl ToIced for bool {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(self))
             .width(Length::Fill)
             .padding(10)
@@ -43,7 +50,7 @@ impl ToIced for bool {
 }
 
 impl ToIced for u8 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(self))
             .width(Length::Fill)
             .padding(10)
@@ -52,7 +59,7 @@ impl ToIced for u8 {
 }
 
 impl ToIced for u16 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(self))
             .width(Length::Fill)
             .padding(10)
@@ -61,7 +68,7 @@ impl ToIced for u16 {
 }
 
 impl ToIced for i16 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(self))
             .width(Length::Fill)
             .padding(10)
@@ -70,7 +77,7 @@ impl ToIced for i16 {
 }
 
 impl ToIced for f32 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(self))
             .width(Length::Fill)
             .padding(10)
@@ -79,7 +86,7 @@ impl ToIced for f32 {
 }
 
 impl ToIced for u32 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(format!("0x{:08X}", self)))
             .width(Length::Fill)
             .padding(10)
@@ -88,7 +95,7 @@ impl ToIced for u32 {
 }
 
 impl ToIced for i32 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(format!("0x{:08X}", self)))
             .width(Length::Fill)
             .padding(10)
@@ -97,7 +104,7 @@ impl ToIced for i32 {
 }
 
 impl ToIced for String {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(self))
             .width(Length::Fill)
             .padding(10)
@@ -106,7 +113,7 @@ impl ToIced for String {
 }
 
 impl ToIced for RGBA8 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(
             text("███")
                 .width(Length::Fixed(20.))
@@ -120,8 +127,8 @@ impl ToIced for RGBA8 {
 }
 
 impl<T: ToIced> ToIced for Vec<T> {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
-        Column::with_children(self.iter().map(|x| x.to_iced(game)).collect())
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
+        Column::with_children(self.iter().map(|x| x.to_iced(view)).collect())
             .width(Length::Fill)
             .padding(10)
             .into()
@@ -129,8 +136,8 @@ impl<T: ToIced> ToIced for Vec<T> {
 }
 
 impl<T: ToIced, const N: usize> ToIced for [T; N] {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
-        Column::with_children(self.iter().map(|x| x.to_iced(game)).collect())
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
+        Column::with_children(self.iter().map(|x| x.to_iced(view)).collect())
             .width(Length::Fill)
             .padding(10)
             .into()
@@ -138,9 +145,9 @@ impl<T: ToIced, const N: usize> ToIced for [T; N] {
 }
 
 impl<T: ToIced> ToIced for Option<T> {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         match self {
-            Some(s) => s.to_iced(game),
+            Some(s) => s.to_iced(view),
             None => Container::new(text("<not set>"))
                 .width(Length::Fill)
                 .padding(10)
@@ -154,9 +161,9 @@ where
     T: ToIced,
     E: Debug,
 {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         match self {
-            Ok(s) => s.to_iced(game),
+            Ok(s) => s.to_iced(view),
             Err(err) => Container::new(text(format!("ERR: {:?}", err)))
                 .width(Length::Fill)
                 .padding(10)
@@ -166,7 +173,7 @@ where
 }
 
 impl ToIced for ObjectBounds {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(format!(
             "[{}, {}, {}] -> [{}, {}, {}]",
             self.x1, self.y1, self.z1, self.x2, self.y2, self.z2
@@ -178,10 +185,10 @@ impl ToIced for ObjectBounds {
 }
 
 impl ToIced for LocalizedString {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
-        let st = game.string_tables();
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
+        let st = view.game.string_tables();
         let val = match self {
-            LocalizedString::Localized(l) => match st.get_string(&l) {
+            LocalizedString::Localized(l) => match st.get_string(l) {
                 Some(s) => text(s.clone()),
                 None => text("<not set>"),
             },
@@ -193,7 +200,7 @@ impl ToIced for LocalizedString {
 }
 
 impl ToIced for WString32 {
-    fn to_iced(&self, _: &Game) -> Element<Message> {
+    fn to_iced(&self, _: &View) -> Element<'_, Message> {
         Container::new(text(self.to_string()))
             .width(Length::Fill)
             .padding(10)
@@ -202,8 +209,12 @@ impl ToIced for WString32 {
 }
 
 impl ToIced for FormID {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
-        let x = match game.get_record_by_form_id(self) {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
+        let record = view
+            .game
+            .global_form_id(view.plugin, self)
+            .and_then(|fid| view.game.get_record_by_form_id(&fid));
+        let x = match record {
             Some(Ok(r)) => format!("{}", r),
             Some(Err(err)) => format!("ERR: {:?}", err),
             None => "<not set>".into(),
@@ -216,7 +227,7 @@ impl ToIced for FormID {
 }
 
 impl ToIced for Property {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let mut g = Grid::with_columns(2);
 
         match self {
@@ -226,11 +237,11 @@ impl ToIced for Property {
                 value,
             } => {
                 g.insert(Container::new(text("Name")).padding(10));
-                g.insert(name.to_iced(game));
+                g.insert(name.to_iced(view));
                 g.insert(Container::new(text("Status")).padding(10));
-                g.insert(status.to_iced(game));
+                g.insert(status.to_iced(view));
                 g.insert(Container::new(text("Value")).padding(10));
-                g.insert(value.to_iced(game));
+                g.insert(value.to_iced(view));
             }
             Property::ObjectV1 {
                 name,
@@ -240,15 +251,15 @@ impl ToIced for Property {
                 unused,
             } => {
                 g.insert(Container::new(text("Name")).padding(10));
-                g.insert(name.to_iced(game));
+                g.insert(name.to_iced(view));
                 g.insert(Container::new(text("Status")).padding(10));
-                g.insert(status.to_iced(game));
+                g.insert(status.to_iced(view));
                 g.insert(Container::new(text("Form ID")).padding(10));
-                g.insert(form_id.to_iced(game));
+                g.insert(form_id.to_iced(view));
                 g.insert(Container::new(text("Alias")).padding(10));
-                g.insert(alias.to_iced(game));
+                g.insert(alias.to_iced(view));
                 g.insert(Container::new(text("Unused")).padding(10));
-                g.insert(unused.to_iced(game));
+                g.insert(unused.to_iced(view));
             }
             Property::ObjectV2 {
                 name,
@@ -258,15 +269,15 @@ impl ToIced for Property {
                 form_id,
             } => {
                 g.insert(Container::new(text("Name")).padding(10));
-                g.insert(name.to_iced(game));
+                g.insert(name.to_iced(view));
                 g.insert(Container::new(text("Status")).padding(10));
-                g.insert(status.to_iced(game));
+                g.insert(status.to_iced(view));
                 g.insert(Container::new(text("Unused")).padding(10));
-                g.insert(unused.to_iced(game));
+                g.insert(unused.to_iced(view));
                 g.insert(Container::new(text("Alias")).padding(10));
-                g.insert(alias.to_iced(game));
+                g.insert(alias.to_iced(view));
                 g.insert(Container::new(text("Form ID")).padding(10));
-                g.insert(form_id.to_iced(game));
+                g.insert(form_id.to_iced(view));
             }
             Property::String {
                 name,
@@ -274,11 +285,11 @@ impl ToIced for Property {
                 value,
             } => {
                 g.insert(Container::new(text("Name")).padding(10));
-                g.insert(name.to_iced(game));
+                g.insert(name.to_iced(view));
                 g.insert(Container::new(text("Status")).padding(10));
-                g.insert(status.to_iced(game));
+                g.insert(status.to_iced(view));
                 g.insert(Container::new(text("Value")).padding(10));
-                g.insert(value.to_iced(game));
+                g.insert(value.to_iced(view));
             }
             Property::Int {
                 name,
@@ -286,11 +297,11 @@ impl ToIced for Property {
                 value,
             } => {
                 g.insert(Container::new(text("Name")).padding(10));
-                g.insert(name.to_iced(game));
+                g.insert(name.to_iced(view));
                 g.insert(Container::new(text("Status")).padding(10));
-                g.insert(status.to_iced(game));
+                g.insert(status.to_iced(view));
                 g.insert(Container::new(text("Value")).padding(10));
-                g.insert(value.to_iced(game));
+                g.insert(value.to_iced(view));
             }
             Property::Float {
                 name,
@@ -298,11 +309,11 @@ impl ToIced for Property {
                 value,
             } => {
                 g.insert(Container::new(text("Name")).padding(10));
-                g.insert(name.to_iced(game));
+                g.insert(name.to_iced(view));
                 g.insert(Container::new(text("Status")).padding(10));
-                g.insert(status.to_iced(game));
+                g.insert(status.to_iced(view));
                 g.insert(Container::new(text("Value")).padding(10));
-                g.insert(value.to_iced(game));
+                g.insert(value.to_iced(view));
             }
             other => g.insert(
                 Container::new(text(to_string_pretty(&other, Default::default()).unwrap()))
@@ -315,202 +326,202 @@ impl ToIced for Property {
 }
 
 impl ToIced for Script {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Name")).padding(10))
-            .push(self.name.to_iced(game))
+            .push(self.name.to_iced(view))
             .push(Container::new(text("Status")).padding(10))
-            .push(self.status.to_iced(game))
+            .push(self.status.to_iced(view))
             .push(Container::new(text("Properties")).padding(10))
-            .push(self.properties.to_iced(game));
+            .push(self.properties.to_iced(view));
 
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for ScriptList {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Version")).padding(10))
-            .push(self.version.to_iced(game))
+            .push(self.version.to_iced(view))
             .push(Container::new(text("Object Format")).padding(10))
-            .push(self.object_format.to_iced(game))
+            .push(self.object_format.to_iced(view))
             .push(Container::new(text("Scripts")).padding(10))
-            .push(self.scripts.to_iced(game));
+            .push(self.scripts.to_iced(view));
 
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 impl ToIced for Unknown4 {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Unknown 1")).padding(10))
-            .push(self.unknown1.to_iced(game))
+            .push(self.unknown1.to_iced(view))
             .push(Container::new(text("Unknown 2")).padding(10))
-            .push(self.unknown2.to_iced(game))
+            .push(self.unknown2.to_iced(view))
             .push(Container::new(text("Unknown 3")).padding(10))
-            .push(self.unknown3.to_iced(game));
+            .push(self.unknown3.to_iced(view));
 
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for ModelTextures {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Count")).padding(10))
-            .push(self.count.to_iced(game))
+            .push(self.count.to_iced(view))
             .push(Container::new(text("Unknown4 Count")).padding(10))
-            .push(self.unknown4_count.to_iced(game))
+            .push(self.unknown4_count.to_iced(view))
             .push(Container::new(text("Unknown2")).padding(10))
-            .push(self.unknown5.to_iced(game))
+            .push(self.unknown5.to_iced(view))
             .push(Container::new(text("Unknown4s")).padding(10))
-            .push(self.unknown4s.to_iced(game));
+            .push(self.unknown4s.to_iced(view));
 
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for Textures {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         match self {
-            Textures::Header(x) => x.to_iced(game),
-            Textures::NoHeader(x) => x.to_iced(game),
+            Textures::Header(x) => x.to_iced(view),
+            Textures::NoHeader(x) => x.to_iced(view),
         }
     }
 }
 
 impl ToIced for AlternateTexture {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Object Name")).padding(10))
-            .push(self.object_name.to_iced(game))
+            .push(self.object_name.to_iced(view))
             .push(Container::new(text("Texture Set")).padding(10))
-            .push(self.texture_set.to_iced(game))
+            .push(self.texture_set.to_iced(view))
             .push(Container::new(text("3D Index")).padding(10))
-            .push(self.threed_index.to_iced(game));
+            .push(self.threed_index.to_iced(view));
 
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for AlternateTextures {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Count")).padding(10))
-            .push(self.count.to_iced(game))
+            .push(self.count.to_iced(view))
             .push(Container::new(text("Textures")).padding(10))
-            .push(self.textures.to_iced(game));
+            .push(self.textures.to_iced(view));
 
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for Model {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Model")).padding(10))
-            .push(self.model.to_iced(game))
+            .push(self.model.to_iced(view))
             .push(Container::new(text("Textures")).padding(10))
-            .push(self.textures.to_iced(game))
+            .push(self.textures.to_iced(view))
             .push(Container::new(text("Alternate Textures")).padding(10))
-            .push(self.alternate_textures.to_iced(game));
+            .push(self.alternate_textures.to_iced(view));
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for DestructionTexture {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Name")).padding(10))
-            .push(self.name.to_iced(game))
+            .push(self.name.to_iced(view))
             .push(Container::new(text("Texture ID")).padding(10))
-            .push(self.texture_id.to_iced(game))
+            .push(self.texture_id.to_iced(view))
             .push(Container::new(text("Unknown 1")).padding(10))
-            .push(self.unknown1.to_iced(game));
+            .push(self.unknown1.to_iced(view));
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for DestructionTextures {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Count")).padding(10))
-            .push(self.count.to_iced(game))
+            .push(self.count.to_iced(view))
             .push(Container::new(text("Textures")).padding(10))
-            .push(self.textures.to_iced(game));
+            .push(self.textures.to_iced(view));
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for StageDataHeader {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Health Percent")).padding(10))
-            .push(self.health_percent.to_iced(game))
+            .push(self.health_percent.to_iced(view))
             .push(Container::new(text("Index")).padding(10))
-            .push(self.index.to_iced(game))
+            .push(self.index.to_iced(view))
             .push(Container::new(text("Damage Stage")).padding(10))
-            .push(self.damage_stage.to_iced(game))
+            .push(self.damage_stage.to_iced(view))
             .push(Container::new(text("Flags")).padding(10))
-            .push(self.flags.to_iced(game))
+            .push(self.flags.to_iced(view))
             .push(Container::new(text("Self Damage Rate")).padding(10))
-            .push(self.self_damage_rate.to_iced(game))
+            .push(self.self_damage_rate.to_iced(view))
             .push(Container::new(text("Explosion ID")).padding(10))
-            .push(self.explosion_id.to_iced(game))
+            .push(self.explosion_id.to_iced(view))
             .push(Container::new(text("Debris ID")).padding(10))
-            .push(self.debris_id.to_iced(game))
+            .push(self.debris_id.to_iced(view))
             .push(Container::new(text("Debris Count")).padding(10))
-            .push(self.debris_count.to_iced(game));
+            .push(self.debris_count.to_iced(view));
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for StageData {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Header")).padding(10))
-            .push(self.header.to_iced(game))
+            .push(self.header.to_iced(view))
             .push(Container::new(text("Replacement Model")).padding(10))
-            .push(self.replacement_model.to_iced(game))
+            .push(self.replacement_model.to_iced(view))
             .push(Container::new(text("Unknown 1")).padding(10))
-            .push(self.unknown1.to_iced(game))
+            .push(self.unknown1.to_iced(view))
             .push(Container::new(text("Textures")).padding(10))
-            .push(self.destruction_textures.to_iced(game));
+            .push(self.destruction_textures.to_iced(view));
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for DestructionDataHeader {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Health")).padding(10))
-            .push(self.health.to_iced(game))
+            .push(self.health.to_iced(view))
             .push(Container::new(text("Count")).padding(10))
-            .push(self.count.to_iced(game))
+            .push(self.count.to_iced(view))
             .push(Container::new(text("Flag")).padding(10))
-            .push(self.flag.to_iced(game))
+            .push(self.flag.to_iced(view))
             .push(Container::new(text("Unknown 1")).padding(10))
-            .push(self.unknown1.to_iced(game))
+            .push(self.unknown1.to_iced(view))
             .push(Container::new(text("Unknown 2")).padding(10))
-            .push(self.unknown2.to_iced(game));
+            .push(self.unknown2.to_iced(view));
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for DestructionData {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         let g = Grid::with_columns(2)
             .push(Container::new(text("Data")).padding(10))
-            .push(self.data.to_iced(game))
+            .push(self.data.to_iced(view))
             .push(Container::new(text("Stage Data")).padding(10))
-            .push(self.stage_data.to_iced(game));
+            .push(self.stage_data.to_iced(view));
         Container::new(g).width(Length::Fill).padding(10).into()
     }
 }
 
 impl ToIced for Record {
-    fn to_iced(&self, game: &Game) -> Element<Message> {
+    fn to_iced(&self, view: &View) -> Element<'_, Message> {
         match self {
             Record::Group(g) => text(format!(
                 "Group - {} items ({})",
@@ -520,13 +531,13 @@ impl ToIced for Record {
             .width(Length::Fill)
             .horizontal_alignment(Horizontal::Center)
             .into(),
-            Record::Book(x) => x.to_iced(game),
-            Record::AddonNode(x) => x.to_iced(game),
-            Record::AcousticSpace(x) => x.to_iced(game),
-            Record::Outfit(x) => x.to_iced(game),
-            Record::FormList(x) => x.to_iced(game),
-            Record::GameSetting(x) => x.to_iced(game),
-            Record::Keyword(x) => x.to_iced(game),
+            Record::Book(x) => x.to_iced(view),
+            Record::AddonNode(x) => x.to_iced(view),
+            Record::AcousticSpace(x) => x.to_iced(view),
+            Record::Outfit(x) => x.to_iced(view),
+            Record::FormList(x) => x.to_iced(view),
+            Record::GameSetting(x) => x.to_iced(view),
+            Record::Keyword(x) => x.to_iced(view),
             rec => text(format!("{:?}", rec)).into(),
         }
     }